@@ -4,6 +4,7 @@ use crate::app_dir::{
 };
 use crate::backend_task::contested_names::ContestedResourceTask;
 use crate::backend_task::core::CoreItem;
+use crate::backend_task::system_task::SystemTask;
 use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::components::core_zmq_listener::{CoreZMQListener, ZMQMessage};
 use crate::context::AppContext;
@@ -17,8 +18,10 @@ use crate::ui::identities::identities_screen::IdentitiesScreen;
 use crate::ui::network_chooser_screen::NetworkChooserScreen;
 use crate::ui::theme::ThemeMode;
 use crate::ui::tokens::tokens_screen::{TokensScreen, TokensSubscreen};
+use crate::ui::tools::activity_log_screen::ActivityLogScreen;
 use crate::ui::tools::contract_visualizer_screen::ContractVisualizerScreen;
 use crate::ui::tools::document_visualizer_screen::DocumentVisualizerScreen;
+use crate::ui::tools::log_viewer_screen::LogViewerScreen;
 use crate::ui::tools::platform_info_screen::PlatformInfoScreen;
 use crate::ui::tools::proof_log_screen::ProofLogScreen;
 use crate::ui::tools::proof_visualizer_screen::ProofVisualizerScreen;
@@ -39,6 +42,9 @@ use std::time::{Duration, Instant, SystemTime};
 use std::vec;
 use tokio::sync::mpsc as tokiompsc;
 
+/// How often the DAPI endpoints of the current network are pinged for the top panel indicator
+const PLATFORM_CONNECTION_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
 #[derive(Debug, From)]
 pub enum TaskResult {
     Refresh,
@@ -77,7 +83,9 @@ pub struct AppState {
     pub task_result_receiver: tokiompsc::Receiver<TaskResult>, // Channel receiver for receiving task results
     pub theme_preference: ThemeMode,                           // Current theme preference
     last_scheduled_vote_check: Instant, // Last time we checked if there are scheduled masternode votes to cast
-    pub subtasks: Arc<TaskManager>,     // Subtasks manager for graceful shutdown
+    last_user_activity: Instant, // Last input from the user, for locking wallets after inactivity
+    last_platform_connection_check: Option<(Network, Instant)>, // Network and time of the last DAPI health check
+    pub subtasks: Arc<TaskManager>, // Subtasks manager for graceful shutdown
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -113,6 +121,11 @@ impl DesiredAppAction {
 #[derive(Debug, Clone, PartialEq)]
 pub enum BackendTasksExecutionMode {
     Sequential,
+    /// One after another, skipping the remaining tasks once one fails
+    SequentialFailFast,
+    /// One after another, running every task even when some fail and reporting how many
+    /// succeeded instead of each error on its own
+    SequentialBestEffort,
     Concurrent,
 }
 
@@ -211,6 +224,8 @@ impl AppState {
             DPNSScreen::new(&mainnet_app_context, DPNSSubscreen::Owned);
         let mut dpns_scheduled_votes_screen =
             DPNSScreen::new(&mainnet_app_context, DPNSSubscreen::ScheduledVotes);
+        let mut dpns_watchlist_screen =
+            DPNSScreen::new(&mainnet_app_context, DPNSSubscreen::Watchlist);
         let mut transition_visualizer_screen =
             TransitionVisualizerScreen::new(&mainnet_app_context);
         let mut proof_visualizer_screen = ProofVisualizerScreen::new(&mainnet_app_context);
@@ -218,6 +233,8 @@ impl AppState {
         let mut contract_visualizer_screen = ContractVisualizerScreen::new(&mainnet_app_context);
         let mut proof_log_screen = ProofLogScreen::new(&mainnet_app_context);
         let mut platform_info_screen = PlatformInfoScreen::new(&mainnet_app_context);
+        let mut log_viewer_screen = LogViewerScreen::new(&mainnet_app_context);
+        let mut activity_log_screen = ActivityLogScreen::new(&mainnet_app_context);
         let mut document_query_screen = DocumentQueryScreen::new(&mainnet_app_context);
         let mut tokens_balances_screen =
             TokensScreen::new(&mainnet_app_context, TokensSubscreen::MyTokens);
@@ -270,6 +287,8 @@ impl AppState {
                     DPNSScreen::new(testnet_app_context, DPNSSubscreen::Owned);
                 dpns_scheduled_votes_screen =
                     DPNSScreen::new(testnet_app_context, DPNSSubscreen::ScheduledVotes);
+                dpns_watchlist_screen =
+                    DPNSScreen::new(testnet_app_context, DPNSSubscreen::Watchlist);
                 transition_visualizer_screen = TransitionVisualizerScreen::new(testnet_app_context);
                 proof_visualizer_screen = ProofVisualizerScreen::new(testnet_app_context);
                 document_visualizer_screen = DocumentVisualizerScreen::new(testnet_app_context);
@@ -278,6 +297,8 @@ impl AppState {
                 wallets_balances_screen = WalletsBalancesScreen::new(testnet_app_context);
                 proof_log_screen = ProofLogScreen::new(testnet_app_context);
                 platform_info_screen = PlatformInfoScreen::new(testnet_app_context);
+                log_viewer_screen = LogViewerScreen::new(testnet_app_context);
+                activity_log_screen = ActivityLogScreen::new(testnet_app_context);
                 tokens_balances_screen =
                     TokensScreen::new(testnet_app_context, TokensSubscreen::MyTokens);
                 token_search_screen =
@@ -295,6 +316,8 @@ impl AppState {
                     DPNSScreen::new(devnet_app_context, DPNSSubscreen::Owned);
                 dpns_scheduled_votes_screen =
                     DPNSScreen::new(devnet_app_context, DPNSSubscreen::ScheduledVotes);
+                dpns_watchlist_screen =
+                    DPNSScreen::new(devnet_app_context, DPNSSubscreen::Watchlist);
                 transition_visualizer_screen = TransitionVisualizerScreen::new(devnet_app_context);
                 proof_visualizer_screen = ProofVisualizerScreen::new(devnet_app_context);
                 document_visualizer_screen = DocumentVisualizerScreen::new(devnet_app_context);
@@ -303,6 +326,8 @@ impl AppState {
                 wallets_balances_screen = WalletsBalancesScreen::new(devnet_app_context);
                 proof_log_screen = ProofLogScreen::new(devnet_app_context);
                 platform_info_screen = PlatformInfoScreen::new(devnet_app_context);
+                log_viewer_screen = LogViewerScreen::new(devnet_app_context);
+                activity_log_screen = ActivityLogScreen::new(devnet_app_context);
                 tokens_balances_screen =
                     TokensScreen::new(devnet_app_context, TokensSubscreen::MyTokens);
                 token_search_screen =
@@ -318,6 +343,8 @@ impl AppState {
                 dpns_my_usernames_screen = DPNSScreen::new(local_app_context, DPNSSubscreen::Owned);
                 dpns_scheduled_votes_screen =
                     DPNSScreen::new(local_app_context, DPNSSubscreen::ScheduledVotes);
+                dpns_watchlist_screen =
+                    DPNSScreen::new(local_app_context, DPNSSubscreen::Watchlist);
                 transition_visualizer_screen = TransitionVisualizerScreen::new(local_app_context);
                 proof_visualizer_screen = ProofVisualizerScreen::new(local_app_context);
                 document_visualizer_screen = DocumentVisualizerScreen::new(local_app_context);
@@ -326,6 +353,8 @@ impl AppState {
                 wallets_balances_screen = WalletsBalancesScreen::new(local_app_context);
                 proof_log_screen = ProofLogScreen::new(local_app_context);
                 platform_info_screen = PlatformInfoScreen::new(local_app_context);
+                log_viewer_screen = LogViewerScreen::new(local_app_context);
+                activity_log_screen = ActivityLogScreen::new(local_app_context);
                 tokens_balances_screen =
                     TokensScreen::new(local_app_context, TokensSubscreen::MyTokens);
                 token_search_screen =
@@ -409,6 +438,10 @@ impl AppState {
                     RootScreenType::RootScreenDPNSScheduledVotes,
                     Screen::DPNSScreen(dpns_scheduled_votes_screen),
                 ),
+                (
+                    RootScreenType::RootScreenDPNSWatchlist,
+                    Screen::DPNSScreen(dpns_watchlist_screen),
+                ),
                 (
                     RootScreenType::RootScreenWalletsBalances,
                     Screen::WalletsBalancesScreen(wallets_balances_screen),
@@ -437,6 +470,14 @@ impl AppState {
                     RootScreenType::RootScreenToolsPlatformInfoScreen,
                     Screen::PlatformInfoScreen(platform_info_screen),
                 ),
+                (
+                    RootScreenType::RootScreenToolsLogViewerScreen,
+                    Screen::LogViewerScreen(log_viewer_screen),
+                ),
+                (
+                    RootScreenType::RootScreenToolsActivityLogScreen,
+                    Screen::ActivityLogScreen(activity_log_screen),
+                ),
                 (
                     RootScreenType::RootScreenDocumentQuery,
                     Screen::DocumentQueryScreen(document_query_screen),
@@ -475,6 +516,8 @@ impl AppState {
             task_result_receiver,
             theme_preference,
             last_scheduled_vote_check: Instant::now(),
+            last_user_activity: Instant::now(),
+            last_platform_connection_check: None,
             subtasks,
         }
     }
@@ -507,6 +550,87 @@ impl AppState {
         }
     }
 
+    /// Locks the unlocked wallets of every network once the user has been idle for longer than
+    /// the configured auto-lock timeout, so screens prompt for the password again.
+    fn lock_wallets_if_idle(&mut self, ctx: &egui::Context) {
+        let active = ctx.input(|i| !i.events.is_empty() || i.pointer.is_moving());
+        if active {
+            self.last_user_activity = Instant::now();
+        }
+
+        let minutes = self.current_app_context().wallet_auto_lock_minutes();
+        if minutes == 0 {
+            return;
+        }
+        let timeout = Duration::from_secs(u64::from(minutes) * 60);
+        let idle = self.last_user_activity.elapsed();
+        if idle < timeout {
+            // Wake up in time to lock even if nothing else repaints
+            ctx.request_repaint_after(timeout - idle);
+            return;
+        }
+
+        let contexts = [
+            Some(&self.mainnet_app_context),
+            self.testnet_app_context.as_ref(),
+            self.devnet_app_context.as_ref(),
+            self.local_app_context.as_ref(),
+        ];
+        let locked: usize = contexts
+            .into_iter()
+            .flatten()
+            .map(|context| context.lock_wallets())
+            .sum();
+        if locked > 0 {
+            tracing::info!(
+                "Locked {} wallet(s) after {} minutes of inactivity",
+                locked,
+                minutes
+            );
+        }
+    }
+
+    /// Clears the secrets copied from any network's screens once they are due, even after
+    /// the screen they were copied from was left.
+    fn clear_clipboard_if_due(&self, ctx: &egui::Context) {
+        let contexts = [
+            Some(&self.mainnet_app_context),
+            self.testnet_app_context.as_ref(),
+            self.devnet_app_context.as_ref(),
+            self.local_app_context.as_ref(),
+        ];
+        if let Some(due_in) = contexts
+            .into_iter()
+            .flatten()
+            .filter_map(|context| context.clear_clipboard_if_due())
+            .min()
+        {
+            ctx.request_repaint_after(due_in);
+        }
+    }
+
+    /// Pings the DAPI endpoints of the current network every
+    /// [`PLATFORM_CONNECTION_CHECK_INTERVAL`], and right away after switching networks.
+    fn check_platform_connection_periodically(&mut self, ctx: &egui::Context) {
+        let network = self.chosen_network;
+        let due = match self.last_platform_connection_check {
+            Some((checked_network, checked_at)) if checked_network == network => {
+                let elapsed = checked_at.elapsed();
+                if elapsed < PLATFORM_CONNECTION_CHECK_INTERVAL {
+                    ctx.request_repaint_after(PLATFORM_CONNECTION_CHECK_INTERVAL - elapsed);
+                    false
+                } else {
+                    true
+                }
+            }
+            _ => true,
+        };
+        if due {
+            self.last_platform_connection_check = Some((network, Instant::now()));
+            self.handle_backend_task(BackendTask::SystemTask(SystemTask::CheckPlatformConnection));
+        }
+    }
+
     // Handle the backend task and send the result through the channel
     fn handle_backend_task(&self, task: BackendTask) {
         let sender = self.task_result_sender.clone();
@@ -529,10 +653,27 @@ impl AppState {
         tokio::spawn(async move {
             let results = match mode {
                 BackendTasksExecutionMode::Sequential => {
+                    // Results are sent one by one as the tasks finish
                     app_context
                         .run_backend_tasks_sequential(tasks, sender.clone())
+                        .await;
+                    Vec::new()
+                }
+                BackendTasksExecutionMode::SequentialFailFast => {
+                    app_context
+                        .run_backend_tasks_fail_fast(tasks, sender.clone())
                         .await
                 }
+                BackendTasksExecutionMode::SequentialBestEffort => {
+                    let (successes, summary) = app_context
+                        .run_backend_tasks_best_effort(tasks, sender.clone())
+                        .await;
+                    let mut results: Vec<_> = successes.into_iter().map(Ok).collect();
+                    if summary.failed() > 0 {
+                        results.push(Err(summary.message()));
+                    }
+                    results
+                }
                 BackendTasksExecutionMode::Concurrent => {
                     app_context
                         .run_backend_tasks_concurrent(tasks, sender.clone())
@@ -612,6 +753,10 @@ impl App for AppState {
         // Apply Dash theme with user preference
         crate::ui::theme::apply_theme(ctx, self.theme_preference);
 
+        self.lock_wallets_if_idle(ctx);
+        self.clear_clipboard_if_due(ctx);
+        self.check_platform_connection_periodically(ctx);
+
         if let Ok(event) = self.current_app_context().rx_zmq_status.try_recv() {
             if let Ok(mut status) = self.current_app_context().zmq_connection_status.lock() {
                 *status = event;
@@ -738,6 +883,14 @@ impl App for AppState {
                 }
             };
 
+            let contest_end_times = match app_context.contest_end_times() {
+                Ok(end_times) => end_times,
+                Err(e) => {
+                    eprintln!("Error querying contest end times: {}", e);
+                    return;
+                }
+            };
+
             // Filter due votes
             let current_time = SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -749,6 +902,7 @@ impl App for AppState {
                     v.unix_timestamp <= current_time
                         && !v.executed_successfully
                         && (v.unix_timestamp + 120000 >= current_time) // Don't cast votes more than 2 minutes behind current time
+                        && !v.contest_has_ended(&contest_end_times, current_time) // Votes can't be cast once the contest is over
                 })
                 .collect();
 
@@ -0,0 +1,596 @@
+use crate::backend_task::BackendTask;
+use crate::backend_task::contested_names::ContestedResourceTask;
+use crate::backend_task::contract::ContractTask;
+use crate::backend_task::document::DocumentTask;
+use crate::backend_task::identity::{
+    IdentityTask, RegisterIdentityFundingMethod, TopUpIdentityFundingMethod,
+};
+use crate::backend_task::tokens::TokenTask;
+use crate::model::qualified_identity::QualifiedIdentity;
+use dash_sdk::dpp::data_contract::TokenContractPosition;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::group::GroupStateTransitionInfoStatus;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::DataContract;
+use dash_sdk::platform::Identifier;
+
+/// What a task that broadcasts state transitions submits, as recorded in the activity log.
+/// Parameters never hold key material.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskActivity {
+    pub action: &'static str,
+    /// Identity signing the transitions, when a single one does
+    pub identity_id: Option<Identifier>,
+    /// Token, contract, document, contested name or identity the action targets
+    pub target: Option<String>,
+    pub parameters: String,
+}
+
+impl TaskActivity {
+    fn new(action: &'static str, identity: Option<&QualifiedIdentity>) -> Self {
+        Self {
+            action,
+            identity_id: identity.map(|identity| identity.identity.id()),
+            target: None,
+            parameters: String::new(),
+        }
+    }
+
+    fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    fn parameters(mut self, parameters: impl Into<String>) -> Self {
+        self.parameters = parameters.into();
+        self
+    }
+}
+
+fn token_target(data_contract: &DataContract, token_position: TokenContractPosition) -> String {
+    data_contract
+        .token_id(token_position)
+        .map(|token_id| token_id.to_string(Encoding::Base58))
+        .unwrap_or_else(|| {
+            format!(
+                "{} token #{}",
+                data_contract.id().to_string(Encoding::Base58),
+                token_position
+            )
+        })
+}
+
+/// Parameters of a token action: its own, then the public note and group action, if any
+fn token_parameters(
+    parameters: String,
+    public_note: &Option<String>,
+    group_info: &Option<GroupStateTransitionInfoStatus>,
+) -> String {
+    let mut parts = Vec::new();
+    if !parameters.is_empty() {
+        parts.push(parameters);
+    }
+    if let Some(note) = public_note {
+        parts.push(format!("note: {}", note));
+    }
+    if group_info.is_some() {
+        parts.push("as a group action".to_string());
+    }
+    parts.join(", ")
+}
+
+impl BackendTask {
+    /// What the task submits, to record in the activity log. `None` for tasks that don't
+    /// broadcast a state transition.
+    pub fn activity(&self) -> Option<TaskActivity> {
+        match self {
+            BackendTask::IdentityTask(task) => identity_task_activity(task),
+            BackendTask::DocumentTask(task) => document_task_activity(task),
+            BackendTask::ContractTask(task) => match task.as_ref() {
+                ContractTask::RegisterDataContract(contract, alias, identity, _) => Some(
+                    TaskActivity::new("Register Contract", Some(identity))
+                        .target(contract.id().to_string(Encoding::Base58))
+                        .parameters(format!("alias: {}", alias)),
+                ),
+                ContractTask::UpdateDataContract(contract, identity, _) => Some(
+                    TaskActivity::new("Update Contract", Some(identity))
+                        .target(contract.id().to_string(Encoding::Base58))
+                        .parameters(format!("version: {}", contract.version())),
+                ),
+                _ => None,
+            },
+            BackendTask::ContestedResourceTask(task) => contested_resource_task_activity(task),
+            BackendTask::TokenTask(task) => token_task_activity(task),
+            BackendTask::BroadcastStateTransition(_) => {
+                Some(TaskActivity::new("Broadcast State Transition", None))
+            }
+            BackendTask::CoreTask(_)
+            | BackendTask::SystemTask(_)
+            | BackendTask::PlatformInfo(_)
+            | BackendTask::None => None,
+        }
+    }
+}
+
+fn identity_task_activity(task: &IdentityTask) -> Option<TaskActivity> {
+    match task {
+        IdentityTask::RegisterIdentity(info) => {
+            let funding = match &info.identity_funding_method {
+                RegisterIdentityFundingMethod::UseAssetLock(..) => {
+                    "funded with an asset lock".to_string()
+                }
+                RegisterIdentityFundingMethod::FundWithUtxo(_, tx_out, ..) => {
+                    format!("funded with a UTXO of {} duffs", tx_out.value)
+                }
+                RegisterIdentityFundingMethod::FundWithWallet(duffs, _) => {
+                    format!("funded with {} duffs from the wallet", duffs)
+                }
+            };
+            Some(
+                TaskActivity::new("Register Identity", None).parameters(format!(
+                    "alias: {}, wallet identity index: {}, {}",
+                    info.alias_input, info.wallet_identity_index, funding
+                )),
+            )
+        }
+        IdentityTask::TopUpIdentity(info) => {
+            let funding = match &info.identity_funding_method {
+                TopUpIdentityFundingMethod::UseAssetLock(..) => {
+                    "funded with an asset lock".to_string()
+                }
+                TopUpIdentityFundingMethod::FundWithUtxo(_, tx_out, ..) => {
+                    format!("funded with a UTXO of {} duffs", tx_out.value)
+                }
+                TopUpIdentityFundingMethod::FundWithWallet(duffs, ..) => {
+                    format!("funded with {} duffs from the wallet", duffs)
+                }
+            };
+            Some(
+                TaskActivity::new("Top Up Identity", Some(&info.qualified_identity))
+                    .parameters(funding),
+            )
+        }
+        IdentityTask::AddKeyToIdentity(identity, public_key, _) => {
+            let key = &public_key.identity_public_key;
+            Some(
+                TaskActivity::new("Add Key", Some(identity)).parameters(format!(
+                    "key {}: {:?}, {:?}, {}",
+                    key.id(),
+                    key.key_type(),
+                    key.purpose(),
+                    key.security_level()
+                )),
+            )
+        }
+        IdentityTask::WithdrawFromIdentity(identity, address, credits, _) => {
+            let activity = TaskActivity::new("Withdraw Credits", Some(identity))
+                .parameters(format!("amount: {} credits", credits));
+            Some(match address {
+                Some(address) => activity.target(address.to_string()),
+                None => activity,
+            })
+        }
+        IdentityTask::Transfer(identity, recipient, credits, _) => Some(
+            TaskActivity::new("Transfer Credits", Some(identity))
+                .target(recipient.to_string(Encoding::Base58))
+                .parameters(format!("amount: {} credits", credits)),
+        ),
+        IdentityTask::RegisterDpnsName(input) => Some(
+            TaskActivity::new("Register DPNS Name", Some(&input.qualified_identity))
+                .target(input.name_input.clone()),
+        ),
+        IdentityTask::LoadIdentity(_)
+        | IdentityTask::SearchIdentityFromWallet(..)
+        | IdentityTask::RefreshIdentity(_)
+        | IdentityTask::RefreshLoadedIdentitiesOwnedDPNSNames => None,
+    }
+}
+
+fn document_task_activity(task: &DocumentTask) -> Option<TaskActivity> {
+    match task {
+        DocumentTask::BroadcastDocument(document, _, _, document_type, _, identity, _) => Some(
+            TaskActivity::new("Create Document", Some(identity)).target(format!(
+                "{} {}",
+                document_type.name(),
+                document.id().to_string(Encoding::Base58)
+            )),
+        ),
+        DocumentTask::DeleteDocument(document_id, document_type, _, identity, ..) => Some(
+            TaskActivity::new("Delete Document", Some(identity)).target(format!(
+                "{} {}",
+                document_type.name(),
+                document_id.to_string(Encoding::Base58)
+            )),
+        ),
+        DocumentTask::ReplaceDocument(document, document_type, _, identity, ..) => Some(
+            TaskActivity::new("Replace Document", Some(identity)).target(format!(
+                "{} {}",
+                document_type.name(),
+                document.id().to_string(Encoding::Base58)
+            )),
+        ),
+        DocumentTask::TransferDocument(document_id, new_owner, document_type, _, identity, ..) => {
+            Some(
+                TaskActivity::new("Transfer Document", Some(identity))
+                    .target(format!(
+                        "{} {}",
+                        document_type.name(),
+                        document_id.to_string(Encoding::Base58)
+                    ))
+                    .parameters(format!(
+                        "new owner: {}",
+                        new_owner.to_string(Encoding::Base58)
+                    )),
+            )
+        }
+        DocumentTask::PurchaseDocument(price, document_id, document_type, _, identity, ..) => Some(
+            TaskActivity::new("Purchase Document", Some(identity))
+                .target(format!(
+                    "{} {}",
+                    document_type.name(),
+                    document_id.to_string(Encoding::Base58)
+                ))
+                .parameters(format!("price: {} credits", price)),
+        ),
+        DocumentTask::SetDocumentPrice(price, document_id, document_type, _, identity, ..) => Some(
+            TaskActivity::new("Set Document Price", Some(identity))
+                .target(format!(
+                    "{} {}",
+                    document_type.name(),
+                    document_id.to_string(Encoding::Base58)
+                ))
+                .parameters(format!("price: {} credits", price)),
+        ),
+        DocumentTask::FetchDocuments(_) | DocumentTask::FetchDocumentsPage(_) => None,
+    }
+}
+
+fn contested_resource_task_activity(task: &ContestedResourceTask) -> Option<TaskActivity> {
+    match task {
+        ContestedResourceTask::VoteOnDPNSNames(votes, voters) => {
+            let identity = match voters.as_slice() {
+                [voter] => Some(voter),
+                _ => None,
+            };
+            Some(
+                TaskActivity::new("Vote on DPNS Names", identity)
+                    .target(
+                        votes
+                            .iter()
+                            .map(|(name, _)| name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                    .parameters(format!(
+                        "{}, by {} voter(s)",
+                        votes
+                            .iter()
+                            .map(|(name, choice)| format!("{}: {}", name, choice))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        voters.len()
+                    )),
+            )
+        }
+        ContestedResourceTask::ResumeDPNSVotes(votes) => Some(
+            TaskActivity::new("Resume DPNS Votes", None)
+                .target(
+                    votes
+                        .iter()
+                        .map(|(name, ..)| name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )
+                .parameters(
+                    votes
+                        .iter()
+                        .map(|(name, choice, voters)| {
+                            format!("{}: {} by {} voter(s)", name, choice, voters.len())
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+        ),
+        ContestedResourceTask::CastScheduledVote(vote, identity) => Some(
+            TaskActivity::new("Cast Scheduled Vote", Some(identity))
+                .target(vote.contested_name.clone())
+                .parameters(format!("choice: {}", vote.choice)),
+        ),
+        ContestedResourceTask::CastScheduledVotes(votes) => {
+            let identity = match votes.as_slice() {
+                [(_, voter)] => Some(voter),
+                _ => None,
+            };
+            Some(
+                TaskActivity::new("Cast Scheduled Votes", identity)
+                    .target(
+                        votes
+                            .iter()
+                            .map(|(vote, _)| vote.contested_name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                    .parameters(
+                        votes
+                            .iter()
+                            .map(|(vote, _)| format!("{}: {}", vote.contested_name, vote.choice))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn token_task_activity(task: &TokenTask) -> Option<TaskActivity> {
+    match task {
+        TokenTask::RegisterTokenContract {
+            identity,
+            token_names,
+            base_supply,
+            decimals,
+            ..
+        } => Some(
+            TaskActivity::new("Register Token Contract", Some(identity))
+                .target(
+                    token_names
+                        .iter()
+                        .map(|(singular, ..)| singular.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )
+                .parameters(format!(
+                    "base supply: {}, decimals: {}",
+                    base_supply, decimals
+                )),
+        ),
+        TokenTask::MintTokens {
+            sending_identity,
+            data_contract,
+            token_position,
+            public_note,
+            amount,
+            recipient_id,
+            group_info,
+            ..
+        } => {
+            let mut parameters = format!("amount: {}", amount);
+            if let Some(recipient_id) = recipient_id {
+                parameters.push_str(&format!(
+                    ", recipient: {}",
+                    recipient_id.to_string(Encoding::Base58)
+                ));
+            }
+            Some(
+                TaskActivity::new("Mint Tokens", Some(sending_identity))
+                    .target(token_target(data_contract, *token_position))
+                    .parameters(token_parameters(parameters, public_note, group_info)),
+            )
+        }
+        TokenTask::TransferTokens {
+            sending_identity,
+            recipient_id,
+            amount,
+            data_contract,
+            token_position,
+            public_note,
+            ..
+        } => Some(
+            TaskActivity::new("Transfer Tokens", Some(sending_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    format!(
+                        "amount: {}, recipient: {}",
+                        amount,
+                        recipient_id.to_string(Encoding::Base58)
+                    ),
+                    public_note,
+                    &None,
+                )),
+        ),
+        TokenTask::BurnTokens {
+            owner_identity,
+            data_contract,
+            token_position,
+            public_note,
+            amount,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Burn Tokens", Some(owner_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    format!("amount: {}", amount),
+                    public_note,
+                    group_info,
+                )),
+        ),
+        TokenTask::DestroyFrozenFunds {
+            actor_identity,
+            data_contract,
+            token_position,
+            public_note,
+            frozen_identity,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Destroy Frozen Funds", Some(actor_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    format!("identity: {}", frozen_identity.to_string(Encoding::Base58)),
+                    public_note,
+                    group_info,
+                )),
+        ),
+        TokenTask::FreezeTokens {
+            actor_identity,
+            data_contract,
+            token_position,
+            public_note,
+            freeze_identity,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Freeze Tokens", Some(actor_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    format!("identity: {}", freeze_identity.to_string(Encoding::Base58)),
+                    public_note,
+                    group_info,
+                )),
+        ),
+        TokenTask::UnfreezeTokens {
+            actor_identity,
+            data_contract,
+            token_position,
+            public_note,
+            unfreeze_identity,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Unfreeze Tokens", Some(actor_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    format!(
+                        "identity: {}",
+                        unfreeze_identity.to_string(Encoding::Base58)
+                    ),
+                    public_note,
+                    group_info,
+                )),
+        ),
+        TokenTask::PauseTokens {
+            actor_identity,
+            data_contract,
+            token_position,
+            public_note,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Pause Tokens", Some(actor_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(String::new(), public_note, group_info)),
+        ),
+        TokenTask::ResumeTokens {
+            actor_identity,
+            data_contract,
+            token_position,
+            public_note,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Resume Tokens", Some(actor_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(String::new(), public_note, group_info)),
+        ),
+        TokenTask::ClaimTokens {
+            data_contract,
+            token_position,
+            actor_identity,
+            distribution_type,
+            public_note,
+            ..
+        } => Some(
+            TaskActivity::new("Claim Tokens", Some(actor_identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    format!("distribution: {:?}", distribution_type),
+                    public_note,
+                    &None,
+                )),
+        ),
+        TokenTask::UpdateTokenConfig {
+            identity_token_info,
+            change_item,
+            public_note,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Update Token Config", Some(&identity_token_info.identity))
+                .target(identity_token_info.token_id.to_string(Encoding::Base58))
+                .parameters(token_parameters(
+                    format!("{:?}", change_item),
+                    public_note,
+                    group_info,
+                )),
+        ),
+        TokenTask::PurchaseTokens {
+            identity,
+            data_contract,
+            token_position,
+            amount,
+            total_agreed_price,
+            ..
+        } => Some(
+            TaskActivity::new("Purchase Tokens", Some(identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(format!(
+                    "amount: {}, total price: {} credits",
+                    amount, total_agreed_price
+                )),
+        ),
+        TokenTask::SetDirectPurchasePrice {
+            identity,
+            data_contract,
+            token_position,
+            token_pricing_schedule,
+            public_note,
+            group_info,
+            ..
+        } => Some(
+            TaskActivity::new("Set Token Price", Some(identity))
+                .target(token_target(data_contract, *token_position))
+                .parameters(token_parameters(
+                    match token_pricing_schedule {
+                        Some(schedule) => format!("pricing: {:?}", schedule),
+                        None => "pricing removed".to_string(),
+                    },
+                    public_note,
+                    group_info,
+                )),
+        ),
+        TokenTask::QueryMyTokenBalances
+        | TokenTask::QueryIdentityTokenBalance(_)
+        | TokenTask::QueryIdentityTokenBalances(_)
+        | TokenTask::SimulateTransition(_)
+        | TokenTask::QueryDescriptionsByKeyword(..)
+        | TokenTask::FetchTokenByContractId(_)
+        | TokenTask::FetchTokenByTokenId(_)
+        | TokenTask::AddTokenByContractId { .. }
+        | TokenTask::SaveTokenLocally(_)
+        | TokenTask::QueryTokenPricing(_)
+        | TokenTask::QueryTokenStatuses(_)
+        | TokenTask::EstimatePerpetualTokenRewardsWithExplanation { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_parameters() {
+        assert_eq!(
+            token_parameters("amount: 5".to_string(), &None, &None),
+            "amount: 5"
+        );
+        assert_eq!(
+            token_parameters(String::new(), &Some("for the team".to_string()), &None),
+            "note: for the team"
+        );
+        assert_eq!(
+            token_parameters(
+                "amount: 5".to_string(),
+                &Some("hi".to_string()),
+                &Some(GroupStateTransitionInfoStatus::GroupStateTransitionInfoProposer(0))
+            ),
+            "amount: 5, note: hi, as a group action"
+        );
+    }
+}
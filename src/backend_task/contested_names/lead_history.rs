@@ -0,0 +1,32 @@
+use crate::context::AppContext;
+
+impl AppContext {
+    /// Records the current leader of every contest still open to votes, so that ended contests
+    /// can tell whether their winner led all along
+    pub(super) fn record_contest_leaders(&self) {
+        let contested_names = match self.db.get_ongoing_contested_names(self) {
+            Ok(contested_names) => contested_names,
+            Err(e) => {
+                tracing::error!(
+                    "Error loading ongoing contests to record their leaders: {}",
+                    e
+                );
+                return;
+            }
+        };
+        let leaders: Vec<_> = contested_names
+            .iter()
+            .filter(|contested_name| contested_name.state.state_is_votable())
+            .map(|contested_name| {
+                (
+                    contested_name.normalized_contested_name.clone(),
+                    contested_name.vote_leader(),
+                )
+            })
+            .collect();
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        if let Err(e) = self.db.record_contest_leaders(&leaders, now, self) {
+            tracing::error!("Error recording contest leaders: {}", e);
+        }
+    }
+}
@@ -1,27 +1,49 @@
+mod lead_history;
 mod query_dpns_contested_resources;
 mod query_dpns_vote_contenders;
 mod query_ending_times;
 mod vote_on_dpns_name;
+mod watchlist_notifications;
 
 use crate::app::TaskResult;
 use crate::backend_task::BackendTaskSuccessResult;
 use crate::context::AppContext;
+use crate::model::contested_name::ContestState;
 use crate::model::qualified_identity::QualifiedIdentity;
 use dash_sdk::Sdk;
+use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
 use dash_sdk::platform::Identifier;
 use futures::future::join_all;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ContestedResourceTask {
     QueryDPNSContests,
+    QueryDPNSContestDetails(String),
+    /// Queries the final state of an ended contest again, updating its cached outcome
+    VerifyDPNSContestOutcome(String),
     VoteOnDPNSNames(Vec<(String, ResourceVoteChoice)>, Vec<QualifiedIdentity>),
+    /// Casts the votes of an interrupted bulk vote that hadn't gone through yet, each name
+    /// with its own voters
+    ResumeDPNSVotes(Vec<(String, ResourceVoteChoice, Vec<QualifiedIdentity>)>),
     ScheduleDPNSVotes(Vec<ScheduledDPNSVote>),
     CastScheduledVote(ScheduledDPNSVote, Box<QualifiedIdentity>),
+    /// Casts several scheduled votes right away and concurrently, each with its voter
+    CastScheduledVotes(Vec<(ScheduledDPNSVote, QualifiedIdentity)>),
+    /// Aborts the in-flight cast of the scheduled vote of a voter on a contested name
+    CancelScheduledVoteCast(Identifier, String),
     ClearAllScheduledVotes,
     ClearExecutedScheduledVotes,
+    /// Removes the votes that weren't cast before their contest ended
+    ClearExpiredScheduledVotes,
     DeleteScheduledVote(Identifier, String),
+    /// Moves the scheduled vote of a voter on a contested name to a new time, in milliseconds
+    RescheduleVote(Identifier, String, u64),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -33,7 +55,171 @@ pub struct ScheduledDPNSVote {
     pub executed_successfully: bool,
 }
 
+impl ScheduledDPNSVote {
+    /// Whether the contest ended before the vote was cast, so it can no longer be. Contests
+    /// without a known end time are taken to be ongoing.
+    pub fn contest_has_ended(
+        &self,
+        contest_end_times: &HashMap<String, TimestampMillis>,
+        now: TimestampMillis,
+    ) -> bool {
+        !self.executed_successfully
+            && contest_end_times
+                .get(&self.contested_name)
+                .is_some_and(|end_time| *end_time <= now)
+    }
+}
+
+/// A vote of an immediate bulk vote and whether it went through, kept until the bulk vote
+/// completes so an interrupted one can be resumed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkVoteProgress {
+    pub voter_id: Identifier,
+    pub contested_name: String,
+    pub choice: ResourceVoteChoice,
+    pub cast: bool,
+}
+
+/// Counts the completed votes of a bulk vote, reporting each one to the UI so it can show
+/// the progress of the batch.
+struct BulkVoteCounter {
+    completed: AtomicUsize,
+    total: usize,
+    sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+}
+
+impl BulkVoteCounter {
+    async fn vote_completed(&self) {
+        let completed = self.completed.fetch_add(1, Ordering::Relaxed) + 1;
+        // Progress is informational, the final result is reported either way
+        let _ = self
+            .sender
+            .send(TaskResult::Success(Box::new(
+                BackendTaskSuccessResult::DPNSVoteProgress {
+                    completed,
+                    total: self.total,
+                },
+            )))
+            .await;
+    }
+}
+
 impl AppContext {
+    /// Casts the votes of a bulk vote concurrently, recording their progress so that a batch
+    /// interrupted by a crash can be resumed without voting twice.
+    async fn cast_dpns_vote_batch(
+        self: &Arc<Self>,
+        votes: &[(String, ResourceVoteChoice, Vec<QualifiedIdentity>)],
+        sdk: &Sdk,
+        sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let progress = votes
+            .iter()
+            .flat_map(|(name, choice, voters)| {
+                voters.iter().map(|voter| BulkVoteProgress {
+                    voter_id: voter.identity.id(),
+                    contested_name: name.clone(),
+                    choice: *choice,
+                    cast: false,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.start_bulk_votes(&progress)
+            .map_err(|e| format!("Error recording bulk vote progress: {}", e))?;
+        let counter = BulkVoteCounter {
+            completed: AtomicUsize::new(0),
+            total: progress.len(),
+            sender: sender.clone(),
+        };
+
+        // Create a vector of async closures that will vote on each name concurrently
+        let futures = votes
+            .iter()
+            .map(|(name, choice, voters)| {
+                let cloned_sender = sender.clone();
+                let app_context = self.clone();
+                let counter = &counter;
+
+                async move {
+                    let result = app_context
+                        .vote_on_dpns_name(name, *choice, voters, sdk, cloned_sender, Some(counter))
+                        .await;
+
+                    (name, choice, result)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Run all futures concurrently
+        let results = join_all(futures).await;
+
+        // Every vote has its result now, so there is nothing left to resume
+        if let Err(e) = self.finish_bulk_votes(&progress) {
+            tracing::error!("Error clearing bulk vote progress: {}", e);
+        }
+
+        let final_results = results
+            .into_iter()
+            .flat_map(|(name, vote_choice, det_execution_result)| {
+                match det_execution_result {
+                    Ok(BackendTaskSuccessResult::DPNSVoteResults(platform_results)) => {
+                        // Voting succeeded in DET, return the Platform results
+                        platform_results
+                    }
+                    Err(det_err_msg) => {
+                        // Voting failed in DET, return the error message
+                        vec![(name.clone(), *vote_choice, Err(det_err_msg))]
+                    }
+                    Ok(_) => {
+                        // Got some other BackendTaskSuccessResult, this shouldn't occur
+                        vec![(name.clone(), *vote_choice, Ok(()))]
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(BackendTaskSuccessResult::DPNSVoteResults(final_results))
+    }
+
+    /// Casts a scheduled vote, which can be aborted while in flight through
+    /// [`ContestedResourceTask::CancelScheduledVoteCast`]. Returns `None` when it was cancelled.
+    async fn cast_scheduled_vote(
+        self: &Arc<Self>,
+        scheduled_vote: &ScheduledDPNSVote,
+        voter: &QualifiedIdentity,
+        sdk: &Sdk,
+        sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Option<Result<BackendTaskSuccessResult, String>> {
+        let key = (
+            scheduled_vote.voter_id,
+            scheduled_vote.contested_name.clone(),
+        );
+        let voters = vec![voter.clone()];
+        let cancellation_token = CancellationToken::new();
+        self.scheduled_vote_casts
+            .lock()
+            .unwrap()
+            .insert(key.clone(), cancellation_token.clone());
+
+        // Cancelling drops the vote future, which aborts the pending platform request
+        let result = tokio::select! {
+            result = self.vote_on_dpns_name(
+                &scheduled_vote.contested_name,
+                scheduled_vote.choice,
+                &voters,
+                sdk,
+                sender,
+                None,
+            ) => Some(result),
+            _ = cancellation_token.cancelled() => None,
+        };
+
+        if result.is_some() {
+            self.scheduled_vote_casts.lock().unwrap().remove(&key);
+        }
+        result
+    }
+
     pub async fn run_contested_resource_task(
         self: &Arc<Self>,
         task: ContestedResourceTask,
@@ -45,64 +231,93 @@ impl AppContext {
                 .query_dpns_contested_resources(sdk, sender)
                 .await
                 .map(|_| BackendTaskSuccessResult::None),
+            ContestedResourceTask::QueryDPNSContestDetails(name) => self
+                .query_dpns_vote_contenders(name, sdk, sender)
+                .await
+                .map(|_| BackendTaskSuccessResult::Refresh)
+                .map_err(|e| format!("Error fetching contest details: {}", e)),
+            ContestedResourceTask::VerifyDPNSContestOutcome(name) => self
+                .query_dpns_vote_contenders(name, sdk, sender)
+                .await
+                .map(|winner| BackendTaskSuccessResult::DPNSContestOutcome {
+                    name: name.clone(),
+                    outcome: winner.and_then(ContestState::from_winner),
+                })
+                .map_err(|e| format!("Error verifying contest outcome of {}: {}", name, e)),
             ContestedResourceTask::VoteOnDPNSNames(votes, all_voters) => {
-                // Create a vector of async closures that will vote on each name concurrently
-                let futures = votes
+                let votes = votes
                     .iter()
-                    .map(|(name, choice)| {
-                        let cloned_sender = sender.clone();
-                        let app_context = self.clone();
-
-                        async move {
-                            let result = app_context
-                                .vote_on_dpns_name(name, *choice, all_voters, sdk, cloned_sender)
-                                .await;
-
-                            (name, choice, result)
-                        }
-                    })
+                    .map(|(name, choice)| (name.clone(), *choice, all_voters.clone()))
                     .collect::<Vec<_>>();
-
-                // Run all futures concurrently
-                let results = join_all(futures).await;
-
-                let final_results = results
-                    .into_iter()
-                    .flat_map(|(name, vote_choice, det_execution_result)| {
-                        match det_execution_result {
+                self.cast_dpns_vote_batch(&votes, sdk, sender).await
+            }
+            ContestedResourceTask::ResumeDPNSVotes(votes) => {
+                self.cast_dpns_vote_batch(votes, sdk, sender).await
+            }
+            ContestedResourceTask::ScheduleDPNSVotes(scheduled_votes) => self
+                .insert_scheduled_votes(scheduled_votes)
+                .map(|_| BackendTaskSuccessResult::Message("Votes scheduled".to_string()))
+                .map_err(|e| format!("Error inserting scheduled votes: {}", e)),
+            ContestedResourceTask::CastScheduledVote(scheduled_vote, voter) => {
+                match self
+                    .cast_scheduled_vote(scheduled_vote, voter, sdk, sender)
+                    .await
+                {
+                    Some(result) => result
+                        .map(|_| {
+                            BackendTaskSuccessResult::CastScheduledVote(scheduled_vote.clone())
+                        })
+                        .map_err(|e| format!("Error casting scheduled vote: {}", e)),
+                    // The screen already reset the vote when it was cancelled
+                    None => Ok(BackendTaskSuccessResult::None),
+                }
+            }
+            ContestedResourceTask::CastScheduledVotes(scheduled_votes) => {
+                let futures = scheduled_votes.iter().map(|(scheduled_vote, voter)| {
+                    let cloned_sender = sender.clone();
+                    async move {
+                        let result = match self
+                            .cast_scheduled_vote(scheduled_vote, voter, sdk, cloned_sender)
+                            .await?
+                        {
                             Ok(BackendTaskSuccessResult::DPNSVoteResults(platform_results)) => {
-                                // Voting succeeded in DET, return the Platform results
                                 platform_results
+                                    .into_iter()
+                                    .find_map(|(_, _, result)| result.err())
+                                    .map_or(Ok(()), Err)
                             }
-                            Err(det_err_msg) => {
-                                // Voting failed in DET, return the error message
-                                vec![(name.clone(), *vote_choice, Err(det_err_msg))]
-                            }
-                            Ok(_) => {
-                                // Got some other BackendTaskSuccessResult, this shouldn't occur
-                                vec![(name.clone(), *vote_choice, Ok(()))]
+                            Ok(_) => Ok(()),
+                            Err(e) => Err(e),
+                        };
+                        if result.is_ok() {
+                            if let Err(e) = self.mark_vote_executed(
+                                scheduled_vote.voter_id.as_slice(),
+                                scheduled_vote.contested_name.clone(),
+                            ) {
+                                tracing::error!("Error marking scheduled vote as executed: {}", e);
                             }
                         }
-                    })
-                    .collect::<Vec<_>>();
+                        Some((scheduled_vote.clone(), result))
+                    }
+                });
 
-                Ok(BackendTaskSuccessResult::DPNSVoteResults(final_results))
+                // Cancelled votes were already reset by the screen, so they have no result
+                let results = join_all(futures).await.into_iter().flatten().collect();
+                Ok(BackendTaskSuccessResult::CastScheduledVotes(results))
+            }
+            ContestedResourceTask::CancelScheduledVoteCast(voter_id, contested_name) => {
+                if let Some(cancellation_token) = self
+                    .scheduled_vote_casts
+                    .lock()
+                    .unwrap()
+                    .remove(&(*voter_id, contested_name.clone()))
+                {
+                    cancellation_token.cancel();
+                }
+                Ok(BackendTaskSuccessResult::Message(
+                    "Scheduled vote cast cancelled".to_string(),
+                ))
             }
-            ContestedResourceTask::ScheduleDPNSVotes(scheduled_votes) => self
-                .insert_scheduled_votes(scheduled_votes)
-                .map(|_| BackendTaskSuccessResult::Message("Votes scheduled".to_string()))
-                .map_err(|e| format!("Error inserting scheduled votes: {}", e)),
-            ContestedResourceTask::CastScheduledVote(scheduled_vote, voter) => self
-                .vote_on_dpns_name(
-                    &scheduled_vote.contested_name,
-                    scheduled_vote.choice,
-                    &vec![(**voter).clone()],
-                    sdk,
-                    sender,
-                )
-                .await
-                .map(|_| BackendTaskSuccessResult::CastScheduledVote(scheduled_vote.clone()))
-                .map_err(|e| format!("Error casting scheduled vote: {}", e)),
             ContestedResourceTask::ClearAllScheduledVotes => self
                 .clear_all_scheduled_votes()
                 .map(|_| BackendTaskSuccessResult::Refresh)
@@ -111,10 +326,43 @@ impl AppContext {
                 .clear_executed_scheduled_votes()
                 .map(|_| BackendTaskSuccessResult::Refresh)
                 .map_err(|e| format!("Error clearing executed scheduled votes: {}", e)),
+            ContestedResourceTask::ClearExpiredScheduledVotes => self
+                .clear_expired_scheduled_votes()
+                .map(|_| BackendTaskSuccessResult::Refresh)
+                .map_err(|e| format!("Error clearing expired scheduled votes: {}", e)),
             ContestedResourceTask::DeleteScheduledVote(voter_id, contested_name) => self
                 .delete_scheduled_vote(voter_id.as_slice(), contested_name)
                 .map(|_| BackendTaskSuccessResult::Refresh)
                 .map_err(|e| format!("Error clearing scheduled vote: {}", e)),
+            ContestedResourceTask::RescheduleVote(voter_id, contested_name, unix_timestamp) => self
+                .update_scheduled_vote_time(voter_id.as_slice(), contested_name, *unix_timestamp)
+                .map(|_| BackendTaskSuccessResult::Refresh)
+                .map_err(|e| format!("Error rescheduling vote: {}", e)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contest_has_ended() {
+        let vote = |contested_name: &str, executed_successfully| ScheduledDPNSVote {
+            contested_name: contested_name.to_string(),
+            voter_id: Identifier::default(),
+            choice: ResourceVoteChoice::Abstain,
+            unix_timestamp: 1_000,
+            executed_successfully,
+        };
+        let end_times = HashMap::from([
+            ("ended".to_string(), 5_000),
+            ("ongoing".to_string(), 20_000),
+        ]);
+
+        assert!(vote("ended", false).contest_has_ended(&end_times, 10_000));
+        assert!(!vote("ended", true).contest_has_ended(&end_times, 10_000));
+        assert!(!vote("ongoing", false).contest_has_ended(&end_times, 10_000));
+        assert!(!vote("unknown", false).contest_has_ended(&end_times, 10_000));
+    }
+}
@@ -27,6 +27,8 @@ impl AppContext {
                 "Contested resource query failed: No contested index on dpns domains.".to_string(),
             );
         };
+        // Compared with the contests once refreshed, to notify about changes to watched names
+        let watched_before = self.watched_contests();
         const MAX_RETRIES: usize = 3;
         let mut start_at_value = None;
         let mut names_to_be_updated = Vec::new();
@@ -240,6 +242,9 @@ impl AppContext {
             }
         }
 
+        self.record_contest_leaders();
+        self.notify_watched_contest_changes(&watched_before);
+
         sender
             .send(TaskResult::Success(Box::new(
                 BackendTaskSuccessResult::Message(
@@ -6,6 +6,7 @@ use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dash_sdk::dpp::platform_value::Value;
 use dash_sdk::dpp::voting::contender_structs::ContenderWithSerializedDocument;
+use dash_sdk::dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
 use dash_sdk::dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
 use dash_sdk::drive::query::vote_poll_vote_state_query::{
     ContestedDocumentVotePollDriveQuery, ContestedDocumentVotePollDriveQueryResultType,
@@ -13,12 +14,14 @@ use dash_sdk::drive::query::vote_poll_vote_state_query::{
 use dash_sdk::platform::FetchMany;
 
 impl AppContext {
+    /// Fetches the contenders and votes of a contest into the database, returning the winner
+    /// once the contest has ended
     pub(super) async fn query_dpns_vote_contenders(
         &self,
         name: &str,
         sdk: &Sdk,
         _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
-    ) -> Result<(), String> {
+    ) -> Result<Option<ContestedDocumentVotePollWinnerInfo>, String> {
         let data_contract = self.dpns_contract.as_ref();
         let document_type = data_contract
             .document_type_for_name("domain")
@@ -52,10 +55,10 @@ impl AppContext {
             match ContenderWithSerializedDocument::fetch_many(sdk, contenders_query.clone()).await {
                 Ok(contenders) => {
                     // If successful, proceed to insert/update contenders
-                    return self
-                        .db
+                    self.db
                         .insert_or_update_contenders(name, &contenders, document_type, self)
-                        .map_err(|e| e.to_string());
+                        .map_err(|e| e.to_string())?;
+                    return Ok(contenders.winner.map(|(winner, _)| winner));
                 }
                 Err(e) => {
                     tracing::error!("Error fetching vote contenders: {}", e);
@@ -1,3 +1,4 @@
+use super::BulkVoteCounter;
 use crate::app::TaskResult;
 use crate::backend_task::BackendTaskSuccessResult;
 use crate::context::AppContext;
@@ -24,6 +25,7 @@ impl AppContext {
         voters: &[QualifiedIdentity],
         sdk: &Sdk,
         sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+        counter: Option<&BulkVoteCounter>,
     ) -> Result<BackendTaskSuccessResult, String> {
         // Send a refresh task to the frontend
         // In particular, use this to show the cast is in progress on Scheduled Votes Screen
@@ -78,7 +80,20 @@ impl AppContext {
                     .map(|_| ())
                     .map_err(|e| format!("Error voting: {}", e));
 
+                // Record it right away, so a crash later in the batch doesn't lead to a
+                // second vote when the batch is resumed
+                if result.is_ok() {
+                    if let Err(e) =
+                        self.mark_bulk_vote_cast(qualified_identity.identity.id().as_slice(), name)
+                    {
+                        tracing::error!("Error recording cast vote for {}: {}", name, e);
+                    }
+                }
+
                 vote_results.push((name.to_owned(), vote_choice, result));
+                if let Some(counter) = counter {
+                    counter.vote_completed().await;
+                }
             } else {
                 return Err(format!(
                     "Error voting: No associated voter identity for qualified identity: {}",
@@ -0,0 +1,191 @@
+use crate::context::AppContext;
+use crate::model::contested_name::{ContestState, ContestedName};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+
+impl AppContext {
+    /// The contests of the names on the watchlist, as currently stored
+    pub(super) fn watched_contests(&self) -> Vec<ContestedName> {
+        let watchlist = match self.db.get_dpns_watchlist(self) {
+            Ok(watchlist) => watchlist,
+            Err(e) => {
+                tracing::error!("Error loading the DPNS watchlist: {}", e);
+                return Vec::new();
+            }
+        };
+        if watchlist.is_empty() {
+            return Vec::new();
+        }
+
+        match self.db.get_all_contested_names(self) {
+            Ok(mut contested_names) => {
+                contested_names.retain(|contested_name| {
+                    watchlist.iter().any(|watched| {
+                        watched.normalized_name == contested_name.normalized_contested_name
+                    })
+                });
+                contested_names
+            }
+            Err(e) => {
+                tracing::error!("Error loading contested names: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Shows a desktop notification for every change to a watched contest between `before`
+    /// and the contests now stored.
+    pub(super) fn notify_watched_contest_changes(&self, before: &[ContestedName]) {
+        for change in watched_contest_changes(before, &self.watched_contests()) {
+            if let Err(e) = notify_rust::Notification::new()
+                .summary("Dash Evo Tool: watched name")
+                .body(&change)
+                .show()
+            {
+                tracing::warn!("Failed to show desktop notification: {}", e);
+            }
+        }
+    }
+}
+
+fn is_ended(state: &ContestState) -> bool {
+    matches!(state, ContestState::WonBy(_) | ContestState::Locked)
+}
+
+/// Describes what changed in the watched contests: names that became contested, new
+/// contestants, a new vote leader and contests that ended.
+fn watched_contest_changes(before: &[ContestedName], after: &[ContestedName]) -> Vec<String> {
+    let mut changes = Vec::new();
+    for contest in after {
+        let name = &contest.normalized_contested_name;
+        let Some(previous) = before
+            .iter()
+            .find(|previous| previous.normalized_contested_name == *name)
+        else {
+            changes.push(format!("\"{}\" is now contested", name));
+            continue;
+        };
+
+        let previous_ids: Vec<Identifier> = previous
+            .contestants
+            .iter()
+            .flatten()
+            .map(|c| c.id)
+            .collect();
+        for contestant in contest.contestants.iter().flatten() {
+            if !previous_ids.contains(&contestant.id) {
+                changes.push(format!(
+                    "New contestant for \"{}\": {}",
+                    name,
+                    contestant.id.to_string(Encoding::Base58)
+                ));
+            }
+        }
+
+        if is_ended(&contest.state) {
+            if !is_ended(&previous.state) {
+                changes.push(match &contest.state {
+                    ContestState::WonBy(winner) => format!(
+                        "Contest for \"{}\" ended, won by {}",
+                        name,
+                        winner.to_string(Encoding::Base58)
+                    ),
+                    _ => format!("Contest for \"{}\" ended, the name is locked", name),
+                });
+            }
+            continue;
+        }
+
+        let new_leader = contest
+            .vote_leader()
+            .filter(|leader| Some(*leader) != previous.vote_leader());
+        if let Some(leader) = new_leader {
+            let votes = contest
+                .contestants
+                .iter()
+                .flatten()
+                .find(|c| c.id == leader)
+                .map_or(0, |c| c.votes);
+            changes.push(format!(
+                "{} now leads \"{}\" with {} votes",
+                leader.to_string(Encoding::Base58),
+                name,
+                votes
+            ));
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::contested_name::Contestant;
+    use std::collections::BTreeMap;
+
+    fn contestant(id: u8, votes: u32) -> Contestant {
+        Contestant {
+            id: Identifier::new([id; 32]),
+            name: "alice".to_string(),
+            info: String::new(),
+            votes,
+            created_at: None,
+            created_at_block_height: None,
+            created_at_core_block_height: None,
+            document_id: Identifier::new([id; 32]),
+        }
+    }
+
+    fn contest(contestants: Vec<Contestant>, state: ContestState) -> ContestedName {
+        ContestedName {
+            normalized_contested_name: "a11ce".to_string(),
+            contestants: Some(contestants),
+            locked_votes: None,
+            abstain_votes: None,
+            awarded_to: None,
+            end_time: None,
+            state,
+            last_updated: None,
+            my_votes: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_watched_contest_changes() {
+        let before = contest(
+            vec![contestant(1, 3), contestant(2, 1)],
+            ContestState::Ongoing,
+        );
+
+        let unchanged = watched_contest_changes(&[before.clone()], &[before.clone()]);
+        assert!(unchanged.is_empty());
+
+        let new_contest = watched_contest_changes(&[], &[before.clone()]);
+        assert_eq!(new_contest, vec!["\"a11ce\" is now contested".to_string()]);
+
+        let lead_change = contest(
+            vec![contestant(1, 3), contestant(2, 4), contestant(3, 0)],
+            ContestState::Ongoing,
+        );
+        let changes = watched_contest_changes(&[before.clone()], &[lead_change]);
+        assert_eq!(changes.len(), 2);
+        assert!(changes[0].starts_with("New contestant for \"a11ce\""));
+        assert!(changes[1].ends_with("now leads \"a11ce\" with 4 votes"));
+
+        // A tie isn't a new lead
+        let tied = contest(
+            vec![contestant(1, 3), contestant(2, 3)],
+            ContestState::Ongoing,
+        );
+        assert!(watched_contest_changes(&[before.clone()], &[tied]).is_empty());
+
+        let ended = contest(
+            vec![contestant(1, 3), contestant(2, 1)],
+            ContestState::WonBy(Identifier::new([1; 32])),
+        );
+        let changes = watched_contest_changes(&[before], &[ended.clone()]);
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].starts_with("Contest for \"a11ce\" ended, won by"));
+        assert!(watched_contest_changes(&[ended.clone()], &[ended]).is_empty());
+    }
+}
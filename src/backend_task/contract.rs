@@ -9,11 +9,13 @@ use crate::model::qualified_contract::QualifiedContract;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::ui::tokens::tokens_screen::{ContractDescriptionInfo, TokenInfo};
 use dash_sdk::Sdk;
+use dash_sdk::dpp::data_contract::GroupContractPosition;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::TokenConfigurationConvention;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
+use dash_sdk::dpp::data_contract::group::GroupMemberPower;
 use dash_sdk::dpp::data_contract::group::accessors::v0::GroupV0Getters;
 use dash_sdk::dpp::document::DocumentV0Getters;
 use dash_sdk::dpp::group::group_action::GroupAction;
@@ -21,7 +23,7 @@ use dash_sdk::dpp::group::group_action_status::GroupActionStatus;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::platform_value::Value;
 use dash_sdk::drive::query::{WhereClause, WhereOperator};
-use dash_sdk::platform::group_actions::GroupActionsQuery;
+use dash_sdk::platform::group_actions::{GroupActionSignersQuery, GroupActionsQuery};
 use dash_sdk::platform::{
     DataContract, Document, DocumentQuery, Fetch, FetchMany, Identifier, IdentityPublicKey,
 };
@@ -32,6 +34,12 @@ pub enum ContractTask {
     FetchContracts(Vec<Identifier>),
     FetchContractsWithDescriptions(Vec<Identifier>),
     FetchActiveGroupActions(QualifiedContract, QualifiedIdentity),
+    /// Members that signed an active group action so far, with their power
+    FetchGroupActionSigners {
+        contract_id: Identifier,
+        group_position: GroupContractPosition,
+        action_id: Identifier,
+    },
     RemoveContract(Identifier),
     RegisterDataContract(DataContract, String, QualifiedIdentity, IdentityPublicKey), // contract, alias, identity, signing_key
     UpdateDataContract(DataContract, QualifiedIdentity, IdentityPublicKey), // contract, identity, signing_key
@@ -176,6 +184,25 @@ impl AppContext {
 
                 Ok(BackendTaskSuccessResult::ActiveGroupActions(actions))
             }
+            ContractTask::FetchGroupActionSigners {
+                contract_id,
+                group_position,
+                action_id,
+            } => {
+                let query = GroupActionSignersQuery {
+                    contract_id,
+                    group_contract_position: group_position,
+                    status: GroupActionStatus::ActionActive,
+                    action_id,
+                };
+                let signers = GroupMemberPower::fetch_many(sdk, query)
+                    .await
+                    .map_err(|e| format!("Error fetching group action signers: {}", e))?
+                    .into_iter()
+                    .filter_map(|(signer, power)| power.map(|power| (signer, power)))
+                    .collect();
+                Ok(BackendTaskSuccessResult::GroupActionSigners { action_id, signers })
+            }
             ContractTask::RegisterDataContract(data_contract, alias, identity, signing_key) => {
                 AppContext::register_data_contract(
                     self,
@@ -9,12 +9,13 @@ use crate::backend_task::system_task::SystemTask;
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::ui::tokens::tokens_screen::{
-    ContractDescriptionInfo, IdentityTokenIdentifier, TokenInfo,
+    ContractDescriptionInfo, IdentityTokenIdentifier, TokenInfo, TokenInfoWithDataContract,
 };
 use crate::utils::egui_mpsc::SenderAsync;
 use contested_names::ScheduledDPNSVote;
 use dash_sdk::dpp::balances::credits::TokenAmount;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::distribution_function::evaluate_interval::IntervalEvaluationExplanation;
+use dash_sdk::dpp::fee::Credits;
 use dash_sdk::dpp::group::group_action::GroupAction;
 use dash_sdk::dpp::prelude::DataContract;
 use dash_sdk::dpp::state_transition::StateTransition;
@@ -26,8 +27,11 @@ use dash_sdk::query_types::{Documents, IndexMap};
 use futures::future::join_all;
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokens::TokenTask;
+use tracing::Instrument;
 
+pub mod activity;
 pub mod broadcast_state_transition;
 pub mod contested_names;
 pub mod contract;
@@ -43,6 +47,38 @@ pub mod update_data_contract;
 // TODO: Refactor how we handle errors and messages, and remove it from here
 pub(crate) const NO_IDENTITIES_FOUND: &str = "No identities found";
 
+/// How many times a query that failed with a transient error is retried, unless set otherwise
+pub const DEFAULT_BACKEND_TASK_RETRIES: u8 = 3;
+
+/// Upper bound on the retries that can be configured in the settings
+pub const MAX_BACKEND_TASK_RETRIES: u8 = 10;
+
+/// Delay before the first retry, doubled for every following one up to [`MAX_RETRY_DELAY`]
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Whether an error looks like a network hiccup or timeout that may go away on its own, as
+/// opposed to a deterministic failure that would fail again the same way.
+pub(crate) fn is_transient_error(error: &str) -> bool {
+    const TRANSIENT_PATTERNS: [&str; 10] = [
+        "timeout",
+        "timed out",
+        "deadline exceeded",
+        "connection reset",
+        "connection refused",
+        "connection closed",
+        "broken pipe",
+        "transport error",
+        "unavailable",
+        "no available addresses",
+    ];
+    let error = error.to_lowercase();
+    TRANSIENT_PATTERNS
+        .iter()
+        .any(|pattern| error.contains(pattern))
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum BackendTask {
     IdentityTask(IdentityTask),
@@ -57,6 +93,78 @@ pub enum BackendTask {
     None,
 }
 
+impl BackendTask {
+    /// Short name of the task used in logs. Task contents can hold secrets, so they aren't logged.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackendTask::IdentityTask(_) => "IdentityTask",
+            BackendTask::DocumentTask(_) => "DocumentTask",
+            BackendTask::ContractTask(_) => "ContractTask",
+            BackendTask::ContestedResourceTask(_) => "ContestedResourceTask",
+            BackendTask::CoreTask(_) => "CoreTask",
+            BackendTask::BroadcastStateTransition(_) => "BroadcastStateTransition",
+            BackendTask::TokenTask(_) => "TokenTask",
+            BackendTask::SystemTask(_) => "SystemTask",
+            BackendTask::PlatformInfo(_) => "PlatformInfo",
+            BackendTask::None => "None",
+        }
+    }
+
+    /// Whether the task only reads data, so running it again after a transient failure can't
+    /// submit anything twice. Tasks that broadcast state transitions are never retried.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BackendTask::IdentityTask(task) => matches!(
+                task,
+                IdentityTask::LoadIdentity(_)
+                    | IdentityTask::SearchIdentityFromWallet(..)
+                    | IdentityTask::RefreshIdentity(_)
+                    | IdentityTask::RefreshLoadedIdentitiesOwnedDPNSNames
+            ),
+            BackendTask::DocumentTask(task) => matches!(
+                task.as_ref(),
+                DocumentTask::FetchDocuments(_) | DocumentTask::FetchDocumentsPage(_)
+            ),
+            BackendTask::ContractTask(task) => matches!(
+                task.as_ref(),
+                ContractTask::FetchContracts(_)
+                    | ContractTask::FetchContractsWithDescriptions(_)
+                    | ContractTask::FetchActiveGroupActions(..)
+                    | ContractTask::FetchGroupActionSigners { .. }
+            ),
+            BackendTask::ContestedResourceTask(task) => matches!(
+                task,
+                ContestedResourceTask::QueryDPNSContests
+                    | ContestedResourceTask::QueryDPNSContestDetails(_)
+                    | ContestedResourceTask::VerifyDPNSContestOutcome(_)
+            ),
+            BackendTask::CoreTask(task) => matches!(
+                task,
+                CoreTask::GetBestChainLock
+                    | CoreTask::GetBestChainLocks
+                    | CoreTask::RefreshWalletInfo(_)
+            ),
+            BackendTask::TokenTask(task) => matches!(
+                task.as_ref(),
+                TokenTask::QueryMyTokenBalances
+                    | TokenTask::QueryIdentityTokenBalance(_)
+                    | TokenTask::QueryIdentityTokenBalances(_)
+                    | TokenTask::SimulateTransition(_)
+                    | TokenTask::QueryDescriptionsByKeyword(..)
+                    | TokenTask::FetchTokenByContractId(_)
+                    | TokenTask::FetchTokenByTokenId(_)
+                    | TokenTask::QueryTokenPricing(_)
+                    | TokenTask::QueryTokenStatuses(_)
+                    | TokenTask::EstimatePerpetualTokenRewardsWithExplanation { .. }
+            ),
+            BackendTask::PlatformInfo(_) => true,
+            BackendTask::BroadcastStateTransition(_)
+            | BackendTask::SystemTask(_)
+            | BackendTask::None => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[allow(clippy::large_enum_variant)]
 pub enum BackendTaskSuccessResult {
@@ -73,13 +181,25 @@ pub enum BackendTaskSuccessResult {
     #[allow(dead_code)] // May be used for reporting successful votes
     SuccessfulVotes(Vec<Vote>),
     DPNSVoteResults(Vec<(String, ResourceVoteChoice, Result<(), String>)>),
+    /// Number of votes of a running bulk vote that completed so far
+    DPNSVoteProgress {
+        completed: usize,
+        total: usize,
+    },
+    /// Final state of a contest as queried from Platform, `None` when it has no winner yet
+    DPNSContestOutcome {
+        name: String,
+        outcome: Option<crate::model::contested_name::ContestState>,
+    },
     CastScheduledVote(ScheduledDPNSVote),
+    CastScheduledVotes(Vec<(ScheduledDPNSVote, Result<(), String>)>),
     FetchedContract(DataContract),
     FetchedContractWithTokenPosition(
         DataContract,
         dash_sdk::dpp::data_contract::TokenContractPosition,
     ),
     FetchedContracts(Vec<Option<DataContract>>),
+    AddedTokenByContractId(TokenInfoWithDataContract),
     PageDocuments(IndexMap<Identifier, Option<Document>>, Option<Start>),
     #[allow(dead_code)] // May be used for token search results
     TokensByKeyword(Vec<TokenInfo>, Option<Start>),
@@ -93,34 +213,182 @@ pub enum BackendTaskSuccessResult {
         BTreeMap<Identifier, (Option<ContractDescriptionInfo>, Vec<TokenInfo>)>,
     ),
     ActiveGroupActions(IndexMap<Identifier, GroupAction>),
+    GroupActionSigners {
+        action_id: Identifier,
+        signers: BTreeMap<Identifier, dash_sdk::dpp::data_contract::group::GroupMemberPower>,
+    },
     TokenPricing {
         token_id: Identifier,
         prices: Option<dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule>,
     },
+    TokenPausedStatuses(BTreeMap<Identifier, bool>),
+    /// A token action that was built and signed but not broadcast
+    SimulationResult {
+        transition: String,
+        size_bytes: usize,
+        minimum_fee: Credits,
+    },
     UpdatedThemePreference(crate::ui::theme::ThemeMode),
+    DapiEndpointTests(Vec<crate::backend_task::system_task::DapiEndpointTest>),
     PlatformInfo(PlatformInfoTaskResult),
 }
 
 impl BackendTaskSuccessResult {}
 
+/// Outcome of every task of a batch run with [`AppContext::run_backend_tasks_best_effort`], in
+/// the order the tasks were given.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackendTasksSummary {
+    pub results: Vec<Result<(), String>>,
+}
+
+impl BackendTasksSummary {
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|result| result.is_ok()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+
+    /// A report such as "3 of 5 tasks succeeded", followed by the errors of the failed tasks
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{} of {} tasks succeeded.",
+            self.succeeded(),
+            self.results.len()
+        );
+        let errors: Vec<String> = self
+            .results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, result)| {
+                result
+                    .as_ref()
+                    .err()
+                    .map(|e| format!("Task {}: {}", i + 1, e))
+            })
+            .collect();
+        if !errors.is_empty() {
+            message.push_str(" Errors:\n\n");
+            message.push_str(&errors.join("\n"));
+        }
+        message
+    }
+}
+
+/// Tasks of the "Refresh All" action, run one after another so the node isn't flooded with
+/// queries
+pub fn refresh_all_tasks() -> Vec<BackendTask> {
+    vec![
+        BackendTask::ContestedResourceTask(ContestedResourceTask::QueryDPNSContests),
+        BackendTask::IdentityTask(IdentityTask::RefreshLoadedIdentitiesOwnedDPNSNames),
+        BackendTask::TokenTask(Box::new(TokenTask::QueryMyTokenBalances)),
+    ]
+}
+
+/// Progress of a "Refresh All" batch, shared by the top panel and the screens whose refresh
+/// the batch covers
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshAllProgress {
+    /// Unix timestamp, in seconds, the batch started at
+    pub started_at: u64,
+    pub total: usize,
+    /// Tasks of the batch that haven't finished yet
+    pending: Vec<BackendTask>,
+}
+
+impl RefreshAllProgress {
+    pub fn new(tasks: &[BackendTask], started_at: u64) -> Self {
+        Self {
+            started_at,
+            total: tasks.len(),
+            pending: tasks.to_vec(),
+        }
+    }
+
+    pub fn completed(&self) -> usize {
+        self.total - self.pending.len()
+    }
+
+    /// Whether `task` is part of the batch and hasn't finished yet
+    pub fn is_pending(&self, task: &BackendTask) -> bool {
+        self.pending.contains(task)
+    }
+
+    /// Marks a task of the batch as finished, returning whether the whole batch is done
+    pub fn task_finished(&mut self, task: &BackendTask) -> bool {
+        if let Some(index) = self.pending.iter().position(|pending| pending == task) {
+            self.pending.remove(index);
+        }
+        self.pending.is_empty()
+    }
+}
+
 impl AppContext {
-    /// Run backend tasks sequentially
+    /// Run backend tasks sequentially, sending each result as soon as its task finishes so
+    /// the screen can show progress through a long batch
     pub async fn run_backend_tasks_sequential(
         self: &Arc<Self>,
         tasks: Vec<BackendTask>,
         sender: SenderAsync<TaskResult>,
+    ) {
+        for task in tasks {
+            let refresh_all_task = self.is_refresh_all_pending(&task).then(|| task.clone());
+            let result = self.run_backend_task(task, sender.clone()).await;
+            // Updated before the result is sent, so the screen handling it sees the batch move on
+            if let Some(task) = refresh_all_task {
+                self.refresh_all_task_finished(&task);
+            }
+            if let Err(e) = sender.send(result.into()).await {
+                tracing::error!("Failed to send task result: {}", e);
+            }
+        }
+    }
+
+    /// Run backend tasks sequentially, stopping at the first error. The tasks after it are not
+    /// run and have no result.
+    pub async fn run_backend_tasks_fail_fast(
+        self: &Arc<Self>,
+        tasks: Vec<BackendTask>,
+        sender: SenderAsync<TaskResult>,
     ) -> Vec<Result<BackendTaskSuccessResult, String>> {
         let mut results = Vec::new();
         for task in tasks {
-            match self.run_backend_task(task, sender.clone()).await {
-                Ok(result) => results.push(Ok(result)),
-                Err(e) => results.push(Err(e)),
-            };
+            let result = self.run_backend_task(task, sender.clone()).await;
+            let failed = result.is_err();
+            results.push(result);
+            if failed {
+                break;
+            }
         }
         results
     }
 
-    /// Run backend tasks concurrently
+    /// Run backend tasks sequentially, running every task even when some fail. Returns the
+    /// successful results, for the screens to handle as usual, along with a summary of how
+    /// each task went.
+    pub async fn run_backend_tasks_best_effort(
+        self: &Arc<Self>,
+        tasks: Vec<BackendTask>,
+        sender: SenderAsync<TaskResult>,
+    ) -> (Vec<BackendTaskSuccessResult>, BackendTasksSummary) {
+        let mut successes = Vec::new();
+        let mut summary = BackendTasksSummary::default();
+        for task in tasks {
+            match self.run_backend_task(task, sender.clone()).await {
+                Ok(result) => {
+                    successes.push(result);
+                    summary.results.push(Ok(()));
+                }
+                Err(e) => summary.results.push(Err(e)),
+            }
+        }
+        (successes, summary)
+    }
+
+    /// Run backend tasks concurrently. Each task is retried on its own, so one failing task
+    /// doesn't rerun the rest of the batch.
     pub async fn run_backend_tasks_concurrent(
         self: &Arc<Self>,
         tasks: Vec<BackendTask>,
@@ -139,10 +407,66 @@ impl AppContext {
         join_all(futures).await
     }
 
+    /// Runs a backend task inside a `backend_task` span, so everything it logs, including SDK
+    /// calls, can be traced back to the task in the log viewer.
+    ///
+    /// Read-only tasks that fail with a transient error are retried with exponential backoff,
+    /// up to the number of retries configured in the settings. Other errors are returned as is.
     pub async fn run_backend_task(
         self: &Arc<Self>,
         task: BackendTask,
         sender: SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let span = tracing::info_span!("backend_task", task = task.name(), network = %self.network);
+        let activity = task.activity().map(|activity| (activity, task.clone()));
+        let retries = if task.is_retryable() {
+            self.backend_task_retries()
+        } else {
+            0
+        };
+        async {
+            tracing::debug!("Backend task started");
+            let mut attempt = 0;
+            let result = loop {
+                if attempt >= retries {
+                    break self.dispatch_backend_task(task, sender).await;
+                }
+                match self
+                    .dispatch_backend_task(task.clone(), sender.clone())
+                    .await
+                {
+                    Err(e) if is_transient_error(&e) => {
+                        let delay =
+                            (RETRY_BASE_DELAY * 2u32.pow(attempt as u32)).min(MAX_RETRY_DELAY);
+                        tracing::warn!(
+                            error = %e,
+                            attempt = attempt + 1,
+                            "Backend task failed with a transient error, retrying in {:?}",
+                            delay
+                        );
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    result => break result,
+                }
+            };
+            match &result {
+                Ok(_) => tracing::debug!("Backend task finished"),
+                Err(e) => tracing::error!(error = %e, "Backend task failed"),
+            }
+            if let Some((activity, task)) = activity {
+                self.record_activity(&activity, task, result.as_ref().err().map(String::as_str));
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn dispatch_backend_task(
+        self: &Arc<Self>,
+        task: BackendTask,
+        sender: SenderAsync<TaskResult>,
     ) -> Result<BackendTaskSuccessResult, String> {
         let sdk = {
             let guard = self.sdk.read().unwrap();
@@ -178,3 +502,69 @@ impl AppContext {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient_error() {
+        assert!(is_transient_error(
+            "Error fetching contests: dapi client error: transport error: Connection reset by peer"
+        ));
+        assert!(is_transient_error("Request timed out"));
+        assert!(is_transient_error(
+            "status: Unavailable, message: \"no healthy upstream\""
+        ));
+
+        assert!(!is_transient_error("Identity not found"));
+        assert!(!is_transient_error(
+            "Insufficient balance to pay for the transition"
+        ));
+    }
+
+    #[test]
+    fn test_backend_tasks_summary_message() {
+        let summary = BackendTasksSummary {
+            results: vec![
+                Ok(()),
+                Err("Token not found".to_string()),
+                Ok(()),
+                Ok(()),
+                Err("Request timed out".to_string()),
+            ],
+        };
+        assert_eq!(summary.succeeded(), 3);
+        assert_eq!(summary.failed(), 2);
+        assert_eq!(
+            summary.message(),
+            "3 of 5 tasks succeeded. Errors:\n\nTask 2: Token not found\nTask 5: Request timed out"
+        );
+
+        let all_ok = BackendTasksSummary {
+            results: vec![Ok(()), Ok(())],
+        };
+        assert_eq!(all_ok.message(), "2 of 2 tasks succeeded.");
+    }
+
+    #[test]
+    fn test_refresh_all_progress() {
+        let tasks = refresh_all_tasks();
+        let mut progress = RefreshAllProgress::new(&tasks, 100);
+        assert_eq!(progress.completed(), 0);
+        assert!(progress.is_pending(&tasks[0]));
+
+        assert!(!progress.task_finished(&tasks[0]));
+        assert_eq!(progress.completed(), 1);
+        assert!(!progress.is_pending(&tasks[0]));
+        assert!(progress.is_pending(&tasks[2]));
+
+        // A task outside the batch doesn't count towards it
+        assert!(!progress.task_finished(&BackendTask::None));
+        assert_eq!(progress.completed(), 1);
+
+        assert!(!progress.task_finished(&tasks[1]));
+        assert!(progress.task_finished(&tasks[2]));
+        assert_eq!(progress.completed(), 3);
+    }
+}
@@ -1,3 +1,7 @@
+mod platform_connection;
+
+pub use platform_connection::{DapiEndpointTest, PlatformConnectionStatus, parse_dapi_addresses};
+
 use crate::app::TaskResult;
 use crate::backend_task::BackendTaskSuccessResult;
 use crate::context::AppContext;
@@ -8,6 +12,12 @@ use std::sync::Arc;
 pub enum SystemTask {
     WipePlatformData,
     UpdateThemePreference(ThemeMode),
+    /// Pings the DAPI endpoints to update the connection status shown in the top panel
+    CheckPlatformConnection,
+    /// Rebuilds the SDK without restarting the app
+    ReconnectPlatform,
+    /// Asks each DAPI address for its status, to check it before saving it in the settings
+    TestDapiEndpoints(Vec<String>),
 }
 
 impl AppContext {
@@ -21,6 +31,12 @@ impl AppContext {
             SystemTask::UpdateThemePreference(theme_mode) => {
                 self.update_theme_preference(theme_mode)
             }
+            SystemTask::CheckPlatformConnection => {
+                self.check_platform_connection().await;
+                Ok(BackendTaskSuccessResult::None)
+            }
+            SystemTask::ReconnectPlatform => self.reconnect_platform().await,
+            SystemTask::TestDapiEndpoints(addresses) => self.test_dapi_endpoints(&addresses).await,
         }
     }
 
@@ -0,0 +1,269 @@
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use dash_sdk::dapi_client::{AddressList, DapiClient, DapiRequestExecutor, RequestSettings};
+use dash_sdk::platform::proto::{GetStatusRequest, get_status_request, get_status_response};
+use dash_sdk::sdk::Uri;
+use futures::future::join_all;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+
+/// How long a DAPI endpoint has to accept a connection to count as reachable
+const PING_TIMEOUT: Duration = Duration::from_secs(3);
+/// How long a tested DAPI endpoint has to answer a status request
+const ENDPOINT_TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reachability of the configured DAPI endpoints, from the last health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlatformConnectionStatus {
+    /// No health check finished yet
+    #[default]
+    Unknown,
+    /// The SDK is being rebuilt
+    Reconnecting,
+    Connected,
+    /// Only some of the endpoints are reachable
+    Degraded {
+        reachable: usize,
+        total: usize,
+    },
+    Disconnected,
+}
+
+impl PlatformConnectionStatus {
+    /// Status of a health check that reached `reachable` of `total` endpoints
+    pub fn from_reachable(reachable: usize, total: usize) -> Self {
+        if reachable == 0 {
+            PlatformConnectionStatus::Disconnected
+        } else if reachable < total {
+            PlatformConnectionStatus::Degraded { reachable, total }
+        } else {
+            PlatformConnectionStatus::Connected
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            PlatformConnectionStatus::Unknown => "Checking the connection to Platform".to_string(),
+            PlatformConnectionStatus::Reconnecting => "Reconnecting to Platform".to_string(),
+            PlatformConnectionStatus::Connected => {
+                "Connected to Platform, every DAPI endpoint is reachable".to_string()
+            }
+            PlatformConnectionStatus::Degraded { reachable, total } => format!(
+                "Degraded connection to Platform, {} of {} DAPI endpoints are reachable",
+                reachable, total
+            ),
+            PlatformConnectionStatus::Disconnected => {
+                "Disconnected from Platform, no DAPI endpoint is reachable".to_string()
+            }
+        }
+    }
+}
+
+/// Outcome of testing a DAPI endpoint entered in the settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct DapiEndpointTest {
+    pub address: String,
+    /// How long the endpoint took to answer a status request, with the Platform block height
+    /// it reported
+    pub result: Result<(Duration, u64), String>,
+}
+
+/// DAPI addresses entered one per line or separated by commas, such as
+/// "https://127.0.0.1:1443". Each needs an http or https scheme and a host.
+pub fn parse_dapi_addresses(input: &str) -> Result<Vec<String>, String> {
+    let addresses = input
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| {
+            let uri = Uri::from_str(address)
+                .map_err(|e| format!("\"{}\" is not a valid URL: {}", address, e))?;
+            if !matches!(uri.scheme_str(), Some("http" | "https")) {
+                return Err(format!(
+                    "\"{}\" needs to start with http:// or https://",
+                    address
+                ));
+            }
+            if uri.host().is_none_or(str::is_empty) {
+                return Err(format!("\"{}\" has no host", address));
+            }
+            Ok(address.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if addresses.is_empty() {
+        return Err("Enter at least one DAPI address".to_string());
+    }
+    Ok(addresses)
+}
+
+/// Asks the DAPI endpoint at `address` alone for its status, without retries
+async fn test_dapi_endpoint(address: &str) -> Result<(Duration, u64), String> {
+    let address_list = AddressList::from_str(address).map_err(|e| e.to_string())?;
+    let settings = RequestSettings {
+        connect_timeout: Some(PING_TIMEOUT),
+        timeout: Some(ENDPOINT_TEST_TIMEOUT),
+        retries: Some(0),
+        ban_failed_address: Some(false),
+    };
+    let client = DapiClient::new(address_list, settings);
+    let request = GetStatusRequest {
+        version: Some(get_status_request::Version::V0(
+            get_status_request::GetStatusRequestV0 {},
+        )),
+    };
+    let started = Instant::now();
+    let response = client
+        .execute(request, settings)
+        .await
+        .map_err(|e| e.inner.to_string())?;
+    let latency = started.elapsed();
+    let height = match response.inner.version {
+        Some(get_status_response::Version::V0(status)) => {
+            status.chain.map(|chain| chain.latest_block_height)
+        }
+        None => None,
+    }
+    .ok_or_else(|| "The endpoint didn't report its block height".to_string())?;
+    Ok((latency, height))
+}
+
+/// Host and port of each DAPI address in the comma separated `addresses` of the network
+/// config, skipping the ones that don't parse
+fn dapi_endpoints(addresses: &str) -> Vec<(String, u16)> {
+    addresses
+        .split(',')
+        .filter_map(|address| {
+            let uri = Uri::from_str(address.trim()).ok()?;
+            let host = uri.host()?.to_string();
+            let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+                Some("http") => 80,
+                _ => 443,
+            });
+            Some((host, port))
+        })
+        .collect()
+}
+
+impl AppContext {
+    pub fn platform_connection_status(&self) -> PlatformConnectionStatus {
+        *self.platform_connection_status.lock().unwrap()
+    }
+
+    fn set_platform_connection_status(&self, status: PlatformConnectionStatus) {
+        *self.platform_connection_status.lock().unwrap() = status;
+    }
+
+    /// Pings every configured DAPI endpoint by opening a connection to it, recording how many
+    /// of them are reachable
+    pub(super) async fn check_platform_connection(&self) -> PlatformConnectionStatus {
+        let addresses = self.config.read().unwrap().dapi_addresses.clone();
+        let endpoints = dapi_endpoints(&addresses);
+        let pings = endpoints.iter().map(|(host, port)| async move {
+            matches!(
+                tokio::time::timeout(PING_TIMEOUT, TcpStream::connect((host.as_str(), *port)))
+                    .await,
+                Ok(Ok(_))
+            )
+        });
+        let reachable = join_all(pings)
+            .await
+            .into_iter()
+            .filter(|reachable| *reachable)
+            .count();
+        let status = PlatformConnectionStatus::from_reachable(reachable, endpoints.len());
+        self.set_platform_connection_status(status);
+        status
+    }
+
+    /// Tests each of `addresses` on its own, concurrently
+    pub(super) async fn test_dapi_endpoints(
+        &self,
+        addresses: &[String],
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let tests = addresses.iter().map(|address| async move {
+            DapiEndpointTest {
+                address: address.clone(),
+                result: test_dapi_endpoint(address).await,
+            }
+        });
+        Ok(BackendTaskSuccessResult::DapiEndpointTests(
+            join_all(tests).await,
+        ))
+    }
+
+    /// Rebuilds the SDK, dropping its banned addresses and stale connections, then checks the
+    /// connection again. Tasks started afterwards use the new SDK.
+    pub(super) async fn reconnect_platform(
+        self: &Arc<Self>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        self.set_platform_connection_status(PlatformConnectionStatus::Reconnecting);
+        if let Err(e) = Arc::clone(self).reinit_sdk() {
+            self.set_platform_connection_status(PlatformConnectionStatus::Disconnected);
+            return Err(format!("Error reconnecting to Platform: {}", e));
+        }
+        match self.check_platform_connection().await {
+            PlatformConnectionStatus::Disconnected => {
+                Err("Error reconnecting to Platform: no DAPI endpoint is reachable".to_string())
+            }
+            status => Ok(BackendTaskSuccessResult::Message(format!(
+                "Reconnected. {}",
+                status.description()
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dapi_endpoints() {
+        assert_eq!(
+            dapi_endpoints("https://1.2.3.4:1443, http://127.0.0.1:2443,https://dapi.example.org"),
+            vec![
+                ("1.2.3.4".to_string(), 1443),
+                ("127.0.0.1".to_string(), 2443),
+                ("dapi.example.org".to_string(), 443),
+            ]
+        );
+        assert!(dapi_endpoints("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_dapi_addresses() {
+        assert_eq!(
+            parse_dapi_addresses("https://1.2.3.4:1443\n\n http://127.0.0.1:2443,").unwrap(),
+            vec!["https://1.2.3.4:1443", "http://127.0.0.1:2443"]
+        );
+        assert!(parse_dapi_addresses("1.2.3.4:1443").is_err());
+        assert!(parse_dapi_addresses("ftp://1.2.3.4:1443").is_err());
+        assert!(parse_dapi_addresses("https://").is_err());
+        assert!(parse_dapi_addresses(" \n ").is_err());
+    }
+
+    #[test]
+    fn test_status_from_reachable() {
+        assert_eq!(
+            PlatformConnectionStatus::from_reachable(3, 3),
+            PlatformConnectionStatus::Connected
+        );
+        assert_eq!(
+            PlatformConnectionStatus::from_reachable(1, 3),
+            PlatformConnectionStatus::Degraded {
+                reachable: 1,
+                total: 3
+            }
+        );
+        assert_eq!(
+            PlatformConnectionStatus::from_reachable(0, 3),
+            PlatformConnectionStatus::Disconnected
+        );
+        assert_eq!(
+            PlatformConnectionStatus::from_reachable(0, 0),
+            PlatformConnectionStatus::Disconnected
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use crate::database::contracts::InsertTokensToo;
+use crate::ui::tokens::tokens_screen::TokenInfoWithDataContract;
+use dash_sdk::Sdk;
+use dash_sdk::dpp::data_contract::TokenContractPosition;
+use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
+use dash_sdk::platform::{DataContract, Fetch, Identifier};
+
+impl AppContext {
+    /// Fetches a contract and starts tracking one of its tokens.
+    ///
+    /// Without a token position the contract must define exactly one token.
+    pub async fn add_token_by_contract_id(
+        &self,
+        contract_id: Identifier,
+        token_position: Option<TokenContractPosition>,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let data_contract = DataContract::fetch_by_identifier(sdk, contract_id)
+            .await
+            .map_err(|e| format!("Error fetching contract: {}", e))?
+            .ok_or_else(|| format!("Contract {} not found", contract_id))?;
+
+        let token_position = match token_position {
+            Some(position) => position,
+            None => {
+                let positions: Vec<_> = data_contract.tokens().keys().copied().collect();
+                match positions.as_slice() {
+                    [] => return Err(format!("Contract {} has no tokens", contract_id)),
+                    [position] => *position,
+                    _ => {
+                        return Err(format!(
+                            "Contract {} has {} tokens, enter the position of the one to add",
+                            contract_id,
+                            positions.len()
+                        ));
+                    }
+                }
+            }
+        };
+
+        let (Some(token_configuration), Some(token_id)) = (
+            data_contract.tokens().get(&token_position).cloned(),
+            data_contract.token_id(token_position),
+        ) else {
+            return Err(format!(
+                "Contract {} has no token at position {}",
+                contract_id, token_position
+            ));
+        };
+
+        self.db
+            .insert_contract_if_not_exists(
+                &data_contract,
+                None,
+                InsertTokensToo::SomeTokensShouldBeAdded(vec![token_position]),
+                self,
+            )
+            .map_err(|e| format!("Error inserting contract into the database: {}", e))?;
+
+        Ok(BackendTaskSuccessResult::AddedTokenByContractId(
+            TokenInfoWithDataContract {
+                token_id,
+                token_name: token_configuration
+                    .conventions()
+                    .singular_form_by_language_code_or_default("en")
+                    .to_string(),
+                data_contract,
+                token_position,
+                description: token_configuration.description().clone(),
+                token_configuration,
+            },
+        ))
+    }
+}
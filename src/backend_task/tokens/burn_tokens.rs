@@ -16,21 +16,18 @@ use dash_sdk::{Error, Sdk};
 use std::sync::Arc;
 
 impl AppContext {
-    #[allow(clippy::too_many_arguments)]
-    pub async fn burn_tokens(
+    /// The builder of a Burn Tokens transition, shared with its simulation
+    pub(super) fn burn_tokens_builder(
         &self,
         owner_identity: &QualifiedIdentity,
         data_contract: Arc<DataContract>,
         token_position: u16,
-        signing_key: IdentityPublicKey,
         public_note: Option<String>,
         amount: u64,
         group_info: Option<GroupStateTransitionInfoStatus>,
-        sdk: &Sdk,
-        _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
-    ) -> Result<BackendTaskSuccessResult, String> {
+    ) -> TokenBurnTransitionBuilder {
         let mut builder = TokenBurnTransitionBuilder::new(
-            data_contract.clone(),
+            data_contract,
             token_position,
             owner_identity.identity.id(),
             amount,
@@ -48,6 +45,30 @@ impl AppContext {
         if let Some(options) = maybe_options {
             builder = builder.with_state_transition_creation_options(options);
         }
+        builder
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn burn_tokens(
+        &self,
+        owner_identity: &QualifiedIdentity,
+        data_contract: Arc<DataContract>,
+        token_position: u16,
+        signing_key: IdentityPublicKey,
+        public_note: Option<String>,
+        amount: u64,
+        group_info: Option<GroupStateTransitionInfoStatus>,
+        sdk: &Sdk,
+        _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let builder = self.burn_tokens_builder(
+            owner_identity,
+            data_contract.clone(),
+            token_position,
+            public_note,
+            amount,
+            group_info,
+        );
 
         let result = sdk
             .token_burn(builder, &signing_key, owner_identity)
@@ -13,21 +13,18 @@ use dash_sdk::{Error, Sdk};
 use std::sync::Arc;
 
 impl AppContext {
-    #[allow(clippy::too_many_arguments)]
-    pub async fn freeze_tokens(
+    /// The builder of a Freeze Tokens transition, shared with its simulation
+    pub(super) fn freeze_tokens_builder(
         &self,
         actor_identity: &QualifiedIdentity,
         data_contract: Arc<DataContract>,
         token_position: u16,
-        signing_key: IdentityPublicKey,
         public_note: Option<String>,
         freeze_identity: Identifier,
         group_info: Option<GroupStateTransitionInfoStatus>,
-        sdk: &Sdk,
-        _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
-    ) -> Result<BackendTaskSuccessResult, String> {
+    ) -> TokenFreezeTransitionBuilder {
         let mut builder = TokenFreezeTransitionBuilder::new(
-            data_contract.clone(),
+            data_contract,
             token_position,
             actor_identity.identity.id(),
             freeze_identity,
@@ -44,6 +41,30 @@ impl AppContext {
         if let Some(options) = self.state_transition_options() {
             builder = builder.with_state_transition_creation_options(options);
         }
+        builder
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn freeze_tokens(
+        &self,
+        actor_identity: &QualifiedIdentity,
+        data_contract: Arc<DataContract>,
+        token_position: u16,
+        signing_key: IdentityPublicKey,
+        public_note: Option<String>,
+        freeze_identity: Identifier,
+        group_info: Option<GroupStateTransitionInfoStatus>,
+        sdk: &Sdk,
+        _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let builder = self.freeze_tokens_builder(
+            actor_identity,
+            data_contract,
+            token_position,
+            public_note,
+            freeze_identity,
+            group_info,
+        );
 
         let state_transition = builder
             .sign(sdk, &signing_key, actor_identity, self.platform_version())
@@ -16,22 +16,20 @@ use dash_sdk::{Error, Sdk};
 use std::sync::Arc;
 
 impl AppContext {
+    /// The builder of a Mint Tokens transition, shared with its simulation
     #[allow(clippy::too_many_arguments)]
-    pub async fn mint_tokens(
+    pub(super) fn mint_tokens_builder(
         &self,
         sending_identity: &QualifiedIdentity,
         data_contract: Arc<DataContract>,
         token_position: u16,
-        signing_key: IdentityPublicKey,
         public_note: Option<String>,
         amount: u64,
         optional_recipient: Option<Identifier>,
         group_info: Option<GroupStateTransitionInfoStatus>,
-        sdk: &Sdk,
-        _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
-    ) -> Result<BackendTaskSuccessResult, String> {
+    ) -> TokenMintTransitionBuilder {
         let builder = TokenMintTransitionBuilder::new(
-            data_contract.clone(),
+            data_contract,
             token_position,
             sending_identity.identity.id(),
             amount,
@@ -55,6 +53,32 @@ impl AppContext {
         if let Some(options) = maybe_options {
             builder = builder.with_state_transition_creation_options(options);
         }
+        builder
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn mint_tokens(
+        &self,
+        sending_identity: &QualifiedIdentity,
+        data_contract: Arc<DataContract>,
+        token_position: u16,
+        signing_key: IdentityPublicKey,
+        public_note: Option<String>,
+        amount: u64,
+        optional_recipient: Option<Identifier>,
+        group_info: Option<GroupStateTransitionInfoStatus>,
+        sdk: &Sdk,
+        _sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let builder = self.mint_tokens_builder(
+            sending_identity,
+            data_contract.clone(),
+            token_position,
+            public_note,
+            amount,
+            optional_recipient,
+            group_info,
+        );
 
         let result = sdk
             .token_mint(builder, &signing_key, sending_identity)
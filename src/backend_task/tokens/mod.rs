@@ -44,6 +44,7 @@ use dash_sdk::{
 };
 use std::{collections::BTreeMap, sync::Arc};
 
+mod add_token_by_contract_id;
 mod burn_tokens;
 mod claim_tokens;
 mod destroy_frozen_funds;
@@ -54,9 +55,11 @@ mod purchase_tokens;
 mod query_my_token_balances;
 mod query_token_non_claimed_perpetual_distribution_rewards;
 mod query_token_pricing;
+mod query_token_statuses;
 mod query_tokens;
 mod resume_tokens;
 mod set_token_price;
+mod simulate_token_transition;
 mod transfer_tokens;
 mod unfreeze_tokens;
 mod update_token_config;
@@ -100,11 +103,19 @@ pub enum TokenTask {
     },
     QueryMyTokenBalances,
     QueryIdentityTokenBalance(IdentityTokenIdentifier),
+    QueryIdentityTokenBalances(Vec<IdentityTokenIdentifier>),
+    /// Builds and signs the transition of a token action without broadcasting it
+    SimulateTransition(Box<TokenTask>),
     QueryDescriptionsByKeyword(String, Option<Start>),
     FetchTokenByContractId(Identifier),
     FetchTokenByTokenId(Identifier),
+    AddTokenByContractId {
+        contract_id: Identifier,
+        token_position: Option<TokenContractPosition>,
+    },
     SaveTokenLocally(TokenInfo),
     QueryTokenPricing(Identifier),
+    QueryTokenStatuses(Vec<Identifier>),
     MintTokens {
         sending_identity: QualifiedIdentity,
         data_contract: Arc<DataContract>,
@@ -519,6 +530,14 @@ impl AppContext {
                 )
                 .await
                 .map_err(|e| format!("Failed to fetch token balance: {e}")),
+            TokenTask::SimulateTransition(task) => self
+                .simulate_token_transition(task, sdk)
+                .await
+                .map_err(|e| format!("Simulation failed: {e}")),
+            TokenTask::QueryIdentityTokenBalances(identity_token_pairs) => {
+                self.query_token_balances(sdk, identity_token_pairs, sender)
+                    .await
+            }
             TokenTask::FetchTokenByContractId(contract_id) => {
                 match DataContract::fetch_by_identifier(sdk, *contract_id).await {
                     Ok(Some(data_contract)) => {
@@ -564,6 +583,13 @@ impl AppContext {
                     Err(e) => Err(format!("Error fetching token info: {}", e)),
                 }
             }
+            TokenTask::AddTokenByContractId {
+                contract_id,
+                token_position,
+            } => {
+                self.add_token_by_contract_id(*contract_id, *token_position, sdk)
+                    .await
+            }
             TokenTask::SaveTokenLocally(token_info) => {
                 let token_config_bytes = bincode::encode_to_vec(
                     &token_info.token_configuration,
@@ -649,6 +675,9 @@ impl AppContext {
                 .query_token_pricing(*token_id, sdk, sender)
                 .await
                 .map_err(|e| format!("Failed to query token pricing: {e}")),
+            TokenTask::QueryTokenStatuses(token_ids) => {
+                self.query_token_statuses(token_ids, sdk).await
+            }
         }
     }
 
@@ -2,15 +2,23 @@
 
 use crate::backend_task::{BackendTaskSuccessResult, NO_IDENTITIES_FOUND};
 use crate::context::AppContext;
+use crate::ui::tokens::tokens_screen::IdentityTokenIdentifier;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::tokens::identity_token_balances::{
     IdentityTokenBalances, IdentityTokenBalancesQuery,
 };
 use dash_sdk::platform::{FetchMany, Identifier};
 use dash_sdk::{Sdk, dpp::balances::credits::TokenAmount};
+use futures::future;
+use futures::stream::{self, StreamExt};
 
 use crate::app::TaskResult;
 
+/// Upper bound on balances fetched at the same time by "Check all", so large batches don't
+/// overwhelm DAPI
+const MAX_CONCURRENT_BALANCE_QUERIES: usize = 8;
+
 impl AppContext {
     pub async fn query_my_token_balances(
         &self,
@@ -139,4 +147,54 @@ impl AppContext {
             "Successfully fetched token balances".to_string(),
         ))
     }
+
+    /// Fetches the balances of several identity and token pairs, at most
+    /// `MAX_CONCURRENT_BALANCE_QUERIES` at once. The screen is refreshed after each balance, so
+    /// results show up as they arrive. A failed pair doesn't stop the others; the error lists
+    /// the identities whose balance couldn't be fetched.
+    pub async fn query_token_balances(
+        &self,
+        sdk: &Sdk,
+        identity_token_pairs: &[IdentityTokenIdentifier],
+        sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let failures: Vec<String> = stream::iter(identity_token_pairs)
+            .map(|identity_token_pair| {
+                let sender = sender.clone();
+                async move {
+                    self.query_token_balance(
+                        sdk,
+                        identity_token_pair.identity_id,
+                        identity_token_pair.token_id,
+                        sender,
+                    )
+                    .await
+                    .err()
+                    .map(|e| {
+                        format!(
+                            "identity {}: {}",
+                            identity_token_pair.identity_id.to_string(Encoding::Base58),
+                            e
+                        )
+                    })
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_BALANCE_QUERIES)
+            .filter_map(future::ready)
+            .collect()
+            .await;
+
+        if failures.is_empty() {
+            Ok(BackendTaskSuccessResult::Message(
+                "Successfully fetched token balances".to_string(),
+            ))
+        } else {
+            Err(format!(
+                "{} of {} token balances failed to fetch: {}",
+                failures.len(),
+                identity_token_pairs.len(),
+                failures.join("; ")
+            ))
+        }
+    }
 }
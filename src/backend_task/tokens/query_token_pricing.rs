@@ -4,6 +4,7 @@ use crate::context::AppContext;
 use dash_sdk::Sdk;
 use dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
 use dash_sdk::platform::{FetchMany, Identifier};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 impl AppContext {
     pub async fn query_token_pricing(
@@ -17,6 +18,18 @@ impl AppContext {
             Ok(pricing_data) => {
                 // Check if we got any pricing data for this token
                 if let Some((_, pricing_option)) = pricing_data.into_iter().next() {
+                    if let Some(schedule) = pricing_option.as_ref() {
+                        let now_ms = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map(|d| d.as_millis() as u64)
+                            .unwrap_or_default();
+                        if let Err(e) = self
+                            .db
+                            .insert_token_price_history_entry(&token_id, now_ms, schedule, self)
+                        {
+                            tracing::error!("Error recording token price history: {}", e);
+                        }
+                    }
                     Ok(BackendTaskSuccessResult::TokenPricing {
                         token_id,
                         prices: pricing_option,
@@ -0,0 +1,35 @@
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use dash_sdk::Sdk;
+use dash_sdk::dpp::tokens::status::TokenStatus;
+use dash_sdk::dpp::tokens::status::v0::TokenStatusV0Accessors;
+use dash_sdk::platform::{FetchMany, Identifier};
+use std::collections::BTreeMap;
+
+impl AppContext {
+    /// Fetches whether each of the given tokens is currently paused.
+    ///
+    /// Tokens without a status on Platform have never been paused and are reported as active.
+    pub async fn query_token_statuses(
+        &self,
+        token_ids: &[Identifier],
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let statuses = TokenStatus::fetch_many(sdk, token_ids)
+            .await
+            .map_err(|e| format!("Failed to fetch token statuses: {}", e))?;
+
+        let paused = token_ids
+            .iter()
+            .map(|token_id| {
+                let is_paused = statuses
+                    .get(token_id)
+                    .and_then(|status| status.as_ref())
+                    .is_some_and(|status| status.paused());
+                (*token_id, is_paused)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(BackendTaskSuccessResult::TokenPausedStatuses(paused))
+    }
+}
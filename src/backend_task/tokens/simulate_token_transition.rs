@@ -0,0 +1,130 @@
+use super::TokenTask;
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use crate::ui::helpers::TransactionType;
+use dash_sdk::Sdk;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::serialization::PlatformSerializable;
+
+impl AppContext {
+    /// Builds and signs the transition of a token action without broadcasting it. Signing
+    /// runs the same local checks as a real action, so an invalid transition is reported as an
+    /// error. The fee is the minimum Platform charges for it, as the actual fee also depends
+    /// on the processing and storage it causes. Signing takes a nonce from the SDK's nonce
+    /// cache, so the cache is refreshed afterwards to keep the next real action on Platform's
+    /// nonce.
+    pub async fn simulate_token_transition(
+        &self,
+        task: &TokenTask,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let platform_version = self.platform_version();
+        let (transition, signer_id, state_transition) = match task {
+            TokenTask::MintTokens {
+                sending_identity,
+                data_contract,
+                token_position,
+                signing_key,
+                public_note,
+                amount,
+                recipient_id,
+                group_info,
+            } => (
+                "Mint Tokens",
+                sending_identity.identity.id(),
+                self.mint_tokens_builder(
+                    sending_identity,
+                    data_contract.clone(),
+                    *token_position,
+                    public_note.clone(),
+                    *amount,
+                    *recipient_id,
+                    *group_info,
+                )
+                .sign(sdk, signing_key, sending_identity, platform_version)
+                .await,
+            ),
+            TokenTask::BurnTokens {
+                owner_identity,
+                data_contract,
+                token_position,
+                signing_key,
+                public_note,
+                amount,
+                group_info,
+            } => (
+                "Burn Tokens",
+                owner_identity.identity.id(),
+                self.burn_tokens_builder(
+                    owner_identity,
+                    data_contract.clone(),
+                    *token_position,
+                    public_note.clone(),
+                    *amount,
+                    *group_info,
+                )
+                .sign(sdk, signing_key, owner_identity, platform_version)
+                .await,
+            ),
+            TokenTask::FreezeTokens {
+                actor_identity,
+                data_contract,
+                token_position,
+                signing_key,
+                public_note,
+                freeze_identity,
+                group_info,
+            } => (
+                "Freeze Tokens",
+                actor_identity.identity.id(),
+                self.freeze_tokens_builder(
+                    actor_identity,
+                    data_contract.clone(),
+                    *token_position,
+                    public_note.clone(),
+                    *freeze_identity,
+                    *group_info,
+                )
+                .sign(sdk, signing_key, actor_identity, platform_version)
+                .await,
+            ),
+            TokenTask::UpdateTokenConfig {
+                identity_token_info,
+                change_item,
+                signing_key,
+                public_note,
+                group_info,
+            } => {
+                let (builder, identity) = self.token_config_update_builder(
+                    identity_token_info,
+                    change_item.clone(),
+                    public_note.clone(),
+                    *group_info,
+                )?;
+                (
+                    "Token Config Update",
+                    identity.identity.id(),
+                    builder
+                        .sign(sdk, signing_key, &identity, platform_version)
+                        .await,
+                )
+            }
+            _ => return Err("This token action can't be simulated".to_string()),
+        };
+
+        sdk.refresh_identity_nonce(&signer_id).await;
+
+        let state_transition =
+            state_transition.map_err(|e| format!("Invalid {} transition: {}", transition, e))?;
+        let size_bytes = state_transition
+            .serialize_to_bytes()
+            .map_err(|e| format!("Error serializing {} transition: {}", transition, e))?
+            .len();
+
+        Ok(BackendTaskSuccessResult::SimulationResult {
+            transition: transition.to_string(),
+            size_bytes,
+            minimum_fee: TransactionType::TokenAction.estimated_fee(platform_version),
+        })
+    }
+}
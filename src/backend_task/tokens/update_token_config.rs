@@ -1,6 +1,7 @@
 use super::BackendTaskSuccessResult;
 use crate::context::AppContext;
 use crate::model::proof_log_item::{ProofLogItem, RequestType};
+use crate::model::qualified_identity::QualifiedIdentity;
 use crate::ui::tokens::tokens_screen::IdentityTokenInfo;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
@@ -17,21 +18,15 @@ use dash_sdk::{
 use std::sync::Arc;
 
 impl AppContext {
-    pub async fn update_token_config(
+    /// The builder of a Token Config Update transition, and the identity signing it, shared
+    /// with its simulation
+    pub(super) fn token_config_update_builder(
         &self,
-        identity_token_info: IdentityTokenInfo,
+        identity_token_info: &IdentityTokenInfo,
         change_item: TokenConfigurationChangeItem,
-        signing_key: &IdentityPublicKey,
         public_note: Option<String>,
         group_info: Option<GroupStateTransitionInfoStatus>,
-        sdk: &Sdk,
-    ) -> Result<BackendTaskSuccessResult, String> {
-        tracing::trace!(
-            ?group_info,
-            ?identity_token_info,
-            ?change_item,
-            "Updating token config for a token",
-        );
+    ) -> Result<(TokenConfigUpdateTransitionBuilder, QualifiedIdentity), String> {
         // Get the existing contract and identity for building the state transition
         // First, fetch the contract from the local database
         let existing_data_contract = &self
@@ -75,7 +70,7 @@ impl AppContext {
             data_contract_arc,
             identity_token_info.token_position,
             identity_token_info.identity.identity.id(),
-            change_item.clone(),
+            change_item,
         );
 
         // Add the optional public note
@@ -91,6 +86,31 @@ impl AppContext {
             builder = builder.with_state_transition_creation_options(options);
         }
 
+        Ok((builder, identity))
+    }
+
+    pub async fn update_token_config(
+        &self,
+        identity_token_info: IdentityTokenInfo,
+        change_item: TokenConfigurationChangeItem,
+        signing_key: &IdentityPublicKey,
+        public_note: Option<String>,
+        group_info: Option<GroupStateTransitionInfoStatus>,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        tracing::trace!(
+            ?group_info,
+            ?identity_token_info,
+            ?change_item,
+            "Updating token config for a token",
+        );
+        let (builder, identity) = self.token_config_update_builder(
+            &identity_token_info,
+            change_item.clone(),
+            public_note,
+            group_info,
+        )?;
+
         // Sign the state transition
         let state_transition = builder
             .sign(sdk, signing_key, &identity, self.platform_version())
@@ -1,16 +1,26 @@
 use crate::app_dir::core_cookie_path;
-use crate::backend_task::contested_names::ScheduledDPNSVote;
+use crate::backend_task::activity::TaskActivity;
+use crate::backend_task::contested_names::{BulkVoteProgress, ScheduledDPNSVote};
+use crate::backend_task::system_task::PlatformConnectionStatus;
+use crate::backend_task::{BackendTask, DEFAULT_BACKEND_TASK_RETRIES, RefreshAllProgress};
 use crate::components::core_zmq_listener::ZMQConnectionEvent;
 use crate::config::{Config, NetworkConfig};
 use crate::context_provider::Provider;
 use crate::database::Database;
+use crate::database::activity_log::MAX_ACTIVITY_LOG_ITEMS;
 use crate::model::contested_name::ContestedName;
+use crate::model::identity_tag::IdentityTag;
 use crate::model::password_info::PasswordInfo;
 use crate::model::qualified_contract::QualifiedContract;
 use crate::model::qualified_identity::{DPNSNameInfo, QualifiedIdentity};
-use crate::model::wallet::{Wallet, WalletSeedHash};
+use crate::model::wallet::{DEFAULT_WALLET_AUTO_LOCK_MINUTES, Wallet, WalletSeedHash};
 use crate::sdk_wrapper::initialize_sdk;
 use crate::ui::RootScreenType;
+use crate::ui::components::low_balance_banner::DEFAULT_LOW_BALANCE_WARNING_CREDITS;
+use crate::ui::components::mainnet_confirmation::{
+    IrreversibleAction, MainnetConfirmationSettings,
+};
+use crate::ui::helpers::TransactionType;
 use crate::ui::tokens::tokens_screen::{IdentityTokenBalance, IdentityTokenIdentifier};
 use crate::utils::tasks::TaskManager;
 use bincode::config;
@@ -22,9 +32,11 @@ use dash_sdk::dpp::dashcore::hashes::Hash;
 use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload::AssetLockPayloadType;
 use dash_sdk::dpp::dashcore::{Address, Network, OutPoint, TxOut, Txid};
 use dash_sdk::dpp::data_contract::TokenConfiguration;
+use dash_sdk::dpp::fee::Credits;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::state_transition::asset_lock_proof::InstantAssetLockProof;
 use dash_sdk::dpp::identity::state_transition::asset_lock_proof::chain::ChainAssetLockProof;
+use dash_sdk::dpp::identity::{KeyID, TimestampMillis};
 use dash_sdk::dpp::prelude::{AssetLockProof, CoreBlockHeight};
 use dash_sdk::dpp::state_transition::StateTransitionSigningOptions;
 use dash_sdk::dpp::state_transition::batch_transition::methods::StateTransitionCreationOptions;
@@ -38,8 +50,11 @@ use egui::Context;
 use rusqlite::Result;
 use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU16, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio_util::sync::CancellationToken;
+use zeroize::Zeroize;
 
 const ANIMATION_REFRESH_TIME: std::time::Duration = std::time::Duration::from_millis(100);
 
@@ -52,6 +67,8 @@ pub struct AppContext {
     pub(crate) db: Arc<Database>,
     pub(crate) sdk: RwLock<Sdk>,
     pub(crate) config: RwLock<NetworkConfig>,
+    /// DAPI addresses from the config file, used again when custom ones are removed
+    default_dapi_addresses: String,
     pub(crate) rx_zmq_status: Receiver<ZMQConnectionEvent>,
     pub(crate) sx_zmq_status: Sender<ZMQConnectionEvent>,
     pub(crate) zmq_connection_status: Mutex<ZMQConnectionEvent>,
@@ -65,6 +82,35 @@ pub struct AppContext {
     #[allow(dead_code)] // May be used for password validation
     pub(crate) password_info: Option<PasswordInfo>,
     pub(crate) transactions_waiting_for_finality: Mutex<BTreeMap<Txid, Option<AssetLockProof>>>,
+    /// Key each identity last signed a given kind of transaction with, mirrored from the database
+    signing_key_preferences: Mutex<HashMap<(Identifier, TransactionType), KeyID>>,
+    /// Whether irreversible actions need a second confirmation on mainnet
+    mainnet_confirmation: Mutex<MainnetConfirmationSettings>,
+    /// User-defined identity tags and the tag name of each tagged identity, mirrored from the
+    /// database
+    identity_tags: Mutex<(Vec<IdentityTag>, HashMap<Identifier, String>)>,
+    /// Cancellation tokens of the scheduled vote casts in flight, by voter and contested name
+    pub(crate) scheduled_vote_casts: Mutex<HashMap<(Identifier, String), CancellationToken>>,
+    /// How many times read-only backend tasks failing with a transient error are retried
+    backend_task_retries: AtomicU8,
+    /// Minutes without user interaction after which unlocked wallets are locked, 0 for never
+    wallet_auto_lock_minutes: AtomicU16,
+    /// Credit balance under which action screens warn about the signing identity, 0 for never
+    low_balance_warning_credits: AtomicU64,
+    /// Minutes before their end active contests are highlighted, 0 for never
+    contest_ending_soon_minutes: AtomicU16,
+    /// Whether the contests are refreshed automatically while one of them ends soon
+    contest_auto_refresh: AtomicBool,
+    /// Progress of the running "Refresh All" batch, if any
+    refresh_all: Mutex<Option<RefreshAllProgress>>,
+    /// Tasks submitted this session by the id of their activity log entry, to run them again.
+    /// Only the newest entries the activity log screen loads are kept.
+    activity_tasks: Mutex<BTreeMap<i64, BackendTask>>,
+    /// Reachability of the DAPI endpoints, from the last health check
+    pub(crate) platform_connection_status: Mutex<PlatformConnectionStatus>,
+    /// When to clear the clipboard, with the copied secret. Checked by the app every frame,
+    /// whichever screen is shown.
+    clipboard_clear_at: Mutex<Option<(Instant, String)>>,
     /// Whether to animate the UI elements.
     ///
     /// This is used to control animations in the UI, such as loading spinners or transitions.
@@ -89,7 +135,13 @@ impl AppContext {
             }
         };
 
-        let network_config = config.config_for_network(network).clone()?;
+        let mut network_config = config.config_for_network(network).clone()?;
+        let default_dapi_addresses = network_config.dapi_addresses.clone();
+        match db.get_custom_dapi_addresses(network) {
+            Ok(Some(addresses)) => network_config.dapi_addresses = addresses.join(","),
+            Ok(None) => {}
+            Err(e) => tracing::error!("Failed to load custom DAPI addresses: {}", e),
+        }
         let (sx_zmq_status, rx_zmq_status) = crossbeam_channel::unbounded();
 
         // we create provider, but we need to set app context to it later, as we have a circular dependency
@@ -148,6 +200,53 @@ impl AppContext {
             .map(|w| (w.seed_hash(), Arc::new(RwLock::new(w))))
             .collect();
 
+        let signing_key_preferences =
+            db.get_signing_key_preferences(&network)
+                .unwrap_or_else(|e| {
+                    tracing::error!("Failed to load signing key preferences: {}", e);
+                    HashMap::new()
+                });
+
+        let mainnet_confirmation = db.get_mainnet_confirmation_settings().unwrap_or_else(|e| {
+            tracing::error!("Failed to load mainnet confirmation settings: {}", e);
+            MainnetConfirmationSettings::default()
+        });
+
+        let backend_task_retries = db.get_backend_task_retries().unwrap_or_else(|e| {
+            tracing::error!("Failed to load backend task retries: {}", e);
+            DEFAULT_BACKEND_TASK_RETRIES
+        });
+
+        let wallet_auto_lock_minutes = db.get_wallet_auto_lock_minutes().unwrap_or_else(|e| {
+            tracing::error!("Failed to load wallet auto-lock timeout: {}", e);
+            DEFAULT_WALLET_AUTO_LOCK_MINUTES
+        });
+
+        let low_balance_warning_credits =
+            db.get_low_balance_warning_credits().unwrap_or_else(|e| {
+                tracing::error!("Failed to load low balance warning threshold: {}", e);
+                DEFAULT_LOW_BALANCE_WARNING_CREDITS
+            });
+
+        let contest_ending_soon_minutes =
+            db.get_contest_ending_soon_minutes().unwrap_or_else(|e| {
+                tracing::error!("Failed to load contest ending soon window: {}", e);
+                0
+            });
+
+        let contest_auto_refresh = db.get_contest_auto_refresh().unwrap_or_else(|e| {
+            tracing::error!("Failed to load contest auto-refresh setting: {}", e);
+            false
+        });
+
+        let identity_tags = db
+            .get_identity_tags(&network)
+            .and_then(|tags| Ok((tags, db.get_identity_tag_assignments(&network)?)))
+            .unwrap_or_else(|e| {
+                tracing::error!("Failed to load identity tags: {}", e);
+                (Vec::new(), HashMap::new())
+            });
+
         let animate = match config.developer_mode.unwrap_or(false) {
             true => {
                 tracing::debug!("developer_mode is enabled, disabling animations");
@@ -163,6 +262,7 @@ impl AppContext {
             db,
             sdk: sdk.into(),
             config: network_config.into(),
+            default_dapi_addresses,
             sx_zmq_status,
             rx_zmq_status,
             dpns_contract: Arc::new(dpns_contract),
@@ -174,6 +274,19 @@ impl AppContext {
             wallets: RwLock::new(wallets),
             password_info,
             transactions_waiting_for_finality: Mutex::new(BTreeMap::new()),
+            signing_key_preferences: Mutex::new(signing_key_preferences),
+            mainnet_confirmation: Mutex::new(mainnet_confirmation),
+            identity_tags: Mutex::new(identity_tags),
+            scheduled_vote_casts: Mutex::new(HashMap::new()),
+            backend_task_retries: AtomicU8::new(backend_task_retries),
+            wallet_auto_lock_minutes: AtomicU16::new(wallet_auto_lock_minutes),
+            low_balance_warning_credits: AtomicU64::new(low_balance_warning_credits),
+            contest_ending_soon_minutes: AtomicU16::new(contest_ending_soon_minutes),
+            contest_auto_refresh: AtomicBool::new(contest_auto_refresh),
+            refresh_all: Mutex::new(None),
+            activity_tasks: Mutex::new(BTreeMap::new()),
+            platform_connection_status: Mutex::new(PlatformConnectionStatus::default()),
+            clipboard_clear_at: Mutex::new(None),
             zmq_connection_status: Mutex::new(ZMQConnectionEvent::Disconnected),
             animate,
             subtasks,
@@ -192,6 +305,308 @@ impl AppContext {
         self.animate.store(animate, Ordering::Relaxed);
     }
 
+    /// Returns the id of the key `identity_id` last signed `transaction_type` with, if any.
+    pub fn remembered_signing_key_id(
+        &self,
+        identity_id: &Identifier,
+        transaction_type: TransactionType,
+    ) -> Option<KeyID> {
+        self.signing_key_preferences
+            .lock()
+            .unwrap()
+            .get(&(*identity_id, transaction_type))
+            .copied()
+    }
+
+    /// Remembers the key `identity_id` chose to sign `transaction_type` with.
+    pub fn remember_signing_key(
+        &self,
+        identity_id: Identifier,
+        transaction_type: TransactionType,
+        key_id: KeyID,
+    ) -> Result<()> {
+        self.db.set_signing_key_preference(
+            &identity_id,
+            transaction_type,
+            key_id,
+            &self.network,
+        )?;
+        self.signing_key_preferences
+            .lock()
+            .unwrap()
+            .insert((identity_id, transaction_type), key_id);
+        Ok(())
+    }
+
+    pub fn mainnet_confirmation_settings(&self) -> MainnetConfirmationSettings {
+        self.mainnet_confirmation.lock().unwrap().clone()
+    }
+
+    pub fn update_mainnet_confirmation_settings(
+        &self,
+        settings: MainnetConfirmationSettings,
+    ) -> Result<()> {
+        self.db.update_mainnet_confirmation_settings(&settings)?;
+        *self.mainnet_confirmation.lock().unwrap() = settings;
+        Ok(())
+    }
+
+    pub fn backend_task_retries(&self) -> u8 {
+        self.backend_task_retries.load(Ordering::Relaxed)
+    }
+
+    pub fn update_backend_task_retries(&self, retries: u8) -> Result<()> {
+        self.db.update_backend_task_retries(retries)?;
+        self.backend_task_retries.store(retries, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Idle minutes after which unlocked wallets are locked, 0 if they never are
+    pub fn wallet_auto_lock_minutes(&self) -> u16 {
+        self.wallet_auto_lock_minutes.load(Ordering::Relaxed)
+    }
+
+    pub fn update_wallet_auto_lock_minutes(&self, minutes: u16) -> Result<()> {
+        self.db.update_wallet_auto_lock_minutes(minutes)?;
+        self.wallet_auto_lock_minutes
+            .store(minutes, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Credit balance under which action screens warn about the signing identity, 0 if they
+    /// never do
+    pub fn low_balance_warning_credits(&self) -> Credits {
+        self.low_balance_warning_credits.load(Ordering::Relaxed)
+    }
+
+    pub fn update_low_balance_warning_credits(&self, credits: Credits) -> Result<()> {
+        self.db.update_low_balance_warning_credits(credits)?;
+        self.low_balance_warning_credits
+            .store(credits, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Minutes before their end active contests are highlighted, 0 if they never are
+    pub fn contest_ending_soon_minutes(&self) -> u16 {
+        self.contest_ending_soon_minutes.load(Ordering::Relaxed)
+    }
+
+    pub fn update_contest_ending_soon_minutes(&self, minutes: u16) -> Result<()> {
+        self.db.update_contest_ending_soon_minutes(minutes)?;
+        self.contest_ending_soon_minutes
+            .store(minutes, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Whether the contests are refreshed automatically while one of them ends soon
+    pub fn contest_auto_refresh(&self) -> bool {
+        self.contest_auto_refresh.load(Ordering::Relaxed)
+    }
+
+    pub fn update_contest_auto_refresh(&self, enabled: bool) -> Result<()> {
+        self.db.update_contest_auto_refresh(enabled)?;
+        self.contest_auto_refresh.store(enabled, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Starts tracking a "Refresh All" batch of `tasks`. Returns false, leaving the running
+    /// batch alone, if one is already in progress.
+    pub fn start_refresh_all(&self, tasks: &[BackendTask]) -> bool {
+        let mut refresh_all = self.refresh_all.lock().unwrap();
+        if refresh_all.is_some() {
+            return false;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        *refresh_all = Some(RefreshAllProgress::new(tasks, now));
+        true
+    }
+
+    pub fn refresh_all_progress(&self) -> Option<RefreshAllProgress> {
+        self.refresh_all.lock().unwrap().clone()
+    }
+
+    /// Whether `task` is part of the running "Refresh All" batch and hasn't finished yet
+    pub fn is_refresh_all_pending(&self, task: &BackendTask) -> bool {
+        self.refresh_all
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|progress| progress.is_pending(task))
+    }
+
+    /// When the running "Refresh All" batch started, if `task` is still pending in it. Lets
+    /// screens show their refreshing indicator for the tasks the batch runs on their behalf.
+    pub fn refresh_all_pending_since(&self, task: &BackendTask) -> Option<u64> {
+        self.refresh_all
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|progress| progress.is_pending(task))
+            .map(|progress| progress.started_at)
+    }
+
+    pub(crate) fn refresh_all_task_finished(&self, task: &BackendTask) {
+        let mut refresh_all = self.refresh_all.lock().unwrap();
+        if refresh_all
+            .as_mut()
+            .is_some_and(|progress| progress.task_finished(task))
+        {
+            *refresh_all = None;
+        }
+    }
+
+    /// Records a submitted state transition in the activity log, `error` being what it failed
+    /// with, and keeps its task to run it again.
+    pub fn record_activity(&self, activity: &TaskActivity, task: BackendTask, error: Option<&str>) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as u64;
+        match self
+            .db
+            .insert_activity_log_item(activity, now, error, self.network)
+        {
+            Ok(id) => {
+                let mut activity_tasks = self.activity_tasks.lock().unwrap();
+                activity_tasks.insert(id, task);
+                while activity_tasks.len() > MAX_ACTIVITY_LOG_ITEMS as usize {
+                    activity_tasks.pop_first();
+                }
+            }
+            Err(e) => tracing::error!("Error recording activity {}: {}", activity.action, e),
+        }
+    }
+
+    /// Task of an activity log entry, if it was submitted this session
+    pub fn activity_task(&self, id: i64) -> Option<BackendTask> {
+        self.activity_tasks.lock().unwrap().get(&id).cloned()
+    }
+
+    pub fn clear_activity_log(&self) -> Result<()> {
+        self.db.clear_activity_log(self.network)?;
+        self.activity_tasks.lock().unwrap().clear();
+        Ok(())
+    }
+
+    /// Schedules clearing `secret`, just copied, from the clipboard after `delay`. Replaces
+    /// the secret scheduled before, erasing it from memory.
+    pub fn schedule_clipboard_clear(&self, secret: String, delay: Duration) {
+        let mut clipboard_clear_at = self.clipboard_clear_at.lock().unwrap();
+        if let Some((_, previous)) = clipboard_clear_at.as_mut() {
+            previous.zeroize();
+        }
+        *clipboard_clear_at = Some((Instant::now() + delay, secret));
+    }
+
+    /// Clears the clipboard once the scheduled secret has been there long enough, unless
+    /// something else was copied since. Returns how long until the pending clear is due.
+    pub fn clear_clipboard_if_due(&self) -> Option<Duration> {
+        let mut clipboard_clear_at = self.clipboard_clear_at.lock().unwrap();
+        let (clear_at, secret) = clipboard_clear_at.as_mut()?;
+        let now = Instant::now();
+        if now < *clear_at {
+            return Some(*clear_at - now);
+        }
+
+        let cleared = arboard::Clipboard::new().and_then(|mut clipboard| {
+            if clipboard.get_text().is_ok_and(|text| text == *secret) {
+                clipboard.clear()
+            } else {
+                Ok(())
+            }
+        });
+        if let Err(e) = cleared {
+            tracing::warn!("Failed to clear the clipboard: {}", e);
+        }
+        secret.zeroize();
+        *clipboard_clear_at = None;
+        None
+    }
+
+    /// Locks every unlocked password-protected wallet, erasing its decrypted seed from memory.
+    /// Wallets in use by a background task are left for the next call.
+    ///
+    /// Returns how many wallets were locked.
+    pub fn lock_wallets(&self) -> usize {
+        let mut locked = 0;
+        for wallet in self.wallets.read().unwrap().values() {
+            let Ok(mut wallet) = wallet.try_write() else {
+                continue;
+            };
+            if wallet.uses_password && wallet.is_open() {
+                wallet.wallet_seed.close();
+                locked += 1;
+            }
+        }
+        locked
+    }
+
+    /// Whether `action` needs a second confirmation. Only mainnet actions ever do.
+    pub fn requires_mainnet_confirmation(&self, action: IrreversibleAction) -> bool {
+        if self.network != Network::Dash {
+            return false;
+        }
+        let settings = self.mainnet_confirmation.lock().unwrap();
+        settings.enabled && settings.actions.contains(&action)
+    }
+
+    /// All identity tags, sorted by name
+    pub fn identity_tags(&self) -> Vec<IdentityTag> {
+        self.identity_tags.lock().unwrap().0.clone()
+    }
+
+    /// The tag assigned to `identity_id`, if any
+    pub fn identity_tag(&self, identity_id: &Identifier) -> Option<IdentityTag> {
+        let identity_tags = self.identity_tags.lock().unwrap();
+        let (tags, assignments) = &*identity_tags;
+        let tag_name = assignments.get(identity_id)?;
+        tags.iter().find(|tag| &tag.name == tag_name).cloned()
+    }
+
+    /// Adds a tag, or changes the color of the existing tag with the same name.
+    pub fn save_identity_tag(&self, tag: IdentityTag) -> Result<()> {
+        self.db.save_identity_tag(&tag, &self.network)?;
+        let mut identity_tags = self.identity_tags.lock().unwrap();
+        let tags = &mut identity_tags.0;
+        match tags.iter_mut().find(|existing| existing.name == tag.name) {
+            Some(existing) => *existing = tag,
+            None => {
+                tags.push(tag);
+                tags.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Deletes a tag and untags the identities that had it.
+    pub fn delete_identity_tag(&self, name: &str) -> Result<()> {
+        self.db.delete_identity_tag(name, &self.network)?;
+        let mut identity_tags = self.identity_tags.lock().unwrap();
+        let (tags, assignments) = &mut *identity_tags;
+        tags.retain(|tag| tag.name != name);
+        assignments.retain(|_, tag_name| tag_name != name);
+        Ok(())
+    }
+
+    /// Tags `identity_id` with the tag called `tag_name`, or untags it when `None`.
+    pub fn set_identity_tag(
+        &self,
+        identity_id: Identifier,
+        tag_name: Option<String>,
+    ) -> Result<()> {
+        self.db
+            .set_identity_tag_assignment(&identity_id, tag_name.as_deref(), &self.network)?;
+        let mut identity_tags = self.identity_tags.lock().unwrap();
+        match tag_name {
+            Some(tag_name) => identity_tags.1.insert(identity_id, tag_name),
+            None => identity_tags.1.remove(&identity_id),
+        };
+        Ok(())
+    }
+
     pub fn enable_developer_mode(&self, enable: bool) {
         self.developer_mode.store(enable, Ordering::Relaxed);
         // Animations are reverse of developer mode
@@ -251,12 +666,7 @@ impl AppContext {
         )
         .map_err(|e| format!("Failed to create new Core RPC client: {e}"))?;
 
-        // 3. Rebuild the Sdk with the updated config
-        let provider = Provider::new(self.db.clone(), self.network, &cfg)
-            .map_err(|e| format!("Failed to init provider: {e}"))?;
-        let new_sdk = initialize_sdk(&cfg, self.network, provider.clone());
-
-        // 4. Swap them in
+        // 3. Swap it in
         {
             let mut client_lock = self
                 .core_client
@@ -264,6 +674,21 @@ impl AppContext {
                 .expect("Core client lock was poisoned");
             *client_lock = new_client;
         }
+
+        // 4. Rebuild the Sdk with the updated config
+        self.reinit_sdk()
+    }
+
+    /// Rebuild the `Sdk` from `self.config`, with fresh connections to the DAPI endpoints.
+    /// Tasks read the SDK when they start, so the ones started afterwards use the new one.
+    pub fn reinit_sdk(self: Arc<Self>) -> Result<(), String> {
+        let cfg = {
+            let cfg_lock = self.config.read().unwrap();
+            cfg_lock.clone()
+        };
+        let provider = Provider::new(self.db.clone(), self.network, &cfg)
+            .map_err(|e| format!("Failed to init provider: {e}"))?;
+        let new_sdk = initialize_sdk(&cfg, self.network, provider.clone());
         {
             let mut sdk_lock = self.sdk.write().unwrap();
             *sdk_lock = new_sdk;
@@ -275,6 +700,42 @@ impl AppContext {
         Ok(())
     }
 
+    /// DAPI addresses the SDK connects to, custom or from the config file
+    pub fn dapi_addresses(&self) -> Vec<String> {
+        self.config
+            .read()
+            .unwrap()
+            .dapi_addresses
+            .split(',')
+            .map(|address| address.trim().to_string())
+            .filter(|address| !address.is_empty())
+            .collect()
+    }
+
+    /// Whether the DAPI addresses were replaced with custom ones
+    pub fn has_custom_dapi_addresses(&self) -> bool {
+        self.config.read().unwrap().dapi_addresses != self.default_dapi_addresses
+    }
+
+    /// Saves `addresses` as the DAPI addresses of this network, or goes back to the ones from
+    /// the config file when `None`, then rebuilds the SDK to connect to them.
+    pub fn update_custom_dapi_addresses(
+        self: &Arc<Self>,
+        addresses: Option<&[String]>,
+    ) -> Result<(), String> {
+        self.db
+            .set_custom_dapi_addresses(self.network, addresses)
+            .map_err(|e| format!("Failed to save custom DAPI addresses: {e}"))?;
+        {
+            let mut config = self.config.write().unwrap();
+            config.dapi_addresses = match addresses {
+                Some(addresses) => addresses.join(","),
+                None => self.default_dapi_addresses.clone(),
+            };
+        }
+        Arc::clone(self).reinit_sdk()
+    }
+
     /// Inserts a local qualified identity into the database
     pub fn insert_local_qualified_identity(
         &self,
@@ -433,6 +894,24 @@ impl AppContext {
         self.db.clear_executed_scheduled_votes(self)
     }
 
+    /// Clears the scheduled votes whose contest ended before they were cast
+    pub fn clear_expired_scheduled_votes(&self) -> Result<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as TimestampMillis;
+        self.db.clear_expired_scheduled_votes(self, now)
+    }
+
+    /// End times of the contests known locally, by normalized contested name
+    pub fn contest_end_times(&self) -> Result<HashMap<String, TimestampMillis>> {
+        Ok(self
+            .all_contested_names()?
+            .into_iter()
+            .filter_map(|contest| Some((contest.normalized_contested_name, contest.end_time?)))
+            .collect())
+    }
+
     /// Deletes a scheduled vote from the database
     #[allow(clippy::ptr_arg)]
     pub fn delete_scheduled_vote(&self, identity_id: &[u8], contested_name: &String) -> Result<()> {
@@ -440,6 +919,43 @@ impl AppContext {
             .delete_scheduled_vote(self, identity_id, contested_name)
     }
 
+    /// Moves a scheduled vote to a new time, in milliseconds since the Unix epoch
+    pub fn update_scheduled_vote_time(
+        &self,
+        identity_id: &[u8],
+        contested_name: &str,
+        unix_timestamp: u64,
+    ) -> Result<()> {
+        self.db
+            .update_scheduled_vote_time(self, identity_id, contested_name, unix_timestamp)
+    }
+
+    /// Records the votes of an immediate bulk vote before they are cast
+    pub fn start_bulk_votes(&self, votes: &[BulkVoteProgress]) -> Result<()> {
+        self.db.start_bulk_votes(self, votes)
+    }
+
+    /// Records that a vote of a bulk vote went through
+    pub fn mark_bulk_vote_cast(&self, identity_id: &[u8], contested_name: &str) -> Result<()> {
+        self.db
+            .mark_bulk_vote_cast(self, identity_id, contested_name)
+    }
+
+    /// Forgets the progress of a bulk vote that ran to completion
+    pub fn finish_bulk_votes(&self, votes: &[BulkVoteProgress]) -> Result<()> {
+        self.db.finish_bulk_votes(self, votes)
+    }
+
+    /// Fetches the progress of bulk votes interrupted before they completed
+    pub fn get_bulk_vote_progress(&self) -> Result<Vec<BulkVoteProgress>> {
+        self.db.get_bulk_vote_progress(self)
+    }
+
+    /// Discards the progress of interrupted bulk votes
+    pub fn clear_bulk_vote_progress(&self) -> Result<()> {
+        self.db.clear_bulk_vote_progress(self)
+    }
+
     /// Marks a scheduled vote as executed in the database
     pub fn mark_vote_executed(&self, identity_id: &[u8], contested_name: String) -> Result<()> {
         self.db
@@ -0,0 +1,148 @@
+use crate::backend_task::activity::TaskActivity;
+use crate::database::Database;
+use crate::model::activity_log_item::ActivityLogItem;
+use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::platform::Identifier;
+use rusqlite::params;
+
+/// Most entries loaded for the activity log screen, newest first
+pub(crate) const MAX_ACTIVITY_LOG_ITEMS: u32 = 1000;
+
+impl Database {
+    pub fn initialize_activity_log_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS activity_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                time_ms INTEGER NOT NULL,
+                action TEXT NOT NULL,
+                identity_id BLOB,
+                target TEXT,
+                parameters TEXT NOT NULL,
+                error TEXT,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_activity_log_network_time ON activity_log (network, time_ms)",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Records a submitted state transition, returning the id of its entry.
+    pub fn insert_activity_log_item(
+        &self,
+        activity: &TaskActivity,
+        time_ms: u64,
+        error: Option<&str>,
+        network: Network,
+    ) -> rusqlite::Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO activity_log (time_ms, action, identity_id, target, parameters, error, network)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            params![
+                time_ms,
+                activity.action,
+                activity.identity_id.map(|id| id.to_vec()),
+                activity.target,
+                activity.parameters,
+                error,
+                network.to_string(),
+            ],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Loads the most recent entries of the activity log of `network`, newest first.
+    pub fn get_activity_log_items(
+        &self,
+        network: Network,
+    ) -> rusqlite::Result<Vec<ActivityLogItem>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, time_ms, action, identity_id, target, parameters, error FROM activity_log
+             WHERE network = ? ORDER BY time_ms DESC, id DESC LIMIT ?",
+        )?;
+        let items = stmt
+            .query_map(
+                params![network.to_string(), MAX_ACTIVITY_LOG_ITEMS],
+                |row| {
+                    let identity_id = row
+                        .get::<_, Option<Vec<u8>>>(3)?
+                        .map(Identifier::from_vec)
+                        .transpose()
+                        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+                    Ok(ActivityLogItem {
+                        id: row.get(0)?,
+                        time_ms: row.get(1)?,
+                        action: row.get(2)?,
+                        identity_id,
+                        target: row.get(4)?,
+                        parameters: row.get(5)?,
+                        error: row.get(6)?,
+                    })
+                },
+            )?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(items)
+    }
+
+    /// Deletes every entry of the activity log of `network`.
+    pub fn clear_activity_log(&self, network: Network) -> rusqlite::Result<()> {
+        self.execute(
+            "DELETE FROM activity_log WHERE network = ?",
+            params![network.to_string()],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_log_roundtrip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_file_path = temp_dir.path().join("test_data.db");
+        let db = Database::new(&db_file_path).unwrap();
+        db.initialize(&db_file_path).unwrap();
+
+        let activity = TaskActivity {
+            action: "Mint Tokens",
+            identity_id: Some(Identifier::new([1; 32])),
+            target: Some("token".to_string()),
+            parameters: "amount: 5".to_string(),
+        };
+        let first = db
+            .insert_activity_log_item(&activity, 1_000, None, Network::Testnet)
+            .unwrap();
+        let second = db
+            .insert_activity_log_item(&activity, 2_000, Some("no balance"), Network::Testnet)
+            .unwrap();
+        db.insert_activity_log_item(&activity, 3_000, None, Network::Dash)
+            .unwrap();
+
+        let items = db.get_activity_log_items(Network::Testnet).unwrap();
+        assert_eq!(
+            items.iter().map(|item| item.id).collect::<Vec<_>>(),
+            vec![second, first]
+        );
+        assert_eq!(items[0].error.as_deref(), Some("no balance"));
+        assert_eq!(items[1].identity_id, activity.identity_id);
+        assert_eq!(items[1].parameters, "amount: 5");
+
+        db.clear_activity_log(Network::Testnet).unwrap();
+        assert!(
+            db.get_activity_log_items(Network::Testnet)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(db.get_activity_log_items(Network::Dash).unwrap().len(), 1);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::context::AppContext;
 use crate::database::Database;
-use crate::model::contested_name::{ContestState, Contestant, ContestedName};
+use crate::model::contested_name::{
+    ContestLeadChange, ContestState, Contestant, ContestedName, WatchedName,
+};
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::data_contract::document_type::DocumentTypeRef;
 use dash_sdk::dpp::document::DocumentV0Getters;
@@ -10,7 +12,7 @@ use dash_sdk::dpp::prelude::{BlockHeight, CoreBlockHeight};
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
 use dash_sdk::dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
 use dash_sdk::query_types::Contenders;
-use rusqlite::{Result, params, params_from_iter};
+use rusqlite::{OptionalExtension, Result, params, params_from_iter};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::Duration;
 use tracing::{error, info};
@@ -383,7 +385,7 @@ impl Database {
                     let tx = conn.transaction()?;
                     tx.execute(
                         "UPDATE contested_name
-         SET awarded_to = ?, last_updated = ?, end_time = ?
+         SET awarded_to = ?, locked = 0, last_updated = ?, end_time = ?
          WHERE normalized_contested_name = ? AND network = ?",
                         params![
                             won_by.to_vec(),
@@ -401,7 +403,7 @@ impl Database {
                     let tx = conn.transaction()?;
                     tx.execute(
                         "UPDATE contested_name
-         SET locked = 1, last_updated = ?, end_time = ?
+         SET locked = 1, awarded_to = NULL, last_updated = ?, end_time = ?
          WHERE normalized_contested_name = ? AND network = ?",
                         params![
                             last_updated,
@@ -773,4 +775,173 @@ impl Database {
         info!("Vote tally updated successfully for '{}'", contested_name);
         Ok(())
     }
+
+    /// Creates the `dpns_watchlist` table, which holds the normalized names the user follows
+    /// whether or not they are contested.
+    ///
+    /// `contest_seen` is set once the user has been shown that a watched name is contested.
+    pub fn initialize_dpns_watchlist_table(&self, conn: &rusqlite::Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS dpns_watchlist (
+                normalized_name TEXT NOT NULL,
+                network TEXT NOT NULL,
+                contest_seen INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY(normalized_name, network)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Adds a name to the watchlist of the current network. Names already watched are left as is.
+    pub fn add_name_to_watchlist(
+        &self,
+        normalized_name: &str,
+        contest_seen: bool,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network.to_string();
+        self.execute(
+            "INSERT OR IGNORE INTO dpns_watchlist (normalized_name, network, contest_seen)
+             VALUES (?, ?, ?)",
+            params![normalized_name, network, contest_seen],
+        )?;
+        Ok(())
+    }
+
+    /// Removes a name from the watchlist of the current network.
+    pub fn remove_name_from_watchlist(
+        &self,
+        normalized_name: &str,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network.to_string();
+        self.execute(
+            "DELETE FROM dpns_watchlist WHERE normalized_name = ? AND network = ?",
+            params![normalized_name, network],
+        )?;
+        Ok(())
+    }
+
+    /// Records that the user has been shown the contests of the given watched names.
+    pub fn mark_watched_contests_seen(
+        &self,
+        normalized_names: &[String],
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network.to_string();
+        for normalized_name in normalized_names {
+            self.execute(
+                "UPDATE dpns_watchlist SET contest_seen = 1
+                 WHERE normalized_name = ? AND network = ?",
+                params![normalized_name, network],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Loads the watchlist of the current network, sorted by name.
+    pub fn get_dpns_watchlist(&self, app_context: &AppContext) -> Result<Vec<WatchedName>> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn.prepare(
+            "SELECT normalized_name, contest_seen FROM dpns_watchlist
+             WHERE network = ? ORDER BY normalized_name",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            Ok(WatchedName {
+                normalized_name: row.get(0)?,
+                contest_seen: row.get(1)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    /// Creates the `contest_lead_history` table, which holds the leader of each contest every
+    /// time it changed on a refresh of the contests. `leader_id` is `NULL` while nobody leads.
+    pub fn initialize_contest_lead_history_table(&self, conn: &rusqlite::Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS contest_lead_history (
+                normalized_contested_name TEXT NOT NULL,
+                network TEXT NOT NULL,
+                recorded_at INTEGER NOT NULL,
+                leader_id BLOB,
+                PRIMARY KEY(normalized_contested_name, network, recorded_at)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Records the current leader of each of the given contests, skipping the contests whose
+    /// leader didn't change since it was last recorded.
+    pub fn record_contest_leaders(
+        &self,
+        leaders: &[(String, Option<Identifier>)],
+        recorded_at: TimestampMillis,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network.to_string();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        for (normalized_contested_name, leader) in leaders {
+            let leader_id = leader.map(|leader| leader.to_vec());
+            let last_leader_id: Option<Option<Vec<u8>>> = tx
+                .query_row(
+                    "SELECT leader_id FROM contest_lead_history
+                     WHERE normalized_contested_name = ? AND network = ?
+                     ORDER BY recorded_at DESC LIMIT 1",
+                    params![normalized_contested_name, network],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            if last_leader_id.as_ref() == Some(&leader_id) {
+                continue;
+            }
+            tx.execute(
+                "INSERT OR REPLACE INTO contest_lead_history
+                 (normalized_contested_name, network, recorded_at, leader_id)
+                 VALUES (?, ?, ?, ?)",
+                params![normalized_contested_name, network, recorded_at, leader_id],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Loads the lead changes of every contest of the current network, keyed by normalized
+    /// contested name and ordered by when they were recorded.
+    pub fn get_contest_lead_history(
+        &self,
+        app_context: &AppContext,
+    ) -> Result<HashMap<String, Vec<ContestLeadChange>>> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn.prepare(
+            "SELECT normalized_contested_name, recorded_at, leader_id FROM contest_lead_history
+             WHERE network = ? ORDER BY recorded_at",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            let leader_id: Option<Vec<u8>> = row.get(2)?;
+            Ok((
+                row.get::<_, String>(0)?,
+                ContestLeadChange {
+                    recorded_at: row.get(1)?,
+                    leader: leader_id.and_then(|id| Identifier::from_bytes(&id).ok()),
+                },
+            ))
+        })?;
+
+        let mut history: HashMap<String, Vec<ContestLeadChange>> = HashMap::new();
+        for row in rows {
+            let (normalized_contested_name, change) = row?;
+            history
+                .entry(normalized_contested_name)
+                .or_default()
+                .push(change);
+        }
+        Ok(history)
+    }
 }
@@ -1,12 +1,13 @@
 use crate::context::AppContext;
 use crate::database::Database;
+use crate::model::identity_tag::IdentityTag;
 use crate::model::qualified_identity::{IdentityStatus, QualifiedIdentity};
 use crate::model::wallet::{Wallet, WalletSeedHash};
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::platform::Identifier;
 use rusqlite::{Connection, params};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
 impl Database {
@@ -511,6 +512,117 @@ impl Database {
         Ok(final_list)
     }
 
+    /// Creates the `identity_tag` table of user-defined tags and the `identity_tag_assignment`
+    /// table that links identities to them.
+    pub fn initialize_identity_tag_tables(&self, conn: &Connection) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS identity_tag (
+                name TEXT NOT NULL,
+                network TEXT NOT NULL,
+                color TEXT NOT NULL,
+                PRIMARY KEY(name, network)
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS identity_tag_assignment (
+                identity_id BLOB NOT NULL,
+                network TEXT NOT NULL,
+                tag_name TEXT NOT NULL,
+                PRIMARY KEY(identity_id, network)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Adds a tag, or updates the color of an existing tag with the same name.
+    pub fn save_identity_tag(&self, tag: &IdentityTag, network: &Network) -> rusqlite::Result<()> {
+        self.execute(
+            "INSERT OR REPLACE INTO identity_tag (name, network, color) VALUES (?, ?, ?)",
+            params![tag.name, network.to_string(), tag.color_hex()],
+        )?;
+        Ok(())
+    }
+
+    /// Deletes a tag and untags every identity that had it.
+    pub fn delete_identity_tag(&self, name: &str, network: &Network) -> rusqlite::Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let tx = conn.unchecked_transaction()?;
+        tx.execute(
+            "DELETE FROM identity_tag_assignment WHERE tag_name = ? AND network = ?",
+            params![name, network.to_string()],
+        )?;
+        tx.execute(
+            "DELETE FROM identity_tag WHERE name = ? AND network = ?",
+            params![name, network.to_string()],
+        )?;
+        tx.commit()
+    }
+
+    /// Tags an identity, or removes its tag when `tag_name` is `None`.
+    pub fn set_identity_tag_assignment(
+        &self,
+        identity_id: &Identifier,
+        tag_name: Option<&str>,
+        network: &Network,
+    ) -> rusqlite::Result<()> {
+        match tag_name {
+            Some(tag_name) => self.execute(
+                "INSERT OR REPLACE INTO identity_tag_assignment (identity_id, network, tag_name)
+                 VALUES (?, ?, ?)",
+                params![identity_id.to_vec(), network.to_string(), tag_name],
+            )?,
+            None => self.execute(
+                "DELETE FROM identity_tag_assignment WHERE identity_id = ? AND network = ?",
+                params![identity_id.to_vec(), network.to_string()],
+            )?,
+        };
+        Ok(())
+    }
+
+    /// Loads the tags on `network`, sorted by name. Tags with a malformed color are skipped.
+    pub fn get_identity_tags(&self, network: &Network) -> rusqlite::Result<Vec<IdentityTag>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT name, color FROM identity_tag WHERE network = ? ORDER BY name")?;
+        let rows = stmt.query_map(params![network.to_string()], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut tags = Vec::new();
+        for row in rows {
+            let (name, color) = row?;
+            if let Some(color) = IdentityTag::parse_color_hex(&color) {
+                tags.push(IdentityTag { name, color });
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Loads the name of the tag of every tagged identity on `network`.
+    pub fn get_identity_tag_assignments(
+        &self,
+        network: &Network,
+    ) -> rusqlite::Result<HashMap<Identifier, String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT identity_id, tag_name FROM identity_tag_assignment WHERE network = ?",
+        )?;
+        let rows = stmt.query_map(params![network.to_string()], |row| {
+            Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut assignments = HashMap::new();
+        for row in rows {
+            let (identity_id, tag_name) = row?;
+            if let Ok(identity_id) = Identifier::from_vec(identity_id) {
+                assignments.insert(identity_id, tag_name);
+            }
+        }
+        Ok(assignments)
+    }
+
     /// Fixes bug in identity table where network name for devnet was stored as `devnet:` instead of `devnet`.
     pub fn fix_identity_devnet_network_name(&self, conn: &Connection) -> rusqlite::Result<()> {
         const TABLES: [&str; 11] = [
@@ -4,7 +4,7 @@ use rusqlite::{Connection, params};
 use std::fs;
 use std::path::Path;
 
-pub const DEFAULT_DB_VERSION: u16 = 11;
+pub const DEFAULT_DB_VERSION: u16 = 31;
 
 pub const DEFAULT_NETWORK: &str = "dash";
 
@@ -34,6 +34,66 @@ impl Database {
 
     fn apply_version_changes(&self, version: u16, tx: &Connection) -> rusqlite::Result<()> {
         match version {
+            31 => {
+                self.add_default_vote_schedule_columns(tx)?;
+            }
+            30 => {
+                self.initialize_contest_lead_history_table(tx)?;
+            }
+            29 => {
+                self.initialize_custom_dapi_addresses_table(tx)?;
+            }
+            28 => {
+                self.add_contest_ending_soon_columns(tx)?;
+            }
+            27 => {
+                self.initialize_token_price_history_table(tx)?;
+            }
+            26 => {
+                self.initialize_activity_log_table(tx)?;
+            }
+            25 => {
+                self.initialize_column_width_preference_table(tx)?;
+            }
+            24 => {
+                self.add_low_balance_warning_credits_column(tx)?;
+            }
+            23 => {
+                self.add_wallet_auto_lock_minutes_column(tx)?;
+            }
+            22 => {
+                self.add_backend_task_retries_column(tx)?;
+            }
+            21 => {
+                self.initialize_sort_preference_table(tx)?;
+            }
+            20 => {
+                self.initialize_bulk_vote_progress_table(tx)?;
+            }
+            19 => {
+                self.initialize_identity_tag_tables(tx)?;
+            }
+            18 => {
+                self.add_mainnet_confirmation_columns(tx)?;
+            }
+            17 => {
+                self.initialize_signing_key_preference_table(tx)?;
+            }
+            16 => {
+                self.initialize_dpns_watchlist_table(tx)?;
+            }
+            15 => {
+                self.add_default_vote_choice_column(tx)?;
+            }
+            14 => {
+                self.initialize_token_search_list_table(tx)?;
+            }
+            13 => {
+                self.add_token_notes_column(tx)?;
+            }
+            12 => {
+                self.initialize_pinned_token_table(tx)?;
+            }
             11 => self.rename_identity_column_is_in_creation_to_status(tx)?,
             10 => {
                 self.add_theme_preference_column(tx)?;
@@ -216,6 +276,16 @@ impl Database {
             custom_dash_qt_path TEXT,
             overwrite_dash_conf INTEGER,
             theme_preference TEXT DEFAULT 'System',
+            default_vote_choice TEXT DEFAULT NULL,
+            mainnet_confirmation INTEGER DEFAULT 0,
+            mainnet_confirmation_actions TEXT DEFAULT NULL,
+            backend_task_retries INTEGER DEFAULT 3,
+            wallet_auto_lock_minutes INTEGER DEFAULT 10,
+            low_balance_warning_credits INTEGER DEFAULT 1000000000,
+            contest_ending_soon_minutes INTEGER DEFAULT 0,
+            contest_auto_refresh INTEGER DEFAULT 0,
+            default_vote_schedule_minutes INTEGER DEFAULT 0,
+            default_vote_schedule_before_end INTEGER DEFAULT 0,
             database_version INTEGER NOT NULL
         )",
             [],
@@ -381,10 +451,22 @@ impl Database {
         self.initialize_proof_log_table(&conn)?;
         self.initialize_top_up_table(&conn)?;
         self.initialize_scheduled_votes_table(&conn)?;
+        self.initialize_bulk_vote_progress_table(&conn)?;
         self.initialize_token_table(&conn)?;
         self.initialize_identity_order_table(&conn)?;
         self.initialize_token_order_table(&conn)?;
         self.initialize_identity_token_balances_table(&conn)?;
+        self.initialize_pinned_token_table(&conn)?;
+        self.initialize_token_search_list_table(&conn)?;
+        self.initialize_dpns_watchlist_table(&conn)?;
+        self.initialize_signing_key_preference_table(&conn)?;
+        self.initialize_identity_tag_tables(&conn)?;
+        self.initialize_sort_preference_table(&conn)?;
+        self.initialize_column_width_preference_table(&conn)?;
+        self.initialize_activity_log_table(&conn)?;
+        self.initialize_token_price_history_table(&conn)?;
+        self.initialize_custom_dapi_addresses_table(&conn)?;
+        self.initialize_contest_lead_history_table(&conn)?;
 
         Ok(())
     }
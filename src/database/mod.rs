@@ -1,3 +1,4 @@
+pub(crate) mod activity_log;
 mod asset_lock_transaction;
 mod contested_names;
 pub(crate) mod contracts;
@@ -6,6 +7,7 @@ mod initialization;
 mod proof_log;
 mod scheduled_votes;
 mod settings;
+mod token_price_history;
 mod tokens;
 mod top_ups;
 mod utxo;
@@ -1,9 +1,11 @@
 use crate::{
-    backend_task::contested_names::ScheduledDPNSVote, context::AppContext, database::Database,
+    backend_task::contested_names::{BulkVoteProgress, ScheduledDPNSVote},
+    context::AppContext,
+    database::Database,
 };
 use dash_sdk::{
     dpp::{
-        platform_value::string_encoding::Encoding,
+        identity::TimestampMillis, platform_value::string_encoding::Encoding,
         voting::vote_choices::resource_vote_choice::ResourceVoteChoice,
     },
     platform::Identifier,
@@ -32,6 +34,27 @@ impl Database {
         Ok(())
     }
 
+    /// Creates the table recording how far an immediate bulk vote got, so a batch interrupted
+    /// by a crash can be resumed without casting votes twice.
+    pub fn initialize_bulk_vote_progress_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS bulk_vote_progress (
+                identity_id BLOB NOT NULL,
+                contested_name TEXT NOT NULL,
+                vote_choice TEXT NOT NULL,
+                cast INTEGER NOT NULL DEFAULT 0,
+                network TEXT NOT NULL,
+                PRIMARY KEY (identity_id, contested_name, network),
+                FOREIGN KEY (identity_id) REFERENCES identity(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
     pub fn update_scheduled_votes_table(
         &self,
         conn: &rusqlite::Connection,
@@ -125,6 +148,21 @@ impl Database {
         Ok(())
     }
 
+    pub fn update_scheduled_vote_time(
+        &self,
+        app_context: &AppContext,
+        identity_id: &[u8],
+        contested_name: &str,
+        unix_timestamp: u64,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        self.execute(
+            "UPDATE scheduled_votes SET time = ? WHERE identity_id = ? AND contested_name = ? AND network = ?",
+            params![unix_timestamp, identity_id, contested_name, network],
+        )?;
+        Ok(())
+    }
+
     pub fn mark_vote_executed(
         &self,
         app_context: &AppContext,
@@ -158,32 +196,7 @@ impl Database {
                 _ => unreachable!(),
             };
 
-            let vote_choice = match vote_choice_string.as_str() {
-                "Abstain" => ResourceVoteChoice::Abstain,
-                "Lock" => ResourceVoteChoice::Lock,
-                other => {
-                    if let Some(inner) = other.strip_prefix("TowardsIdentity(") {
-                        if let Some(inner) = inner.strip_suffix(')') {
-                            let towards_id = inner.to_string();
-                            ResourceVoteChoice::TowardsIdentity(
-                                Identifier::from_string(&towards_id, Encoding::Base58).map_err(
-                                    |e| {
-                                        rusqlite::Error::FromSqlConversionFailure(
-                                            0,
-                                            rusqlite::types::Type::Blob,
-                                            Box::new(e),
-                                        )
-                                    },
-                                )?,
-                            )
-                        } else {
-                            return Err(rusqlite::Error::InvalidQuery);
-                        }
-                    } else {
-                        return Err(rusqlite::Error::InvalidQuery);
-                    }
-                }
-            };
+            let vote_choice = parse_vote_choice(&vote_choice_string)?;
 
             let scheduled_vote = ScheduledDPNSVote {
                 voter_id: Identifier::from_bytes(&voter_id_bytes).map_err(|e| {
@@ -230,4 +243,140 @@ impl Database {
 
         Ok(())
     }
+
+    /// Deletes the votes that weren't cast before the end time of their contest
+    pub fn clear_expired_scheduled_votes(
+        &self,
+        app_context: &AppContext,
+        now: TimestampMillis,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+
+        conn.execute(
+            "DELETE FROM scheduled_votes
+            WHERE executed = 0 AND network = ?1 AND contested_name IN (
+                SELECT normalized_contested_name FROM contested_name
+                WHERE network = ?1 AND end_time IS NOT NULL AND end_time <= ?2
+            )",
+            params![network, now],
+        )?;
+
+        Ok(())
+    }
+
+    /// Records the votes of an immediate bulk vote as not cast yet, before any is submitted
+    pub fn start_bulk_votes(
+        &self,
+        app_context: &AppContext,
+        votes: &[BulkVoteProgress],
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        for vote in votes {
+            tx.execute(
+                "INSERT OR REPLACE INTO bulk_vote_progress (identity_id, contested_name, vote_choice, cast, network) VALUES (?, ?, ?, 0, ?)",
+                params![vote.voter_id.as_slice(), vote.contested_name, vote.choice.to_string(), network],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Marks a vote of a bulk vote as cast. Votes that aren't part of a bulk vote, such as
+    /// scheduled ones, have no row and are left alone.
+    pub fn mark_bulk_vote_cast(
+        &self,
+        app_context: &AppContext,
+        identity_id: &[u8],
+        contested_name: &str,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        self.execute(
+            "UPDATE bulk_vote_progress SET cast = 1 WHERE identity_id = ? AND contested_name = ? AND network = ?",
+            params![identity_id, contested_name, network],
+        )?;
+        Ok(())
+    }
+
+    /// Forgets the progress of votes whose bulk vote ran to completion
+    pub fn finish_bulk_votes(
+        &self,
+        app_context: &AppContext,
+        votes: &[BulkVoteProgress],
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        for vote in votes {
+            tx.execute(
+                "DELETE FROM bulk_vote_progress WHERE identity_id = ? AND contested_name = ? AND network = ?",
+                params![vote.voter_id.as_slice(), vote.contested_name, network],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Bulk votes that were started but never completed, as left behind by a crash
+    pub fn get_bulk_vote_progress(
+        &self,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<Vec<BulkVoteProgress>> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT identity_id, contested_name, vote_choice, cast FROM bulk_vote_progress WHERE network = ?",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            let voter_id_bytes: Vec<u8> = row.get(0)?;
+            let vote_choice_string: String = row.get(2)?;
+            Ok(BulkVoteProgress {
+                voter_id: Identifier::from_bytes(&voter_id_bytes).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        0,
+                        rusqlite::types::Type::Blob,
+                        Box::new(e),
+                    )
+                })?,
+                contested_name: row.get(1)?,
+                choice: parse_vote_choice(&vote_choice_string)?,
+                cast: row.get(3)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    pub fn clear_bulk_vote_progress(&self, app_context: &AppContext) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        self.execute(
+            "DELETE FROM bulk_vote_progress WHERE network = ?",
+            params![network],
+        )?;
+        Ok(())
+    }
+}
+
+/// Parses a vote choice stored with its `Display` form
+fn parse_vote_choice(vote_choice: &str) -> rusqlite::Result<ResourceVoteChoice> {
+    match vote_choice {
+        "Abstain" => Ok(ResourceVoteChoice::Abstain),
+        "Lock" => Ok(ResourceVoteChoice::Lock),
+        other => {
+            let towards_id = other
+                .strip_prefix("TowardsIdentity(")
+                .and_then(|inner| inner.strip_suffix(')'))
+                .ok_or(rusqlite::Error::InvalidQuery)?;
+            let towards_id =
+                Identifier::from_string(towards_id, Encoding::Base58).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        0,
+                        rusqlite::types::Type::Blob,
+                        Box::new(e),
+                    )
+                })?;
+            Ok(ResourceVoteChoice::TowardsIdentity(towards_id))
+        }
+    }
 }
@@ -1,11 +1,20 @@
+use crate::backend_task::DEFAULT_BACKEND_TASK_RETRIES;
 use crate::database::Database;
 use crate::database::initialization::DEFAULT_DB_VERSION;
 use crate::model::password_info::PasswordInfo;
+use crate::model::wallet::DEFAULT_WALLET_AUTO_LOCK_MINUTES;
 use crate::ui::RootScreenType;
+use crate::ui::components::low_balance_banner::DEFAULT_LOW_BALANCE_WARNING_CREDITS;
+use crate::ui::components::mainnet_confirmation::MainnetConfirmationSettings;
+use crate::ui::helpers::TransactionType;
 use crate::ui::theme::ThemeMode;
 use dash_sdk::dpp::dashcore::Network;
-use rusqlite::{Connection, Result, params};
-use std::{path::PathBuf, str::FromStr};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::KeyID;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use rusqlite::{Connection, OptionalExtension, Result, params};
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
 
 impl Database {
     /// Inserts or updates the settings in the database. This method ensures that only one row exists.
@@ -130,6 +139,65 @@ impl Database {
         Ok(())
     }
 
+    pub fn add_default_vote_choice_column(&self, conn: &rusqlite::Connection) -> Result<()> {
+        let default_vote_choice_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='default_vote_choice'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !default_vote_choice_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN default_vote_choice TEXT DEFAULT NULL;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the vote choice used by DPNS quick voting. Only Abstain and Lock can be defaults,
+    /// since a vote towards an identity only makes sense for one contest.
+    pub fn update_default_vote_choice(
+        &self,
+        default_vote_choice: Option<ResourceVoteChoice>,
+    ) -> Result<()> {
+        let choice_str = match default_vote_choice {
+            Some(ResourceVoteChoice::Abstain) => Some("Abstain"),
+            Some(ResourceVoteChoice::Lock) => Some("Lock"),
+            Some(ResourceVoteChoice::TowardsIdentity(_)) | None => None,
+        };
+
+        self.execute(
+            "UPDATE settings
+            SET default_vote_choice = ?
+            WHERE id = 1",
+            rusqlite::params![choice_str],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves the vote choice used by DPNS quick voting, if one is configured.
+    pub fn get_default_vote_choice(&self) -> Result<Option<ResourceVoteChoice>> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT default_vote_choice FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<String>>(0),
+        );
+
+        match result {
+            Ok(choice) => Ok(match choice.as_deref() {
+                Some("Abstain") => Some(ResourceVoteChoice::Abstain),
+                Some("Lock") => Some(ResourceVoteChoice::Lock),
+                _ => None,
+            }),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Updates the database version in the settings table.
     pub fn update_database_version(&self, new_version: u16, conn: &Connection) -> Result<()> {
         // Ensure the database version is updated
@@ -214,4 +282,568 @@ impl Database {
             Err(e) => Err(e),
         }
     }
+
+    pub fn add_mainnet_confirmation_columns(&self, conn: &Connection) -> Result<()> {
+        let mainnet_confirmation_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='mainnet_confirmation'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !mainnet_confirmation_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN mainnet_confirmation INTEGER DEFAULT 0;",
+                (),
+            )?;
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN mainnet_confirmation_actions TEXT DEFAULT NULL;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves whether irreversible mainnet actions need a second confirmation, and which ones.
+    pub fn update_mainnet_confirmation_settings(
+        &self,
+        settings: &MainnetConfirmationSettings,
+    ) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET mainnet_confirmation = ?,
+                mainnet_confirmation_actions = ?
+            WHERE id = 1",
+            params![settings.enabled, settings.actions_to_storage()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves the mainnet confirmation settings, or the defaults if none are saved.
+    pub fn get_mainnet_confirmation_settings(&self) -> Result<MainnetConfirmationSettings> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT mainnet_confirmation, mainnet_confirmation_actions FROM settings WHERE id = 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, Option<bool>>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                ))
+            },
+        );
+
+        match result {
+            Ok((enabled, actions)) => Ok(MainnetConfirmationSettings {
+                enabled: enabled.unwrap_or(false),
+                actions: MainnetConfirmationSettings::actions_from_storage(actions.as_deref()),
+            }),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(MainnetConfirmationSettings::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn add_backend_task_retries_column(&self, conn: &Connection) -> Result<()> {
+        let backend_task_retries_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='backend_task_retries'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !backend_task_retries_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN backend_task_retries INTEGER DEFAULT 3;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves how many times queries failing with a transient error are retried.
+    pub fn update_backend_task_retries(&self, retries: u8) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET backend_task_retries = ?
+            WHERE id = 1",
+            params![retries],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves how many times queries failing with a transient error are retried, or the
+    /// default if it was never set.
+    pub fn get_backend_task_retries(&self) -> Result<u8> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT backend_task_retries FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<u8>>(0),
+        );
+
+        match result {
+            Ok(retries) => Ok(retries.unwrap_or(DEFAULT_BACKEND_TASK_RETRIES)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(DEFAULT_BACKEND_TASK_RETRIES),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn add_wallet_auto_lock_minutes_column(&self, conn: &Connection) -> Result<()> {
+        let wallet_auto_lock_minutes_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='wallet_auto_lock_minutes'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !wallet_auto_lock_minutes_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN wallet_auto_lock_minutes INTEGER DEFAULT 10;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves after how many idle minutes unlocked wallets are locked again, 0 to never lock
+    /// them.
+    pub fn update_wallet_auto_lock_minutes(&self, minutes: u16) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET wallet_auto_lock_minutes = ?
+            WHERE id = 1",
+            params![minutes],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves after how many idle minutes unlocked wallets are locked again, or the default
+    /// if it was never set.
+    pub fn get_wallet_auto_lock_minutes(&self) -> Result<u16> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT wallet_auto_lock_minutes FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<u16>>(0),
+        );
+
+        match result {
+            Ok(minutes) => Ok(minutes.unwrap_or(DEFAULT_WALLET_AUTO_LOCK_MINUTES)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(DEFAULT_WALLET_AUTO_LOCK_MINUTES),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn add_low_balance_warning_credits_column(&self, conn: &Connection) -> Result<()> {
+        let low_balance_warning_credits_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='low_balance_warning_credits'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !low_balance_warning_credits_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN low_balance_warning_credits INTEGER DEFAULT 1000000000;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the credit balance under which action screens warn about the signing identity,
+    /// 0 to never warn.
+    pub fn update_low_balance_warning_credits(&self, credits: Credits) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET low_balance_warning_credits = ?
+            WHERE id = 1",
+            params![credits],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves the credit balance under which action screens warn about the signing
+    /// identity, or the default if it was never set.
+    pub fn get_low_balance_warning_credits(&self) -> Result<Credits> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT low_balance_warning_credits FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<Credits>>(0),
+        );
+
+        match result {
+            Ok(credits) => Ok(credits.unwrap_or(DEFAULT_LOW_BALANCE_WARNING_CREDITS)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(DEFAULT_LOW_BALANCE_WARNING_CREDITS),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn add_contest_ending_soon_columns(&self, conn: &Connection) -> Result<()> {
+        let contest_ending_soon_minutes_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='contest_ending_soon_minutes'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !contest_ending_soon_minutes_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN contest_ending_soon_minutes INTEGER DEFAULT 0;",
+                (),
+            )?;
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN contest_auto_refresh INTEGER DEFAULT 0;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves how many minutes before their end active contests are highlighted, 0 to never
+    /// highlight them.
+    pub fn update_contest_ending_soon_minutes(&self, minutes: u16) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET contest_ending_soon_minutes = ?
+            WHERE id = 1",
+            params![minutes],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves how many minutes before their end active contests are highlighted, 0 if
+    /// they never are.
+    pub fn get_contest_ending_soon_minutes(&self) -> Result<u16> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT contest_ending_soon_minutes FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<u16>>(0),
+        );
+
+        match result {
+            Ok(minutes) => Ok(minutes.unwrap_or(0)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(0),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Saves whether the contests are refreshed automatically while one of them ends soon.
+    pub fn update_contest_auto_refresh(&self, enabled: bool) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET contest_auto_refresh = ?
+            WHERE id = 1",
+            params![enabled],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves whether the contests are refreshed automatically while one of them ends
+    /// soon, off if it was never set.
+    pub fn get_contest_auto_refresh(&self) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT contest_auto_refresh FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<bool>>(0),
+        );
+
+        match result {
+            Ok(enabled) => Ok(enabled.unwrap_or(false)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn add_default_vote_schedule_columns(&self, conn: &Connection) -> Result<()> {
+        let default_vote_schedule_minutes_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='default_vote_schedule_minutes'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !default_vote_schedule_minutes_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN default_vote_schedule_minutes INTEGER DEFAULT 0;",
+                (),
+            )?;
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN default_vote_schedule_before_end INTEGER DEFAULT 0;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the delay, in minutes, the Schedule option of bulk votes starts with.
+    pub fn update_default_vote_schedule_minutes(&self, minutes: u32) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET default_vote_schedule_minutes = ?
+            WHERE id = 1",
+            params![minutes],
+        )?;
+
+        Ok(())
+    }
+
+    /// Saves whether the delay of scheduled bulk votes counts back from the end of each
+    /// contest rather than from now.
+    pub fn update_default_vote_schedule_before_end(&self, before_end: bool) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET default_vote_schedule_before_end = ?
+            WHERE id = 1",
+            params![before_end],
+        )?;
+
+        Ok(())
+    }
+
+    /// Retrieves the delay, in minutes, the Schedule option of bulk votes starts with, and
+    /// whether it counts back from the end of each contest. No delay from now if never set.
+    pub fn get_default_vote_schedule(&self) -> Result<(u32, bool)> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT default_vote_schedule_minutes, default_vote_schedule_before_end
+             FROM settings WHERE id = 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, Option<u32>>(0)?,
+                    row.get::<_, Option<bool>>(1)?,
+                ))
+            },
+        );
+
+        match result {
+            Ok((minutes, before_end)) => Ok((minutes.unwrap_or(0), before_end.unwrap_or(false))),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok((0, false)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates the `signing_key_preference` table, which remembers the key each identity last
+    /// signed a given kind of transaction with.
+    pub fn initialize_signing_key_preference_table(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS signing_key_preference (
+                identity_id BLOB NOT NULL,
+                network TEXT NOT NULL,
+                transaction_type TEXT NOT NULL,
+                key_id INTEGER NOT NULL,
+                PRIMARY KEY(identity_id, network, transaction_type)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Remembers `key_id` as the signing key of `identity_id` for `transaction_type`.
+    pub fn set_signing_key_preference(
+        &self,
+        identity_id: &Identifier,
+        transaction_type: TransactionType,
+        key_id: KeyID,
+        network: &Network,
+    ) -> Result<()> {
+        self.execute(
+            "INSERT OR REPLACE INTO signing_key_preference
+             (identity_id, network, transaction_type, key_id)
+             VALUES (?, ?, ?, ?)",
+            params![
+                identity_id.to_vec(),
+                network.to_string(),
+                transaction_type.storage_key(),
+                key_id
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the remembered signing keys of all identities on `network`.
+    ///
+    /// Rows with an unknown transaction type or a malformed identity id are skipped.
+    pub fn get_signing_key_preferences(
+        &self,
+        network: &Network,
+    ) -> Result<HashMap<(Identifier, TransactionType), KeyID>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT identity_id, transaction_type, key_id FROM signing_key_preference
+             WHERE network = ?",
+        )?;
+        let rows = stmt.query_map(params![network.to_string()], |row| {
+            Ok((
+                row.get::<_, Vec<u8>>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, KeyID>(2)?,
+            ))
+        })?;
+
+        let mut preferences = HashMap::new();
+        for row in rows {
+            let (identity_id, transaction_type, key_id) = row?;
+            let (Ok(identity_id), Some(transaction_type)) = (
+                Identifier::from_vec(identity_id),
+                TransactionType::from_storage_key(&transaction_type),
+            ) else {
+                continue;
+            };
+            preferences.insert((identity_id, transaction_type), key_id);
+        }
+        Ok(preferences)
+    }
+
+    /// Creates the `sort_preference` table, which remembers how the user last sorted each
+    /// table. Tables without a row keep their default sort.
+    pub fn initialize_sort_preference_table(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS sort_preference (
+                table_key TEXT PRIMARY KEY,
+                sort_column TEXT NOT NULL,
+                ascending INTEGER NOT NULL
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Remembers the sort column and direction of the table identified by `table_key`.
+    pub fn set_sort_preference(
+        &self,
+        table_key: &str,
+        sort_column: &str,
+        ascending: bool,
+    ) -> Result<()> {
+        self.execute(
+            "INSERT OR REPLACE INTO sort_preference (table_key, sort_column, ascending)
+             VALUES (?, ?, ?)",
+            params![table_key, sort_column, ascending],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the sort column and whether it is ascending of the table identified by
+    /// `table_key`, or `None` if the user never sorted it.
+    pub fn get_sort_preference(&self, table_key: &str) -> Result<Option<(String, bool)>> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT sort_column, ascending FROM sort_preference WHERE table_key = ?",
+            params![table_key],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?)),
+        )
+        .optional()
+    }
+
+    /// Creates the `column_width_preference` table, which remembers the column widths the user
+    /// resized each table to. Tables without a row keep their default widths.
+    pub fn initialize_column_width_preference_table(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS column_width_preference (
+                table_key TEXT PRIMARY KEY,
+                widths TEXT NOT NULL
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Remembers the column widths of the table identified by `table_key`, left to right.
+    pub fn set_column_widths(&self, table_key: &str, widths: &[f32]) -> Result<()> {
+        let widths = widths
+            .iter()
+            .map(|width| width.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.execute(
+            "INSERT OR REPLACE INTO column_width_preference (table_key, widths) VALUES (?, ?)",
+            params![table_key, widths],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the column widths of the table identified by `table_key`, empty if the user never
+    /// resized it.
+    pub fn get_column_widths(&self, table_key: &str) -> Result<Vec<f32>> {
+        let conn = self.conn.lock().unwrap();
+        let widths = conn
+            .query_row(
+                "SELECT widths FROM column_width_preference WHERE table_key = ?",
+                params![table_key],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?;
+        Ok(widths
+            .map(|widths| {
+                widths
+                    .split(',')
+                    .filter_map(|width| width.parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Forgets the column widths of the table identified by `table_key`.
+    pub fn delete_column_widths(&self, table_key: &str) -> Result<()> {
+        self.execute(
+            "DELETE FROM column_width_preference WHERE table_key = ?",
+            params![table_key],
+        )?;
+        Ok(())
+    }
+
+    pub fn initialize_custom_dapi_addresses_table(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS custom_dapi_addresses (
+                network TEXT PRIMARY KEY,
+                addresses TEXT NOT NULL
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the DAPI addresses from the config of `network` with `addresses`, or goes back
+    /// to the configured ones when `None`.
+    pub fn set_custom_dapi_addresses(
+        &self,
+        network: Network,
+        addresses: Option<&[String]>,
+    ) -> Result<()> {
+        match addresses {
+            Some(addresses) => self.execute(
+                "INSERT OR REPLACE INTO custom_dapi_addresses (network, addresses) VALUES (?, ?)",
+                params![network.to_string(), addresses.join(",")],
+            )?,
+            None => self.execute(
+                "DELETE FROM custom_dapi_addresses WHERE network = ?",
+                params![network.to_string()],
+            )?,
+        };
+        Ok(())
+    }
+
+    /// Loads the custom DAPI addresses of `network`, `None` when it uses the configured ones.
+    pub fn get_custom_dapi_addresses(&self, network: Network) -> Result<Option<Vec<String>>> {
+        let conn = self.conn.lock().unwrap();
+        let addresses = conn
+            .query_row(
+                "SELECT addresses FROM custom_dapi_addresses WHERE network = ?",
+                params![network.to_string()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?;
+        Ok(addresses.map(|addresses| addresses.split(',').map(str::to_string).collect()))
+    }
 }
@@ -0,0 +1,95 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::token_price_history::TokenPriceHistoryEntry;
+use bincode::config::standard;
+use dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
+use dash_sdk::platform::Identifier;
+use rusqlite::params;
+
+impl Database {
+    pub fn initialize_token_price_history_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS token_price_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_id BLOB NOT NULL,
+                time_ms INTEGER NOT NULL,
+                schedule BLOB NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_token_price_history_token ON token_price_history (token_id, network, time_ms)",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Records a pricing schedule fetched for a token at `time_ms`.
+    pub fn insert_token_price_history_entry(
+        &self,
+        token_id: &Identifier,
+        time_ms: u64,
+        schedule: &TokenPricingSchedule,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let schedule_bytes = bincode::encode_to_vec(schedule, standard())
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        self.execute(
+            "INSERT INTO token_price_history (token_id, time_ms, schedule, network)
+             VALUES (?, ?, ?, ?)",
+            params![
+                token_id.to_vec(),
+                time_ms,
+                schedule_bytes,
+                app_context.network.to_string()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the recorded pricing schedules of a token, oldest first. Entries that no longer
+    /// decode are skipped.
+    pub fn get_token_price_history(
+        &self,
+        token_id: &Identifier,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<Vec<TokenPriceHistoryEntry>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT time_ms, schedule FROM token_price_history
+             WHERE token_id = ? AND network = ? ORDER BY time_ms ASC, id ASC",
+        )?;
+        let rows = stmt
+            .query_map(
+                params![token_id.to_vec(), app_context.network.to_string()],
+                |row| Ok((row.get::<_, u64>(0)?, row.get::<_, Vec<u8>>(1)?)),
+            )?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|(time_ms, bytes)| {
+                let (schedule, _) =
+                    bincode::decode_from_slice::<TokenPricingSchedule, _>(&bytes, standard())
+                        .ok()?;
+                Some(TokenPriceHistoryEntry { time_ms, schedule })
+            })
+            .collect())
+    }
+
+    /// Deletes the recorded pricing schedules of a token.
+    pub fn clear_token_price_history(
+        &self,
+        token_id: &Identifier,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "DELETE FROM token_price_history WHERE token_id = ? AND network = ?",
+            params![token_id.to_vec(), app_context.network.to_string()],
+        )?;
+        Ok(())
+    }
+}
@@ -8,10 +8,12 @@ use dash_sdk::query_types::IndexMap;
 use rusqlite::Connection;
 use rusqlite::OptionalExtension;
 use rusqlite::params;
+use std::collections::HashSet;
 
 use super::Database;
 use crate::ui::tokens::tokens_screen::{
-    IdentityTokenIdentifier, TokenInfo, TokenInfoWithDataContract,
+    IdentityTokenIdentifier, TokenInfo, TokenInfoWithDataContract, TokenSearchListEntry,
+    TokenSearchListKind,
 };
 use crate::{context::AppContext, ui::tokens::tokens_screen::IdentityTokenBalance};
 
@@ -26,6 +28,7 @@ impl Database {
                 data_contract_id BLOB NOT NULL,
                 token_position INTEGER NOT NULL,
                 network TEXT NOT NULL,
+                notes TEXT,
                 FOREIGN KEY (data_contract_id, network)
                     REFERENCES contract(contract_id, network)
                     ON DELETE CASCADE
@@ -35,6 +38,61 @@ impl Database {
         Ok(())
     }
 
+    /// Adds the `notes` column to the `token` table if it doesn't exist yet.
+    pub fn add_token_notes_column(&self, conn: &rusqlite::Connection) -> rusqlite::Result<()> {
+        let notes_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('token') WHERE name='notes'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !notes_exists {
+            conn.execute("ALTER TABLE token ADD COLUMN notes TEXT", [])?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the user's private notes for a token, if any. Notes are local only.
+    pub fn get_token_notes(
+        &self,
+        token_id: &Identifier,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<Option<String>> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+
+        conn.query_row(
+            "SELECT notes FROM token WHERE id = ? AND network = ?",
+            params![token_id.to_vec(), network],
+            |row| row.get::<_, Option<String>>(0),
+        )
+        .optional()
+        .map(Option::flatten)
+    }
+
+    /// Sets (or clears, when `notes` is empty) the user's private notes for a token.
+    pub fn set_token_notes(
+        &self,
+        token_id: &Identifier,
+        notes: &str,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        let notes = if notes.trim().is_empty() {
+            None
+        } else {
+            Some(notes)
+        };
+
+        self.execute(
+            "UPDATE token SET notes = ? WHERE id = ? AND network = ?",
+            params![notes, token_id.to_vec(), network],
+        )?;
+
+        Ok(())
+    }
+
     pub fn get_token_config_for_id(
         &self,
         token_id: &Identifier,
@@ -270,7 +328,6 @@ impl Database {
     /// Retrieves all known tokens as a map from token ID to `TokenInfo`.
     ///
     /// Now also fetches and decodes the **`token_config`** blob.
-    #[allow(dead_code)] // May be used for token overview displays without contract data
     pub fn get_all_known_tokens(
         &self,
         app_context: &AppContext,
@@ -533,6 +590,196 @@ impl Database {
         Ok(result)
     }
 
+    /// Creates the `pinned_token` table, which holds the tokens the user pinned to the top of
+    /// the token list.
+    pub fn initialize_pinned_token_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS pinned_token (
+                token_id BLOB NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY(token_id, network),
+                FOREIGN KEY (token_id) REFERENCES token(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Pins or unpins a token for the current network.
+    pub fn set_token_pinned(
+        &self,
+        token_id: &Identifier,
+        pinned: bool,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+        let token_id_bytes = token_id.to_vec();
+
+        if pinned {
+            self.execute(
+                "INSERT OR IGNORE INTO pinned_token (token_id, network) VALUES (?, ?)",
+                params![token_id_bytes, network],
+            )?;
+        } else {
+            self.execute(
+                "DELETE FROM pinned_token WHERE token_id = ? AND network = ?",
+                params![token_id_bytes, network],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads the set of pinned tokens for the current network.
+    pub fn get_pinned_tokens(
+        &self,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<HashSet<Identifier>> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn.prepare("SELECT token_id FROM pinned_token WHERE network = ?")?;
+        let rows = stmt.query_map(params![network], |row| row.get::<_, Vec<u8>>(0))?;
+
+        let mut result = HashSet::new();
+        for token_id_bytes in rows {
+            if let Ok(token_id) = Identifier::from_vec(token_id_bytes?) {
+                result.insert(token_id);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Creates the `token_search_list` table, which holds the tokens the user always wants
+    /// tracked (allowlist) or never wants to see in search results (denylist).
+    ///
+    /// Denylisted tokens usually aren't tracked, so there is no foreign key to `token`.
+    pub fn initialize_token_search_list_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS token_search_list (
+                token_id BLOB NOT NULL,
+                network TEXT NOT NULL,
+                data_contract_id BLOB NOT NULL,
+                token_name TEXT NOT NULL,
+                list TEXT NOT NULL,
+                PRIMARY KEY(token_id, network)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Puts a token on the allowlist or denylist, moving it if it was on the other list.
+    pub fn set_token_search_list(
+        &self,
+        entry: &TokenSearchListEntry,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+
+        self.execute(
+            "INSERT INTO token_search_list (token_id, network, data_contract_id, token_name, list)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(token_id, network) DO UPDATE SET
+                data_contract_id = excluded.data_contract_id,
+                token_name = excluded.token_name,
+                list = excluded.list",
+            params![
+                entry.token_id.to_vec(),
+                network,
+                entry.data_contract_id.to_vec(),
+                entry.token_name,
+                entry.kind.as_str()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Removes a token from whichever search list it is on.
+    pub fn remove_token_from_search_lists(
+        &self,
+        token_id: &Identifier,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+
+        self.execute(
+            "DELETE FROM token_search_list WHERE token_id = ? AND network = ?",
+            params![token_id.to_vec(), network],
+        )?;
+
+        Ok(())
+    }
+
+    /// Removes every entry from one of the search lists for the current network.
+    pub fn clear_token_search_list(
+        &self,
+        kind: TokenSearchListKind,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network.to_string();
+
+        self.execute(
+            "DELETE FROM token_search_list WHERE list = ? AND network = ?",
+            params![kind.as_str(), network],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the allowlist and denylist entries for the current network, keyed by token ID.
+    pub fn get_token_search_lists(
+        &self,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<IndexMap<Identifier, TokenSearchListEntry>> {
+        let network = app_context.network.to_string();
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn.prepare(
+            "SELECT token_id, data_contract_id, token_name, list FROM token_search_list
+             WHERE network = ? ORDER BY token_name",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            Ok((
+                row.get::<_, Vec<u8>>(0)?,
+                row.get::<_, Vec<u8>>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        let mut result = IndexMap::new();
+        for row in rows {
+            let (token_id_bytes, data_contract_id_bytes, token_name, list) = row?;
+            let (Ok(token_id), Ok(data_contract_id), Some(kind)) = (
+                Identifier::from_vec(token_id_bytes),
+                Identifier::from_vec(data_contract_id_bytes),
+                TokenSearchListKind::from_db_str(&list),
+            ) else {
+                continue;
+            };
+            result.insert(
+                token_id,
+                TokenSearchListEntry {
+                    token_id,
+                    data_contract_id,
+                    token_name,
+                    kind,
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
     /// Deletes all local tokens in Devnet variants and Regtest.
     pub fn delete_all_local_tokens_in_all_devnets_and_regtest(
         &self,
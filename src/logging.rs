@@ -1,12 +1,34 @@
 use crate::{VERSION, app_dir::app_user_data_file_path};
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::fs::{self, File};
+use std::io::{self, Write};
 use std::panic;
-use tracing::{error, info};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber, error, info};
 use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Name of the log file in the app data directory
+pub const LOG_FILE_NAME: &str = "det.log";
+/// Number of log entries kept in memory for the log viewer
+const LOG_BUFFER_CAPACITY: usize = 10_000;
+/// The log file is rotated once it grows past this many bytes
+const MAX_LOG_FILE_SIZE: u64 = 20 * 1024 * 1024;
+/// Number of rotated log files kept next to the current one
+const MAX_ROTATED_LOG_FILES: usize = 5;
 
 pub fn initialize_logger() {
     // Initialize log file, with improved error handling
-    let log_file_path = app_user_data_file_path("det.log").expect("should create log file path");
-    let log_file = match std::fs::File::create(&log_file_path) {
+    let log_file_path =
+        app_user_data_file_path(LOG_FILE_NAME).expect("should create log file path");
+    let log_file = match RotatingLogFile::create(log_file_path.clone()) {
         Ok(file) => file,
         Err(e) => panic!("Failed to create log file: {:?}", e),
     };
@@ -16,11 +38,14 @@ pub fn initialize_logger() {
     )
         .unwrap_or_else(|e| panic!("Failed to create EnvFilter: {:?}", e));
 
-    let subscriber = tracing_subscriber::fmt()
-        .with_env_filter(filter)
-        .with_writer(log_file)
-        .with_ansi(false)
-        .finish();
+    let subscriber = tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(Mutex::new(log_file))
+                .with_ansi(false),
+        )
+        .with(LogBufferLayer);
 
     // Set global subscriber with proper error handling
     if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
@@ -54,3 +79,319 @@ pub fn initialize_logger() {
         "Dash-Evo-Tool logging initialized successfully"
     );
 }
+
+/// A log event kept in memory for the log viewer.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    /// Spans the event happened in, outermost first, e.g. `backend_task{task=TokenTask}`
+    pub spans: String,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Whether the entry is at most as verbose as `max_level` and its module contains
+    /// `module_filter`
+    pub fn matches(&self, max_level: Level, module_filter: &str) -> bool {
+        let module_filter = module_filter.trim();
+        self.level <= max_level && (module_filter.is_empty() || self.target.contains(module_filter))
+    }
+
+    /// The entry formatted like a line of the log file
+    pub fn to_line(&self) -> String {
+        let spans = if self.spans.is_empty() {
+            String::new()
+        } else {
+            format!("{}: ", self.spans)
+        };
+        format!(
+            "{} {:>5} {}{}: {}",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+            self.level,
+            spans,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Rolling buffer of the most recent log entries.
+pub struct LogBuffer {
+    entries: Mutex<VecDeque<LogEntry>>,
+    capacity: usize,
+    /// Bumped on every change, so viewers only copy the entries when something changed
+    version: AtomicU64,
+}
+
+impl LogBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            version: AtomicU64::new(0),
+        }
+    }
+
+    fn push(&self, entry: LogEntry) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+        self.version.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Relaxed)
+    }
+
+    /// Entries matching the filter, oldest first
+    pub fn filtered(&self, max_level: Level, module_filter: &str) -> Vec<LogEntry> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry.matches(max_level, module_filter))
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+        self.version.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// The in-memory log shown in the log viewer
+pub fn log_buffer() -> &'static LogBuffer {
+    static LOG_BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+    LOG_BUFFER.get_or_init(|| LogBuffer::new(LOG_BUFFER_CAPACITY))
+}
+
+/// Layer that copies every event into [`log_buffer`].
+struct LogBufferLayer;
+
+/// Formatted fields of a span, stored in the span's extensions.
+struct SpanFields(String);
+
+impl<S> Layer<S> for LogBufferLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(visitor.finish()));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+        let mut extensions = span.extensions_mut();
+        match extensions.get_mut::<SpanFields>() {
+            Some(SpanFields(fields)) => {
+                if !fields.is_empty() {
+                    fields.push(' ');
+                }
+                fields.push_str(&visitor.finish());
+            }
+            None => extensions.insert(SpanFields(visitor.finish())),
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| match span.extensions().get::<SpanFields>() {
+                        Some(SpanFields(fields)) if !fields.is_empty() => {
+                            format!("{}{{{}}}", span.name(), fields)
+                        }
+                        _ => span.name().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(":")
+            })
+            .unwrap_or_default();
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        log_buffer().push(LogEntry {
+            timestamp: Local::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            spans,
+            message: visitor.finish(),
+        });
+    }
+}
+
+/// Collects the message and the other fields of an event or span.
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: String,
+}
+
+impl FieldVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+
+    fn separate_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.separate_field();
+            let _ = write!(self.fields, "{}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.separate_field();
+            let _ = write!(self.fields, "{}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Log file that is rotated on startup and whenever it grows past [`MAX_LOG_FILE_SIZE`].
+struct RotatingLogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl RotatingLogFile {
+    fn create(path: PathBuf) -> io::Result<Self> {
+        rotate_log_files(&path)?;
+        let file = File::create(&path)?;
+        Ok(Self {
+            path,
+            file,
+            size: 0,
+        })
+    }
+}
+
+impl Write for RotatingLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > MAX_LOG_FILE_SIZE {
+            self.file.flush()?;
+            rotate_log_files(&self.path)?;
+            self.file = File::create(&self.path)?;
+            self.size = 0;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Moves `det.log` to `det.log.1`, `det.log.1` to `det.log.2` and so on, dropping the oldest.
+fn rotate_log_files(path: &Path) -> io::Result<()> {
+    for index in (1..MAX_ROTATED_LOG_FILES).rev() {
+        let from = rotated_log_path(path, index);
+        if from.exists() {
+            fs::rename(&from, rotated_log_path(path, index + 1))?;
+        }
+    }
+    if path.exists() {
+        fs::rename(path, rotated_log_path(path, 1))?;
+    }
+    Ok(())
+}
+
+fn rotated_log_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: Level, target: &str) -> LogEntry {
+        LogEntry {
+            timestamp: Local::now(),
+            level,
+            target: target.to_string(),
+            spans: String::new(),
+            message: "message".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_entry_matches() {
+        let entry = entry(Level::DEBUG, "dash_evo_tool::backend_task");
+        assert!(entry.matches(Level::TRACE, ""));
+        assert!(entry.matches(Level::DEBUG, "backend_task"));
+        assert!(!entry.matches(Level::INFO, ""));
+        assert!(!entry.matches(Level::TRACE, "dash_sdk"));
+    }
+
+    #[test]
+    fn test_log_buffer_drops_oldest_entries() {
+        let buffer = LogBuffer::new(2);
+        buffer.push(entry(Level::INFO, "first"));
+        buffer.push(entry(Level::INFO, "second"));
+        buffer.push(entry(Level::INFO, "third"));
+
+        let targets = buffer
+            .filtered(Level::TRACE, "")
+            .into_iter()
+            .map(|entry| entry.target)
+            .collect::<Vec<_>>();
+        assert_eq!(targets, vec!["second", "third"]);
+        assert_eq!(buffer.version(), 3);
+    }
+
+    #[test]
+    fn test_rotate_log_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        for run in 0..=MAX_ROTATED_LOG_FILES + 1 {
+            rotate_log_files(&path).unwrap();
+            fs::write(&path, run.to_string()).unwrap();
+        }
+
+        let last_run = MAX_ROTATED_LOG_FILES + 1;
+        assert_eq!(fs::read_to_string(&path).unwrap(), last_run.to_string());
+        for index in 1..=MAX_ROTATED_LOG_FILES {
+            assert_eq!(
+                fs::read_to_string(rotated_log_path(&path, index)).unwrap(),
+                (last_run - index).to_string()
+            );
+        }
+        assert!(!rotated_log_path(&path, MAX_ROTATED_LOG_FILES + 1).exists());
+    }
+}
@@ -0,0 +1,18 @@
+use dash_sdk::platform::Identifier;
+
+/// A state transition submitted from the app, as kept in the activity log
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityLogItem {
+    pub id: i64,
+    pub time_ms: u64,
+    /// Kind of action, as "Mint Tokens"
+    pub action: String,
+    /// Identity that signed the transition, when a single one did
+    pub identity_id: Option<Identifier>,
+    /// Token, contract, document, contested name or identity the action targeted
+    pub target: Option<String>,
+    /// Parameters of the action, without any key material
+    pub parameters: String,
+    /// Error the submission failed with, `None` if it went through
+    pub error: Option<String>,
+}
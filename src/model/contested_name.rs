@@ -3,6 +3,7 @@ use bincode::{Decode, Encode};
 use dash_sdk::dpp::identity::{KeyID, TimestampMillis};
 use dash_sdk::dpp::prelude::{BlockHeight, CoreBlockHeight, Identifier};
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
 use std::collections::BTreeMap;
 
 #[derive(Debug, Encode, Decode, Clone, PartialEq)]
@@ -15,7 +16,6 @@ pub enum ContestState {
 }
 
 impl ContestState {
-    #[allow(dead_code)] // May be used for UI state validation
     #[allow(clippy::match_like_matches_macro)]
     pub fn state_is_votable(&self) -> bool {
         match self {
@@ -23,6 +23,17 @@ impl ContestState {
             _ => false,
         }
     }
+
+    /// State of an ended contest with `winner`, `None` when nobody won it
+    pub fn from_winner(winner: ContestedDocumentVotePollWinnerInfo) -> Option<Self> {
+        match winner {
+            ContestedDocumentVotePollWinnerInfo::WonByIdentity(identity_id) => {
+                Some(ContestState::WonBy(identity_id))
+            }
+            ContestedDocumentVotePollWinnerInfo::Locked => Some(ContestState::Locked),
+            ContestedDocumentVotePollWinnerInfo::NoWinner => None,
+        }
+    }
 }
 
 #[derive(Debug, Encode, Decode, Clone)]
@@ -38,6 +49,19 @@ pub struct ContestedName {
     pub my_votes: BTreeMap<(Identifier, PrivateKeyTarget, KeyID), ResourceVoteChoice>,
 }
 
+impl ContestedName {
+    /// The contestant with the most votes, `None` when nobody has votes or the lead is tied
+    pub fn vote_leader(&self) -> Option<Identifier> {
+        let contestants = self.contestants.as_ref()?;
+        let most_votes = contestants.iter().map(|c| c.votes).max()?;
+        let mut leaders = contestants.iter().filter(|c| c.votes == most_votes);
+        match (leaders.next(), leaders.next()) {
+            (Some(leader), None) if most_votes > 0 => Some(leader.id),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Encode, Decode, Clone)]
 pub struct Contestant {
     pub id: Identifier,
@@ -49,3 +73,106 @@ pub struct Contestant {
     pub created_at_core_block_height: Option<CoreBlockHeight>,
     pub document_id: Identifier,
 }
+
+/// A DPNS name on the user's watchlist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchedName {
+    pub normalized_name: String,
+    /// Whether the user has already been shown that this name is contested
+    pub contest_seen: bool,
+}
+
+/// The leader of a contest as of a refresh of the contests, recorded only when it changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContestLeadChange {
+    pub recorded_at: TimestampMillis,
+    /// `None` while nobody leads, such as on a tie
+    pub leader: Option<Identifier>,
+}
+
+/// Whether the winner of a contest led it all along, from its recorded lead changes
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeadSummary {
+    /// No leader was recorded while the contest was running, or nobody had votes yet
+    NoHistory,
+    LedThroughout,
+    /// Someone else led first. `took_lead_at` is when the winner's final lead was recorded,
+    /// `None` when they only overtook after the last refresh.
+    OvertookLate {
+        took_lead_at: Option<TimestampMillis>,
+    },
+}
+
+impl LeadSummary {
+    /// Summarizes the lead changes of a contest won by `winner`, ordered by `recorded_at`
+    pub fn from_history(history: &[ContestLeadChange], winner: &Identifier) -> Self {
+        let leads = |change: &ContestLeadChange| change.leader.as_ref() == Some(winner);
+        // Until someone has votes nobody leads, which doesn't count against the winner
+        let first_lead = history
+            .iter()
+            .position(|change| change.leader.is_some())
+            .unwrap_or(history.len());
+        let history = &history[first_lead..];
+        if history.is_empty() {
+            LeadSummary::NoHistory
+        } else if history.iter().all(leads) {
+            LeadSummary::LedThroughout
+        } else {
+            // The final lead starts after the last change where the winner didn't lead
+            let took_lead_at = history
+                .iter()
+                .rposition(|change| !leads(change))
+                .and_then(|last_trailing| history.get(last_trailing + 1))
+                .map(|change| change.recorded_at);
+            LeadSummary::OvertookLate { took_lead_at }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(recorded_at: TimestampMillis, leader: Option<u8>) -> ContestLeadChange {
+        ContestLeadChange {
+            recorded_at,
+            leader: leader.map(|id| Identifier::new([id; 32])),
+        }
+    }
+
+    #[test]
+    fn test_lead_summary_from_history() {
+        let winner = Identifier::new([1; 32]);
+        assert_eq!(
+            LeadSummary::from_history(&[], &winner),
+            LeadSummary::NoHistory
+        );
+        assert_eq!(
+            LeadSummary::from_history(&[change(10, Some(1))], &winner),
+            LeadSummary::LedThroughout
+        );
+        assert_eq!(
+            LeadSummary::from_history(
+                &[change(10, None), change(20, Some(2)), change(30, Some(1))],
+                &winner
+            ),
+            LeadSummary::OvertookLate {
+                took_lead_at: Some(30)
+            }
+        );
+        // Nobody leading before the first votes doesn't count against the winner
+        assert_eq!(
+            LeadSummary::from_history(&[change(10, None), change(20, Some(1))], &winner),
+            LeadSummary::LedThroughout
+        );
+        assert_eq!(
+            LeadSummary::from_history(&[change(10, None)], &winner),
+            LeadSummary::NoHistory
+        );
+        // Leading early doesn't count once someone else took over
+        assert_eq!(
+            LeadSummary::from_history(&[change(10, Some(1)), change(20, Some(2))], &winner),
+            LeadSummary::OvertookLate { took_lead_at: None }
+        );
+    }
+}
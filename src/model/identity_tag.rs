@@ -0,0 +1,48 @@
+/// A user-defined category for identities, such as "Masternodes", shown as a colored dot
+/// next to tagged identities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityTag {
+    pub name: String,
+    /// RGB color of the tag's dot
+    pub color: [u8; 3],
+}
+
+impl IdentityTag {
+    /// The color as `#rrggbb`, the form it is stored in
+    pub fn color_hex(&self) -> String {
+        let [r, g, b] = self.color;
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+
+    /// Parses a color written by [`IdentityTag::color_hex`]
+    pub fn parse_color_hex(hex: &str) -> Option<[u8; 3]> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some([channel(0)?, channel(2)?, channel(4)?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_hex_round_trip() {
+        let tag = IdentityTag {
+            name: "Masternodes".to_string(),
+            color: [0, 141, 228],
+        };
+        assert_eq!(tag.color_hex(), "#008de4");
+        assert_eq!(
+            IdentityTag::parse_color_hex(&tag.color_hex()),
+            Some(tag.color)
+        );
+
+        assert_eq!(IdentityTag::parse_color_hex("008de4"), None);
+        assert_eq!(IdentityTag::parse_color_hex("#008de"), None);
+        assert_eq!(IdentityTag::parse_color_hex("#00zze4"), None);
+    }
+}
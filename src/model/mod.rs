@@ -1,6 +1,9 @@
+pub mod activity_log_item;
 pub mod contested_name;
+pub mod identity_tag;
 pub mod password_info;
 pub mod proof_log_item;
 pub mod qualified_contract;
 pub mod qualified_identity;
+pub mod token_price_history;
 pub mod wallet;
@@ -1,4 +1,5 @@
 pub mod encrypted_key_storage;
+mod public_export;
 pub mod qualified_identity_public_key;
 
 use crate::model::qualified_identity::encrypted_key_storage::KeyStorage;
@@ -529,7 +530,6 @@ impl QualifiedIdentity {
         keys
     }
 
-    #[allow(dead_code)]
     pub fn available_authentication_keys_with_critical_or_high_security_level(
         &self,
     ) -> Vec<&QualifiedIdentityPublicKey> {
@@ -561,6 +561,97 @@ impl QualifiedIdentity {
         keys
     }
 
+    /// Explains why this identity can't sign platform state transitions such as document and
+    /// token actions, which need an enabled HIGH or CRITICAL authentication key whose private
+    /// key is loaded. Empty when such a key is available or the identity isn't a user identity.
+    pub fn platform_signing_issues(&self) -> Vec<String> {
+        if self.identity_type != IdentityType::User {
+            return vec![];
+        }
+
+        let is_enabled = |key: &IdentityPublicKey| {
+            self.identity
+                .get_public_key_by_id(key.id())
+                .is_some_and(|current| current.disabled_at().is_none())
+        };
+        let signing_keys =
+            self.available_authentication_keys_with_critical_or_high_security_level();
+        if signing_keys
+            .iter()
+            .any(|key| is_enabled(&key.identity_public_key))
+        {
+            return vec![];
+        }
+
+        let mut issues = vec![];
+        let is_signing_key = |key: &IdentityPublicKey| {
+            key.purpose() == Purpose::AUTHENTICATION
+                && matches!(
+                    key.security_level(),
+                    SecurityLevel::CRITICAL | SecurityLevel::HIGH
+                )
+        };
+        let enabled_on_identity = self
+            .identity
+            .public_keys()
+            .values()
+            .filter(|key| is_signing_key(*key) && key.disabled_at().is_none())
+            .count();
+        let disabled_on_identity = self
+            .identity
+            .public_keys()
+            .values()
+            .filter(|key| is_signing_key(*key) && key.disabled_at().is_some())
+            .count();
+
+        if enabled_on_identity > 0 {
+            issues.push(format!(
+                "The private key of its {} HIGH or CRITICAL authentication key(s) isn't loaded",
+                enabled_on_identity
+            ));
+        } else {
+            issues.push("It has no enabled HIGH or CRITICAL authentication key".to_string());
+        }
+        if disabled_on_identity > 0 {
+            issues.push(format!(
+                "{} HIGH or CRITICAL authentication key(s) are disabled",
+                disabled_on_identity
+            ));
+        }
+        issues
+    }
+
+    /// Explains why this identity can't vote on contests right now, which needs a masternode
+    /// voting identity with an enabled voting key whose private key is loaded. `None` when it
+    /// can vote.
+    pub fn voting_issue(&self) -> Option<&'static str> {
+        if self.identity_type == IdentityType::User {
+            return Some("Only masternodes and evonodes can vote on contests");
+        }
+        let Some((voter_identity, voting_key)) = &self.associated_voter_identity else {
+            return Some("No voting identity is loaded for this masternode");
+        };
+        if voter_identity
+            .get_public_key_by_id(voting_key.id())
+            .is_some_and(|key| key.disabled_at().is_some())
+        {
+            return Some("Its voting key is disabled");
+        }
+        if !self
+            .private_keys
+            .has(&(PrivateKeyTarget::PrivateKeyOnVoterIdentity, voting_key.id()))
+        {
+            return Some("The private key of its voting key isn't loaded");
+        }
+        if matches!(
+            self.status,
+            IdentityStatus::NotFound | IdentityStatus::FailedCreation
+        ) {
+            return Some("The identity wasn't found on Platform");
+        }
+        None
+    }
+
     /// Returns the wallet info for the first public key that is in a wallet.
     ///
     /// If more than one public key is in a wallet, it returns the first one found.
@@ -0,0 +1,195 @@
+use crate::model::qualified_identity::{DPNSNameInfo, IdentityType, QualifiedIdentity};
+use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::identity::Identity;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use serde_json::{Value, json};
+
+/// Version of the public identity export, raised when its layout changes
+const PUBLIC_EXPORT_VERSION: u64 = 1;
+
+impl QualifiedIdentity {
+    /// JSON export of the public portions of the identity to share it with others: its id,
+    /// public keys, alias, DPNS names and the tokens tracked for it. Private keys are never
+    /// read, so none can leak, whether they are stored in the clear, encrypted or in a wallet.
+    ///
+    /// The identity is also exported bincode encoded as `identity`, which only holds its
+    /// public keys, balance and revision, so it can be imported exactly.
+    pub fn public_export(&self, network: Network, tracked_tokens: &[Identifier]) -> String {
+        let public_keys: Vec<Value> = self
+            .identity
+            .public_keys()
+            .values()
+            .map(|key| {
+                json!({
+                    "id": key.id(),
+                    "purpose": format!("{:?}", key.purpose()),
+                    "security_level": format!("{:?}", key.security_level()),
+                    "key_type": format!("{:?}", key.key_type()),
+                    "data": hex::encode(key.data().as_slice()),
+                    "read_only": key.read_only(),
+                    "disabled_at": key.disabled_at(),
+                })
+            })
+            .collect();
+        let dpns_names: Vec<Value> = self
+            .dpns_names
+            .iter()
+            .map(|name| json!({ "name": name.name, "acquired_at": name.acquired_at }))
+            .collect();
+        let identity = bincode::encode_to_vec(&self.identity, bincode::config::standard())
+            .map(hex::encode)
+            .unwrap_or_default();
+
+        let export = json!({
+            "version": PUBLIC_EXPORT_VERSION,
+            "network": network.to_string(),
+            "identity_id": self.identity.id().to_string(Encoding::Base58),
+            "identity_type": self.identity_type.to_string(),
+            "alias": self.alias,
+            "public_keys": public_keys,
+            "dpns_names": dpns_names,
+            "tracked_tokens": tracked_tokens
+                .iter()
+                .map(|token_id| token_id.to_string(Encoding::Base58))
+                .collect::<Vec<_>>(),
+            "identity": identity,
+        });
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+
+    /// Reads an identity exported with [`QualifiedIdentity::public_export`] as a watch-only
+    /// identity, without any private key, with the ids of the tokens tracked for it
+    pub fn from_public_export(
+        export: &str,
+        network: Network,
+    ) -> Result<(QualifiedIdentity, Vec<Identifier>), String> {
+        let export: Value =
+            serde_json::from_str(export).map_err(|e| format!("Invalid identity export: {}", e))?;
+        let field = |name: &str| {
+            export
+                .get(name)
+                .ok_or_else(|| format!("The identity export has no \"{}\"", name))
+        };
+        let version = field("version")?.as_u64();
+        if version != Some(PUBLIC_EXPORT_VERSION) {
+            return Err("Unsupported identity export version".to_string());
+        }
+        let export_network = field("network")?.as_str().unwrap_or_default();
+        if export_network != network.to_string() {
+            return Err(format!(
+                "The identity was exported on {}, switch to that network to import it",
+                export_network
+            ));
+        }
+
+        let bytes = hex::decode(field("identity")?.as_str().unwrap_or_default())
+            .map_err(|e| format!("Invalid identity in the export: {}", e))?;
+        let (identity, _): (Identity, usize) =
+            bincode::decode_from_slice(&bytes, bincode::config::standard())
+                .map_err(|e| format!("Invalid identity in the export: {}", e))?;
+        let identity_id = field("identity_id")?.as_str().unwrap_or_default();
+        if identity.id().to_string(Encoding::Base58) != identity_id {
+            return Err("The identity in the export doesn't match its identity_id".to_string());
+        }
+
+        let identity_type = match field("identity_type")?.as_str() {
+            Some("Masternode") => IdentityType::Masternode,
+            Some("Evonode") => IdentityType::Evonode,
+            _ => IdentityType::User,
+        };
+        let dpns_names = field("dpns_names")?
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|name| {
+                Some(DPNSNameInfo {
+                    name: name.get("name")?.as_str()?.to_string(),
+                    acquired_at: name.get("acquired_at")?.as_u64()?,
+                })
+            })
+            .collect();
+        let tracked_tokens = export
+            .get("tracked_tokens")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|token_id| {
+                Identifier::from_string(token_id.as_str()?, Encoding::Base58).ok()
+            })
+            .collect();
+
+        let mut qualified_identity = QualifiedIdentity::from(identity);
+        qualified_identity.identity_type = identity_type;
+        qualified_identity.alias = export
+            .get("alias")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        qualified_identity.dpns_names = dpns_names;
+        Ok((qualified_identity, tracked_tokens))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::qualified_identity::PrivateKeyTarget;
+    use crate::model::qualified_identity::encrypted_key_storage::PrivateKeyData;
+    use dash_sdk::dpp::identity::v0::IdentityV0;
+    use dash_sdk::dpp::identity::{
+        IdentityPublicKey, KeyType, Purpose, SecurityLevel,
+        identity_public_key::v0::IdentityPublicKeyV0,
+    };
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_public_export_roundtrip_without_private_keys() {
+        let key = IdentityPublicKey::V0(IdentityPublicKeyV0 {
+            id: 0,
+            purpose: Purpose::AUTHENTICATION,
+            security_level: SecurityLevel::MASTER,
+            contract_bounds: None,
+            key_type: KeyType::ECDSA_HASH160,
+            read_only: false,
+            data: vec![7; 20].into(),
+            disabled_at: None,
+        });
+        let identity = Identity::V0(IdentityV0 {
+            id: Identifier::new([1; 32]),
+            public_keys: BTreeMap::from([(0, key.clone())]),
+            balance: 10,
+            revision: 2,
+        });
+        let mut qualified_identity = QualifiedIdentity::from(identity);
+        qualified_identity.alias = Some("alice".to_string());
+        qualified_identity.dpns_names = vec![DPNSNameInfo {
+            name: "alice".to_string(),
+            acquired_at: 1_700_000_000_000,
+        }];
+        let private_key = [0xab; 32];
+        qualified_identity.private_keys.private_keys.insert(
+            (PrivateKeyTarget::PrivateKeyOnMainIdentity, 0),
+            (key.into(), PrivateKeyData::Clear(private_key)),
+        );
+        let token_id = Identifier::new([2; 32]);
+
+        let export = qualified_identity.public_export(Network::Testnet, &[token_id]);
+        assert!(!export.contains(&hex::encode(private_key)));
+        assert!(!export.contains("private"));
+
+        let (imported, tracked_tokens) =
+            QualifiedIdentity::from_public_export(&export, Network::Testnet).unwrap();
+        assert_eq!(imported.identity, qualified_identity.identity);
+        assert_eq!(imported.alias, qualified_identity.alias);
+        assert_eq!(imported.dpns_names, qualified_identity.dpns_names);
+        assert!(imported.private_keys.private_keys.is_empty());
+        assert_eq!(tracked_tokens, vec![token_id]);
+
+        assert!(QualifiedIdentity::from_public_export(&export, Network::Dash).is_err());
+        assert!(QualifiedIdentity::from_public_export("{}", Network::Testnet).is_err());
+    }
+}
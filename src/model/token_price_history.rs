@@ -0,0 +1,8 @@
+use dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
+
+/// Pricing schedule of a token as fetched from Platform at `time_ms`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenPriceHistoryEntry {
+    pub time_ms: u64,
+    pub schedule: TokenPricingSchedule,
+}
@@ -1,6 +1,7 @@
 mod asset_lock_transaction;
 pub mod encryption;
 mod utxos;
+mod watch_only;
 
 use dash_sdk::dashcore_rpc::dashcore::bip32::{ChildNumber, ExtendedPubKey, KeyDerivationType};
 
@@ -144,6 +145,12 @@ pub struct Wallet {
 
 pub type WalletSeedHash = [u8; 32];
 
+/// Minutes without user interaction after which unlocked wallets are locked again, unless
+/// changed in the settings
+pub const DEFAULT_WALLET_AUTO_LOCK_MINUTES: u16 = 10;
+/// Longest auto-lock timeout offered in the settings, in minutes
+pub const MAX_WALLET_AUTO_LOCK_MINUTES: u16 = 240;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum WalletSeed {
     Open(OpenWalletSeed),
@@ -189,16 +196,24 @@ impl WalletSeed {
             WalletSeed::Closed(closed_seed) => {
                 // Try to decrypt the seed
                 let seed = closed_seed.decrypt_seed(password)?;
-                let open_wallet_seed = OpenWalletSeed {
-                    seed,
-                    wallet_info: closed_seed.clone(),
-                };
-                *self = WalletSeed::Open(open_wallet_seed);
+                self.open_with_decrypted_seed(seed);
                 Ok(())
             }
         }
     }
 
+    /// Opens the wallet with a seed already decrypted by [`ClosedKeyItem::decrypt_seed`], which
+    /// lets the slow key derivation run off the UI thread.
+    pub fn open_with_decrypted_seed(&mut self, seed: [u8; 64]) {
+        if let WalletSeed::Closed(closed_seed) = self {
+            let open_wallet_seed = OpenWalletSeed {
+                seed,
+                wallet_info: closed_seed.clone(),
+            };
+            *self = WalletSeed::Open(open_wallet_seed);
+        }
+    }
+
     /// Opens the wallet by decrypting the seed without using a password.
     pub fn open_no_password(&mut self) -> Result<(), String> {
         match self {
@@ -223,9 +238,6 @@ impl WalletSeed {
     }
 
     /// Closes the wallet by securely erasing the seed and transitioning to Closed state.
-    // Allow dead_code: This method provides explicit wallet closure functionality,
-    // useful for security-conscious applications requiring manual wallet management
-    #[allow(dead_code)]
     pub fn close(&mut self) {
         match self {
             WalletSeed::Open(open_seed) => {
@@ -363,6 +375,24 @@ impl Wallet {
         Ok(extended_private_key.to_priv())
     }
 
+    /// Returns the extended public key of the node one level above `derivation_path` (the
+    /// account the key was derived from) together with that node's path.
+    pub fn parent_extended_public_key(
+        &self,
+        derivation_path: &DerivationPath,
+        network: Network,
+    ) -> Result<(DerivationPath, ExtendedPubKey), String> {
+        let children: &[ChildNumber] = derivation_path.as_ref();
+        let Some((_, parent)) = children.split_last() else {
+            return Err("The master key has no parent".to_string());
+        };
+        let parent_path = DerivationPath::from(parent);
+        let extended_public_key = parent_path
+            .derive_pub_ecdsa_for_master_seed(self.seed_bytes()?, network)
+            .map_err(|e| e.to_string())?;
+        Ok((parent_path, extended_public_key))
+    }
+
     pub fn private_key_for_address(
         &self,
         address: &Address,
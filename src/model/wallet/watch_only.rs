@@ -0,0 +1,128 @@
+use crate::model::wallet::{DerivationPathReference, Wallet};
+use crate::ui::wallets::add_new_wallet_screen::{
+    DASH_BIP44_ACCOUNT_0_PATH_MAINNET, DASH_BIP44_ACCOUNT_0_PATH_TESTNET,
+};
+use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::dashcore::bip32::DerivationPath;
+use serde_json::{Value, json};
+
+/// Characters a descriptor may hold, ordered as the descriptor checksum expects
+const DESCRIPTOR_INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+/// Characters of the descriptor checksum itself
+const DESCRIPTOR_CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn descriptor_polymod(checksum: u64, value: u64) -> u64 {
+    const GENERATORS: [u64; 5] = [
+        0xf5dee51989,
+        0xa9fdca3312,
+        0x1bab10e32d,
+        0x3706b1677a,
+        0x644d626ffd,
+    ];
+    let top = checksum >> 35;
+    let mut checksum = ((checksum & 0x7ffffffff) << 5) ^ value;
+    for (bit, generator) in GENERATORS.iter().enumerate() {
+        if (top >> bit) & 1 == 1 {
+            checksum ^= generator;
+        }
+    }
+    checksum
+}
+
+/// Appends the checksum Dash Core requires to import a descriptor, as in
+/// "raw(deadbeef)#89f8spxm". `None` if the descriptor holds a character descriptors can't.
+fn with_descriptor_checksum(descriptor: &str) -> Option<String> {
+    let mut checksum = 1;
+    let mut group = 0;
+    let mut group_len = 0;
+    for c in descriptor.chars() {
+        let position = DESCRIPTOR_INPUT_CHARSET.find(c)? as u64;
+        checksum = descriptor_polymod(checksum, position & 31);
+        group = group * 3 + (position >> 5);
+        group_len += 1;
+        if group_len == 3 {
+            checksum = descriptor_polymod(checksum, group);
+            group = 0;
+            group_len = 0;
+        }
+    }
+    if group_len > 0 {
+        checksum = descriptor_polymod(checksum, group);
+    }
+    for _ in 0..8 {
+        checksum = descriptor_polymod(checksum, 0);
+    }
+    checksum ^= 1;
+
+    let suffix: String = (0..8)
+        .map(|i| DESCRIPTOR_CHECKSUM_CHARSET[((checksum >> (5 * (7 - i))) & 31) as usize] as char)
+        .collect();
+    Some(format!("{}#{}", descriptor, suffix))
+}
+
+impl Wallet {
+    /// JSON export to track the wallet in another tool without its private keys, its
+    /// `descriptors` as the `importdescriptors` RPC of Dash Core takes them. Built from public
+    /// material only, so the wallet doesn't need to be unlocked.
+    ///
+    /// The BIP44 account is exported through its extended public key, covering every receive
+    /// and change address. Addresses on the hardened identity paths can't be derived from it,
+    /// so each one the wallet knows is exported on its own, labeled with its path.
+    pub fn watch_only_descriptors(&self, network: Network) -> String {
+        let account_path = match network {
+            Network::Dash => DerivationPath::from(DASH_BIP44_ACCOUNT_0_PATH_MAINNET.as_slice()),
+            _ => DerivationPath::from(DASH_BIP44_ACCOUNT_0_PATH_TESTNET.as_slice()),
+        };
+        let xpub = &self.master_bip44_ecdsa_extended_public_key;
+
+        let mut descriptors: Vec<Value> = [(0, false), (1, true)]
+            .into_iter()
+            .filter_map(|(chain, internal)| {
+                let descriptor = with_descriptor_checksum(&format!("pkh({}/{}/*)", xpub, chain))?;
+                Some(json!({
+                    "desc": descriptor,
+                    "timestamp": 0,
+                    "active": true,
+                    "internal": internal,
+                }))
+            })
+            .collect();
+        descriptors.extend(
+            self.watched_addresses
+                .iter()
+                .filter(|(_, info)| info.path_reference != DerivationPathReference::BIP44)
+                .filter_map(|(path, info)| {
+                    let descriptor = with_descriptor_checksum(&format!("addr({})", info.address))?;
+                    Some(json!({
+                        "desc": descriptor,
+                        "timestamp": 0,
+                        "label": format!("{:?} {}", info.path_reference, path),
+                    }))
+                }),
+        );
+
+        let export = json!({
+            "wallet": self.alias.clone().unwrap_or_else(|| "Unnamed Wallet".to_string()),
+            "network": network.to_string(),
+            "bip44_account_path": account_path.to_string(),
+            "bip44_account_xpub": xpub.to_string(),
+            "descriptors": descriptors,
+        });
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_descriptor_checksum() {
+        // Test vector of BIP 380
+        assert_eq!(
+            with_descriptor_checksum("raw(deadbeef)"),
+            Some("raw(deadbeef)#89f8spxm".to_string())
+        );
+        assert_eq!(with_descriptor_checksum("addr(é)"), None);
+    }
+}
@@ -14,6 +14,7 @@ pub fn add_dpns_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext)
         DPNSSubscreen::Past,
         DPNSSubscreen::Owned,
         DPNSSubscreen::ScheduledVotes,
+        DPNSSubscreen::Watchlist,
     ];
 
     let active_screen = match app_context.get_settings() {
@@ -22,6 +23,7 @@ pub fn add_dpns_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext)
             ui::RootScreenType::RootScreenDPNSPastContests => DPNSSubscreen::Past,
             ui::RootScreenType::RootScreenDPNSOwnedNames => DPNSSubscreen::Owned,
             ui::RootScreenType::RootScreenDPNSScheduledVotes => DPNSSubscreen::ScheduledVotes,
+            ui::RootScreenType::RootScreenDPNSWatchlist => DPNSSubscreen::Watchlist,
             _ => DPNSSubscreen::Active,
         },
         _ => DPNSSubscreen::Active, // Fallback to Active screen if settings unavailable
@@ -106,6 +108,11 @@ pub fn add_dpns_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext)
                                             RootScreenType::RootScreenDPNSScheduledVotes,
                                         )
                                     }
+                                    DPNSSubscreen::Watchlist => {
+                                        action = AppAction::SetMainScreen(
+                                            RootScreenType::RootScreenDPNSWatchlist,
+                                        )
+                                    }
                                 }
                             }
 
@@ -0,0 +1,101 @@
+use crate::context::AppContext;
+use crate::ui::theme::DashColors;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use egui::{Key, Label, RichText, Sense, TextEdit, Ui};
+
+/// Alias of an identity being edited in place in a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineAliasEdit {
+    identity_id: Identifier,
+    text: String,
+    /// Whether the text field was given the focus already
+    focused: bool,
+}
+
+/// Alias entered in the text field, `None` once cleared
+fn entered_alias(text: &str) -> Option<String> {
+    let alias = text.trim();
+    (!alias.is_empty()).then(|| alias.to_string())
+}
+
+/// Renders the alias of `identity_id`, or its base58 id without one, which turns into a text
+/// field on double-click. Enter or clicking away saves the alias, Escape reverts it. Returns
+/// the new alias once saved, `Some(None)` when it was cleared.
+pub fn render_inline_identity_alias(
+    ui: &mut Ui,
+    app_context: &AppContext,
+    identity_id: &Identifier,
+    alias: Option<&str>,
+    edit: &mut Option<InlineAliasEdit>,
+) -> Option<Option<String>> {
+    let Some(current) = edit
+        .as_mut()
+        .filter(|edit| edit.identity_id == *identity_id)
+    else {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let text = match alias {
+            Some(alias) => RichText::new(alias).color(DashColors::text_primary(dark_mode)),
+            None => RichText::new(identity_id.to_string(Encoding::Base58))
+                .color(DashColors::text_secondary(dark_mode)),
+        };
+        let response = ui
+            .add(Label::new(text).truncate().sense(Sense::click()))
+            .on_hover_text("Double-click to edit the alias");
+        if response.double_clicked() {
+            *edit = Some(InlineAliasEdit {
+                identity_id: *identity_id,
+                text: alias.unwrap_or_default().to_string(),
+                focused: false,
+            });
+        }
+        return None;
+    };
+
+    let response = ui.add(
+        TextEdit::singleline(&mut current.text)
+            .hint_text("Alias")
+            .desired_width(ui.available_width()),
+    );
+    if !current.focused {
+        response.request_focus();
+        current.focused = true;
+        return None;
+    }
+    if ui.input(|i| i.key_pressed(Key::Escape)) {
+        *edit = None;
+        return None;
+    }
+    if !response.lost_focus() {
+        return None;
+    }
+
+    let new_alias = entered_alias(&current.text);
+    *edit = None;
+    if new_alias.as_deref() == alias {
+        return None;
+    }
+    match app_context.set_identity_alias(identity_id, new_alias.as_deref()) {
+        Ok(()) => Some(new_alias),
+        Err(e) => {
+            tracing::error!(
+                "Failed to save the alias of identity {}: {}",
+                identity_id.to_string(Encoding::Base58),
+                e
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entered_alias() {
+        assert_eq!(entered_alias("  alice "), Some("alice".to_string()));
+        assert_eq!(entered_alias("   "), None);
+        assert_eq!(entered_alias(""), None);
+    }
+}
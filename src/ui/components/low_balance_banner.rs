@@ -0,0 +1,77 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::ui::Screen;
+use crate::ui::identities::top_up_identity_screen::TopUpIdentityScreen;
+use crate::ui::theme::{DashColors, Shape};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use egui::{RichText, Ui};
+use std::sync::Arc;
+
+/// Credit balance under which the signing identity is warned about, unless changed in the
+/// settings. 0.01 DASH.
+pub const DEFAULT_LOW_BALANCE_WARNING_CREDITS: Credits = 1_000_000_000;
+
+/// Whether `balance` is under `threshold`, a threshold of 0 disabling the warning
+fn is_balance_low(balance: Credits, threshold: Credits) -> bool {
+    threshold > 0 && balance < threshold
+}
+
+/// Renders a warning banner with a "Top Up" shortcut when the credit balance of the signing
+/// identity is under the threshold set in the settings. Nothing is shown otherwise.
+pub fn render_low_balance_banner(
+    ui: &mut Ui,
+    app_context: &Arc<AppContext>,
+    identity: &QualifiedIdentity,
+) -> AppAction {
+    let balance = identity.identity.balance();
+    let threshold = app_context.low_balance_warning_credits();
+    if !is_balance_low(balance, threshold) {
+        return AppAction::None;
+    }
+
+    let dark_mode = ui.ctx().style().visuals.dark_mode;
+    let mut action = AppAction::None;
+    egui::Frame::new()
+        .fill(DashColors::WARNING.gamma_multiply(0.15))
+        .stroke(egui::Stroke::new(1.0, DashColors::WARNING))
+        .corner_radius(egui::CornerRadius::same(Shape::RADIUS_MD))
+        .inner_margin(egui::Margin::same(8))
+        .show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(
+                    RichText::new(format!(
+                        "⚠ {} has {} credits (~{:.8} DASH) left, under the {} credits warning \
+                         threshold. Actions may fail for lack of credits.",
+                        identity.display_short_string(),
+                        balance,
+                        balance as f64 / 100_000_000_000.0,
+                        threshold
+                    ))
+                    .color(DashColors::text_primary(dark_mode)),
+                );
+                if ui.button("Top Up").clicked() {
+                    action = AppAction::AddScreen(Screen::TopUpIdentityScreen(
+                        TopUpIdentityScreen::new(identity.clone(), app_context),
+                    ));
+                }
+            });
+        });
+    ui.add_space(10.0);
+    action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_balance_low() {
+        assert!(is_balance_low(0, DEFAULT_LOW_BALANCE_WARNING_CREDITS));
+        assert!(is_balance_low(999, 1_000));
+        assert!(!is_balance_low(1_000, 1_000));
+        // A threshold of 0 disables the warning, even for an empty identity
+        assert!(!is_balance_low(0, 0));
+    }
+}
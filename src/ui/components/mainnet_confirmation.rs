@@ -0,0 +1,199 @@
+use crate::context::AppContext;
+use crate::ui::theme::DashColors;
+use dash_sdk::dpp::balances::credits::{Credits, TokenAmount};
+use egui::{Frame, Margin, RichText, Stroke, Ui};
+use std::collections::BTreeSet;
+
+/// Credit transfers of at least this many credits (1 DASH) count as large.
+pub const LARGE_CREDIT_TRANSFER_THRESHOLD: Credits = 100_000_000_000;
+
+/// Irreversible actions that can require a second confirmation on mainnet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IrreversibleAction {
+    BurnTokens,
+    DestroyFrozenFunds,
+    ControlGroupChange,
+    LargeTransfer,
+}
+
+impl IrreversibleAction {
+    pub const ALL: [IrreversibleAction; 4] = [
+        IrreversibleAction::BurnTokens,
+        IrreversibleAction::DestroyFrozenFunds,
+        IrreversibleAction::ControlGroupChange,
+        IrreversibleAction::LargeTransfer,
+    ];
+
+    /// Label shown in the settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            IrreversibleAction::BurnTokens => "Burning tokens",
+            IrreversibleAction::DestroyFrozenFunds => "Destroying frozen funds",
+            IrreversibleAction::ControlGroupChange => {
+                "Token config updates that change who controls the token"
+            }
+            IrreversibleAction::LargeTransfer => {
+                "Large transfers (1 DASH or more, or half or more of a token balance)"
+            }
+        }
+    }
+
+    /// Identifier used for this action in the database
+    pub fn storage_key(&self) -> &'static str {
+        match self {
+            IrreversibleAction::BurnTokens => "burn_tokens",
+            IrreversibleAction::DestroyFrozenFunds => "destroy_frozen_funds",
+            IrreversibleAction::ControlGroupChange => "control_group_change",
+            IrreversibleAction::LargeTransfer => "large_transfer",
+        }
+    }
+
+    /// Parses an identifier returned by [`IrreversibleAction::storage_key`]
+    pub fn from_storage_key(key: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.storage_key() == key)
+    }
+}
+
+/// Whether mainnet actions need a second confirmation, and for which actions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MainnetConfirmationSettings {
+    pub enabled: bool,
+    pub actions: BTreeSet<IrreversibleAction>,
+}
+
+impl Default for MainnetConfirmationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            actions: IrreversibleAction::ALL.into_iter().collect(),
+        }
+    }
+}
+
+impl MainnetConfirmationSettings {
+    /// Comma separated storage keys of the selected actions
+    pub fn actions_to_storage(&self) -> String {
+        self.actions
+            .iter()
+            .map(|action| action.storage_key())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Parses the output of [`MainnetConfirmationSettings::actions_to_storage`], ignoring
+    /// unknown actions. A missing value selects every action.
+    pub fn actions_from_storage(stored: Option<&str>) -> BTreeSet<IrreversibleAction> {
+        match stored {
+            Some(stored) => stored
+                .split(',')
+                .filter_map(IrreversibleAction::from_storage_key)
+                .collect(),
+            None => IrreversibleAction::ALL.into_iter().collect(),
+        }
+    }
+}
+
+/// Whether a token transfer moves half or more of the sender's balance.
+pub fn is_large_token_transfer(amount: TokenAmount, balance: TokenAmount) -> bool {
+    amount.saturating_mul(2) >= balance
+}
+
+/// Second confirmation step for irreversible actions on mainnet, shown inside the screen's
+/// own confirmation. Reset it whenever that confirmation is opened.
+#[derive(Debug, Default)]
+pub struct MainnetConfirmation {
+    typed_text: String,
+    acknowledged: bool,
+}
+
+impl MainnetConfirmation {
+    pub fn reset(&mut self) {
+        self.typed_text.clear();
+        self.acknowledged = false;
+    }
+
+    /// Renders the confirmation if `action` needs one on this network and returns whether the
+    /// action may go ahead.
+    ///
+    /// The user has to type `expected_text`, such as the token or identity name, or tick a
+    /// checkbox when there is no name to type.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        app_context: &AppContext,
+        action: IrreversibleAction,
+        expected_text: Option<&str>,
+    ) -> bool {
+        if !app_context.requires_mainnet_confirmation(action) {
+            return true;
+        }
+
+        let mut confirmed = false;
+        Frame::new()
+            .stroke(Stroke::new(1.0, DashColors::ERROR))
+            .corner_radius(4)
+            .inner_margin(Margin::same(8))
+            .show(ui, |ui| {
+                ui.label(
+                    RichText::new("⚠ This is MAINNET")
+                        .strong()
+                        .color(DashColors::ERROR),
+                );
+                ui.label(
+                    "This action uses real funds and is irreversible. It cannot be undone once \
+                     it is broadcast.",
+                );
+                ui.add_space(5.0);
+                match expected_text.filter(|text| !text.is_empty()) {
+                    Some(expected_text) => {
+                        ui.label(format!("Type \"{}\" to confirm:", expected_text));
+                        ui.text_edit_singleline(&mut self.typed_text);
+                        confirmed = self.typed_text.trim() == expected_text;
+                    }
+                    None => {
+                        ui.checkbox(&mut self.acknowledged, "I understand this is irreversible");
+                        confirmed = self.acknowledged;
+                    }
+                }
+            });
+        ui.add_space(10.0);
+        confirmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirmation_actions_storage_round_trip() {
+        let mut settings = MainnetConfirmationSettings::default();
+        assert_eq!(
+            MainnetConfirmationSettings::actions_from_storage(Some(&settings.actions_to_storage())),
+            settings.actions
+        );
+
+        settings.actions.remove(&IrreversibleAction::LargeTransfer);
+        settings.actions.remove(&IrreversibleAction::BurnTokens);
+        assert_eq!(
+            MainnetConfirmationSettings::actions_from_storage(Some(&settings.actions_to_storage())),
+            settings.actions
+        );
+
+        // Nothing selected stays nothing, a missing value means every action
+        assert!(MainnetConfirmationSettings::actions_from_storage(Some("")).is_empty());
+        assert_eq!(
+            MainnetConfirmationSettings::actions_from_storage(None).len(),
+            IrreversibleAction::ALL.len()
+        );
+    }
+
+    #[test]
+    fn test_is_large_token_transfer() {
+        assert!(is_large_token_transfer(50, 100));
+        assert!(is_large_token_transfer(100, 100));
+        assert!(!is_large_token_transfer(49, 100));
+    }
+}
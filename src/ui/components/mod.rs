@@ -2,9 +2,17 @@ pub mod clickable_collapsing_header;
 pub mod contract_chooser_panel;
 pub mod dpns_subscreen_chooser_panel;
 pub mod entropy_grid;
+pub mod inline_alias_edit;
 pub mod left_panel;
 pub mod left_wallet_panel;
+pub mod low_balance_banner;
+pub mod mainnet_confirmation;
+pub mod password_strength;
+pub mod qr_code;
 pub mod styled;
+pub mod table_column_widths;
+pub mod token_action_simulation;
+pub mod token_amount_input;
 pub mod tokens_subscreen_chooser_panel;
 pub mod tools_subscreen_chooser_panel;
 pub mod top_panel;
@@ -0,0 +1,147 @@
+use crate::ui::theme::DashColors;
+use egui::{Color32, RichText, Ui};
+use zxcvbn::zxcvbn;
+
+/// Passwords shorter than this get a warning, whatever their estimated strength
+pub const MIN_RECOMMENDED_PASSWORD_LENGTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordStrength {
+    Weak,
+    Medium,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Maps a zxcvbn score, from 0 to 4, to a strength
+    fn from_score(score: u8) -> Self {
+        match score {
+            0 | 1 => PasswordStrength::Weak,
+            2 => PasswordStrength::Medium,
+            _ => PasswordStrength::Strong,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            PasswordStrength::Weak => "Weak",
+            PasswordStrength::Medium => "Medium",
+            PasswordStrength::Strong => "Strong",
+        }
+    }
+
+    fn color(self) -> Color32 {
+        match self {
+            PasswordStrength::Weak => DashColors::ERROR,
+            PasswordStrength::Medium => DashColors::WARNING,
+            PasswordStrength::Strong => DashColors::SUCCESS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PasswordEstimate {
+    strength: PasswordStrength,
+    /// zxcvbn score from 0 to 4
+    score: u8,
+    entropy_bits: f64,
+    time_to_crack: String,
+    too_short: bool,
+}
+
+/// Strength meter shown under a wallet password field. Purely advisory, it never prevents
+/// using a weak password.
+#[derive(Debug, Default)]
+pub struct PasswordStrengthMeter {
+    estimate: Option<PasswordEstimate>,
+}
+
+impl PasswordStrengthMeter {
+    /// Re-estimates the strength, call it whenever the password changes
+    pub fn update(&mut self, password: &str) {
+        if password.is_empty() {
+            self.estimate = None;
+            return;
+        }
+
+        let entropy = zxcvbn(password, &[]);
+        let score = u8::from(entropy.score());
+        self.estimate = Some(PasswordEstimate {
+            strength: PasswordStrength::from_score(score),
+            score,
+            entropy_bits: entropy.guesses_log10() * std::f64::consts::LOG2_10,
+            time_to_crack: entropy
+                .crack_times()
+                .offline_slow_hashing_1e4_per_second()
+                .to_string(),
+            too_short: password.chars().count() < MIN_RECOMMENDED_PASSWORD_LENGTH,
+        });
+    }
+
+    /// Renders a colored bar with the strength, and a warning for very short passwords.
+    /// Nothing is shown while the password is empty.
+    pub fn show(&self, ui: &mut Ui) {
+        let Some(estimate) = &self.estimate else {
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Password Strength:");
+            ui.add(
+                egui::ProgressBar::new((estimate.score + 1) as f32 / 5.0)
+                    .desired_width(200.0)
+                    .fill(estimate.strength.color())
+                    .text(
+                        RichText::new(estimate.strength.label())
+                            .color(Color32::WHITE)
+                            .strong(),
+                    ),
+            );
+            ui.label(
+                RichText::new(format!("~{:.0} bits", estimate.entropy_bits))
+                    .color(DashColors::TEXT_SECONDARY),
+            );
+        });
+        ui.label(
+            RichText::new(format!(
+                "Estimated time to crack: {}",
+                estimate.time_to_crack
+            ))
+            .color(DashColors::TEXT_SECONDARY),
+        );
+        if estimate.too_short {
+            ui.colored_label(
+                DashColors::WARNING,
+                format!(
+                    "⚠ This password is very short, use at least {} characters",
+                    MIN_RECOMMENDED_PASSWORD_LENGTH
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_strength_meter() {
+        let mut meter = PasswordStrengthMeter::default();
+        let strength = |meter: &PasswordStrengthMeter| {
+            meter.estimate.as_ref().map(|estimate| estimate.strength)
+        };
+        assert_eq!(strength(&meter), None);
+
+        meter.update("abc");
+        assert_eq!(strength(&meter), Some(PasswordStrength::Weak));
+        assert!(meter.estimate.as_ref().unwrap().too_short);
+
+        meter.update("vX9#qLm2!tZr7@wKp4");
+        assert_eq!(strength(&meter), Some(PasswordStrength::Strong));
+        assert!(!meter.estimate.as_ref().unwrap().too_short);
+
+        meter.update("");
+        assert_eq!(strength(&meter), None);
+    }
+}
@@ -0,0 +1,84 @@
+use egui::{Color32, Rect, Response, Sense, Ui, Vec2};
+use qrcode::QrCode;
+
+/// Modules of blank margin the QR specification requires around a code for it to scan
+const QUIET_ZONE_MODULES: usize = 4;
+
+/// Encodes `data` as a QR code, returning its width in modules and whether each module, row
+/// by row, is dark.
+fn qr_modules(data: &str) -> Option<(usize, Vec<bool>)> {
+    let code = QrCode::new(data.as_bytes()).ok()?;
+    let modules = code
+        .to_colors()
+        .into_iter()
+        .map(|color| color == qrcode::Color::Dark)
+        .collect();
+    Some((code.width(), modules))
+}
+
+/// Draws `data` as a QR code with the painter, each module `module_size` points wide. The code
+/// is always dark on light, whatever the theme, so phone cameras can read it.
+pub fn qr_code(ui: &mut Ui, data: &str, module_size: f32) -> Response {
+    let Some((width, modules)) = qr_modules(data) else {
+        return ui.label("Failed to generate QR code.");
+    };
+
+    let side = (width + 2 * QUIET_ZONE_MODULES) as f32 * module_size;
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(side), Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, Color32::WHITE);
+        let origin = rect.min + Vec2::splat(QUIET_ZONE_MODULES as f32 * module_size);
+        for (i, _) in modules.iter().enumerate().filter(|(_, dark)| **dark) {
+            let module_min =
+                origin + Vec2::new((i % width) as f32, (i / width) as f32) * module_size;
+            painter.rect_filled(
+                Rect::from_min_size(module_min, Vec2::splat(module_size)),
+                0.0,
+                Color32::BLACK,
+            );
+        }
+    }
+    response
+}
+
+/// Shows an address as text, or as a QR code of `qr_data`, with a button to switch between
+/// the two. The choice is remembered per `id_salt`.
+pub fn address_with_qr_toggle(
+    ui: &mut Ui,
+    id_salt: &str,
+    address: &str,
+    qr_data: &str,
+    text_color: Color32,
+) {
+    let id = ui.make_persistent_id(id_salt);
+    let mut show_qr = ui.data(|d| d.get_temp::<bool>(id).unwrap_or(false));
+
+    ui.horizontal(|ui| {
+        if show_qr {
+            qr_code(ui, qr_data, 3.0).on_hover_text(address);
+        } else {
+            ui.label(egui::RichText::new(address).color(text_color));
+        }
+        let toggle_text = if show_qr { "Show Text" } else { "Show QR" };
+        if ui.small_button(toggle_text).clicked() {
+            show_qr = !show_qr;
+        }
+    });
+
+    ui.data_mut(|d| d.insert_temp(id, show_qr));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_modules() {
+        let (width, modules) = qr_modules("dash:XtVXKTmA7hYDVvAVd3nzwcJFYGzy7Fbbz3").unwrap();
+        assert_eq!(modules.len(), width * width);
+        // Every code starts with a finder pattern, whose corner module is dark
+        assert!(modules[0]);
+        assert!(modules.iter().any(|dark| !dark));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::context::AppContext;
+use egui::Response;
+
+/// Column widths of a table, restored from the settings database and saved to it whenever the
+/// user finishes resizing a column.
+///
+/// Give the table [`Self::id_salt`], build its columns from [`Self::initial_widths`], pass
+/// the body widths to [`Self::track`] every frame and add [`Self::header_context_menu`] to the
+/// header cells.
+#[derive(Debug)]
+pub struct TableColumnWidths {
+    table_key: &'static str,
+    saved: Vec<f32>,
+    /// Widths seen in the previous frame
+    last_seen: Vec<f32>,
+    /// Whether the widths changed since the pointer was pressed
+    resizing: bool,
+    /// Bumped on reset, so the table starts over from its initial widths
+    generation: u32,
+}
+
+impl TableColumnWidths {
+    pub fn load(app_context: &AppContext, table_key: &'static str) -> Self {
+        let saved = app_context
+            .db
+            .get_column_widths(table_key)
+            .unwrap_or_else(|e| {
+                tracing::error!("Error loading column widths of {}: {}", table_key, e);
+                Vec::new()
+            });
+        Self {
+            table_key,
+            saved,
+            last_seen: Vec::new(),
+            resizing: false,
+            generation: 0,
+        }
+    }
+
+    /// Id salt of the table, changed by a reset to drop the widths egui remembers
+    pub fn id_salt(&self) -> (&'static str, u32) {
+        (self.table_key, self.generation)
+    }
+
+    /// Initial widths of the columns: the saved ones, or `defaults` where there are none
+    pub fn initial_widths(&self, defaults: &[f32]) -> Vec<f32> {
+        defaults
+            .iter()
+            .enumerate()
+            .map(|(index, default)| self.saved.get(index).copied().unwrap_or(*default))
+            .collect()
+    }
+
+    /// Saves the widths once the user releases a column they resized
+    pub fn track(&mut self, ctx: &egui::Context, app_context: &AppContext, widths: &[f32]) {
+        let pointer_down = ctx.input(|i| i.pointer.any_down());
+        let Some(resized) = self.observe(widths, pointer_down) else {
+            return;
+        };
+        if let Err(e) = app_context.db.set_column_widths(self.table_key, &resized) {
+            tracing::error!("Error saving column widths of {}: {}", self.table_key, e);
+        }
+        self.saved = resized;
+    }
+
+    /// Records the widths of a frame, returning them when a resize just finished
+    fn observe(&mut self, widths: &[f32], pointer_down: bool) -> Option<Vec<f32>> {
+        let changed = self.last_seen.len() == widths.len()
+            && self
+                .last_seen
+                .iter()
+                .zip(widths)
+                .any(|(before, after)| (before - after).abs() > 0.5);
+        self.last_seen = widths.to_vec();
+        if pointer_down {
+            self.resizing |= changed;
+            return None;
+        }
+        if !std::mem::take(&mut self.resizing) || widths == self.saved.as_slice() {
+            return None;
+        }
+        Some(widths.to_vec())
+    }
+
+    /// Adds a context menu with "Reset column widths" to a header cell's response
+    pub fn header_context_menu(&mut self, response: &Response, app_context: &AppContext) {
+        response.context_menu(|ui| {
+            if ui.button("Reset column widths").clicked() {
+                if let Err(e) = app_context.db.delete_column_widths(self.table_key) {
+                    tracing::error!("Error resetting column widths of {}: {}", self.table_key, e);
+                }
+                self.saved.clear();
+                self.last_seen.clear();
+                self.resizing = false;
+                self.generation += 1;
+                ui.close_menu();
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths() -> TableColumnWidths {
+        TableColumnWidths {
+            table_key: "test",
+            saved: vec![100.0, 200.0],
+            last_seen: Vec::new(),
+            resizing: false,
+            generation: 0,
+        }
+    }
+
+    #[test]
+    fn test_observe_saves_after_resize() {
+        let mut widths = widths();
+        assert_eq!(
+            widths.initial_widths(&[50.0, 50.0, 50.0]),
+            vec![100.0, 200.0, 50.0]
+        );
+
+        // Widths settling without the pointer held, as when content changes, aren't saved
+        assert_eq!(widths.observe(&[100.0, 200.0], false), None);
+        assert_eq!(widths.observe(&[120.0, 200.0], false), None);
+
+        // Dragging a column edge, then releasing it
+        assert_eq!(widths.observe(&[120.0, 200.0], true), None);
+        assert_eq!(widths.observe(&[150.0, 200.0], true), None);
+        assert_eq!(widths.observe(&[180.0, 200.0], true), None);
+        assert_eq!(
+            widths.observe(&[180.0, 200.0], false),
+            Some(vec![180.0, 200.0])
+        );
+        assert_eq!(widths.observe(&[180.0, 200.0], false), None);
+
+        // A click without resizing saves nothing
+        assert_eq!(widths.observe(&[180.0, 200.0], true), None);
+        assert_eq!(widths.observe(&[180.0, 200.0], false), None);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::backend_task::BackendTask;
+use crate::backend_task::tokens::TokenTask;
+use crate::ui::theme::DashColors;
+use dash_sdk::dpp::fee::Credits;
+use egui::{RichText, Ui};
+
+/// "Simulate" toggle of the token action screens. While it's on, the action's transition is
+/// built and signed to check it and see its minimum fee, but never broadcast.
+#[derive(Debug, Default)]
+pub struct TokenActionSimulation {
+    pub enabled: bool,
+    /// Outcome of the last simulation
+    result: Option<String>,
+}
+
+impl TokenActionSimulation {
+    pub fn checkbox(&mut self, ui: &mut Ui) {
+        ui.checkbox(&mut self.enabled, "Simulate").on_hover_text(
+            "Build and sign the transition to check it and see its minimum fee, without \
+                 submitting it to Platform",
+        );
+    }
+
+    /// The task running a token action, or only its simulation when the toggle is on.
+    /// Starting a task clears the outcome of the previous simulation.
+    pub fn task(&mut self, task: TokenTask) -> BackendTask {
+        self.result = None;
+        if self.enabled {
+            BackendTask::TokenTask(Box::new(TokenTask::SimulateTransition(Box::new(task))))
+        } else {
+            BackendTask::TokenTask(Box::new(task))
+        }
+    }
+
+    pub fn set_result(&mut self, transition: &str, size_bytes: usize, minimum_fee: Credits) {
+        self.result = Some(format!(
+            "{} transition is valid: {} bytes, minimum fee {} credits",
+            transition, size_bytes, minimum_fee
+        ));
+    }
+
+    /// Shows the outcome of the last simulation, if any
+    pub fn show_result(&self, ui: &mut Ui) {
+        let Some(result) = &self.result else {
+            return;
+        };
+        ui.colored_label(DashColors::SUCCESS, format!("Simulation: {}", result));
+        ui.label(
+            RichText::new("Nothing was broadcast.")
+                .small()
+                .color(DashColors::TEXT_SECONDARY),
+        );
+    }
+}
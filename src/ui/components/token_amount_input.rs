@@ -0,0 +1,143 @@
+use crate::ui::helpers::format_token_amount;
+use crate::ui::theme::DashColors;
+use dash_sdk::dpp::balances::credits::TokenAmount;
+use egui::{TextEdit, Ui};
+
+/// Parses an amount typed with the token's decimals, such as "1.5", into base units. Digits
+/// beyond the token's precision are rejected rather than rounded, unless they are zeros.
+pub fn parse_token_amount(input: &str, decimals: u8) -> Result<TokenAmount, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Enter an amount".to_string());
+    }
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if fraction.contains('.') {
+        return Err("Invalid amount: too many decimal points".to_string());
+    }
+    if whole.is_empty() && fraction.is_empty() {
+        return Err("Invalid amount: must be a number".to_string());
+    }
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if !is_digits(whole) || !is_digits(fraction) {
+        return Err("Invalid amount: must be a number".to_string());
+    }
+
+    let (fraction, excess) = fraction.split_at(fraction.len().min(decimals as usize));
+    if excess.chars().any(|c| c != '0') {
+        return Err(format!(
+            "Too many decimal places. Maximum allowed: {}",
+            decimals
+        ));
+    }
+
+    let too_large = || "Amount too large".to_string();
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<TokenAmount>().map_err(|_| too_large())?
+    };
+    let fraction = format!("{:0<width$}", fraction, width = decimals as usize);
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<TokenAmount>().map_err(|_| too_large())?
+    };
+    10u64
+        .checked_pow(decimals as u32)
+        .and_then(|multiplier| whole.checked_mul(multiplier))
+        .and_then(|whole| whole.checked_add(fraction))
+        .ok_or_else(too_large)
+}
+
+/// Parses an amount like [`parse_token_amount`], also rejecting amounts above `max`, such as
+/// the token's max supply
+pub fn check_token_amount(
+    input: &str,
+    decimals: u8,
+    max: Option<TokenAmount>,
+) -> Result<TokenAmount, String> {
+    let amount = parse_token_amount(input, decimals)?;
+    match max {
+        Some(max) if amount > max => Err(format!(
+            "Amount exceeds the maximum of {}",
+            format_token_amount(max, decimals)
+        )),
+        _ => Ok(amount),
+    }
+}
+
+/// Renders a text field for an amount typed with the token's decimals, with the reason it's
+/// invalid next to it. Returns the amount in base units.
+pub fn render_token_amount_input(
+    ui: &mut Ui,
+    amount: &mut String,
+    decimals: u8,
+    max: Option<TokenAmount>,
+) -> Result<TokenAmount, String> {
+    let hint = if decimals == 0 { "1" } else { "1.5" };
+    ui.add(TextEdit::singleline(amount).hint_text(hint));
+    let parsed = check_token_amount(amount, decimals, max);
+    // An empty field isn't an error until the amount is submitted
+    let error = parsed.as_ref().err().filter(|_| !amount.trim().is_empty());
+    if let Some(e) = error {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.colored_label(DashColors::error_color(dark_mode), e.as_str());
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_amount() {
+        assert_eq!(parse_token_amount("1.5", 8), Ok(150_000_000));
+        assert_eq!(parse_token_amount(" 42 ", 2), Ok(4_200));
+        assert_eq!(parse_token_amount(".25", 2), Ok(25));
+        assert_eq!(parse_token_amount("3.", 2), Ok(300));
+        assert_eq!(parse_token_amount("7", 0), Ok(7));
+        assert!(parse_token_amount("", 2).is_err());
+        assert!(parse_token_amount(".", 2).is_err());
+        assert!(parse_token_amount("1.2.3", 2).is_err());
+        assert!(parse_token_amount("-1", 2).is_err());
+        assert!(parse_token_amount("1e3", 2).is_err());
+        assert!(parse_token_amount("1.5", 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_token_amount_rejects_rounding() {
+        // Over-precision is an error instead of being rounded or truncated
+        assert!(parse_token_amount("1.555", 2).is_err());
+        assert!(parse_token_amount("0.001", 2).is_err());
+        // Trailing zeros don't change the amount
+        assert_eq!(parse_token_amount("1.500", 2), Ok(150));
+        assert_eq!(parse_token_amount("0.10000000000", 1), Ok(1));
+    }
+
+    #[test]
+    fn test_parse_token_amount_overflow() {
+        assert_eq!(
+            parse_token_amount("18446744073709551615", 0),
+            Ok(TokenAmount::MAX)
+        );
+        assert_eq!(
+            parse_token_amount("184467440737.09551615", 8),
+            Ok(TokenAmount::MAX)
+        );
+        assert!(parse_token_amount("18446744073709551616", 0).is_err());
+        assert!(parse_token_amount("184467440737.09551616", 8).is_err());
+        assert!(parse_token_amount("184467440738", 8).is_err());
+    }
+
+    #[test]
+    fn test_check_token_amount_max_supply() {
+        let max_supply = Some(100_000);
+        assert_eq!(check_token_amount("1000", 2, max_supply), Ok(100_000));
+        assert_eq!(
+            check_token_amount("1000.01", 2, max_supply),
+            Err("Amount exceeds the maximum of 1000".to_string())
+        );
+        assert_eq!(check_token_amount("1000.01", 2, None), Ok(100_001));
+    }
+}
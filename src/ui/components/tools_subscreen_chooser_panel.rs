@@ -12,6 +12,8 @@ pub enum ToolsSubscreen {
     ProofViewer,
     ContractViewer,
     PlatformInfo,
+    Logs,
+    ActivityLog,
 }
 
 impl ToolsSubscreen {
@@ -23,6 +25,8 @@ impl ToolsSubscreen {
             Self::DocumentViewer => "Document deserializer",
             Self::ContractViewer => "Contract deserializer",
             Self::PlatformInfo => "Platform info",
+            Self::Logs => "Logs",
+            Self::ActivityLog => "Activity log",
         }
     }
 }
@@ -38,6 +42,8 @@ pub fn add_tools_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext
         ToolsSubscreen::DocumentViewer,
         ToolsSubscreen::ContractViewer,
         ToolsSubscreen::PlatformInfo,
+        ToolsSubscreen::Logs,
+        ToolsSubscreen::ActivityLog,
     ];
 
     let active_screen = match app_context.get_settings() {
@@ -54,6 +60,8 @@ pub fn add_tools_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext
                 ToolsSubscreen::ContractViewer
             }
             ui::RootScreenType::RootScreenToolsPlatformInfoScreen => ToolsSubscreen::PlatformInfo,
+            ui::RootScreenType::RootScreenToolsLogViewerScreen => ToolsSubscreen::Logs,
+            ui::RootScreenType::RootScreenToolsActivityLogScreen => ToolsSubscreen::ActivityLog,
             _ => ToolsSubscreen::ProofLog,
         },
         _ => ToolsSubscreen::ProofLog, // Fallback to Active screen if settings unavailable
@@ -148,6 +156,16 @@ pub fn add_tools_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext
                                     RootScreenType::RootScreenToolsPlatformInfoScreen,
                                 )
                             }
+                            ToolsSubscreen::Logs => {
+                                action = AppAction::SetMainScreen(
+                                    RootScreenType::RootScreenToolsLogViewerScreen,
+                                )
+                            }
+                            ToolsSubscreen::ActivityLog => {
+                                action = AppAction::SetMainScreen(
+                                    RootScreenType::RootScreenToolsActivityLogScreen,
+                                )
+                            }
                         }
                             }
 
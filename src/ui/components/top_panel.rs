@@ -1,10 +1,11 @@
-use crate::app::{AppAction, DesiredAppAction};
-use crate::backend_task::BackendTask;
+use crate::app::{AppAction, BackendTasksExecutionMode, DesiredAppAction};
 use crate::backend_task::core::CoreTask;
+use crate::backend_task::system_task::{PlatformConnectionStatus, SystemTask};
+use crate::backend_task::{BackendTask, refresh_all_tasks};
 use crate::components::core_zmq_listener::ZMQConnectionEvent;
 use crate::context::AppContext;
-use crate::ui::ScreenType;
 use crate::ui::theme::{DashColors, Shadow, Shape};
+use crate::ui::{RootScreenType, ScreenType};
 use dash_sdk::dashcore_rpc::dashcore::Network;
 use egui::{
     Align, Color32, Context, Frame, Margin, RichText, Stroke, TextureHandle, TopBottomPanel, Ui,
@@ -180,15 +181,84 @@ fn add_connection_indicator(ui: &mut Ui, app_context: &Arc<AppContext>) -> AppAc
     action
 }
 
-pub fn add_top_panel(
-    ctx: &Context,
-    app_context: &Arc<AppContext>,
-    location: Vec<(&str, AppAction)>,
-    right_buttons: Vec<(&str, DesiredAppAction)>,
-) -> AppAction {
-    let mut action = AppAction::None;
-    let dark_mode = ctx.style().visuals.dark_mode;
-    let network_accent = match app_context.network {
+/// Dot showing whether the DAPI endpoints answered the last health check, followed by a
+/// "Reconnect" button rebuilding the SDK while they don't all answer
+fn add_platform_connection_indicator(ui: &mut Ui, app_context: &Arc<AppContext>) -> AppAction {
+    let dark_mode = ui.ctx().style().visuals.dark_mode;
+    let status = app_context.platform_connection_status();
+    let color = match status {
+        PlatformConnectionStatus::Connected => DashColors::success_color(dark_mode),
+        PlatformConnectionStatus::Degraded { .. } => DashColors::warning_color(dark_mode),
+        PlatformConnectionStatus::Disconnected => DashColors::error_color(dark_mode),
+        PlatformConnectionStatus::Unknown | PlatformConnectionStatus::Reconnecting => {
+            DashColors::text_secondary(dark_mode)
+        }
+    };
+
+    ui.add_space(6.0);
+    let circle_size = 10.0;
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(circle_size, circle_size), egui::Sense::hover());
+    ui.painter()
+        .circle_filled(rect.center(), circle_size / 2.0, color);
+    response.on_hover_text(status.description());
+
+    match status {
+        PlatformConnectionStatus::Reconnecting => {
+            ui.add(egui::widgets::Spinner::default().color(DashColors::DASH_BLUE));
+            AppAction::None
+        }
+        PlatformConnectionStatus::Degraded { .. } | PlatformConnectionStatus::Disconnected => {
+            let response = ui
+                .small_button(RichText::new("Reconnect").color(DashColors::text_primary(dark_mode)))
+                .on_hover_text("Rebuild the connection to Platform without restarting the app");
+            ui.add_space(6.0);
+            if response.clicked() {
+                AppAction::BackendTask(BackendTask::SystemTask(SystemTask::ReconnectPlatform))
+            } else {
+                AppAction::None
+            }
+        }
+        PlatformConnectionStatus::Unknown | PlatformConnectionStatus::Connected => {
+            ui.add_space(6.0);
+            AppAction::None
+        }
+    }
+}
+
+/// A "Refresh All" button queuing the refresh of contests, owned names and token balances as a
+/// single sequential batch, replaced by the progress of the batch while it runs
+fn add_refresh_all(ui: &mut Ui, app_context: &Arc<AppContext>) -> AppAction {
+    let dark_mode = ui.ctx().style().visuals.dark_mode;
+    if let Some(progress) = app_context.refresh_all_progress() {
+        ui.add(egui::widgets::Spinner::default().color(DashColors::DASH_BLUE));
+        ui.label(
+            RichText::new(format!(
+                "Refreshing all... {}/{}",
+                progress.completed(),
+                progress.total
+            ))
+            .color(DashColors::text_primary(dark_mode)),
+        );
+        return AppAction::None;
+    }
+
+    let response = ui
+        .button(RichText::new("⟳ Refresh All").color(DashColors::text_primary(dark_mode)))
+        .on_hover_text("Refresh DPNS contests, owned names and token balances, one after another");
+    if !response.clicked() {
+        return AppAction::None;
+    }
+    let tasks = refresh_all_tasks();
+    if !app_context.start_refresh_all(&tasks) {
+        return AppAction::None;
+    }
+    AppAction::BackendTasks(tasks, BackendTasksExecutionMode::Sequential)
+}
+
+/// Accent color of a network, used for the network badge and the action buttons
+fn network_accent_color(network: Network, dark_mode: bool) -> Color32 {
+    match network {
         Network::Dash => {
             if dark_mode {
                 Color32::from_rgb(0, 113, 182) // Muted blue for dark mode (20% darker)
@@ -224,7 +294,55 @@ pub fn add_top_panel(
                 DashColors::DASH_BLUE
             }
         }
-    };
+    }
+}
+
+fn network_label(network: Network) -> &'static str {
+    match network {
+        Network::Dash => "Mainnet",
+        Network::Testnet => "Testnet",
+        Network::Devnet => "Devnet",
+        Network::Regtest => "Local",
+        _ => "Unknown",
+    }
+}
+
+/// Badge in the network's color naming the network actions are submitted on, opening the
+/// network chooser when clicked
+fn add_network_badge(ui: &mut Ui, app_context: &Arc<AppContext>) -> AppAction {
+    let dark_mode = ui.ctx().style().visuals.dark_mode;
+    let label = network_label(app_context.network);
+    let response = Frame::new()
+        .fill(network_accent_color(app_context.network, dark_mode))
+        .corner_radius(egui::CornerRadius::same(Shape::RADIUS_SM))
+        .inner_margin(Margin::symmetric(8, 3))
+        .show(ui, |ui| {
+            ui.label(RichText::new(label).color(Color32::WHITE).strong());
+        })
+        .response
+        .interact(egui::Sense::click())
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+        .on_hover_text(format!(
+            "Actions are submitted on {}. Click to change the network.",
+            label
+        ));
+    ui.add_space(8.0);
+    if response.clicked() {
+        AppAction::SetMainScreenThenGoToMainScreen(RootScreenType::RootScreenNetworkChooser)
+    } else {
+        AppAction::None
+    }
+}
+
+pub fn add_top_panel(
+    ctx: &Context,
+    app_context: &Arc<AppContext>,
+    location: Vec<(&str, AppAction)>,
+    right_buttons: Vec<(&str, DesiredAppAction)>,
+) -> AppAction {
+    let mut action = AppAction::None;
+    let dark_mode = ctx.style().visuals.dark_mode;
+    let network_accent = network_accent_color(app_context.network, dark_mode);
 
     TopBottomPanel::top("top_panel")
         .frame(
@@ -262,16 +380,18 @@ pub fn add_top_panel(
                                 .with_cross_align(Align::Center),
                             |ui| {
                                 action |= add_connection_indicator(ui, app_context);
+                                action |= add_platform_connection_indicator(ui, app_context);
+                                action |= add_network_badge(ui, app_context);
                                 action |= add_location_view(ui, location, dark_mode);
                             },
                         );
 
-                        // Center column: Placeholder for future logo placement
+                        // Center column: "Refresh All" and the progress of its batch
                         columns[1].with_layout(
-                            egui::Layout::centered_and_justified(egui::Direction::TopDown),
+                            egui::Layout::left_to_right(egui::Align::Center)
+                                .with_cross_align(Align::Center),
                             |ui| {
-                                // Placeholder - logo moved back to left panel for now
-                                ui.label("");
+                                action |= add_refresh_all(ui, app_context);
                             },
                         );
 
@@ -424,3 +544,29 @@ pub fn add_top_panel(
 
     action
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_badges_are_distinct() {
+        let networks = [
+            Network::Dash,
+            Network::Testnet,
+            Network::Devnet,
+            Network::Regtest,
+        ];
+        for dark_mode in [false, true] {
+            for (i, a) in networks.iter().enumerate() {
+                for b in &networks[i + 1..] {
+                    assert_ne!(network_label(*a), network_label(*b));
+                    assert_ne!(
+                        network_accent_color(*a, dark_mode),
+                        network_accent_color(*b, dark_mode)
+                    );
+                }
+            }
+        }
+    }
+}
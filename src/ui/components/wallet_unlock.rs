@@ -1,10 +1,37 @@
-use crate::model::wallet::Wallet;
+use crate::model::wallet::{Wallet, WalletSeed};
 use crate::ui::components::styled::StyledCheckbox;
 use eframe::epaint::Color32;
 use egui::Ui;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use zeroize::Zeroize;
 
+/// Result of a seed decryption running on a background thread, `None` until it finishes.
+/// Kept in egui's temporary memory, keyed by wallet, so screens don't need to track it.
+type PendingUnlock = Arc<Mutex<Option<Result<[u8; 64], String>>>>;
+
+fn pending_unlock_id(wallet: &Wallet) -> egui::Id {
+    egui::Id::new(("pending_wallet_unlock", wallet.seed_hash()))
+}
+
+/// Decrypts the wallet seed on a background thread, as deriving the key from the password
+/// takes long enough to freeze the UI.
+fn start_unlock(ctx: &egui::Context, wallet: &Wallet, mut password: String) {
+    let WalletSeed::Closed(closed_seed) = &wallet.wallet_seed else {
+        return;
+    };
+    let closed_seed = closed_seed.clone();
+    let pending = PendingUnlock::default();
+    ctx.data_mut(|d| d.insert_temp(pending_unlock_id(wallet), pending.clone()));
+
+    let ctx = ctx.clone();
+    std::thread::spawn(move || {
+        let result = closed_seed.decrypt_seed(&password);
+        password.zeroize();
+        *pending.lock().unwrap() = Some(result);
+        ctx.request_repaint();
+    });
+}
+
 pub trait ScreenWithWalletUnlock {
     fn selected_wallet_ref(&self) -> &Option<Arc<RwLock<Wallet>>>;
     // Allow dead_code: This method provides read-only access to wallet passwords,
@@ -59,7 +86,36 @@ pub trait ScreenWithWalletUnlock {
 
                 ui.add_space(5.0);
 
-                let mut unlocked = false;
+                let pending_id = pending_unlock_id(&wallet);
+                let pending: Option<PendingUnlock> = ui.ctx().data(|d| d.get_temp(pending_id));
+                if let Some(pending) = pending {
+                    let Some(result) = pending.lock().unwrap().take() else {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Unlocking wallet...");
+                        });
+                        return false;
+                    };
+                    ui.ctx().data_mut(|d| d.remove::<PendingUnlock>(pending_id));
+
+                    match result {
+                        Ok(mut seed) => {
+                            wallet.wallet_seed.open_with_decrypted_seed(seed);
+                            seed.zeroize();
+                            self.set_error_message(None);
+                            return true;
+                        }
+                        Err(_) => {
+                            let error_message = match wallet.password_hint() {
+                                Some(hint) => {
+                                    format!("Incorrect Password, password hint is {}", hint)
+                                }
+                                None => "Incorrect Password".to_string(),
+                            };
+                            self.set_error_message(Some(error_message));
+                        }
+                    }
+                }
 
                 // Capture necessary values before the closure
                 let show_password = self.show_password();
@@ -84,27 +140,8 @@ pub trait ScreenWithWalletUnlock {
 
                     if password_input.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))
                     {
-                        // Use the password from wallet_password_mut
-                        let wallet_password_ref = &*wallet_password_mut;
-
-                        let unlock_result = wallet.wallet_seed.open(wallet_password_ref);
-
-                        match unlock_result {
-                            Ok(_) => {
-                                local_error_message = None;
-                                unlocked = true;
-                            }
-                            Err(_) => {
-                                if let Some(hint) = wallet.password_hint() {
-                                    local_error_message = Some(format!(
-                                        "Incorrect Password, password hint is {}",
-                                        hint
-                                    ));
-                                } else {
-                                    local_error_message = Some("Incorrect Password".to_string());
-                                }
-                            }
-                        }
+                        local_error_message = None;
+                        start_unlock(ui.ctx(), &wallet, wallet_password_mut.clone());
                         // Clear the password field after submission
                         wallet_password_mut.zeroize();
                     }
@@ -122,7 +159,7 @@ pub trait ScreenWithWalletUnlock {
                     ui.colored_label(Color32::RED, error_message);
                 }
 
-                return unlocked;
+                return false;
             }
         }
         false
@@ -9,6 +9,7 @@ use crate::ui::components::contract_chooser_panel::{
 };
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::{DialogKeyAction, dialog_key_action};
 use crate::ui::theme::{DashColors, Shadow, Shape};
 use crate::ui::{BackendTaskSuccessResult, MessageType, RootScreenType, ScreenLike, ScreenType};
 use crate::utils::parsers::{DocumentQueryTextInputParser, TextInputParser};
@@ -502,6 +503,7 @@ impl DocumentQueryScreen {
             .collapsible(false)
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
                 let contract_alias_or_id =
                     match self.app_context.get_contract_by_id(&contract_to_remove) {
                         Ok(Some(contract)) => contract
@@ -516,7 +518,7 @@ impl DocumentQueryScreen {
                 ));
 
                 // Confirm button
-                if ui.button("Confirm").clicked() {
+                if ui.button("Confirm").clicked() || key_action == DialogKeyAction::Confirm {
                     app_action = AppAction::BackendTask(BackendTask::ContractTask(Box::new(
                         ContractTask::RemoveContract(contract_to_remove),
                     )));
@@ -525,7 +527,7 @@ impl DocumentQueryScreen {
                 }
 
                 // Cancel button
-                if ui.button("Cancel").clicked() {
+                if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
                     self.confirm_remove_contract_popup = false;
                     self.contract_to_remove = None;
                 }
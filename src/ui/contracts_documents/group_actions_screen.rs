@@ -18,7 +18,7 @@ use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::helpers::add_contract_chooser_pre_filtered;
-use crate::ui::helpers::render_identity_selector;
+use crate::ui::helpers::{format_token_amount, render_identity_selector};
 use crate::ui::tokens::burn_tokens_screen::BurnTokensScreen;
 use crate::ui::tokens::destroy_frozen_funds_screen::DestroyFrozenFundsScreen;
 use crate::ui::tokens::freeze_tokens_screen::FreezeTokensScreen;
@@ -36,6 +36,7 @@ use dash_sdk::dpp::data_contract::TokenContractPosition;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 use dash_sdk::dpp::data_contract::change_control_rules::ChangeControlRules;
 use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use dash_sdk::dpp::group::action_event::GroupActionEvent;
@@ -349,18 +350,20 @@ impl GroupActionsScreen {
         identity_token_info: IdentityTokenInfo,
         action: &mut AppAction,
     ) {
+        // Amounts are typed with the token's decimals on the mint and burn screens
+        let decimals = identity_token_info.token_config.conventions().decimals();
         match token_event {
             TokenEvent::Mint(amount, _identifier, note_opt) => {
                 let mut mint_screen = MintTokensScreen::new(identity_token_info, &self.app_context);
                 mint_screen.group_action_id = Some(action_id);
-                mint_screen.amount_to_mint = amount.to_string();
+                mint_screen.amount_to_mint = format_token_amount(*amount, decimals);
                 mint_screen.public_note = note_opt.clone();
                 *action |= AppAction::AddScreen(Screen::MintTokensScreen(mint_screen));
             }
             TokenEvent::Burn(amount, _burn_from, note_opt) => {
                 let mut burn_screen = BurnTokensScreen::new(identity_token_info, &self.app_context);
                 burn_screen.group_action_id = Some(action_id);
-                burn_screen.amount_to_burn = amount.to_string();
+                burn_screen.amount_to_burn = format_token_amount(*amount, decimals);
                 burn_screen.public_note = note_opt.clone();
                 *action |= AppAction::AddScreen(Screen::BurnTokensScreen(burn_screen));
             }
@@ -523,8 +526,12 @@ impl ScreenLike for GroupActionsScreen {
             ui.heading("2. Select an identity:");
 
             ui.add_space(10.0);
-            self.selected_identity =
-                render_identity_selector(ui, &self.qualified_identities, &self.selected_identity);
+            self.selected_identity = render_identity_selector(
+                ui,
+                &self.app_context,
+                &self.qualified_identities,
+                &self.selected_identity,
+            );
 
             let mut fetch_clicked = false;
             if self.selected_contract.is_some() && self.selected_identity.is_some() {
@@ -9,9 +9,12 @@ use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, render_json_validation_errors,
+};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::{BackendTaskSuccessResult, MessageType, ScreenLike};
+use crate::utils::json_validation::{JsonValidationError, parse_json, validate_data_contract_json};
 use dash_sdk::dpp::data_contract::accessors::v0::{DataContractV0Getters, DataContractV0Setters};
 use dash_sdk::dpp::data_contract::conversion::json::DataContractJsonConversionMethodsV0;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
@@ -28,7 +31,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 #[derive(PartialEq)]
 enum BroadcastStatus {
     Idle,
-    ParsingError(String),
+    ParsingError(Vec<JsonValidationError>),
     ValidContract(Box<DataContract>),
     FetchingNonce(u64),
     Broadcasting(u64),
@@ -118,34 +121,40 @@ impl UpdateDataContractScreen {
             return;
         }
 
-        // Try to parse the user’s JSON -> serde_json::Value
-        let json_result: Result<serde_json::Value, serde_json::Error> =
-            serde_json::from_str(&self.contract_json_input);
-
-        match json_result {
-            Ok(json_val) => {
-                let platform_version = self.app_context.platform_version();
-                match DataContract::from_json(json_val, true, platform_version) {
-                    Ok(mut contract) => {
-                        // ------------------------------------------
-                        // 1) Overwrite the contract’s ownerId
-                        // ------------------------------------------
-                        if let Some(qualified_identity) = &self.selected_qualified_identity {
-                            let new_owner_id = qualified_identity.identity.id();
-                            contract.set_owner_id(new_owner_id);
-                        }
+        // Parse the user’s JSON and check its structure before handing it to the SDK
+        let json_val = match parse_json(&self.contract_json_input) {
+            Ok(json_val) => json_val,
+            Err(e) => {
+                self.broadcast_status = BroadcastStatus::ParsingError(vec![e]);
+                return;
+            }
+        };
 
-                        // Mark it as a valid contract in our screen state
-                        self.broadcast_status = BroadcastStatus::ValidContract(Box::new(contract));
-                    }
-                    Err(e) => {
-                        self.broadcast_status =
-                            BroadcastStatus::ParsingError(format!("DataContract parse error: {e}"));
-                    }
+        let validation_errors = validate_data_contract_json(&json_val);
+        if !validation_errors.is_empty() {
+            self.broadcast_status = BroadcastStatus::ParsingError(validation_errors);
+            return;
+        }
+
+        let platform_version = self.app_context.platform_version();
+        match DataContract::from_json(json_val, true, platform_version) {
+            Ok(mut contract) => {
+                // ------------------------------------------
+                // 1) Overwrite the contract’s ownerId
+                // ------------------------------------------
+                if let Some(qualified_identity) = &self.selected_qualified_identity {
+                    let new_owner_id = qualified_identity.identity.id();
+                    contract.set_owner_id(new_owner_id);
                 }
+
+                // Mark it as a valid contract in our screen state
+                self.broadcast_status = BroadcastStatus::ValidContract(Box::new(contract));
             }
             Err(e) => {
-                self.broadcast_status = BroadcastStatus::ParsingError(format!("Invalid JSON: {e}"));
+                self.broadcast_status =
+                    BroadcastStatus::ParsingError(vec![JsonValidationError::root(format!(
+                        "DataContract parse error: {e}"
+                    ))]);
             }
         }
     }
@@ -176,8 +185,8 @@ impl UpdateDataContractScreen {
 
         match &self.broadcast_status {
             BroadcastStatus::Idle => {}
-            BroadcastStatus::ParsingError(err) => {
-                ui.colored_label(Color32::RED, format!("Parsing error: {err}"));
+            BroadcastStatus::ParsingError(errors) => {
+                render_json_validation_errors(ui, errors);
             }
             BroadcastStatus::ValidContract(contract) => {
                 // “Update” button
@@ -0,0 +1,711 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, ComboBox, Context, RichText, Ui};
+
+use crate::app::{AppAction, DesiredAppAction};
+use crate::backend_task::contested_names::{ContestedResourceTask, ScheduledDPNSVote};
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
+use crate::context::AppContext;
+use crate::model::contested_name::{ContestState, ContestedName};
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::ui::components::dpns_subscreen_chooser_panel::add_dpns_subscreen_chooser_panel;
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::dpns::dpns_contested_names_screen::{
+    VoteHandlingStatus, VoteOption, render_scheduled_vote_time, validate_scheduled_vote_time,
+};
+use crate::ui::helpers::{TimestampStyle, format_timestamp_millis, render_identifier_cell};
+use crate::ui::theme::DashColors;
+use crate::ui::{MessageType, RootScreenType, ScreenLike};
+
+/// Vote tallies observed at one point in time, so the screen can show how a contest evolves
+/// across refreshes.
+struct TallySnapshot {
+    taken_at: DateTime<Utc>,
+    contestant_votes: Vec<(Identifier, u32)>,
+    locked_votes: u32,
+    abstain_votes: u32,
+}
+
+impl TallySnapshot {
+    fn from_contest(contest: &ContestedName) -> Self {
+        Self {
+            taken_at: Utc::now(),
+            contestant_votes: contest
+                .contestants
+                .iter()
+                .flatten()
+                .map(|contestant| (contestant.id, contestant.votes))
+                .collect(),
+            locked_votes: contest.locked_votes.unwrap_or(0),
+            abstain_votes: contest.abstain_votes.unwrap_or(0),
+        }
+    }
+
+    fn same_tallies(&self, other: &Self) -> bool {
+        self.contestant_votes == other.contestant_votes
+            && self.locked_votes == other.locked_votes
+            && self.abstain_votes == other.abstain_votes
+    }
+}
+
+/// Focused view of a single DPNS contest: every contestant, the contest timeline, the tally
+/// history seen during this session, and a form to cast or schedule a vote.
+pub struct ContestDetailsScreen {
+    pub contested_name: String,
+    contest: Option<ContestedName>,
+    tally_history: Vec<TallySnapshot>,
+    scheduled_votes: Vec<ScheduledDPNSVote>,
+    voting_identities: Vec<QualifiedIdentity>,
+    selected_voters: Vec<bool>,
+    vote_choice: Option<ResourceVoteChoice>,
+    vote_option: VoteOption,
+    vote_handling_status: VoteHandlingStatus,
+    refreshing: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+    pub app_context: Arc<AppContext>,
+}
+
+impl ContestDetailsScreen {
+    pub fn new(contested_name: String, app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            contested_name,
+            contest: None,
+            tally_history: Vec::new(),
+            scheduled_votes: Vec::new(),
+            voting_identities: Vec::new(),
+            selected_voters: Vec::new(),
+            vote_choice: None,
+            vote_option: VoteOption::CastNow,
+            vote_handling_status: VoteHandlingStatus::NotStarted,
+            refreshing: false,
+            message: None,
+            app_context: app_context.clone(),
+        };
+        screen.load_from_db();
+        screen
+    }
+
+    fn load_from_db(&mut self) {
+        self.contest = self
+            .app_context
+            .all_contested_names()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|contest| contest.normalized_contested_name == self.contested_name);
+
+        if let Some(contest) = &self.contest {
+            let snapshot = TallySnapshot::from_contest(contest);
+            if self
+                .tally_history
+                .last()
+                .is_none_or(|last| !last.same_tallies(&snapshot))
+            {
+                self.tally_history.push(snapshot);
+            }
+        }
+
+        self.scheduled_votes = self
+            .app_context
+            .get_scheduled_votes()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|vote| vote.contested_name == self.contested_name)
+            .collect();
+
+        let voting_identities = self
+            .app_context
+            .db
+            .get_local_voting_identities(&self.app_context)
+            .unwrap_or_default();
+        // Selections are by position, so they only carry over to the same identities. After a
+        // network switch every identity of the new network starts selected
+        let same_identities = voting_identities.len() == self.voting_identities.len()
+            && voting_identities
+                .iter()
+                .zip(&self.voting_identities)
+                .all(|(identity, previous)| identity.identity.id() == previous.identity.id());
+        if !same_identities || self.selected_voters.len() != voting_identities.len() {
+            self.selected_voters = vec![true; voting_identities.len()];
+        }
+        self.voting_identities = voting_identities;
+    }
+
+    fn contest_details_task(&self) -> BackendTask {
+        BackendTask::ContestedResourceTask(ContestedResourceTask::QueryDPNSContestDetails(
+            self.contested_name.clone(),
+        ))
+    }
+
+    fn format_timestamp(&self, timestamp: u64) -> String {
+        format_timestamp_millis(
+            timestamp,
+            TimestampStyle::AbsoluteAndRelative,
+            self.app_context.is_developer_mode(),
+        )
+    }
+
+    fn choice_label(&self, choice: &ResourceVoteChoice) -> String {
+        match choice {
+            ResourceVoteChoice::TowardsIdentity(id) => {
+                let name = self
+                    .contest
+                    .as_ref()
+                    .and_then(|contest| contest.contestants.as_ref())
+                    .and_then(|contestants| contestants.iter().find(|c| c.id == *id))
+                    .map(|contestant| contestant.name.clone());
+                match name {
+                    Some(name) => format!("{} ({})", name, id.to_string(Encoding::Base58)),
+                    None => id.to_string(Encoding::Base58),
+                }
+            }
+            other => other.to_string(),
+        }
+    }
+
+    fn voter_label(&self, voter_id: &Identifier) -> String {
+        self.voting_identities
+            .iter()
+            .find(|identity| identity.identity.id() == *voter_id)
+            .and_then(|identity| identity.alias.clone())
+            .unwrap_or_else(|| voter_id.to_string(Encoding::Base58))
+    }
+
+    fn render_overview(ui: &mut Ui, contest: &ContestedName) {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.heading(RichText::new("Overview").color(DashColors::text_primary(dark_mode)));
+        ui.add_space(5.0);
+
+        egui::Grid::new("contest_overview_grid")
+            .num_columns(2)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("State:");
+                match &contest.state {
+                    ContestState::Unknown => ui.label("Unknown"),
+                    ContestState::Joinable => ui.label("Active (joinable)"),
+                    ContestState::Ongoing => ui.label("Active"),
+                    ContestState::WonBy(winner) => {
+                        ui.label(format!("Won by {}", winner.to_string(Encoding::Base58)))
+                    }
+                    ContestState::Locked => ui.label("Locked"),
+                };
+                ui.end_row();
+
+                ui.label("Locked votes:");
+                ui.label(contest.locked_votes.unwrap_or(0).to_string());
+                ui.end_row();
+
+                ui.label("Abstain votes:");
+                ui.label(contest.abstain_votes.unwrap_or(0).to_string());
+                ui.end_row();
+
+                ui.label("Last updated:");
+                match contest.last_updated {
+                    Some(last_updated) => ui.label(format_timestamp_millis(
+                        last_updated.saturating_mul(1000),
+                        TimestampStyle::Relative,
+                        self.app_context.is_developer_mode(),
+                    )),
+                    None => ui.label("Never"),
+                };
+                ui.end_row();
+            });
+    }
+
+    fn render_timeline(ui: &mut Ui, contest: &ContestedName) {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.heading(RichText::new("Timeline").color(DashColors::text_primary(dark_mode)));
+        ui.add_space(5.0);
+
+        // The contest starts when the first contestant registers the name
+        let start_time = contest
+            .contestants
+            .iter()
+            .flatten()
+            .filter_map(|contestant| contestant.created_at)
+            .min();
+
+        egui::Grid::new("contest_timeline_grid")
+            .num_columns(2)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Started:");
+                ui.label(
+                    start_time
+                        .map(|timestamp| self.format_timestamp(timestamp))
+                        .unwrap_or_else(|| "Unknown".to_string()),
+                );
+                ui.end_row();
+
+                ui.label("Ends:");
+                ui.label(
+                    contest
+                        .end_time
+                        .map(|timestamp| self.format_timestamp(timestamp))
+                        .unwrap_or_else(|| "Fetching".to_string()),
+                );
+                ui.end_row();
+            });
+
+        if let (Some(start_time), Some(end_time)) = (start_time, contest.end_time) {
+            if end_time > start_time {
+                let now = Utc::now().timestamp_millis().max(0) as u64;
+                let progress = (now.saturating_sub(start_time) as f32
+                    / (end_time - start_time) as f32)
+                    .clamp(0.0, 1.0);
+                ui.add_space(5.0);
+                ui.add(
+                    egui::ProgressBar::new(progress)
+                        .show_percentage()
+                        .desired_width(400.0),
+                );
+            }
+        }
+    }
+
+    fn render_contestants(&self, ui: &mut Ui, contest: &ContestedName) {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.heading(RichText::new("Contestants").color(DashColors::text_primary(dark_mode)));
+        ui.add_space(5.0);
+
+        let Some(contestants) = contest.contestants.as_ref().filter(|c| !c.is_empty()) else {
+            ui.label("No contestants fetched yet.");
+            return;
+        };
+
+        let max_votes = contestants.iter().map(|c| c.votes).max().unwrap_or(0);
+        let locked_votes = contest.locked_votes.unwrap_or(0);
+
+        egui::Grid::new("contest_contestants_grid")
+            .num_columns(4)
+            .striped(true)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                ui.label(RichText::new("Name").strong());
+                ui.label(RichText::new("Identity").strong());
+                ui.label(RichText::new("Votes").strong());
+                ui.label(RichText::new("Registered").strong());
+                ui.end_row();
+
+                for contestant in contestants {
+                    ui.label(&contestant.name);
+                    ui.allocate_ui(egui::vec2(320.0, 20.0), |ui| {
+                        render_identifier_cell(ui, &contestant.id, None);
+                    });
+                    let votes = RichText::new(contestant.votes.to_string());
+                    if contestant.votes == max_votes && locked_votes < max_votes {
+                        ui.label(votes.strong().color(Color32::from_rgb(0, 100, 0)));
+                    } else {
+                        ui.label(votes);
+                    }
+                    ui.label(
+                        contestant
+                            .created_at
+                            .map(|timestamp| self.format_timestamp(timestamp))
+                            .unwrap_or_else(|| "Unknown".to_string()),
+                    );
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_vote_history(&self, ui: &mut Ui, contest: &ContestedName) {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.heading(RichText::new("Vote History").color(DashColors::text_primary(dark_mode)));
+        ui.add_space(5.0);
+
+        if !contest.my_votes.is_empty() {
+            ui.label(RichText::new("Votes cast by your identities:").strong());
+            for ((voter_id, _, _), choice) in &contest.my_votes {
+                ui.label(format!(
+                    "{} voted for {}",
+                    self.voter_label(voter_id),
+                    self.choice_label(choice)
+                ));
+            }
+            ui.add_space(5.0);
+        }
+
+        if !self.scheduled_votes.is_empty() {
+            ui.label(RichText::new("Scheduled votes:").strong());
+            for vote in &self.scheduled_votes {
+                let status = if vote.executed_successfully {
+                    "cast"
+                } else {
+                    "pending"
+                };
+                ui.label(format!(
+                    "{} for {} at {} ({})",
+                    self.voter_label(&vote.voter_id),
+                    self.choice_label(&vote.choice),
+                    self.format_timestamp(vote.unix_timestamp),
+                    status
+                ));
+            }
+            ui.add_space(5.0);
+        }
+
+        ui.label(RichText::new("Tallies observed in this session:").strong());
+        egui::Grid::new("contest_tally_history_grid")
+            .striped(true)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                ui.label(RichText::new("Observed").strong());
+                for contestant in contest.contestants.iter().flatten() {
+                    ui.label(RichText::new(&contestant.name).strong())
+                        .on_hover_text(contestant.id.to_string(Encoding::Base58));
+                }
+                ui.label(RichText::new("Lock").strong());
+                ui.label(RichText::new("Abstain").strong());
+                ui.end_row();
+
+                for snapshot in &self.tally_history {
+                    ui.label(snapshot.taken_at.format("%H:%M:%S").to_string());
+                    for contestant in contest.contestants.iter().flatten() {
+                        let votes = snapshot
+                            .contestant_votes
+                            .iter()
+                            .find(|(id, _)| *id == contestant.id)
+                            .map(|(_, votes)| votes.to_string())
+                            .unwrap_or_else(|| "-".to_string());
+                        ui.label(votes);
+                    }
+                    ui.label(snapshot.locked_votes.to_string());
+                    ui.label(snapshot.abstain_votes.to_string());
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_vote_form(&mut self, ui: &mut Ui, contest: &ContestedName) -> AppAction {
+        let mut action = AppAction::None;
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.heading(
+            RichText::new("Cast or Schedule a Vote").color(DashColors::text_primary(dark_mode)),
+        );
+        ui.add_space(5.0);
+
+        if !contest.state.state_is_votable() {
+            ui.label("This contest is no longer accepting votes.");
+            return action;
+        }
+
+        if self.voting_identities.is_empty() {
+            ui.colored_label(
+                Color32::DARK_RED,
+                "No masternode identities loaded. Please go to the Identities screen to load your masternodes.",
+            );
+            return action;
+        }
+
+        let mut choices: Vec<ResourceVoteChoice> = contest
+            .contestants
+            .iter()
+            .flatten()
+            .map(|contestant| ResourceVoteChoice::TowardsIdentity(contestant.id))
+            .collect();
+        choices.push(ResourceVoteChoice::Lock);
+        choices.push(ResourceVoteChoice::Abstain);
+
+        ui.horizontal(|ui| {
+            ui.label("Vote for:");
+            let selected_text = self
+                .vote_choice
+                .as_ref()
+                .map(|choice| self.choice_label(choice))
+                .unwrap_or_else(|| "Select a choice".to_string());
+            let mut new_choice = None;
+            ComboBox::from_id_salt("contest_vote_choice")
+                .width(300.0)
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for choice in &choices {
+                        if ui
+                            .selectable_label(
+                                self.vote_choice.as_ref() == Some(choice),
+                                self.choice_label(choice),
+                            )
+                            .clicked()
+                        {
+                            new_choice = Some(*choice);
+                        }
+                    }
+                });
+            if new_choice.is_some() {
+                self.vote_choice = new_choice;
+            }
+        });
+
+        ui.add_space(5.0);
+        ui.label("Voters:");
+        for (identity, selected) in self
+            .voting_identities
+            .iter()
+            .zip(self.selected_voters.iter_mut())
+        {
+            let label = identity
+                .alias
+                .clone()
+                .unwrap_or_else(|| identity.identity.id().to_string(Encoding::Base58));
+            ui.checkbox(selected, label);
+        }
+
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.vote_option, VoteOption::CastNow, "Cast now");
+            let is_scheduled = matches!(self.vote_option, VoteOption::Scheduled { .. });
+            if ui.radio(is_scheduled, "Schedule").clicked() && !is_scheduled {
+                self.vote_option = VoteOption::Scheduled {
+                    days: 0,
+                    hours: 0,
+                    minutes: 0,
+                };
+            }
+            if let VoteOption::Scheduled {
+                days,
+                hours,
+                minutes,
+            } = &mut self.vote_option
+            {
+                ui.label("In:");
+                ui.add(egui::DragValue::new(days).prefix("Days: ").range(0..=14));
+                ui.add(egui::DragValue::new(hours).prefix("Hours: ").range(0..=23));
+                ui.add(egui::DragValue::new(minutes).prefix("Min: ").range(0..=59));
+            }
+            if let Some(offset) = self.vote_option.schedule_offset() {
+                render_scheduled_vote_time(ui, offset, contest.end_time);
+            }
+        });
+        if matches!(self.vote_option, VoteOption::Scheduled { .. }) {
+            ui.colored_label(
+                Color32::DARK_RED,
+                "NOTE: Dash Evo Tool must remain running and connected for scheduled votes to execute on time.",
+            );
+        }
+
+        ui.add_space(10.0);
+        let in_progress = matches!(
+            self.vote_handling_status,
+            VoteHandlingStatus::CastingVotes(_) | VoteHandlingStatus::SchedulingVotes
+        );
+        let has_voters = self.selected_voters.iter().any(|selected| *selected);
+        let button = egui::Button::new(RichText::new("Apply Vote").color(Color32::WHITE))
+            .fill(Color32::from_rgb(0, 128, 255))
+            .corner_radius(3.0);
+        let response = ui.add_enabled(
+            self.vote_choice.is_some() && has_voters && !in_progress,
+            button,
+        );
+        if response.clicked() {
+            action = self.apply_vote();
+        }
+
+        ui.add_space(5.0);
+        match &self.vote_handling_status {
+            VoteHandlingStatus::NotStarted => {}
+            VoteHandlingStatus::CastingVotes(start_time) => {
+                let elapsed = (Utc::now().timestamp() as u64).saturating_sub(*start_time);
+                ui.horizontal(|ui| {
+                    ui.label(format!("Casting vote... Time taken so far: {}", elapsed));
+                    ui.add(egui::Spinner::new());
+                });
+            }
+            VoteHandlingStatus::SchedulingVotes => {
+                ui.label("Scheduling vote...");
+            }
+            VoteHandlingStatus::Completed => {
+                ui.colored_label(Color32::DARK_GREEN, "Vote submitted.");
+            }
+            VoteHandlingStatus::Failed(message) => {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    format!("Error casting/scheduling vote: {}", message),
+                );
+            }
+        }
+
+        action
+    }
+
+    fn apply_vote(&mut self) -> AppAction {
+        let Some(choice) = self.vote_choice else {
+            return AppAction::None;
+        };
+        let voters: Vec<QualifiedIdentity> = self
+            .voting_identities
+            .iter()
+            .zip(&self.selected_voters)
+            .filter(|(_, selected)| **selected)
+            .map(|(identity, _)| identity.clone())
+            .collect();
+
+        match self.vote_option {
+            VoteOption::NoVote | VoteOption::CastNow => {
+                self.vote_handling_status =
+                    VoteHandlingStatus::CastingVotes(Utc::now().timestamp() as u64);
+                AppAction::BackendTask(BackendTask::ContestedResourceTask(
+                    ContestedResourceTask::VoteOnDPNSNames(
+                        vec![(self.contested_name.clone(), choice)],
+                        voters,
+                    ),
+                ))
+            }
+            VoteOption::Scheduled { .. } => {
+                let offset = self
+                    .vote_option
+                    .schedule_offset()
+                    .unwrap_or_else(chrono::Duration::zero);
+                let end_time = self.contest.as_ref().and_then(|contest| contest.end_time);
+                let scheduled_time = match validate_scheduled_vote_time(offset, end_time) {
+                    Ok(scheduled_time) => scheduled_time.timestamp_millis() as u64,
+                    Err(e) => {
+                        self.vote_handling_status = VoteHandlingStatus::Failed(e);
+                        return AppAction::None;
+                    }
+                };
+                let scheduled_votes = voters
+                    .iter()
+                    .map(|voter| ScheduledDPNSVote {
+                        contested_name: self.contested_name.clone(),
+                        voter_id: voter.identity.id(),
+                        choice,
+                        unix_timestamp: scheduled_time,
+                        executed_successfully: false,
+                    })
+                    .collect();
+                self.vote_handling_status = VoteHandlingStatus::SchedulingVotes;
+                AppAction::BackendTask(BackendTask::ContestedResourceTask(
+                    ContestedResourceTask::ScheduleDPNSVotes(scheduled_votes),
+                ))
+            }
+        }
+    }
+}
+
+impl ScreenLike for ContestDetailsScreen {
+    fn refresh(&mut self) {
+        self.load_from_db();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message.contains("Votes scheduled") {
+            self.vote_handling_status = VoteHandlingStatus::Completed;
+            self.load_from_db();
+        } else if message_type == MessageType::Error {
+            if matches!(
+                self.vote_handling_status,
+                VoteHandlingStatus::CastingVotes(_) | VoteHandlingStatus::SchedulingVotes
+            ) {
+                self.vote_handling_status = VoteHandlingStatus::Failed(message.to_string());
+            }
+            self.refreshing = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::DPNSVoteResults(results) => {
+                let errors: Vec<String> = results
+                    .iter()
+                    .filter_map(|(_, _, result)| result.as_ref().err().cloned())
+                    .collect();
+                if errors.is_empty() {
+                    self.vote_handling_status = VoteHandlingStatus::Completed;
+                } else {
+                    self.vote_handling_status = VoteHandlingStatus::Failed(errors.join("\n"));
+                }
+                self.load_from_db();
+            }
+            BackendTaskSuccessResult::Refresh => {
+                self.refreshing = false;
+                self.load_from_db();
+            }
+            _ => {}
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("DPNS", AppAction::GoToMainScreen),
+                (&self.contested_name, AppAction::None),
+            ],
+            vec![(
+                "Refresh",
+                DesiredAppAction::BackendTask(Box::new(self.contest_details_task())),
+            )],
+        );
+        if matches!(action, AppAction::BackendTask(_)) {
+            self.refreshing = true;
+        }
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            RootScreenType::RootScreenDPNSActiveContests,
+        );
+
+        action |= add_dpns_subscreen_chooser_panel(ctx, self.app_context.as_ref());
+
+        action |= island_central_panel(ctx, |ui| {
+            let mut inner_action = AppAction::None;
+            let dark_mode = ui.ctx().style().visuals.dark_mode;
+
+            ui.heading(
+                RichText::new(format!("Contest for \"{}\"", self.contested_name))
+                    .color(DashColors::text_primary(dark_mode)),
+            );
+            ui.add_space(5.0);
+
+            if self.refreshing {
+                ui.horizontal(|ui| {
+                    ui.label("Fetching contest details...");
+                    ui.add(egui::Spinner::new());
+                });
+            } else if let Some((message, message_type, _)) = &self.message {
+                let color = match message_type {
+                    MessageType::Error => Color32::DARK_RED,
+                    MessageType::Info => DashColors::text_primary(dark_mode),
+                    MessageType::Success => Color32::DARK_GREEN,
+                };
+                ui.colored_label(color, message);
+            }
+            ui.add_space(10.0);
+
+            let Some(contest) = self.contest.clone() else {
+                ui.label("This contest has not been fetched yet. Click Refresh to load it.");
+                return inner_action;
+            };
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                Self::render_overview(ui, &contest);
+                ui.add_space(10.0);
+                ui.separator();
+                Self::render_timeline(ui, &contest);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_contestants(ui, &contest);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_vote_history(ui, &contest);
+                ui.add_space(10.0);
+                ui.separator();
+                inner_action |= self.render_vote_form(ui, &contest);
+            });
+
+            inner_action
+        });
+
+        action
+    }
+}
@@ -1,28 +1,46 @@
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use chrono::{DateTime, LocalResult, TimeZone, Utc};
-use chrono_humanize::HumanTime;
+use chrono::{DateTime, NaiveDate, Utc};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::TimestampMillis;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::util::strings::convert_to_homograph_safe_chars;
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
 use dash_sdk::platform::Identifier;
 use eframe::egui::{self, Button, Color32, ComboBox, Context, Label, RichText, Ui};
-use egui_extras::{Column, TableBuilder};
+use egui_extras::{Column, DatePickerButton, TableBuilder};
 use itertools::Itertools;
 
 use crate::app::{AppAction, BackendTasksExecutionMode, DesiredAppAction};
 use crate::backend_task::BackendTask;
-use crate::backend_task::contested_names::{ContestedResourceTask, ScheduledDPNSVote};
+use crate::backend_task::contested_names::{
+    BulkVoteProgress, ContestedResourceTask, ScheduledDPNSVote,
+};
 use crate::backend_task::identity::IdentityTask;
 use crate::context::AppContext;
-use crate::model::contested_name::{ContestState, ContestedName};
+use crate::model::contested_name::{
+    ContestLeadChange, ContestState, ContestedName, LeadSummary, WatchedName,
+};
 use crate::model::qualified_identity::{DPNSNameInfo, QualifiedIdentity};
 use crate::ui::components::dpns_subscreen_chooser_panel::add_dpns_subscreen_chooser_panel;
+use crate::ui::components::inline_alias_edit::{InlineAliasEdit, render_inline_identity_alias};
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::table_column_widths::TableColumnWidths;
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::dpns::contest_details_screen::ContestDetailsScreen;
+use crate::ui::dpns::scheduled_votes_import::{ImportedVoteRow, parse_scheduled_votes_import};
+use crate::ui::helpers::{
+    DialogKeyAction, TimestampStyle, copy_with_confirmation, csv_field, dialog_key_action,
+    format_timestamp_millis, recently_copied, render_identifier_cell, render_identity_tag_dot,
+};
 use crate::ui::theme::DashColors;
-use crate::ui::{BackendTaskSuccessResult, MessageType, RootScreenType, ScreenLike, ScreenType};
+use crate::ui::{
+    BackendTaskSuccessResult, MessageType, RootScreenType, Screen, ScreenLike, ScreenType,
+};
 
 /// Which DPNS sub-screen is currently showing.
 #[derive(PartialEq)]
@@ -31,6 +49,7 @@ pub enum DPNSSubscreen {
     Past,
     Owned,
     ScheduledVotes,
+    Watchlist,
 }
 
 impl DPNSSubscreen {
@@ -40,6 +59,18 @@ impl DPNSSubscreen {
             Self::Past => "Past contests",
             Self::Owned => "My usernames",
             Self::ScheduledVotes => "Scheduled votes",
+            Self::Watchlist => "Watchlist",
+        }
+    }
+
+    /// Identifies the subscreen's table in the saved sort preferences
+    fn sort_preference_key(&self) -> &'static str {
+        match self {
+            Self::Active => "dpns_active",
+            Self::Past => "dpns_past",
+            Self::Owned => "dpns_owned",
+            Self::ScheduledVotes => "dpns_scheduled_votes",
+            Self::Watchlist => "dpns_watchlist",
         }
     }
 }
@@ -52,13 +83,411 @@ pub struct SelectedVote {
     pub end_time: Option<u64>,
 }
 
-#[derive(Clone)]
+/// Votes about to be cast or scheduled, waiting for the user to confirm them. Dropped when
+/// the selection they were prepared from changes.
+struct PendingBulkVotes {
+    selected_votes: Vec<SelectedVote>,
+    options: Vec<VoteOption>,
+    immediate_voters: Vec<QualifiedIdentity>,
+    scheduled_votes: Vec<ScheduledDPNSVote>,
+    /// Number of identities voting now or later
+    voter_count: usize,
+    /// Warnings about votes repeating a recorded vote and scheduled voters that can't pay for
+    /// their votes yet
+    warnings: Vec<String>,
+    /// Voters casting now that can't pay for their votes, which blocks the confirmation
+    shortfalls: Vec<String>,
+    /// Total cost of the votes in credits
+    total_cost: Credits,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum VoteOption {
     NoVote,
     CastNow,
     Scheduled { days: u32, hours: u32, minutes: u32 },
 }
 
+impl VoteOption {
+    /// How long from now a scheduled vote waits before being cast, or `None` if not scheduling.
+    pub fn schedule_offset(&self) -> Option<chrono::Duration> {
+        match self {
+            VoteOption::Scheduled {
+                days,
+                hours,
+                minutes,
+            } => Some(
+                chrono::Duration::days(*days as i64)
+                    + chrono::Duration::hours(*hours as i64)
+                    + chrono::Duration::minutes(*minutes as i64),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Checks that a vote scheduled `offset` from now fires in the future and before the contest
+/// ends, returning the time it will be cast.
+pub fn validate_scheduled_vote_time(
+    offset: chrono::Duration,
+    contest_end_time: Option<u64>,
+) -> Result<DateTime<Utc>, String> {
+    if offset <= chrono::Duration::zero() {
+        return Err(
+            "A scheduled vote needs a delay of at least one minute. Use Cast Now to vote immediately."
+                .to_string(),
+        );
+    }
+    let scheduled_time = Utc::now() + offset;
+    if let Some(end_time) = contest_end_time {
+        if scheduled_time.timestamp_millis() >= end_time as i64 {
+            let end = format_timestamp_millis(end_time, TimestampStyle::Absolute, false);
+            return Err(format!(
+                "The vote would be cast at {}, after the contest ends at {}.",
+                scheduled_time.format("%Y-%m-%d %H:%M:%S"),
+                end
+            ));
+        }
+    }
+    Ok(scheduled_time)
+}
+
+/// Shows inline when a scheduled vote will fire, or why that time is not allowed.
+pub fn render_scheduled_vote_time(
+    ui: &mut Ui,
+    offset: chrono::Duration,
+    contest_end_time: Option<u64>,
+) {
+    match validate_scheduled_vote_time(offset, contest_end_time) {
+        Ok(scheduled_time) => {
+            let dark_mode = ui.ctx().style().visuals.dark_mode;
+            ui.label(
+                RichText::new(format!(
+                    "Casts at {}",
+                    scheduled_time.format("%Y-%m-%d %H:%M:%S")
+                ))
+                .color(DashColors::text_primary(dark_mode)),
+            );
+        }
+        Err(e) => {
+            ui.colored_label(Color32::DARK_RED, e);
+        }
+    }
+}
+
+/// What the delay of a scheduled bulk vote counts from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleAnchor {
+    /// Votes are cast once the delay has passed
+    FromNow,
+    /// Each vote is cast the delay before its contest ends
+    BeforeContestEnd,
+}
+
+impl ScheduleAnchor {
+    /// Checks when a vote scheduled `offset` from this anchor is cast in a contest ending at
+    /// `contest_end_time`, like [`validate_scheduled_vote_time`]. Before the end of a contest
+    /// whose end isn't known, the delay counts from now instead.
+    pub fn scheduled_vote_time(
+        self,
+        offset: chrono::Duration,
+        contest_end_time: Option<u64>,
+    ) -> Result<DateTime<Utc>, String> {
+        match self {
+            ScheduleAnchor::FromNow => validate_scheduled_vote_time(offset, contest_end_time),
+            ScheduleAnchor::BeforeContestEnd => match contest_end_time {
+                Some(end_time) => validate_vote_time_before_end(offset, end_time, Utc::now()),
+                // Without a known end, the delay counts from now
+                None => validate_scheduled_vote_time(offset, None),
+            },
+        }
+    }
+}
+
+/// Checks that a vote scheduled `offset` before its contest ends at `contest_end_time` is
+/// cast after `now`, returning the time it will be cast.
+fn validate_vote_time_before_end(
+    offset: chrono::Duration,
+    contest_end_time: u64,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, String> {
+    if offset <= chrono::Duration::zero() {
+        return Err(
+            "A vote scheduled before the contest ends needs a delay of at least one minute."
+                .to_string(),
+        );
+    }
+    let end_time = DateTime::from_timestamp_millis(contest_end_time as i64)
+        .ok_or_else(|| "The contest's end time is invalid.".to_string())?;
+    let scheduled_time = end_time - offset;
+    if scheduled_time <= now {
+        return Err(format!(
+            "The vote would be cast at {}, which has already passed.",
+            scheduled_time.format("%Y-%m-%d %H:%M:%S")
+        ));
+    }
+    Ok(scheduled_time)
+}
+
+/// Shows inline when the scheduled votes of a bulk vote fire in contests ending at
+/// `contest_end_times`, or why that time is not allowed.
+fn render_bulk_scheduled_vote_time(
+    ui: &mut Ui,
+    anchor: ScheduleAnchor,
+    offset: chrono::Duration,
+    contest_end_times: &[Option<u64>],
+) {
+    if anchor == ScheduleAnchor::FromNow {
+        let earliest_end_time = contest_end_times.iter().flatten().min().copied();
+        render_scheduled_vote_time(ui, offset, earliest_end_time);
+        return;
+    }
+    let scheduled_times: Result<Vec<DateTime<Utc>>, String> = contest_end_times
+        .iter()
+        .map(|end_time| anchor.scheduled_vote_time(offset, *end_time))
+        .collect();
+    let range =
+        scheduled_times.map(|times| (times.iter().min().copied(), times.iter().max().copied()));
+    match range {
+        Ok((Some(first), Some(last))) => {
+            let dark_mode = ui.ctx().style().visuals.dark_mode;
+            let text = if first == last {
+                format!("Casts at {}", first.format("%Y-%m-%d %H:%M:%S"))
+            } else {
+                format!(
+                    "Casts from {} to {}",
+                    first.format("%Y-%m-%d %H:%M:%S"),
+                    last.format("%Y-%m-%d %H:%M:%S")
+                )
+            };
+            ui.label(RichText::new(text).color(DashColors::text_primary(dark_mode)));
+        }
+        Ok(_) => {}
+        Err(e) => {
+            ui.colored_label(Color32::DARK_RED, e);
+        }
+    }
+    let unknown_ends = contest_end_times.iter().filter(|end| end.is_none()).count();
+    if unknown_ends > 0 {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.colored_label(
+            DashColors::warning_color(dark_mode),
+            format!(
+                "{} contests have no known end, their votes count from now",
+                unknown_ends
+            ),
+        );
+    }
+}
+
+/// A scheduled vote whose time is being edited, with the new delay from now
+struct ScheduledVoteTimeEdit {
+    voter_id: Identifier,
+    contested_name: String,
+    days: u32,
+    hours: u32,
+    minutes: u32,
+}
+
+impl ScheduledVoteTimeEdit {
+    /// Starts editing `vote`, prefilled with the time left until it is cast
+    fn new(vote: &ScheduledDPNSVote) -> Self {
+        let offset = chrono::Duration::milliseconds(
+            vote.unix_timestamp as i64 - Utc::now().timestamp_millis(),
+        );
+        let (days, hours, minutes) = schedule_offset_parts(offset);
+        Self {
+            voter_id: vote.voter_id,
+            contested_name: vote.contested_name.clone(),
+            days,
+            hours,
+            minutes,
+        }
+    }
+
+    fn offset(&self) -> chrono::Duration {
+        chrono::Duration::days(self.days as i64)
+            + chrono::Duration::hours(self.hours as i64)
+            + chrono::Duration::minutes(self.minutes as i64)
+    }
+}
+
+/// Splits a delay into the days, hours and minutes of the schedule inputs, rounding up to the
+/// next minute. Past times give no delay, and delays beyond the inputs' 14 days are capped.
+fn schedule_offset_parts(offset: chrono::Duration) -> (u32, u32, u32) {
+    const MAX_MINUTES: i64 = 14 * 24 * 60 + 23 * 60 + 59;
+    let total_minutes = ((offset.num_seconds() + 59) / 60).clamp(0, MAX_MINUTES) as u32;
+    (
+        total_minutes / (24 * 60),
+        total_minutes / 60 % 24,
+        total_minutes % 60,
+    )
+}
+
+/// Carries each identity's bulk vote choice over to a new set of voting identities, so there
+/// is always exactly one option per identity. New identities default to Cast Now.
+fn reconcile_bulk_identity_options(
+    previous: &[(Identifier, VoteOption)],
+    identity_ids: &[Identifier],
+) -> Vec<VoteOption> {
+    identity_ids
+        .iter()
+        .map(|id| {
+            previous
+                .iter()
+                .find(|(previous_id, _)| previous_id == id)
+                .map(|(_, option)| option.clone())
+                .unwrap_or(VoteOption::CastNow)
+        })
+        .collect()
+}
+
+fn default_vote_choice_label(choice: Option<ResourceVoteChoice>) -> &'static str {
+    match choice {
+        Some(ResourceVoteChoice::Abstain) => "Abstain",
+        Some(ResourceVoteChoice::Lock) => "Lock",
+        Some(ResourceVoteChoice::TowardsIdentity(_)) | None => "None",
+    }
+}
+
+/// Normalizes a name typed into the watchlist the way DPNS does, ignoring a `.dash` suffix.
+fn normalize_watchlist_input(input: &str) -> Option<String> {
+    let label = input.trim();
+    let label = label.strip_suffix(".dash").unwrap_or(label);
+    if label.is_empty() {
+        None
+    } else {
+        Some(convert_to_homograph_safe_chars(label))
+    }
+}
+
+/// Watched names that have a contest the user hasn't been shown yet.
+fn newly_contested_watched_names(
+    watchlist: &[WatchedName],
+    contested_names: &[ContestedName],
+) -> Vec<String> {
+    watchlist
+        .iter()
+        .filter(|watched| !watched.contest_seen)
+        .filter(|watched| {
+            contested_names
+                .iter()
+                .any(|c| c.normalized_contested_name == watched.normalized_name)
+        })
+        .map(|watched| watched.normalized_name.clone())
+        .collect()
+}
+
+/// One-line summary of the votes in a contest, highest contestant first.
+fn contest_standings_summary(contested_name: &ContestedName) -> String {
+    let mut parts = contested_name
+        .contestants
+        .iter()
+        .flatten()
+        .sorted_by(|a, b| b.votes.cmp(&a.votes))
+        .map(|contestant| {
+            let first_6_chars: String = contestant
+                .id
+                .to_string(Encoding::Base58)
+                .chars()
+                .take(6)
+                .collect();
+            format!(
+                "{} ({}...): {} votes",
+                contestant.name, first_6_chars, contestant.votes
+            )
+        })
+        .collect::<Vec<_>>();
+    parts.push(format!(
+        "Lock: {}",
+        contested_name.locked_votes.unwrap_or(0)
+    ));
+    parts.push(format!(
+        "Abstain: {}",
+        contested_name.abstain_votes.unwrap_or(0)
+    ));
+    parts.join(", ")
+}
+
+/// Result of checking the cached outcome of a past contest against Platform
+#[derive(Debug, Clone, PartialEq)]
+enum ContestVerification {
+    /// Waiting for Platform, with the outcome that was cached when the check started
+    Verifying(ContestState),
+    Matched,
+    /// The cached outcome differed from Platform's and was replaced
+    Stale {
+        cached: ContestState,
+    },
+    Failed(String),
+}
+
+impl ContestVerification {
+    /// Compares the outcome `cached` before the check with the one Platform returned
+    fn of_outcome(cached: ContestState, on_chain: Option<&ContestState>) -> Self {
+        match on_chain {
+            None => ContestVerification::Failed(
+                "Platform has no outcome for this contest yet".to_string(),
+            ),
+            Some(outcome) if *outcome == cached => ContestVerification::Matched,
+            Some(_) => ContestVerification::Stale { cached },
+        }
+    }
+}
+
+/// Short description of the outcome of a contest, for the verification hover text
+fn contest_outcome_text(state: &ContestState) -> String {
+    match state {
+        ContestState::WonBy(identity_id) => {
+            format!("won by {}", identity_id.to_string(Encoding::Base58))
+        }
+        ContestState::Locked => "locked".to_string(),
+        ContestState::Joinable | ContestState::Ongoing => "active".to_string(),
+        ContestState::Unknown => "unknown".to_string(),
+    }
+}
+
+/// Contest shown in the details popup of the active contests table
+struct ContestDetailsPopup {
+    contested_name: String,
+    /// Whether the contest's details are being fetched
+    fetching: bool,
+}
+
+/// The choice with the most votes in a contest, with its votes. `None` when nobody voted yet
+/// or the lead is tied.
+fn leading_choice(contested_name: &ContestedName) -> Option<(ResourceVoteChoice, u32)> {
+    let choices = contested_name
+        .contestants
+        .iter()
+        .flatten()
+        .map(|contestant| {
+            (
+                ResourceVoteChoice::TowardsIdentity(contestant.id),
+                contestant.votes,
+            )
+        })
+        .chain([
+            (
+                ResourceVoteChoice::Lock,
+                contested_name.locked_votes.unwrap_or(0),
+            ),
+            (
+                ResourceVoteChoice::Abstain,
+                contested_name.abstain_votes.unwrap_or(0),
+            ),
+        ])
+        .collect::<Vec<_>>();
+    let most_votes = choices.iter().map(|(_, votes)| *votes).max().unwrap_or(0);
+    let mut leaders = choices
+        .into_iter()
+        .filter(|(_, votes)| *votes == most_votes);
+    match (leaders.next(), leaders.next()) {
+        (Some(leader), None) if most_votes > 0 => Some(leader),
+        _ => None,
+    }
+}
+
 /// Tracks the casting status for each scheduled vote item.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ScheduledVoteCastingStatus {
@@ -66,6 +495,18 @@ pub enum ScheduledVoteCastingStatus {
     InProgress,
     Failed,
     Completed,
+    /// The contest ended before the vote was cast
+    ContestEnded,
+}
+
+impl ScheduledVoteCastingStatus {
+    /// Whether the vote still has to be cast, either because it never was or its cast failed
+    fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            ScheduledVoteCastingStatus::NotStarted | ScheduledVoteCastingStatus::Failed
+        )
+    }
 }
 
 #[derive(PartialEq)]
@@ -94,12 +535,288 @@ enum SortColumn {
     AwardedTo,
 }
 
+impl SortColumn {
+    /// Stable identifier used to persist the sort column
+    fn storage_key(&self) -> &'static str {
+        match self {
+            SortColumn::ContestedName => "contested_name",
+            SortColumn::LockedVotes => "locked_votes",
+            SortColumn::AbstainVotes => "abstain_votes",
+            SortColumn::EndingTime => "ending_time",
+            SortColumn::LastUpdated => "last_updated",
+            SortColumn::AwardedTo => "awarded_to",
+        }
+    }
+
+    /// Parses an identifier returned by [`SortColumn::storage_key`]
+    fn from_storage_key(key: &str) -> Option<Self> {
+        match key {
+            "contested_name" => Some(SortColumn::ContestedName),
+            "locked_votes" => Some(SortColumn::LockedVotes),
+            "abstain_votes" => Some(SortColumn::AbstainVotes),
+            "ending_time" => Some(SortColumn::EndingTime),
+            "last_updated" => Some(SortColumn::LastUpdated),
+            "awarded_to" => Some(SortColumn::AwardedTo),
+            _ => None,
+        }
+    }
+
+    fn compare(&self, a: &ContestedName, b: &ContestedName) -> std::cmp::Ordering {
+        match self {
+            SortColumn::ContestedName => a
+                .normalized_contested_name
+                .cmp(&b.normalized_contested_name),
+            SortColumn::LockedVotes => a.locked_votes.cmp(&b.locked_votes),
+            SortColumn::AbstainVotes => a.abstain_votes.cmp(&b.abstain_votes),
+            SortColumn::EndingTime => a.end_time.cmp(&b.end_time),
+            SortColumn::LastUpdated => a.last_updated.cmp(&b.last_updated),
+            SortColumn::AwardedTo => a.awarded_to.cmp(&b.awarded_to),
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum SortOrder {
     Ascending,
     Descending,
 }
 
+impl SortOrder {
+    fn flipped(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+
+    fn arrow(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "▲",
+            SortOrder::Descending => "▼",
+        }
+    }
+}
+
+/// Orders contests by `primary`, breaking ties with `secondary` when there is one
+fn compare_contested_names(
+    a: &ContestedName,
+    b: &ContestedName,
+    primary: (SortColumn, SortOrder),
+    secondary: Option<(SortColumn, SortOrder)>,
+) -> std::cmp::Ordering {
+    let by = |(column, order): (SortColumn, SortOrder)| {
+        let ordering = column.compare(a, b);
+        if order == SortOrder::Descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+    by(primary).then_with(|| secondary.map_or(std::cmp::Ordering::Equal, by))
+}
+
+/// Groups the votes of an interrupted bulk vote that weren't cast yet by name and choice,
+/// with the voters still to vote that way.
+fn remaining_bulk_votes(
+    progress: &[BulkVoteProgress],
+) -> Vec<(String, ResourceVoteChoice, Vec<Identifier>)> {
+    let mut remaining: Vec<(String, ResourceVoteChoice, Vec<Identifier>)> = Vec::new();
+    for vote in progress.iter().filter(|vote| !vote.cast) {
+        match remaining
+            .iter_mut()
+            .find(|(name, choice, _)| *name == vote.contested_name && *choice == vote.choice)
+        {
+            Some((_, _, voters)) => voters.push(vote.voter_id),
+            None => remaining.push((
+                vote.contested_name.clone(),
+                vote.choice,
+                vec![vote.voter_id],
+            )),
+        }
+    }
+    remaining
+}
+
+/// Rough number of seconds left in a bulk vote, from the average time its completed votes
+/// took. Unknown until a vote completed.
+fn bulk_vote_eta_secs(elapsed_secs: u64, completed: usize, total: usize) -> Option<u64> {
+    if completed == 0 {
+        return None;
+    }
+    let remaining = total.saturating_sub(completed) as u64;
+    Some(elapsed_secs * remaining / completed as u64)
+}
+
+/// Ending soon window picked when the highlight is turned on in the settings
+pub const DEFAULT_CONTEST_ENDING_SOON_MINUTES: u16 = 60;
+/// Longest ending soon window the settings offer, a day
+pub const MAX_CONTEST_ENDING_SOON_MINUTES: u16 = 1440;
+/// How often the contests are refreshed while one of them ends soon, if enabled
+const CONTEST_AUTO_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Whether a contest ending at `end_time` ends within the next `window_ms` milliseconds
+fn is_ending_soon(end_time: Option<u64>, now_ms: u64, window_ms: u64) -> bool {
+    end_time.is_some_and(|end_time| end_time > now_ms && end_time - now_ms <= window_ms)
+}
+
+/// Fills a cell of the active contests table with the ending soon highlight
+fn paint_ending_soon_background(ui: &Ui, ending_soon: bool) {
+    if ending_soon {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.painter().rect_filled(
+            ui.max_rect(),
+            0.0,
+            DashColors::warning_color(dark_mode).gamma_multiply(0.2),
+        );
+    }
+}
+
+/// Keys selecting the first nine contestants of the highlighted active contest
+const CONTESTANT_KEYS: [egui::Key; 9] = [
+    egui::Key::Num1,
+    egui::Key::Num2,
+    egui::Key::Num3,
+    egui::Key::Num4,
+    egui::Key::Num5,
+    egui::Key::Num6,
+    egui::Key::Num7,
+    egui::Key::Num8,
+    egui::Key::Num9,
+];
+
+/// Moves the highlighted row of a table with `len` rows one row down or up, stopping at the
+/// first and last rows. Without a highlighted row the first row gets highlighted.
+fn move_highlight(current: Option<usize>, down: bool, len: usize) -> usize {
+    match current {
+        None => 0,
+        Some(index) if down => (index + 1).min(len.saturating_sub(1)),
+        Some(index) => index.saturating_sub(1),
+    }
+}
+
+/// Whether a name acquired at `acquired_at_ms` falls between the days `after` and `before`,
+/// both included. Days are in UTC, like the acquisition times are shown.
+fn acquired_within(
+    acquired_at_ms: u64,
+    after: Option<NaiveDate>,
+    before: Option<NaiveDate>,
+) -> bool {
+    let day_start_ms = |date: NaiveDate| {
+        date.and_hms_opt(0, 0, 0)
+            .map(|start| start.and_utc().timestamp_millis())
+    };
+    let Ok(acquired_at_ms) = i64::try_from(acquired_at_ms) else {
+        return before.is_none();
+    };
+    let is_after = after
+        .and_then(day_start_ms)
+        .is_none_or(|start| acquired_at_ms >= start);
+    let is_before = before
+        .and_then(|date| date.succ_opt())
+        .and_then(day_start_ms)
+        .is_none_or(|end| acquired_at_ms < end);
+    is_after && is_before
+}
+
+/// Builds the CSV export of contests: one row per contest, with a pair of columns per
+/// contestant. Values that aren't known yet are left empty.
+fn contested_names_csv(contested_names: &[ContestedName]) -> String {
+    let max_contestants = contested_names
+        .iter()
+        .map(|name| name.contestants.as_ref().map_or(0, Vec::len))
+        .max()
+        .unwrap_or(0);
+    let iso_time = |timestamp: Option<u64>| {
+        timestamp
+            .and_then(|ms| i64::try_from(ms).ok())
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|time| time.to_rfc3339())
+            .unwrap_or_default()
+    };
+    let count = |votes: Option<u32>| votes.map(|votes| votes.to_string()).unwrap_or_default();
+
+    let mut header = vec![
+        "contested_name".to_string(),
+        "locked_votes".to_string(),
+        "abstain_votes".to_string(),
+        "end_time".to_string(),
+        "last_updated".to_string(),
+    ];
+    for i in 1..=max_contestants {
+        header.push(format!("contestant_{}_id", i));
+        header.push(format!("contestant_{}_votes", i));
+    }
+    let mut csv = header.join(",");
+    csv.push('\n');
+
+    for name in contested_names {
+        let mut fields = vec![
+            name.normalized_contested_name.clone(),
+            count(name.locked_votes),
+            count(name.abstain_votes),
+            iso_time(name.end_time),
+            iso_time(name.last_updated),
+        ];
+        for contestant in name.contestants.iter().flatten() {
+            fields.push(contestant.id.to_string(Encoding::Base58));
+            fields.push(contestant.votes.to_string());
+        }
+        fields.resize(header.len(), String::new());
+        csv.push_str(
+            &fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Lowercases a contest filter and maps the characters DPNS normalizes (o to 0, l to 1)
+fn normalize_contest_filter(filter: &str) -> String {
+    filter
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'o' => '0',
+            'l' => '1',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Shortest filter matched against contestant IDs, shorter ones would match most contests
+const MIN_IDENTITY_FILTER_LEN: usize = 6;
+
+/// Whether a filter could be part of a base58 identity ID
+fn looks_like_partial_identifier(filter: &str) -> bool {
+    filter.chars().count() >= MIN_IDENTITY_FILTER_LEN
+        && filter
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+}
+
+/// Whether a contest matches the active contests filter: its name contains the normalized
+/// filter, or one of its contestant IDs contains the filter as typed
+fn contest_matches_filter(contested_name: &ContestedName, filter: &str) -> bool {
+    let filter = filter.trim();
+    if contested_name
+        .normalized_contested_name
+        .to_lowercase()
+        .contains(&normalize_contest_filter(filter))
+    {
+        return true;
+    }
+    looks_like_partial_identifier(filter)
+        && contested_name
+            .contestants
+            .iter()
+            .flatten()
+            .any(|contestant| contestant.id.to_string(Encoding::Base58).contains(filter))
+}
+
 /// The main, combined DPNSScreen:
 /// - Displays active/past/owned DPNS contests
 /// - Allows clicking selection of votes (bulk scheduling)
@@ -115,14 +832,26 @@ pub struct DPNSScreen {
     pub selected_votes: Vec<SelectedVote>,
     pub app_context: Arc<AppContext>,
     message: Option<(String, MessageType, DateTime<Utc>)>,
+    /// Set when the current subscreen's data could not be loaded, so the failure
+    /// isn't shown as an empty list
+    load_error: Option<String>,
     pending_backend_task: Option<BackendTask>,
 
     /// Sorting
     sort_column: SortColumn,
     sort_order: SortOrder,
+    /// Column breaking ties of the primary sort, picked by shift-clicking a header
+    secondary_sort: Option<(SortColumn, SortOrder)>,
+    /// Column widths of each subscreen's table, by sort preference key
+    column_widths: BTreeMap<&'static str, TableColumnWidths>,
     active_filter_term: String,
+    /// Whether the active contests table only shows names on the watchlist
+    only_watched_contests: bool,
     past_filter_term: String,
     owned_filter_term: String,
+    /// First and last days of acquisition of the owned names shown, both included
+    owned_acquired_after: Option<NaiveDate>,
+    owned_acquired_before: Option<NaiveDate>,
 
     /// Which sub-screen is active: Active contests, Past, Owned, or Scheduled
     pub dpns_subscreen: DPNSSubscreen,
@@ -134,63 +863,89 @@ pub struct DPNSScreen {
     bulk_schedule_message: Option<(MessageType, String)>,
     bulk_vote_handling_status: VoteHandlingStatus,
     set_all_option: VoteOption,
+    /// Days, hours and minutes the Schedule option starts with, from the settings
+    default_schedule: (u32, u32, u32),
+    /// What the delays of scheduled bulk votes count from
+    schedule_anchor: ScheduleAnchor,
+    /// Alias of a voting identity being edited in place
+    voter_alias_edit: Option<InlineAliasEdit>,
+    /// Vote choice applied by "Quick vote", from the settings
+    default_vote_choice: Option<ResourceVoteChoice>,
+
+    /// Names the user follows, whatever the state of their contest
+    watchlist: Vec<WatchedName>,
+    watchlist_input: String,
+
+    /// Progress of an immediate bulk vote that was interrupted, found on startup
+    unfinished_bulk_votes: Vec<BulkVoteProgress>,
+    /// Whether the running vote is the resumption of an interrupted bulk vote
+    resuming_bulk_votes: bool,
+    /// Votes shown for confirmation after "Apply Votes" was clicked
+    pending_bulk_votes: Option<PendingBulkVotes>,
+    /// Row of the active contests table that keyboard voting applies to
+    highlighted_active_contest: Option<usize>,
+    /// When the contests were last refreshed because one of them ends soon
+    last_auto_refresh: Instant,
+    /// Votes of the running bulk vote completed so far, out of its total
+    bulk_vote_progress: Option<(usize, usize)>,
+    /// Scheduled vote whose time is being edited
+    scheduled_vote_time_edit: Option<ScheduledVoteTimeEdit>,
+    /// Contest opened with a row's "Details" button
+    contest_details_popup: Option<ContestDetailsPopup>,
+    /// Outcome checks of past contests against Platform, by contested name
+    contest_verifications: BTreeMap<String, ContestVerification>,
+    /// Leaders recorded on each refresh of the contests, by contested name
+    contest_lead_history: HashMap<String, Vec<ContestLeadChange>>,
+    /// Per-entry outcome of the last imported voting plan, shown until dismissed
+    scheduled_votes_import_report: Option<Vec<ImportedVoteRow>>,
 }
 
 impl DPNSScreen {
     pub fn new(app_context: &Arc<AppContext>, dpns_subscreen: DPNSSubscreen) -> Self {
-        // Load contested names, local dpns, scheduled, etc.:
-        let contested_names = Arc::new(Mutex::new(match dpns_subscreen {
-            DPNSSubscreen::Active => app_context.ongoing_contested_names().unwrap_or_default(),
-            DPNSSubscreen::Past => app_context.all_contested_names().unwrap_or_default(),
-            DPNSSubscreen::Owned => Vec::new(),
-            DPNSSubscreen::ScheduledVotes => Vec::new(),
-        }));
-
-        let local_dpns_names = Arc::new(Mutex::new(match dpns_subscreen {
-            DPNSSubscreen::Active => Vec::new(),
-            DPNSSubscreen::Past => Vec::new(),
-            DPNSSubscreen::Owned => app_context.local_dpns_names().unwrap_or_default(),
-            DPNSSubscreen::ScheduledVotes => Vec::new(),
-        }));
-
-        let scheduled_votes = app_context.get_scheduled_votes().unwrap_or_default();
-        let scheduled_votes_with_status = Arc::new(Mutex::new(
-            scheduled_votes
-                .iter()
-                .map(|vote| {
-                    if vote.executed_successfully {
-                        (vote.clone(), ScheduledVoteCastingStatus::Completed)
-                    } else {
-                        (vote.clone(), ScheduledVoteCastingStatus::NotStarted)
-                    }
-                })
-                .collect::<Vec<_>>(),
-        ));
-
-        let voting_identities = app_context
-            .db
-            .get_local_voting_identities(app_context)
-            .unwrap_or_default();
         let user_identities = app_context.load_local_user_identities().unwrap_or_default();
+        // The sort the user last picked for this subscreen, by contested name otherwise
+        let (sort_column, sort_order) = app_context
+            .db
+            .get_sort_preference(dpns_subscreen.sort_preference_key())
+            .ok()
+            .flatten()
+            .and_then(|(column, ascending)| {
+                let order = if ascending {
+                    SortOrder::Ascending
+                } else {
+                    SortOrder::Descending
+                };
+                Some((SortColumn::from_storage_key(&column)?, order))
+            })
+            .unwrap_or((SortColumn::ContestedName, SortOrder::Ascending));
+        let (default_schedule_minutes, schedule_before_end) = app_context
+            .db
+            .get_default_vote_schedule()
+            .unwrap_or_else(|e| {
+                tracing::error!("Failed to load the default vote schedule: {}", e);
+                (0, false)
+            });
 
-        // Initialize vote handling pop-up state to hidden
-        let identity_count = voting_identities.len();
-        let bulk_identity_options = vec![VoteOption::CastNow; identity_count];
-
-        Self {
-            voting_identities,
+        let mut screen = Self {
+            voting_identities: Vec::new(),
             user_identities,
-            contested_names,
-            local_dpns_names,
-            scheduled_votes: scheduled_votes_with_status,
+            contested_names: Arc::new(Mutex::new(Vec::new())),
+            local_dpns_names: Arc::new(Mutex::new(Vec::new())),
+            scheduled_votes: Arc::new(Mutex::new(Vec::new())),
             selected_votes: Vec::new(),
             app_context: app_context.clone(),
             message: None,
-            sort_column: SortColumn::ContestedName,
-            sort_order: SortOrder::Ascending,
+            load_error: None,
+            sort_column,
+            sort_order,
+            secondary_sort: None,
+            column_widths: BTreeMap::new(),
             active_filter_term: String::new(),
+            only_watched_contests: false,
             past_filter_term: String::new(),
             owned_filter_term: String::new(),
+            owned_acquired_after: None,
+            owned_acquired_before: None,
             scheduled_vote_cast_in_progress: false,
             pending_backend_task: None,
             dpns_subscreen,
@@ -198,11 +953,137 @@ impl DPNSScreen {
 
             // Vote handling
             show_bulk_schedule_popup: false,
-            bulk_identity_options,
+            bulk_identity_options: Vec::new(),
             bulk_schedule_message: None,
             bulk_vote_handling_status: VoteHandlingStatus::NotStarted,
             set_all_option: VoteOption::CastNow,
+            default_schedule: schedule_offset_parts(chrono::Duration::minutes(
+                default_schedule_minutes as i64,
+            )),
+            schedule_anchor: if schedule_before_end {
+                ScheduleAnchor::BeforeContestEnd
+            } else {
+                ScheduleAnchor::FromNow
+            },
+            voter_alias_edit: None,
+            default_vote_choice: app_context.db.get_default_vote_choice().ok().flatten(),
+            watchlist: Vec::new(),
+            watchlist_input: String::new(),
+            // Only one screen per network offers to resume, and it only looks once at startup,
+            // before any bulk vote of this session could be running
+            unfinished_bulk_votes: if dpns_subscreen == DPNSSubscreen::Active {
+                app_context.get_bulk_vote_progress().unwrap_or_default()
+            } else {
+                Vec::new()
+            },
+            resuming_bulk_votes: false,
+            pending_bulk_votes: None,
+            highlighted_active_contest: None,
+            last_auto_refresh: Instant::now(),
+            bulk_vote_progress: None,
+            scheduled_vote_time_edit: None,
+            contest_details_popup: None,
+            contest_verifications: BTreeMap::new(),
+            contest_lead_history: HashMap::new(),
+            scheduled_votes_import_report: None,
+        };
+        screen.reload_voting_identities();
+        // Load contested names, local dpns, scheduled, etc.:
+        screen.refresh();
+        screen
+    }
+
+    /// Reloads the voting identities for the current network, keeping the bulk vote options
+    /// in step with them.
+    pub fn reload_voting_identities(&mut self) {
+        let voting_identities = self
+            .app_context
+            .db
+            .get_local_voting_identities(&self.app_context)
+            .unwrap_or_default();
+        self.set_voting_identities(voting_identities);
+    }
+
+    fn set_voting_identities(&mut self, voting_identities: Vec<QualifiedIdentity>) {
+        let previous_options = self
+            .voting_identities
+            .iter()
+            .map(|identity| identity.identity.id())
+            .zip(self.bulk_identity_options.iter().cloned())
+            .collect::<Vec<_>>();
+        let identity_ids = voting_identities
+            .iter()
+            .map(|identity| identity.identity.id())
+            .collect::<Vec<_>>();
+        self.bulk_identity_options =
+            reconcile_bulk_identity_options(&previous_options, &identity_ids);
+        self.voting_identities = voting_identities;
+    }
+
+    // ---------------------------
+    // Data loading
+    // ---------------------------
+    /// Reloads the data shown by the current subscreen from the database.
+    ///
+    /// Errors are returned instead of being replaced with an empty list, so a failed
+    /// load can be told apart from a genuinely empty one.
+    fn reload_subscreen_data(&mut self) -> rusqlite::Result<()> {
+        self.watchlist = self.app_context.db.get_dpns_watchlist(&self.app_context)?;
+        match self.dpns_subscreen {
+            DPNSSubscreen::Active => {
+                let contested_names = self.app_context.ongoing_contested_names()?;
+                *self.contested_names.lock().unwrap() = contested_names;
+            }
+            DPNSSubscreen::Past => {
+                let contested_names = self.app_context.all_contested_names()?;
+                *self.contested_names.lock().unwrap() = contested_names;
+                self.contest_lead_history = self
+                    .app_context
+                    .db
+                    .get_contest_lead_history(&self.app_context)?;
+            }
+            DPNSSubscreen::Watchlist => {
+                let contested_names = self.app_context.all_contested_names()?;
+                *self.contested_names.lock().unwrap() = contested_names;
+            }
+            DPNSSubscreen::Owned => {
+                let dpns_names = self.app_context.local_dpns_names()?;
+                *self.local_dpns_names.lock().unwrap() = dpns_names;
+            }
+            DPNSSubscreen::ScheduledVotes => {
+                let new_scheduled = self.app_context.get_scheduled_votes()?;
+                let contest_end_times = self.app_context.contest_end_times()?;
+                let now = Utc::now().timestamp_millis() as TimestampMillis;
+                let mut scheduled_votes = self.scheduled_votes.lock().unwrap();
+                *scheduled_votes = new_scheduled
+                    .iter()
+                    .map(|newv| {
+                        if newv.executed_successfully {
+                            (newv.clone(), ScheduledVoteCastingStatus::Completed)
+                        } else if newv.contest_has_ended(&contest_end_times, now) {
+                            (newv.clone(), ScheduledVoteCastingStatus::ContestEnded)
+                        } else if let Some(existing) = scheduled_votes.iter().find(|(old, _)| {
+                            old.contested_name == newv.contested_name
+                                && old.voter_id == newv.voter_id
+                        }) {
+                            // preserve old status if InProgress/Failed
+                            match existing.1 {
+                                ScheduledVoteCastingStatus::InProgress => {
+                                    (newv.clone(), ScheduledVoteCastingStatus::InProgress)
+                                }
+                                ScheduledVoteCastingStatus::Failed => {
+                                    (newv.clone(), ScheduledVoteCastingStatus::Failed)
+                                }
+                                _ => (newv.clone(), ScheduledVoteCastingStatus::NotStarted),
+                            }
+                        } else {
+                            (newv.clone(), ScheduledVoteCastingStatus::NotStarted)
+                        }
+                    })
+                    .collect();
+            }
         }
+        Ok(())
     }
 
     // ---------------------------
@@ -227,39 +1108,204 @@ impl DPNSScreen {
     // ---------------------------
     fn toggle_sort(&mut self, column: SortColumn) {
         if self.sort_column == column {
-            self.sort_order = match self.sort_order {
-                SortOrder::Ascending => SortOrder::Descending,
-                SortOrder::Descending => SortOrder::Ascending,
-            };
+            self.sort_order = self.sort_order.flipped();
         } else {
             self.sort_column = column;
             self.sort_order = SortOrder::Ascending;
+            if self
+                .secondary_sort
+                .is_some_and(|(secondary, _)| secondary == column)
+            {
+                self.secondary_sort = None;
+            }
+        }
+        if let Err(e) = self.app_context.db.set_sort_preference(
+            self.dpns_subscreen.sort_preference_key(),
+            self.sort_column.storage_key(),
+            self.sort_order == SortOrder::Ascending,
+        ) {
+            tracing::error!("Error saving DPNS sort preference: {}", e);
         }
     }
 
-    fn sort_contested_names(&self, contested_names: &mut [ContestedName]) {
-        contested_names.sort_by(|a, b| {
-            let order = match self.sort_column {
-                SortColumn::ContestedName => a
-                    .normalized_contested_name
-                    .cmp(&b.normalized_contested_name),
-                SortColumn::LockedVotes => a.locked_votes.cmp(&b.locked_votes),
-                SortColumn::AbstainVotes => a.abstain_votes.cmp(&b.abstain_votes),
-                SortColumn::EndingTime => a.end_time.cmp(&b.end_time),
-                SortColumn::LastUpdated => a.last_updated.cmp(&b.last_updated),
-                SortColumn::AwardedTo => a.awarded_to.cmp(&b.awarded_to),
-            };
-            if self.sort_order == SortOrder::Descending {
-                order.reverse()
-            } else {
-                order
+    /// The contests of the active or past table, filtered and sorted as they are shown
+    fn visible_contested_names(&self) -> Vec<ContestedName> {
+        let mut contested_names = self.contested_names.lock().unwrap().clone();
+        match self.dpns_subscreen {
+            DPNSSubscreen::Past => {
+                contested_names
+                    .retain(|c| c.awarded_to.is_some() || c.state == ContestState::Locked);
+                if !self.past_filter_term.is_empty() {
+                    let filter_lc = normalize_contest_filter(&self.past_filter_term);
+                    contested_names.retain(|c| {
+                        c.normalized_contested_name
+                            .to_lowercase()
+                            .contains(&filter_lc)
+                    });
+                }
             }
-        });
+            _ => {
+                if self.only_watched_contests {
+                    contested_names.retain(|c| self.is_watched(&c.normalized_contested_name));
+                }
+                if !self.active_filter_term.is_empty() {
+                    contested_names.retain(|c| contest_matches_filter(c, &self.active_filter_term));
+                }
+            }
+        }
+        self.sort_contested_names(&mut contested_names);
+        contested_names
+    }
+
+    /// Asks for a file name and writes the contests currently shown to it as CSV.
+    fn export_contested_names_csv(&mut self) {
+        let contested_names = self.visible_contested_names();
+        let file_name = match self.dpns_subscreen {
+            DPNSSubscreen::Past => "dpns-past-contests.csv",
+            _ => "dpns-active-contests.csv",
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter("CSV", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+
+        self.message = Some(
+            match std::fs::write(&path, contested_names_csv(&contested_names)) {
+                Ok(()) => (
+                    format!(
+                        "Exported {} contests to {}",
+                        contested_names.len(),
+                        path.display()
+                    ),
+                    MessageType::Success,
+                    Utc::now(),
+                ),
+                Err(e) => (
+                    format!("Failed to export contests: {}", e),
+                    MessageType::Error,
+                    Utc::now(),
+                ),
+            },
+        );
+    }
+
+    /// Sets the column breaking ties of the primary sort, or flips its direction if it
+    /// already is. Picking the primary column clears it.
+    fn toggle_secondary_sort(&mut self, column: SortColumn) {
+        self.secondary_sort = match self.secondary_sort {
+            _ if column == self.sort_column => None,
+            Some((secondary, order)) if secondary == column => Some((column, order.flipped())),
+            _ => Some((column, SortOrder::Ascending)),
+        };
+    }
+
+    /// When the running "Refresh All" batch started, if it still has to refresh what the
+    /// current subscreen shows
+    fn refresh_all_pending_since(&self) -> Option<u64> {
+        let task = match self.dpns_subscreen {
+            DPNSSubscreen::Active | DPNSSubscreen::Past | DPNSSubscreen::Watchlist => {
+                BackendTask::ContestedResourceTask(ContestedResourceTask::QueryDPNSContests)
+            }
+            DPNSSubscreen::Owned => {
+                BackendTask::IdentityTask(IdentityTask::RefreshLoadedIdentitiesOwnedDPNSNames)
+            }
+            DPNSSubscreen::ScheduledVotes => return None,
+        };
+        self.app_context.refresh_all_pending_since(&task)
+    }
+
+    /// Column widths of the current subscreen's table, loaded on first use
+    fn column_widths(&mut self) -> &mut TableColumnWidths {
+        let key = self.dpns_subscreen.sort_preference_key();
+        self.column_widths
+            .entry(key)
+            .or_insert_with(|| TableColumnWidths::load(&self.app_context, key))
+    }
+
+    /// Id salt and initial column widths of the current subscreen's table
+    fn table_column_layout(&mut self, defaults: &[f32]) -> ((&'static str, u32), Vec<f32>) {
+        let column_widths = self.column_widths();
+        (
+            column_widths.id_salt(),
+            column_widths.initial_widths(defaults),
+        )
+    }
+
+    fn track_column_widths(&mut self, ctx: &Context, widths: &[f32]) {
+        let app_context = self.app_context.clone();
+        self.column_widths().track(ctx, &app_context, widths);
+    }
+
+    fn column_widths_menu(&mut self, response: &egui::Response) {
+        let app_context = self.app_context.clone();
+        self.column_widths()
+            .header_context_menu(response, &app_context);
+    }
+
+    /// Header button sorting by `column` on click, or breaking ties by it on shift-click,
+    /// marked with the direction it sorts in and, with a secondary sort, its rank.
+    fn render_sort_header(&mut self, ui: &mut Ui, label: &str, column: SortColumn) {
+        let indicator = if column == self.sort_column {
+            let rank = if self.secondary_sort.is_some() {
+                "1"
+            } else {
+                ""
+            };
+            format!(" {}{}", self.sort_order.arrow(), rank)
+        } else {
+            match self.secondary_sort {
+                Some((secondary, order)) if secondary == column => format!(" {}2", order.arrow()),
+                _ => String::new(),
+            }
+        };
+        let response = ui
+            .button(format!("{}{}", label, indicator))
+            .on_hover_text("Click to sort, Shift+click to break ties by this column");
+        self.column_widths_menu(&response);
+        if response.clicked() {
+            if ui.input(|i| i.modifiers.shift) {
+                self.toggle_secondary_sort(column);
+            } else {
+                self.toggle_sort(column);
+            }
+        }
+    }
+
+    fn sort_contested_names(&self, contested_names: &mut [ContestedName]) {
+        contested_names.sort_by(|a, b| {
+            compare_contested_names(
+                a,
+                b,
+                (self.sort_column, self.sort_order),
+                self.secondary_sort,
+            )
+        });
+    }
+
+    // ---------------------------
+    // Rendering: Empty states
+    // ---------------------------
+    fn render_load_error(&mut self, ui: &mut Ui, error: &str) {
+        ui.vertical_centered(|ui| {
+            ui.add_space(20.0);
+            ui.label(
+                egui::RichText::new("Could not load this list.")
+                    .heading()
+                    .strong()
+                    .color(Color32::DARK_RED),
+            );
+            ui.add_space(10.0);
+            ui.label(egui::RichText::new(error).color(Color32::DARK_RED));
+            ui.add_space(10.0);
+            if ui.button("Retry").clicked() {
+                self.refresh();
+            }
+        });
     }
 
-    // ---------------------------
-    // Rendering: Empty states
-    // ---------------------------
     fn render_no_active_contests_or_owned_names(&mut self, ui: &mut Ui) -> AppAction {
         let mut app_action = AppAction::None;
         ui.vertical_centered(|ui| {
@@ -297,6 +1343,14 @@ impl DPNSScreen {
                             .color(Color32::GRAY),
                     );
                 }
+                DPNSSubscreen::Watchlist => {
+                    ui.label(
+                        egui::RichText::new("No watched names.")
+                            .heading()
+                            .strong()
+                            .color(Color32::GRAY),
+                    );
+                }
             }
             ui.add_space(10.0);
 
@@ -312,7 +1366,9 @@ impl DPNSScreen {
                         self.refreshing_status = RefreshingStatus::Refreshing(now);
                         self.message = None; // Clear any existing message
                         match self.dpns_subscreen {
-                            DPNSSubscreen::Active | DPNSSubscreen::Past => {
+                            DPNSSubscreen::Active
+                            | DPNSSubscreen::Past
+                            | DPNSSubscreen::Watchlist => {
                                 app_action = AppAction::BackendTask(BackendTask::ContestedResourceTask(
                                     ContestedResourceTask::QueryDPNSContests,
                                 ));
@@ -344,86 +1400,166 @@ impl DPNSScreen {
     // Rendering: Active, Past, Owned, Scheduled
     // ---------------------------
 
+    /// Shows the default vote picker and the button that selects it for every visible contest.
+    /// Quick voting only fills in the selections; votes are cast from the voting window.
+    fn render_quick_vote_controls(&mut self, ui: &mut Ui, visible_contests: &[ContestedName]) {
+        ui.horizontal(|ui| {
+            let dark_mode = ui.ctx().style().visuals.dark_mode;
+            ui.label(RichText::new("Default vote:").color(DashColors::text_primary(dark_mode)));
+            let mut selected_choice = self.default_vote_choice;
+            ComboBox::from_id_salt("default_vote_choice")
+                .width(100.0)
+                .selected_text(default_vote_choice_label(selected_choice))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut selected_choice, None, "None");
+                    ui.selectable_value(
+                        &mut selected_choice,
+                        Some(ResourceVoteChoice::Abstain),
+                        "Abstain",
+                    );
+                    ui.selectable_value(
+                        &mut selected_choice,
+                        Some(ResourceVoteChoice::Lock),
+                        "Lock",
+                    );
+                });
+            if selected_choice != self.default_vote_choice {
+                match self
+                    .app_context
+                    .db
+                    .update_default_vote_choice(selected_choice)
+                {
+                    Ok(()) => self.default_vote_choice = selected_choice,
+                    Err(e) => {
+                        self.message = Some((
+                            format!("Error saving default vote: {}", e),
+                            MessageType::Error,
+                            Utc::now(),
+                        ));
+                    }
+                }
+            }
+
+            let Some(choice) = self.default_vote_choice else {
+                return;
+            };
+            let votable = visible_contests
+                .iter()
+                .filter(|contest| contest.state.state_is_votable())
+                .collect::<Vec<_>>();
+            let button = Button::new(format!(
+                "Quick vote {} on all visible ({})",
+                default_vote_choice_label(Some(choice)),
+                votable.len()
+            ));
+            if ui
+                .add_enabled(!votable.is_empty(), button)
+                .on_hover_text(
+                    "Selects your default vote for every contest listed below and opens the \
+                     voting window. Nothing is cast until you confirm there.",
+                )
+                .clicked()
+            {
+                for contest in votable {
+                    if let Some(existing) = self
+                        .selected_votes
+                        .iter_mut()
+                        .find(|sv| sv.contested_name == contest.normalized_contested_name)
+                    {
+                        existing.vote_choice = choice;
+                    } else {
+                        self.selected_votes.push(SelectedVote {
+                            contested_name: contest.normalized_contested_name.clone(),
+                            vote_choice: choice,
+                            end_time: contest.end_time,
+                        });
+                    }
+                }
+                self.show_bulk_schedule_popup = true;
+            }
+        });
+    }
+
     /// Show the Active Contests table
-    fn render_table_active_contests(&mut self, ui: &mut Ui) {
+    fn render_table_active_contests(&mut self, ui: &mut Ui) -> AppAction {
+        let developer_mode = self.app_context.is_developer_mode();
+        let mut action = self.render_newly_contested_banner(ui);
         ui.horizontal(|ui| {
             let dark_mode = ui.ctx().style().visuals.dark_mode;
-            ui.label(RichText::new("Filter by name:").color(DashColors::text_primary(dark_mode)));
-            ui.text_edit_singleline(&mut self.active_filter_term);
+            ui.label(
+                RichText::new("Filter by name or contestant ID:")
+                    .color(DashColors::text_primary(dark_mode)),
+            );
+            ui.text_edit_singleline(&mut self.active_filter_term)
+                .on_hover_text(format!(
+                    "IDs match from {} characters, case-sensitive",
+                    MIN_IDENTITY_FILTER_LEN
+                ));
+            ui.checkbox(&mut self.only_watched_contests, "★ Watched only");
+            ui.label(
+                RichText::new("↑/↓ move · L lock · A abstain · 1-9 contestant · Enter vote")
+                    .small()
+                    .color(DashColors::text_secondary(dark_mode)),
+            );
         });
 
-        let contested_names = {
-            let guard = self.contested_names.lock().unwrap();
-            let mut cn = guard.clone();
-            if !self.active_filter_term.is_empty() {
-                let mut filter_lc = self.active_filter_term.to_lowercase();
-                // Convert o and O to 0 and l to 1 in filter_lc
-                filter_lc = filter_lc
-                    .chars()
-                    .map(|c| match c {
-                        'o' | 'O' => '0',
-                        'l' => '1',
-                        _ => c,
-                    })
-                    .collect();
-                cn.retain(|c| {
-                    c.normalized_contested_name
-                        .to_lowercase()
-                        .contains(&filter_lc)
-                });
-            }
-            self.sort_contested_names(&mut cn);
-            cn
-        };
+        action |= self.render_contest_details_popup(ui);
+
+        let contested_names = self.visible_contested_names();
+        let highlight_moved = self.handle_active_contests_keys(ui.ctx(), &contested_names);
+        let ending_soon_window_ms = self.app_context.contest_ending_soon_minutes() as u64 * 60_000;
+        let now_ms = Utc::now().timestamp_millis() as u64;
+        let highlighted = self.highlighted_active_contest;
+        let mut highlighted_rect: Option<egui::Rect> = None;
+
+        self.render_quick_vote_controls(ui, &contested_names);
 
         // Space allocation for UI elements is handled by the layout system
 
         egui::ScrollArea::both().show(ui, |ui| {
+            let ctx = ui.ctx().clone();
+            let (id_salt, widths) = self.table_column_layout(&[200.0, 100.0, 100.0, 200.0, 200.0]);
             TableBuilder::new(ui)
+                .id_salt(id_salt)
                 .striped(false)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                .column(Column::initial(200.0).resizable(true)) // Contested Name
-                .column(Column::initial(100.0).resizable(true)) // Locked
-                .column(Column::initial(100.0).resizable(true)) // Abstain
-                .column(Column::initial(200.0).resizable(true)) // Ending Time
-                .column(Column::initial(200.0).resizable(true)) // Last Updated
+                .column(Column::initial(widths[0]).resizable(true)) // Contested Name
+                .column(Column::initial(widths[1]).resizable(true)) // Locked
+                .column(Column::initial(widths[2]).resizable(true)) // Abstain
+                .column(Column::initial(widths[3]).resizable(true)) // Ending Time
+                .column(Column::initial(widths[4]).resizable(true)) // Last Updated
                 .column(Column::remainder()) // Contestants
                 .header(30.0, |mut header| {
                     header.col(|ui| {
-                        if ui.button("Contested Name").clicked() {
-                            self.toggle_sort(SortColumn::ContestedName);
-                        }
+                        self.render_sort_header(ui, "Contested Name", SortColumn::ContestedName);
                     });
                     header.col(|ui| {
-                        if ui.button("Locked Votes").clicked() {
-                            self.toggle_sort(SortColumn::LockedVotes);
-                        }
+                        self.render_sort_header(ui, "Locked Votes", SortColumn::LockedVotes);
                     });
                     header.col(|ui| {
-                        if ui.button("Abstain Votes").clicked() {
-                            self.toggle_sort(SortColumn::AbstainVotes);
-                        }
+                        self.render_sort_header(ui, "Abstain Votes", SortColumn::AbstainVotes);
                     });
                     header.col(|ui| {
-                        if ui.button("Ending Time").clicked() {
-                            self.toggle_sort(SortColumn::EndingTime);
-                        }
+                        self.render_sort_header(ui, "Ending Time", SortColumn::EndingTime);
                     });
                     header.col(|ui| {
-                        if ui.button("Last Updated").clicked() {
-                            self.toggle_sort(SortColumn::LastUpdated);
-                        }
+                        self.render_sort_header(ui, "Last Updated", SortColumn::LastUpdated);
                     });
                     header.col(|ui| {
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
-                        ui.heading(
+                        let response = ui.heading(
                             RichText::new("Contestants").color(DashColors::text_primary(dark_mode)),
                         );
+                        self.column_widths_menu(&response);
                     });
                 })
                 .body(|mut body| {
-                    for contested_name in &contested_names {
+                    self.track_column_widths(&ctx, body.widths());
+                    for (index, contested_name) in contested_names.iter().enumerate() {
+                        let is_highlighted = highlighted == Some(index);
+                        let ending_soon =
+                            is_ending_soon(contested_name.end_time, now_ms, ending_soon_window_ms);
                         body.row(25.0, |mut row| {
                             let locked_votes = contested_name.locked_votes.unwrap_or(0);
                             let max_contestant_votes = contested_name
@@ -437,6 +1573,17 @@ impl DPNSScreen {
 
                             // Contested Name
                             row.col(|ui| {
+                                paint_ending_soon_background(ui, ending_soon);
+                                if is_highlighted {
+                                    highlighted_rect = Some(ui.max_rect());
+                                    if highlight_moved {
+                                        ui.scroll_to_rect(ui.max_rect(), None);
+                                    }
+                                }
+                                self.render_watch_toggle(
+                                    ui,
+                                    &contested_name.normalized_contested_name,
+                                );
                                 let (used_name, highlighted) =
                                     if let Some(contestants) = &contested_name.contestants {
                                         if let Some(first) = contestants.first() {
@@ -479,291 +1626,1007 @@ impl DPNSScreen {
                                     };
 
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                let label_response = ui.label(
-                                    RichText::new(used_name)
-                                        .color(DashColors::text_primary(dark_mode)),
+                                let mut label_response = ui.add(
+                                    Label::new(
+                                        RichText::new(used_name)
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    )
+                                    .sense(egui::Sense::click()),
                                 );
                                 if let Some(tooltip) = highlighted {
-                                    label_response.on_hover_text(tooltip);
+                                    label_response = label_response.on_hover_text(tooltip);
+                                }
+                                if label_response
+                                    .on_hover_text("Click to open the contest details")
+                                    .clicked()
+                                {
+                                    action = Self::open_contest_details(
+                                        contested_name,
+                                        &self.app_context,
+                                    );
+                                }
+                                if ui
+                                    .small_button("Details")
+                                    .on_hover_text("Show every contestant and vote count")
+                                    .clicked()
+                                {
+                                    action = self.open_contest_details_popup(contested_name);
+                                }
+                            });
+
+                            // LOCK button
+                            row.col(|ui| {
+                                paint_ending_soon_background(ui, ending_soon);
+                                let label_text = format!("{}", locked_votes);
+                                let text_widget = if is_locked_votes_bold {
+                                    RichText::new(label_text).strong()
+                                } else {
+                                    RichText::new(label_text)
+                                };
+
+                                // See if this (LOCK) is selected
+                                let is_selected = self.selected_votes.iter().any(|sv| {
+                                    sv.contested_name == contested_name.normalized_contested_name
+                                        && sv.vote_choice == ResourceVoteChoice::Lock
+                                });
+
+                                let button = if is_selected {
+                                    Button::new(text_widget).fill(Color32::from_rgb(0, 150, 255))
+                                } else {
+                                    Button::new(text_widget)
+                                };
+                                if ui.add(button).clicked() {
+                                    self.toggle_vote_selection(
+                                        contested_name,
+                                        ResourceVoteChoice::Lock,
+                                    );
+                                }
+                            });
+
+                            // ABSTAIN button
+                            row.col(|ui| {
+                                paint_ending_soon_background(ui, ending_soon);
+                                let abstain_votes = contested_name.abstain_votes.unwrap_or(0);
+                                let label_text = format!("{}", abstain_votes);
+
+                                let is_selected = self.selected_votes.iter().any(|sv| {
+                                    sv.contested_name == contested_name.normalized_contested_name
+                                        && sv.vote_choice == ResourceVoteChoice::Abstain
+                                });
+
+                                let button = if is_selected {
+                                    Button::new(label_text).fill(Color32::from_rgb(0, 150, 255))
+                                } else {
+                                    Button::new(label_text)
+                                };
+                                if ui.add(button).clicked() {
+                                    self.toggle_vote_selection(
+                                        contested_name,
+                                        ResourceVoteChoice::Abstain,
+                                    );
+                                }
+                            });
+
+                            // Ending Time
+                            row.col(|ui| {
+                                paint_ending_soon_background(ui, ending_soon);
+                                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                                if let Some(ending_time) = contested_name.end_time {
+                                    let text = format_timestamp_millis(
+                                        ending_time,
+                                        TimestampStyle::AbsoluteAndRelative,
+                                        developer_mode,
+                                    );
+                                    let response = ui.label(
+                                        RichText::new(text)
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
+                                    if ending_soon {
+                                        response.on_hover_text(format!(
+                                            "Ends within {} minutes",
+                                            self.app_context.contest_ending_soon_minutes()
+                                        ));
+                                    }
+                                } else {
+                                    ui.label(
+                                        RichText::new("Fetching")
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
+                                }
+                            });
+
+                            // Last Updated
+                            row.col(|ui| {
+                                paint_ending_soon_background(ui, ending_soon);
+                                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                                if let Some(last_updated) = contested_name.last_updated {
+                                    let text = format_timestamp_millis(
+                                        last_updated.saturating_mul(1000),
+                                        TimestampStyle::Relative,
+                                        developer_mode,
+                                    );
+                                    ui.label(
+                                        RichText::new(text)
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
+                                } else {
+                                    ui.label(
+                                        RichText::new("Fetching")
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
+                                }
+                            });
+
+                            // Contestants
+                            row.col(|ui| {
+                                paint_ending_soon_background(ui, ending_soon);
+                                if is_highlighted {
+                                    highlighted_rect =
+                                        highlighted_rect.map(|rect| rect.union(ui.max_rect()));
+                                }
+                                self.show_contestants_for_contested_name(
+                                    ui,
+                                    contested_name,
+                                    is_locked_votes_bold,
+                                    max_contestant_votes,
+                                );
+                            });
+                        });
+                    }
+                });
+
+            // Focus outline around the row keyboard voting applies to
+            if let Some(rect) = highlighted_rect {
+                ui.painter().rect_stroke(
+                    rect,
+                    2.0,
+                    egui::Stroke::new(2.0, DashColors::DASH_BLUE),
+                    egui::StrokeKind::Inside,
+                );
+            }
+        });
+
+        action
+    }
+
+    /// Refreshes the contests every [`CONTEST_AUTO_REFRESH_INTERVAL`] while the active contests
+    /// are shown and one of them ends soon, if enabled in the settings.
+    fn auto_refresh_ending_soon_contests(&mut self, ctx: &Context) -> AppAction {
+        let window_ms = self.app_context.contest_ending_soon_minutes() as u64 * 60_000;
+        if self.dpns_subscreen != DPNSSubscreen::Active
+            || window_ms == 0
+            || !self.app_context.contest_auto_refresh()
+        {
+            return AppAction::None;
+        }
+        let now_ms = Utc::now().timestamp_millis() as u64;
+        let any_ending_soon = self
+            .contested_names
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|contest| is_ending_soon(contest.end_time, now_ms, window_ms));
+        if !any_ending_soon {
+            return AppAction::None;
+        }
+
+        // Keep repainting so the timer fires without user input
+        ctx.request_repaint_after(CONTEST_AUTO_REFRESH_INTERVAL);
+        if self.refreshing_status != RefreshingStatus::NotRefreshing
+            || self.last_auto_refresh.elapsed() < CONTEST_AUTO_REFRESH_INTERVAL
+        {
+            return AppAction::None;
+        }
+        self.last_auto_refresh = Instant::now();
+        self.refreshing_status = RefreshingStatus::Refreshing(Utc::now().timestamp() as u64);
+        AppAction::BackendTask(BackendTask::ContestedResourceTask(
+            ContestedResourceTask::QueryDPNSContests,
+        ))
+    }
+
+    /// Keyboard voting in the active contests table: the arrow keys move the highlighted row,
+    /// L and A toggle Lock and Abstain for it, 1-9 select its Nth contestant and Enter opens the
+    /// voting window. Keys are left alone while a text field such as the filter has focus, or
+    /// while the voting window is open.
+    ///
+    /// Returns whether the highlighted row moved, so it can be scrolled into view.
+    fn handle_active_contests_keys(
+        &mut self,
+        ctx: &Context,
+        contested_names: &[ContestedName],
+    ) -> bool {
+        if contested_names.is_empty() {
+            self.highlighted_active_contest = None;
+            return false;
+        }
+        // Filtering can leave fewer rows than the highlighted one
+        self.highlighted_active_contest = self
+            .highlighted_active_contest
+            .map(|index| index.min(contested_names.len() - 1));
+        if ctx.wants_keyboard_input()
+            || self.show_bulk_schedule_popup
+            || self.contest_details_popup.is_some()
+        {
+            return false;
+        }
+
+        let pressed = |key| ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, key));
+        let mut moved = false;
+        if pressed(egui::Key::ArrowDown) {
+            self.highlighted_active_contest = Some(move_highlight(
+                self.highlighted_active_contest,
+                true,
+                contested_names.len(),
+            ));
+            moved = true;
+        }
+        if pressed(egui::Key::ArrowUp) {
+            self.highlighted_active_contest = Some(move_highlight(
+                self.highlighted_active_contest,
+                false,
+                contested_names.len(),
+            ));
+            moved = true;
+        }
+
+        if let Some(contested_name) = self
+            .highlighted_active_contest
+            .map(|index| &contested_names[index])
+        {
+            if pressed(egui::Key::L) {
+                self.toggle_vote_selection(contested_name, ResourceVoteChoice::Lock);
+            }
+            if pressed(egui::Key::A) {
+                self.toggle_vote_selection(contested_name, ResourceVoteChoice::Abstain);
+            }
+            for (position, key) in CONTESTANT_KEYS.into_iter().enumerate() {
+                if !pressed(key) {
+                    continue;
+                }
+                if let Some(contestant) = contested_name
+                    .contestants
+                    .as_ref()
+                    .and_then(|contestants| contestants.get(position))
+                {
+                    self.toggle_vote_selection(
+                        contested_name,
+                        ResourceVoteChoice::TowardsIdentity(contestant.id),
+                    );
+                }
+            }
+        }
+
+        if !self.selected_votes.is_empty() && pressed(egui::Key::Enter) {
+            self.show_bulk_schedule_popup = true;
+        }
+        moved
+    }
+
+    /// Opens the detail screen of a single contest.
+    fn open_contest_details(
+        contested_name: &ContestedName,
+        app_context: &Arc<AppContext>,
+    ) -> AppAction {
+        AppAction::AddScreen(Screen::ContestDetailsScreen(ContestDetailsScreen::new(
+            contested_name.normalized_contested_name.clone(),
+            app_context,
+        )))
+    }
+
+    /// Opens the details popup of a contest, fetching its details if they weren't yet
+    fn open_contest_details_popup(&mut self, contested_name: &ContestedName) -> AppAction {
+        let fetching = contested_name.contestants.is_none() || contested_name.end_time.is_none();
+        self.contest_details_popup = Some(ContestDetailsPopup {
+            contested_name: contested_name.normalized_contested_name.clone(),
+            fetching,
+        });
+        if fetching {
+            self.contest_details_task(&contested_name.normalized_contested_name)
+        } else {
+            AppAction::None
+        }
+    }
+
+    /// "Verify" button of a past contest, re-querying its outcome from Platform, followed by
+    /// the result of the last check
+    fn render_contest_verification(
+        &mut self,
+        ui: &mut Ui,
+        contested_name: &ContestedName,
+    ) -> Option<AppAction> {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let name = &contested_name.normalized_contested_name;
+        let verification = self.contest_verifications.get(name);
+        let verifying = matches!(verification, Some(ContestVerification::Verifying(_)));
+        let clicked = ui
+            .add_enabled(!verifying, Button::new("Verify"))
+            .on_hover_text("Query the outcome of this contest from Platform again")
+            .clicked();
+        match verification {
+            Some(ContestVerification::Verifying(_)) => {
+                ui.add(egui::Spinner::new());
+            }
+            Some(ContestVerification::Matched) => {
+                ui.label(RichText::new("✔").color(DashColors::success_color(dark_mode)))
+                    .on_hover_text("The cached outcome matches Platform");
+            }
+            Some(ContestVerification::Stale { cached }) => {
+                ui.label(RichText::new("⚠").color(DashColors::warning_color(dark_mode)))
+                    .on_hover_text(format!(
+                        "The cached outcome ({}) was stale and has been updated",
+                        contest_outcome_text(cached)
+                    ));
+            }
+            Some(ContestVerification::Failed(e)) => {
+                ui.label(RichText::new("✖").color(DashColors::error_color(dark_mode)))
+                    .on_hover_text(e.as_str());
+            }
+            None => {}
+        }
+        if !clicked {
+            return None;
+        }
+        self.contest_verifications.insert(
+            name.clone(),
+            ContestVerification::Verifying(contested_name.state.clone()),
+        );
+        Some(AppAction::BackendTask(BackendTask::ContestedResourceTask(
+            ContestedResourceTask::VerifyDPNSContestOutcome(name.clone()),
+        )))
+    }
+
+    /// Fetches the contestants, votes and end time of a single contest
+    fn contest_details_task(&self, normalized_name: &str) -> AppAction {
+        AppAction::BackendTask(BackendTask::ContestedResourceTask(
+            ContestedResourceTask::QueryDPNSContestDetails(normalized_name.to_string()),
+        ))
+    }
+
+    /// Asks for a voting plan file and schedules the votes in it that pass validation. Every
+    /// entry's outcome is kept for the import report.
+    fn import_scheduled_votes(&mut self) -> AppAction {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Voting plan", &["json", "csv"])
+            .pick_file()
+        else {
+            return AppAction::None;
+        };
+
+        let is_json = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        let voter_ids: Vec<Identifier> = self
+            .voting_identities
+            .iter()
+            .map(|identity| identity.identity.id())
+            .collect();
+        let rows = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+            .and_then(|contents| {
+                let contested_names = self
+                    .app_context
+                    .all_contested_names()
+                    .map_err(|e| format!("Error loading contested names: {}", e))?;
+                parse_scheduled_votes_import(&contents, is_json, &voter_ids, &contested_names)
+            });
+        let rows = match rows {
+            Ok(rows) => rows,
+            Err(e) => {
+                self.message = Some((e, MessageType::Error, Utc::now()));
+                return AppAction::None;
+            }
+        };
+
+        let accepted: Vec<ScheduledDPNSVote> = rows
+            .iter()
+            .filter_map(|row| row.result.as_ref().ok().cloned())
+            .collect();
+        self.scheduled_votes_import_report = Some(rows);
+        if accepted.is_empty() {
+            return AppAction::None;
+        }
+        AppAction::BackendTask(BackendTask::ContestedResourceTask(
+            ContestedResourceTask::ScheduleDPNSVotes(accepted),
+        ))
+    }
+
+    /// Renders the accepted and rejected entries of the last imported voting plan
+    fn render_scheduled_votes_import_report(&mut self, ui: &mut Ui) {
+        let Some(rows) = self.scheduled_votes_import_report.take() else {
+            return;
+        };
+
+        let accepted = rows.iter().filter(|row| row.result.is_ok()).count();
+        let mut is_open = true;
+        let mut close = false;
+        egui::Window::new("Imported Votes")
+            .collapsible(false)
+            .resizable(true)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                close = dialog_key_action(ui.ctx()) != DialogKeyAction::None;
+                ui.label(format!(
+                    "{} accepted, {} rejected",
+                    accepted,
+                    rows.len() - accepted
+                ));
+                ui.add_space(5.0);
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        for row in &rows {
+                            match &row.result {
+                                Ok(vote) => ui.colored_label(
+                                    DashColors::SUCCESS,
+                                    format!(
+                                        "Line {}: scheduled \"{}\" for {}",
+                                        row.line,
+                                        vote.contested_name,
+                                        vote.voter_id.to_string(Encoding::Base58)
+                                    ),
+                                ),
+                                Err(e) => ui.colored_label(
+                                    DashColors::ERROR,
+                                    format!("Line {}: rejected, {}", row.line, e),
+                                ),
+                            };
+                        }
+                    });
+                ui.add_space(5.0);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+
+        if is_open && !close {
+            self.scheduled_votes_import_report = Some(rows);
+        }
+    }
+
+    /// Renders the popup with every contestant and vote count of a contest, while one is open
+    fn render_contest_details_popup(&mut self, ui: &mut Ui) -> AppAction {
+        let Some(mut popup) = self.contest_details_popup.take() else {
+            return AppAction::None;
+        };
+        let contest = self
+            .contested_names
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|contest| contest.normalized_contested_name == popup.contested_name)
+            .cloned();
+        let developer_mode = self.app_context.is_developer_mode();
+        let format_time = |timestamp| {
+            format_timestamp_millis(
+                timestamp,
+                TimestampStyle::AbsoluteAndRelative,
+                developer_mode,
+            )
+        };
+
+        let mut action = AppAction::None;
+        let mut is_open = true;
+        let mut close = false;
+        egui::Window::new(format!("Contest \"{}\"", popup.contested_name))
+            .collapsible(false)
+            .resizable(true)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                close = dialog_key_action(ui.ctx()) == DialogKeyAction::Cancel;
+                let Some(contest) = &contest else {
+                    ui.label("This contest is no longer active.");
+                    return;
+                };
+
+                // The contest starts when the first contestant registers the name
+                let start_time = contest
+                    .contestants
+                    .iter()
+                    .flatten()
+                    .filter_map(|contestant| contestant.created_at)
+                    .min();
+                let leading = match leading_choice(contest) {
+                    Some((ResourceVoteChoice::TowardsIdentity(id), votes)) => {
+                        let name = contest
+                            .contestants
+                            .iter()
+                            .flatten()
+                            .find(|contestant| contestant.id == id)
+                            .map(|contestant| contestant.name.clone())
+                            .unwrap_or_default();
+                        format!("{} ({} votes)", name, votes)
+                    }
+                    Some((ResourceVoteChoice::Lock, votes)) => format!("Lock ({} votes)", votes),
+                    Some((_, votes)) => format!("Abstain ({} votes)", votes),
+                    None => "No clear lead".to_string(),
+                };
+
+                egui::Grid::new("contest_details_popup_overview")
+                    .num_columns(2)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Started:");
+                        ui.label(start_time.map_or_else(|| "Unknown".to_string(), format_time));
+                        ui.end_row();
+                        ui.label("Ends:");
+                        ui.label(
+                            contest
+                                .end_time
+                                .map_or_else(|| "Fetching".to_string(), format_time),
+                        );
+                        ui.end_row();
+                        ui.label("Locked votes:");
+                        ui.label(contest.locked_votes.unwrap_or(0).to_string());
+                        ui.end_row();
+                        ui.label("Abstain votes:");
+                        ui.label(contest.abstain_votes.unwrap_or(0).to_string());
+                        ui.end_row();
+                        ui.label("Leading:");
+                        ui.label(RichText::new(leading).strong());
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+                match contest.contestants.as_ref().filter(|c| !c.is_empty()) {
+                    Some(contestants) => {
+                        egui::Grid::new("contest_details_popup_contestants")
+                            .num_columns(3)
+                            .striped(true)
+                            .spacing([20.0, 6.0])
+                            .show(ui, |ui| {
+                                ui.label(RichText::new("Name").strong());
+                                ui.label(RichText::new("Identity").strong());
+                                ui.label(RichText::new("Votes").strong());
+                                ui.end_row();
+
+                                for contestant in
+                                    contestants.iter().sorted_by(|a, b| b.votes.cmp(&a.votes))
+                                {
+                                    let id = contestant.id.to_string(Encoding::Base58);
+                                    ui.label(&contestant.name);
+                                    ui.horizontal(|ui| {
+                                        ui.label(RichText::new(&id).monospace());
+                                        let copy_id =
+                                            ui.make_persistent_id(("contest_details_copy", &id));
+                                        let copy_text = if recently_copied(ui.ctx(), copy_id) {
+                                            "✔"
+                                        } else {
+                                            "📋"
+                                        };
+                                        if ui
+                                            .small_button(copy_text)
+                                            .on_hover_text("Copy identity ID")
+                                            .clicked()
+                                        {
+                                            copy_with_confirmation(ui.ctx(), copy_id, id.clone());
+                                        }
+                                    });
+                                    ui.label(contestant.votes.to_string());
+                                    ui.end_row();
                                 }
                             });
+                    }
+                    None => {
+                        ui.label("No contestants fetched yet.");
+                    }
+                }
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!popup.fetching, egui::Button::new("Refresh"))
+                        .clicked()
+                    {
+                        popup.fetching = true;
+                        action = self.contest_details_task(&popup.contested_name);
+                    }
+                    if popup.fetching {
+                        ui.spinner();
+                    }
+                    if ui.button("Open Full View").clicked() {
+                        action = Self::open_contest_details(contest, &self.app_context);
+                        close = true;
+                    }
+                });
+            });
+
+        if is_open && !close {
+            self.contest_details_popup = Some(popup);
+        }
+        action
+    }
+
+    // ---------------------------
+    // Watchlist
+    // ---------------------------
+    fn is_watched(&self, normalized_name: &str) -> bool {
+        self.watchlist
+            .iter()
+            .any(|watched| watched.normalized_name == normalized_name)
+    }
+
+    /// Adds a name to the watchlist, or removes it if it is already watched.
+    ///
+    /// A name that is already contested when it is added counts as seen, so only contests
+    /// that start later are announced.
+    fn toggle_watched(&mut self, normalized_name: &str) {
+        let result = if self.is_watched(normalized_name) {
+            self.app_context
+                .db
+                .remove_name_from_watchlist(normalized_name, &self.app_context)
+        } else {
+            let contest_seen = self
+                .contested_names
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|c| c.normalized_contested_name == normalized_name);
+            self.app_context.db.add_name_to_watchlist(
+                normalized_name,
+                contest_seen,
+                &self.app_context,
+            )
+        };
+        self.reload_watchlist(result);
+    }
+
+    fn mark_watched_contests_seen(&mut self, normalized_names: &[String]) {
+        let result = self
+            .app_context
+            .db
+            .mark_watched_contests_seen(normalized_names, &self.app_context);
+        self.reload_watchlist(result);
+    }
+
+    /// Reloads the watchlist after `result` of a change to it, reporting any error.
+    fn reload_watchlist(&mut self, result: rusqlite::Result<()>) {
+        match result.and_then(|_| self.app_context.db.get_dpns_watchlist(&self.app_context)) {
+            Ok(watchlist) => self.watchlist = watchlist,
+            Err(e) => {
+                self.message = Some((
+                    format!("Error updating the watchlist: {}", e),
+                    MessageType::Error,
+                    Utc::now(),
+                ));
+            }
+        }
+    }
+
+    /// Star button that adds a name to the watchlist or removes it.
+    fn render_watch_toggle(&mut self, ui: &mut Ui, normalized_name: &str) {
+        let (text, hover_text) = if self.is_watched(normalized_name) {
+            ("★", "Remove from watchlist")
+        } else {
+            ("☆", "Add to watchlist")
+        };
+        if ui.small_button(text).on_hover_text(hover_text).clicked() {
+            self.toggle_watched(normalized_name);
+        }
+    }
+
+    /// Announces watched names that became contested since the user last looked, until the
+    /// contest is opened or the banner is dismissed.
+    fn render_newly_contested_banner(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let newly_contested = {
+            let guard = self.contested_names.lock().unwrap();
+            let names = newly_contested_watched_names(&self.watchlist, &guard);
+            guard
+                .iter()
+                .filter(|c| names.contains(&c.normalized_contested_name))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        if newly_contested.is_empty() {
+            return action;
+        }
+
+        let mut seen = Vec::new();
+        egui::Frame::new()
+            .stroke(egui::Stroke::new(1.0, DashColors::DASH_BLUE))
+            .corner_radius(egui::CornerRadius::same(4))
+            .inner_margin(egui::Margin::same(8))
+            .show(ui, |ui| {
+                ui.label(
+                    RichText::new("★ Watched names are now contested")
+                        .strong()
+                        .color(DashColors::DASH_BLUE),
+                );
+                ui.horizontal_wrapped(|ui| {
+                    for contested_name in &newly_contested {
+                        if ui
+                            .button(&contested_name.normalized_contested_name)
+                            .on_hover_text("Open the contest details")
+                            .clicked()
+                        {
+                            action = Self::open_contest_details(contested_name, &self.app_context);
+                            seen.push(contested_name.normalized_contested_name.clone());
+                        }
+                    }
+                    if ui.button("Dismiss").clicked() {
+                        seen = newly_contested
+                            .iter()
+                            .map(|c| c.normalized_contested_name.clone())
+                            .collect();
+                    }
+                });
+            });
+        ui.add_space(10.0);
 
-                            // LOCK button
-                            row.col(|ui| {
-                                let label_text = format!("{}", locked_votes);
-                                let text_widget = if is_locked_votes_bold {
-                                    RichText::new(label_text).strong()
-                                } else {
-                                    RichText::new(label_text)
-                                };
+        if !seen.is_empty() {
+            self.mark_watched_contests_seen(&seen);
+        }
+        action
+    }
 
-                                // See if this (LOCK) is selected
-                                let is_selected = self.selected_votes.iter().any(|sv| {
-                                    sv.contested_name == contested_name.normalized_contested_name
-                                        && sv.vote_choice == ResourceVoteChoice::Lock
-                                });
+    /// Show the watchlist with the current state of each watched name
+    fn render_table_watchlist(&mut self, ui: &mut Ui) -> AppAction {
+        let developer_mode = self.app_context.is_developer_mode();
+        let mut action = self.render_newly_contested_banner(ui);
 
-                                let button = if is_selected {
-                                    Button::new(text_widget).fill(Color32::from_rgb(0, 150, 255))
-                                } else {
-                                    Button::new(text_widget)
-                                };
-                                let resp = ui.add(button);
-                                if resp.clicked() {
-                                    // Is there already a selection for this contested name?
-                                    if let Some(existing_index) =
-                                        self.selected_votes.iter().position(|sv| {
-                                            sv.contested_name
-                                                == contested_name.normalized_contested_name
-                                        })
-                                    {
-                                        // If the user clicked the same choice, that toggles it off (unselect).
-                                        if self.selected_votes[existing_index].vote_choice
-                                            == ResourceVoteChoice::Lock
-                                        {
-                                            // Remove it entirely -> no selection
-                                            self.selected_votes.remove(existing_index);
-                                        } else {
-                                            // Otherwise replace the old choice with Lock
-                                            self.selected_votes[existing_index].vote_choice =
-                                                ResourceVoteChoice::Lock;
-                                        }
-                                    } else {
-                                        // No existing selection for this name, so add this new Lock
-                                        self.selected_votes.push(SelectedVote {
-                                            contested_name: contested_name
-                                                .normalized_contested_name
-                                                .clone(),
-                                            vote_choice: ResourceVoteChoice::Lock,
-                                            end_time: contested_name.end_time,
-                                        });
-                                    }
-                                }
-                            });
+        let mut add_clicked = false;
+        ui.horizontal(|ui| {
+            let dark_mode = ui.ctx().style().visuals.dark_mode;
+            ui.label(RichText::new("Watch a name:").color(DashColors::text_primary(dark_mode)));
+            let response = ui.text_edit_singleline(&mut self.watchlist_input);
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            add_clicked = ui.button("Add").clicked() || submitted;
+        });
+        if add_clicked {
+            if let Some(normalized_name) = normalize_watchlist_input(&self.watchlist_input) {
+                if !self.is_watched(&normalized_name) {
+                    self.toggle_watched(&normalized_name);
+                }
+                self.watchlist_input.clear();
+            }
+        }
 
-                            // ABSTAIN button
-                            row.col(|ui| {
-                                let abstain_votes = contested_name.abstain_votes.unwrap_or(0);
-                                let label_text = format!("{}", abstain_votes);
+        if self.watchlist.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.label(
+                    egui::RichText::new("No watched names.")
+                        .heading()
+                        .strong()
+                        .color(Color32::GRAY),
+                );
+                ui.add_space(10.0);
+                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                ui.label(
+                    RichText::new(
+                        "Star a contested name, or add any name above to follow it before it is contested.",
+                    )
+                    .color(DashColors::text_primary(dark_mode)),
+                );
+            });
+            return action;
+        }
 
-                                let is_selected = self.selected_votes.iter().any(|sv| {
-                                    sv.contested_name == contested_name.normalized_contested_name
-                                        && sv.vote_choice == ResourceVoteChoice::Abstain
-                                });
+        let watchlist = self.watchlist.clone();
+        let contested_names = self.contested_names.lock().unwrap().clone();
 
-                                let button = if is_selected {
-                                    Button::new(label_text).fill(Color32::from_rgb(0, 150, 255))
-                                } else {
-                                    Button::new(label_text)
-                                };
-                                let resp = ui.add(button);
-                                if resp.clicked() {
-                                    // Is there already a selection for this contested name?
-                                    if let Some(existing_index) =
-                                        self.selected_votes.iter().position(|sv| {
-                                            sv.contested_name
-                                                == contested_name.normalized_contested_name
-                                        })
-                                    {
-                                        // If the user clicked the same choice, that toggles it off (unselect).
-                                        if self.selected_votes[existing_index].vote_choice
-                                            == ResourceVoteChoice::Abstain
-                                        {
-                                            // Remove it entirely -> no selection
-                                            self.selected_votes.remove(existing_index);
-                                        } else {
-                                            // Otherwise replace the old choice with Abstain
-                                            self.selected_votes[existing_index].vote_choice =
-                                                ResourceVoteChoice::Abstain;
-                                        }
-                                    } else {
-                                        // No existing selection for this name, so add this new Abstain
-                                        self.selected_votes.push(SelectedVote {
-                                            contested_name: contested_name
-                                                .normalized_contested_name
-                                                .clone(),
-                                            vote_choice: ResourceVoteChoice::Abstain,
-                                            end_time: contested_name.end_time,
-                                        });
-                                    }
-                                }
+        egui::ScrollArea::both().show(ui, |ui| {
+            let ctx = ui.ctx().clone();
+            let (id_salt, widths) = self.table_column_layout(&[40.0, 200.0, 200.0, 200.0]);
+            TableBuilder::new(ui)
+                .id_salt(id_salt)
+                .striped(false)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::initial(widths[0]).resizable(false)) // Watch toggle
+                .column(Column::initial(widths[1]).resizable(true)) // Name
+                .column(Column::initial(widths[2]).resizable(true)) // State
+                .column(Column::initial(widths[3]).resizable(true)) // Ending Time
+                .column(Column::remainder()) // Standings
+                .header(30.0, |mut header| {
+                    header.col(|_ui| {});
+                    for title in ["Name", "State", "Ending Time", "Standings"] {
+                        header.col(|ui| {
+                            let dark_mode = ui.ctx().style().visuals.dark_mode;
+                            let response = ui.heading(
+                                RichText::new(title).color(DashColors::text_primary(dark_mode)),
+                            );
+                            self.column_widths_menu(&response);
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    self.track_column_widths(&ctx, body.widths());
+                    for watched in &watchlist {
+                        let contest = contested_names
+                            .iter()
+                            .find(|c| c.normalized_contested_name == watched.normalized_name);
+                        body.row(25.0, |mut row| {
+                            // Watch toggle
+                            row.col(|ui| {
+                                self.render_watch_toggle(ui, &watched.normalized_name);
                             });
-
-                            // Ending Time
+                            // Name
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                if let Some(ending_time) = contested_name.end_time {
-                                    if let LocalResult::Single(dt) =
-                                        Utc.timestamp_millis_opt(ending_time as i64)
+                                let text = RichText::new(&watched.normalized_name)
+                                    .color(DashColors::text_primary(dark_mode));
+                                if let Some(contested_name) = contest {
+                                    if ui
+                                        .add(Label::new(text).sense(egui::Sense::click()))
+                                        .on_hover_text("Click to open the contest details")
+                                        .clicked()
                                     {
-                                        let iso_date = dt.format("%Y-%m-%d %H:%M:%S");
-                                        let relative_time = HumanTime::from(dt).to_string();
-                                        let text = format!("{} ({})", iso_date, relative_time);
-                                        ui.label(
-                                            RichText::new(text)
-                                                .color(DashColors::text_primary(dark_mode)),
-                                        );
-                                    } else {
-                                        ui.label(
-                                            RichText::new("Invalid timestamp")
-                                                .color(DashColors::text_primary(dark_mode)),
+                                        action = Self::open_contest_details(
+                                            contested_name,
+                                            &self.app_context,
                                         );
                                     }
                                 } else {
+                                    ui.label(text);
+                                }
+                                if contest.is_some() && !watched.contest_seen {
                                     ui.label(
-                                        RichText::new("Fetching")
-                                            .color(DashColors::text_primary(dark_mode)),
+                                        RichText::new("New contest")
+                                            .strong()
+                                            .color(DashColors::DASH_BLUE),
                                     );
                                 }
                             });
-
-                            // Last Updated
+                            // State
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                if let Some(last_updated) = contested_name.last_updated {
-                                    if let LocalResult::Single(dt) =
-                                        Utc.timestamp_opt(last_updated as i64, 0)
-                                    {
-                                        let rel_time = HumanTime::from(dt).to_string();
-                                        if rel_time.contains("seconds") {
-                                            ui.label(
-                                                RichText::new("now")
-                                                    .color(DashColors::text_primary(dark_mode)),
-                                            );
-                                        } else {
-                                            ui.label(
-                                                RichText::new(rel_time)
-                                                    .color(DashColors::text_primary(dark_mode)),
-                                            );
-                                        }
-                                    } else {
+                                let text_color = DashColors::text_primary(dark_mode);
+                                match contest.map(|c| &c.state) {
+                                    None => {
                                         ui.label(
-                                            RichText::new("Invalid timestamp")
-                                                .color(DashColors::text_primary(dark_mode)),
+                                            RichText::new("No contest found").color(Color32::GRAY),
                                         );
                                     }
-                                } else {
+                                    Some(ContestState::Unknown) => {
+                                        ui.label(RichText::new("Fetching").color(text_color));
+                                    }
+                                    Some(ContestState::Joinable | ContestState::Ongoing) => {
+                                        ui.label(RichText::new("Active").color(text_color));
+                                    }
+                                    Some(ContestState::WonBy(identifier)) => {
+                                        ui.label(RichText::new("Awarded to").color(text_color));
+                                        render_identifier_cell(ui, identifier, None);
+                                    }
+                                    Some(ContestState::Locked) => {
+                                        ui.label(RichText::new("Locked").color(text_color));
+                                    }
+                                }
+                            });
+                            // Ending Time
+                            row.col(|ui| {
+                                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                                if let Some(end_time) = contest.and_then(|c| c.end_time) {
+                                    let text = format_timestamp_millis(
+                                        end_time,
+                                        TimestampStyle::AbsoluteAndRelative,
+                                        developer_mode,
+                                    );
                                     ui.label(
-                                        RichText::new("Fetching")
+                                        RichText::new(text)
                                             .color(DashColors::text_primary(dark_mode)),
                                     );
                                 }
                             });
-
-                            // Contestants
+                            // Standings
                             row.col(|ui| {
-                                self.show_contestants_for_contested_name(
-                                    ui,
-                                    contested_name,
-                                    is_locked_votes_bold,
-                                    max_contestant_votes,
-                                );
+                                if let Some(contested_name) = contest {
+                                    let dark_mode = ui.ctx().style().visuals.dark_mode;
+                                    ui.label(
+                                        RichText::new(contest_standings_summary(contested_name))
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
+                                }
                             });
                         });
                     }
                 });
         });
+
+        action
     }
 
     /// Show a Past Contests table
-    fn render_table_past_contests(&mut self, ui: &mut Ui) {
+    fn render_table_past_contests(&mut self, ui: &mut Ui) -> AppAction {
+        let developer_mode = self.app_context.is_developer_mode();
+        let mut action = AppAction::None;
         ui.horizontal(|ui| {
             let dark_mode = ui.ctx().style().visuals.dark_mode;
             ui.label(RichText::new("Filter by name:").color(DashColors::text_primary(dark_mode)));
             ui.text_edit_singleline(&mut self.past_filter_term);
         });
 
-        let contested_names = {
-            let guard = self.contested_names.lock().unwrap();
-            let mut cn = guard.clone();
-            cn.retain(|c| c.awarded_to.is_some() || c.state == ContestState::Locked);
-            // 1) Filter by `past_filter_term`
-            if !self.past_filter_term.is_empty() {
-                let mut filter_lc = self.past_filter_term.to_lowercase();
-                // Convert o and O to 0 and l to 1 in filter_lc
-                filter_lc = filter_lc
-                    .chars()
-                    .map(|c| match c {
-                        'o' | 'O' => '0',
-                        'l' => '1',
-                        _ => c,
-                    })
-                    .collect();
-
-                cn.retain(|c| {
-                    c.normalized_contested_name
-                        .to_lowercase()
-                        .contains(&filter_lc)
-                });
-            }
-            self.sort_contested_names(&mut cn);
-            cn
-        };
+        let contested_names = self.visible_contested_names();
 
         // Allocate space for refreshing indicator
         // Space allocation for UI elements is handled by the layout system
 
         egui::ScrollArea::both().show(ui, |ui| {
+            let ctx = ui.ctx().clone();
+            let (id_salt, widths) =
+                self.table_column_layout(&[200.0, 200.0, 200.0, 200.0, 150.0, 100.0]);
             TableBuilder::new(ui)
+                .id_salt(id_salt)
                 .striped(false)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                .column(Column::initial(200.0).resizable(true)) // Name
-                .column(Column::initial(200.0).resizable(true)) // Ended Time
-                .column(Column::initial(200.0).resizable(true)) // Last Updated
-                .column(Column::initial(200.0).resizable(true)) // Awarded To
+                .column(Column::initial(widths[0]).resizable(true)) // Name
+                .column(Column::initial(widths[1]).resizable(true)) // Ended Time
+                .column(Column::initial(widths[2]).resizable(true)) // Last Updated
+                .column(Column::initial(widths[3]).resizable(true)) // Awarded To
+                .column(Column::initial(widths[4]).resizable(true)) // Lead
+                .column(Column::initial(widths[5]).resizable(true)) // Verify
                 .header(30.0, |mut header| {
                     header.col(|ui| {
-                        if ui.button("Contested Name").clicked() {
-                            self.toggle_sort(SortColumn::ContestedName);
-                        }
+                        self.render_sort_header(ui, "Contested Name", SortColumn::ContestedName);
                     });
                     header.col(|ui| {
-                        if ui.button("Ended Time").clicked() {
-                            self.toggle_sort(SortColumn::EndingTime);
-                        }
+                        self.render_sort_header(ui, "Ended Time", SortColumn::EndingTime);
                     });
                     header.col(|ui| {
-                        if ui.button("Last Updated").clicked() {
-                            self.toggle_sort(SortColumn::LastUpdated);
-                        }
+                        self.render_sort_header(ui, "Last Updated", SortColumn::LastUpdated);
                     });
                     header.col(|ui| {
-                        if ui.button("Awarded To").clicked() {
-                            self.toggle_sort(SortColumn::AwardedTo);
-                        }
+                        self.render_sort_header(ui, "Awarded To", SortColumn::AwardedTo);
+                    });
+                    header.col(|ui| {
+                        let dark_mode = ui.ctx().style().visuals.dark_mode;
+                        ui.heading(RichText::new("Lead").color(DashColors::text_primary(dark_mode)))
+                            .on_hover_text(
+                                "Whether the winner led on every refresh of the contests or overtook late",
+                            );
+                    });
+                    header.col(|ui| {
+                        let dark_mode = ui.ctx().style().visuals.dark_mode;
+                        let response = ui.heading(
+                            RichText::new("Verify").color(DashColors::text_primary(dark_mode)),
+                        );
+                        self.column_widths_menu(&response);
                     });
                 })
                 .body(|mut body| {
+                    self.track_column_widths(&ctx, body.widths());
                     for contested_name in &contested_names {
                         body.row(25.0, |mut row| {
                             // Name
                             row.col(|ui| {
-                                let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                ui.label(
-                                    RichText::new(&contested_name.normalized_contested_name)
-                                        .color(DashColors::text_primary(dark_mode)),
+                                self.render_watch_toggle(
+                                    ui,
+                                    &contested_name.normalized_contested_name,
                                 );
+                                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                                if ui
+                                    .add(
+                                        Label::new(
+                                            RichText::new(
+                                                &contested_name.normalized_contested_name,
+                                            )
+                                            .color(DashColors::text_primary(dark_mode)),
+                                        )
+                                        .sense(egui::Sense::click()),
+                                    )
+                                    .on_hover_text("Click to open the contest details")
+                                    .clicked()
+                                {
+                                    action = Self::open_contest_details(
+                                        contested_name,
+                                        &self.app_context,
+                                    );
+                                }
                             });
                             // Ended Time
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
                                 if let Some(ended_time) = contested_name.end_time {
-                                    if let LocalResult::Single(dt) =
-                                        Utc.timestamp_millis_opt(ended_time as i64)
-                                    {
-                                        let iso = dt.format("%Y-%m-%d %H:%M:%S").to_string();
-                                        let relative = HumanTime::from(dt).to_string();
-                                        ui.label(
-                                            RichText::new(format!("{} ({})", iso, relative))
-                                                .color(DashColors::text_primary(dark_mode)),
-                                        );
-                                    } else {
-                                        ui.label(
-                                            RichText::new("Invalid timestamp")
-                                                .color(DashColors::text_primary(dark_mode)),
-                                        );
-                                    }
+                                    let text = format_timestamp_millis(
+                                        ended_time,
+                                        TimestampStyle::AbsoluteAndRelative,
+                                        developer_mode,
+                                    );
+                                    ui.label(
+                                        RichText::new(text)
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
                                 } else {
                                     ui.label(
                                         RichText::new("Fetching")
@@ -775,27 +2638,15 @@ impl DPNSScreen {
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
                                 if let Some(last_updated) = contested_name.last_updated {
-                                    if let LocalResult::Single(dt) =
-                                        Utc.timestamp_opt(last_updated as i64, 0)
-                                    {
-                                        let rel = HumanTime::from(dt).to_string();
-                                        if rel.contains("seconds") {
-                                            ui.label(
-                                                RichText::new("now")
-                                                    .color(DashColors::text_primary(dark_mode)),
-                                            );
-                                        } else {
-                                            ui.label(
-                                                RichText::new(rel)
-                                                    .color(DashColors::text_primary(dark_mode)),
-                                            );
-                                        }
-                                    } else {
-                                        ui.label(
-                                            RichText::new("Invalid timestamp")
-                                                .color(DashColors::text_primary(dark_mode)),
-                                        );
-                                    }
+                                    let text = format_timestamp_millis(
+                                        last_updated.saturating_mul(1000),
+                                        TimestampStyle::Relative,
+                                        developer_mode,
+                                    );
+                                    ui.label(
+                                        RichText::new(text)
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
                                 } else {
                                     ui.label(
                                         RichText::new("Fetching")
@@ -820,13 +2671,7 @@ impl DPNSScreen {
                                         );
                                     }
                                     ContestState::WonBy(identifier) => {
-                                        ui.add(
-                                            egui::Label::new(
-                                                identifier.to_string(Encoding::Base58),
-                                            )
-                                            .sense(egui::Sense::hover())
-                                            .truncate(),
-                                        );
+                                        render_identifier_cell(ui, &identifier, None);
                                     }
                                     ContestState::Locked => {
                                         ui.label(
@@ -836,29 +2681,149 @@ impl DPNSScreen {
                                     }
                                 }
                             });
+                            // Lead
+                            row.col(|ui| {
+                                self.render_lead_summary(ui, contested_name, developer_mode);
+                            });
+                            // Verify
+                            row.col(|ui| {
+                                if let Some(verify_action) =
+                                    self.render_contest_verification(ui, contested_name)
+                                {
+                                    action = verify_action;
+                                }
+                            });
                         });
                     }
                 });
         });
+
+        action
+    }
+
+    /// Whether the winner of a past contest led throughout or overtook late, with the recorded
+    /// lead changes on hover
+    fn render_lead_summary(
+        &self,
+        ui: &mut Ui,
+        contested_name: &ContestedName,
+        developer_mode: bool,
+    ) {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let ContestState::WonBy(winner) = contested_name.state else {
+            ui.label(RichText::new("-").color(DashColors::text_secondary(dark_mode)));
+            return;
+        };
+        let history = self
+            .contest_lead_history
+            .get(&contested_name.normalized_contested_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let text = match LeadSummary::from_history(history, &winner) {
+            LeadSummary::NoHistory => {
+                ui.label(RichText::new("No history").color(DashColors::text_secondary(dark_mode)))
+                    .on_hover_text("No leader was recorded while the contest was running");
+                return;
+            }
+            LeadSummary::LedThroughout => "Led throughout".to_string(),
+            LeadSummary::OvertookLate {
+                took_lead_at: Some(took_lead_at),
+            } => format!(
+                "Overtook {}",
+                format_timestamp_millis(took_lead_at, TimestampStyle::Relative, developer_mode)
+            ),
+            LeadSummary::OvertookLate { took_lead_at: None } => {
+                "Overtook after the last refresh".to_string()
+            }
+        };
+        let changes: Vec<String> = history
+            .iter()
+            .map(|change| {
+                let leader = match &change.leader {
+                    Some(leader) if *leader == winner => {
+                        format!("{} (winner)", leader.to_string(Encoding::Base58))
+                    }
+                    Some(leader) => leader.to_string(Encoding::Base58),
+                    None => "Nobody".to_string(),
+                };
+                format!(
+                    "{}: {}",
+                    format_timestamp_millis(
+                        change.recorded_at,
+                        TimestampStyle::Absolute,
+                        developer_mode
+                    ),
+                    leader
+                )
+            })
+            .collect();
+        ui.label(RichText::new(text).color(DashColors::text_primary(dark_mode)))
+            .on_hover_text(format!("Lead changes:\n{}", changes.join("\n")));
     }
 
     /// Show the Owned DPNS names table
     fn render_table_local_dpns_names(&mut self, ui: &mut Ui) {
+        let developer_mode = self.app_context.is_developer_mode();
         ui.horizontal(|ui| {
             let dark_mode = ui.ctx().style().visuals.dark_mode;
             ui.label(RichText::new("Filter by name:").color(DashColors::text_primary(dark_mode)));
             ui.text_edit_singleline(&mut self.owned_filter_term);
         });
+        ui.horizontal(|ui| {
+            let today = Utc::now().date_naive();
+            for (label, date, id_salt) in [
+                (
+                    "Acquired after",
+                    &mut self.owned_acquired_after,
+                    "owned_acquired_after",
+                ),
+                (
+                    "Acquired before",
+                    &mut self.owned_acquired_before,
+                    "owned_acquired_before",
+                ),
+            ] {
+                let mut enabled = date.is_some();
+                if ui
+                    .checkbox(&mut enabled, label)
+                    .on_hover_text("The day is included, in UTC like the acquisition times")
+                    .changed()
+                {
+                    *date = enabled.then_some(today);
+                }
+                if let Some(date) = date {
+                    ui.add(DatePickerButton::new(date).id_salt(id_salt));
+                }
+                ui.add_space(10.0);
+            }
+        });
 
-        let mut filtered_names = {
+        let (mut filtered_names, has_names_outside_range) = {
             let guard = self.local_dpns_names.lock().unwrap();
             let mut name_infos = guard.clone();
             if !self.owned_filter_term.is_empty() {
                 let filter_lc = self.owned_filter_term.to_lowercase();
                 name_infos.retain(|c| c.1.name.to_lowercase().contains(&filter_lc));
             }
-            name_infos
+            let matching_name_count = name_infos.len();
+            name_infos.retain(|c| {
+                acquired_within(
+                    c.1.acquired_at,
+                    self.owned_acquired_after,
+                    self.owned_acquired_before,
+                )
+            });
+            let has_names_outside_range = name_infos.len() < matching_name_count;
+            (name_infos, has_names_outside_range)
         };
+        if filtered_names.is_empty() && has_names_outside_range {
+            ui.add_space(10.0);
+            ui.colored_label(
+                DashColors::warning_color(ui.ctx().style().visuals.dark_mode),
+                "No owned names were acquired in the selected date range.",
+            );
+            return;
+        }
         // Sort
         filtered_names.sort_by(|a, b| match self.sort_column {
             SortColumn::ContestedName => {
@@ -891,31 +2856,41 @@ impl DPNSScreen {
         // Space allocation for UI elements is handled by the layout system
 
         egui::ScrollArea::both().show(ui, |ui| {
+            let ctx = ui.ctx().clone();
+            let (id_salt, widths) = self.table_column_layout(&[200.0, 400.0, 300.0]);
             TableBuilder::new(ui)
+                .id_salt(id_salt)
                 .striped(false)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                .column(Column::initial(200.0).resizable(true)) // DPNS Name
-                .column(Column::initial(400.0).resizable(true)) // Owner ID
-                .column(Column::initial(300.0).resizable(true)) // Acquired At
+                .column(Column::initial(widths[0]).resizable(true)) // DPNS Name
+                .column(Column::initial(widths[1]).resizable(true)) // Owner ID
+                .column(Column::initial(widths[2]).resizable(true)) // Acquired At
                 .header(30.0, |mut header| {
                     header.col(|ui| {
-                        if ui.button("Name").clicked() {
+                        let response = ui.button("Name");
+                        self.column_widths_menu(&response);
+                        if response.clicked() {
                             self.toggle_sort(SortColumn::ContestedName);
                         }
                     });
                     header.col(|ui| {
-                        if ui.button("Owner ID").clicked() {
+                        let response = ui.button("Owner ID");
+                        self.column_widths_menu(&response);
+                        if response.clicked() {
                             self.toggle_sort(SortColumn::AwardedTo);
                         }
                     });
                     header.col(|ui| {
-                        if ui.button("Acquired At").clicked() {
+                        let response = ui.button("Acquired At");
+                        self.column_widths_menu(&response);
+                        if response.clicked() {
                             self.toggle_sort(SortColumn::EndingTime);
                         }
                     });
                 })
                 .body(|mut body| {
+                    self.track_column_widths(&ctx, body.widths());
                     for (identifier, dpns_info) in filtered_names {
                         body.row(25.0, |mut row| {
                             row.col(|ui| {
@@ -927,17 +2902,17 @@ impl DPNSScreen {
                             });
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                ui.label(
-                                    RichText::new(identifier.to_string(Encoding::Base58))
-                                        .color(DashColors::text_primary(dark_mode)),
+                                render_identifier_cell(
+                                    ui,
+                                    &identifier,
+                                    Some(DashColors::text_primary(dark_mode)),
                                 );
                             });
-                            let dt = DateTime::from_timestamp(
-                                dpns_info.acquired_at as i64 / 1000,
-                                ((dpns_info.acquired_at % 1000) * 1_000_000) as u32,
-                            )
-                            .map(|dt| dt.to_string())
-                            .unwrap_or_else(|| "Invalid timestamp".to_string());
+                            let dt = format_timestamp_millis(
+                                dpns_info.acquired_at,
+                                TimestampStyle::Absolute,
+                                developer_mode,
+                            );
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
                                 ui.label(
@@ -950,8 +2925,63 @@ impl DPNSScreen {
         });
     }
 
+    /// Marks every pending scheduled vote in progress and casts them all at once. Votes whose
+    /// voter identity is no longer loaded are left alone and listed in a warning.
+    fn cast_all_pending_scheduled_votes(&mut self) -> AppAction {
+        let voters = match self.app_context.load_local_voting_identities() {
+            Ok(voters) => voters,
+            Err(e) => {
+                self.message = Some((
+                    format!("Error loading voting identities: {}", e),
+                    MessageType::Error,
+                    Utc::now(),
+                ));
+                return AppAction::None;
+            }
+        };
+
+        let mut votes = Vec::new();
+        let mut skipped = Vec::new();
+        if let Ok(mut guard) = self.scheduled_votes.lock() {
+            for (vote, status) in guard.iter_mut().filter(|(_, status)| status.is_pending()) {
+                match voters.iter().find(|v| v.identity.id() == vote.voter_id) {
+                    Some(voter) => {
+                        *status = ScheduledVoteCastingStatus::InProgress;
+                        votes.push((vote.clone(), voter.clone()));
+                    }
+                    None => skipped.push(format!(
+                        "{} (voter {})",
+                        vote.contested_name,
+                        vote.voter_id.to_string(Encoding::Hex)
+                    )),
+                }
+            }
+        }
+
+        if !skipped.is_empty() {
+            self.message = Some((
+                format!(
+                    "Skipped {} scheduled votes whose voter identity is no longer loaded: {}",
+                    skipped.len(),
+                    skipped.join(", ")
+                ),
+                MessageType::Error,
+                Utc::now(),
+            ));
+        }
+        if votes.is_empty() {
+            return AppAction::None;
+        }
+
+        self.scheduled_vote_cast_in_progress = true;
+        AppAction::BackendTask(BackendTask::ContestedResourceTask(
+            ContestedResourceTask::CastScheduledVotes(votes),
+        ))
+    }
+
     /// Show the Scheduled Votes table
     fn render_table_scheduled_votes(&mut self, ui: &mut Ui) -> AppAction {
+        let developer_mode = self.app_context.is_developer_mode();
         let mut action = AppAction::None;
         let mut sorted_votes = {
             let guard = self.scheduled_votes.lock().unwrap();
@@ -968,53 +2998,66 @@ impl DPNSScreen {
         });
 
         egui::ScrollArea::both().show(ui, |ui| {
+            let ctx = ui.ctx().clone();
+            let (id_salt, widths) =
+                self.table_column_layout(&[100.0, 200.0, 200.0, 200.0, 100.0, 100.0]);
             TableBuilder::new(ui)
+                .id_salt(id_salt)
                 .striped(false)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                .column(Column::initial(100.0).resizable(true)) // ContestedName
-                .column(Column::initial(200.0).resizable(true)) // Voter
-                .column(Column::initial(200.0).resizable(true)) // Choice
-                .column(Column::initial(200.0).resizable(true)) // Time
-                .column(Column::initial(100.0).resizable(true)) // Status
-                .column(Column::initial(100.0).resizable(true)) // Actions
+                .column(Column::initial(widths[0]).resizable(true)) // ContestedName
+                .column(Column::initial(widths[1]).resizable(true)) // Voter
+                .column(Column::initial(widths[2]).resizable(true)) // Choice
+                .column(Column::initial(widths[3]).resizable(true)) // Time
+                .column(Column::initial(widths[4]).resizable(true)) // Status
+                .column(Column::initial(widths[5]).resizable(true)) // Actions
                 .header(30.0, |mut header| {
                     header.col(|ui| {
-                        if ui.button("Contested Name").clicked() {
+                        let response = ui.button("Contested Name");
+                        self.column_widths_menu(&response);
+                        if response.clicked() {
                             self.toggle_sort(SortColumn::ContestedName);
                         }
                     });
                     header.col(|ui| {
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
-                        ui.heading(
+                        let response = ui.heading(
                             RichText::new("Voter").color(DashColors::text_primary(dark_mode)),
                         );
+                        self.column_widths_menu(&response);
                     });
                     header.col(|ui| {
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
-                        ui.heading(
+                        let response = ui.heading(
                             RichText::new("Vote Choice").color(DashColors::text_primary(dark_mode)),
                         );
+                        self.column_widths_menu(&response);
                     });
                     header.col(|ui| {
-                        if ui.button("Scheduled Time").clicked() {
+                        let response = ui.button("Scheduled Time");
+                        self.column_widths_menu(&response);
+                        if response.clicked() {
                             self.toggle_sort(SortColumn::EndingTime);
                         }
                     });
                     header.col(|ui| {
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
-                        ui.heading(
+                        let response = ui.heading(
                             RichText::new("Status").color(DashColors::text_primary(dark_mode)),
                         );
+                        self.column_widths_menu(&response);
                     });
                     header.col(|ui| {
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
-                        ui.heading(
+                        let response = ui.heading(
                             RichText::new("Actions").color(DashColors::text_primary(dark_mode)),
                         );
+                        self.column_widths_menu(&response);
                     });
                 })
                 .body(|mut body| {
+                    self.track_column_widths(&ctx, body.widths());
                     for vote in sorted_votes.iter_mut() {
                         body.row(25.0, |mut row| {
                             // Contested name
@@ -1040,27 +3083,14 @@ impl DPNSScreen {
                             // Time
                             row.col(|ui| {
                                 let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                if let LocalResult::Single(dt) =
-                                    Utc.timestamp_millis_opt(vote.0.unix_timestamp as i64)
-                                {
-                                    let iso = dt.format("%Y-%m-%d %H:%M:%S").to_string();
-                                    let rel_time = HumanTime::from(dt).to_string();
-                                    let relative = if rel_time.contains("seconds") {
-                                        "now".to_string()
-                                    } else {
-                                        rel_time
-                                    };
-                                    let text = format!("{} ({})", iso, relative);
-                                    ui.label(
-                                        RichText::new(text)
-                                            .color(DashColors::text_primary(dark_mode)),
-                                    );
-                                } else {
-                                    ui.label(
-                                        RichText::new("Invalid timestamp")
-                                            .color(DashColors::text_primary(dark_mode)),
-                                    );
-                                }
+                                let text = format_timestamp_millis(
+                                    vote.0.unix_timestamp,
+                                    TimestampStyle::AbsoluteAndRelative,
+                                    developer_mode,
+                                );
+                                ui.label(
+                                    RichText::new(text).color(DashColors::text_primary(dark_mode)),
+                                );
                             });
                             // Status
                             row.col(|ui| {
@@ -1084,10 +3114,24 @@ impl DPNSScreen {
                                     ScheduledVoteCastingStatus::Completed => {
                                         ui.colored_label(Color32::DARK_GREEN, "Casted");
                                     }
+                                    ScheduledVoteCastingStatus::ContestEnded => {
+                                        ui.colored_label(DashColors::WARNING, "Contest Ended")
+                                            .on_hover_text(
+                                                "The contest ended before this vote was cast, \
+                                                 so it will not be cast",
+                                            );
+                                    }
                                 }
                             });
                             // Actions
                             row.col(|ui| {
+                                if ui
+                                    .add_enabled(vote.1.is_pending(), Button::new("Edit Time"))
+                                    .clicked()
+                                {
+                                    self.scheduled_vote_time_edit =
+                                        Some(ScheduledVoteTimeEdit::new(&vote.0));
+                                }
                                 if ui.button("Remove").clicked() {
                                     action =
                                         AppAction::BackendTask(BackendTask::ContestedResourceTask(
@@ -1099,12 +3143,8 @@ impl DPNSScreen {
                                 }
                                 // If the user wants to do "Cast Now" from here, they can
                                 // if NotStarted or Failed. If in progress or done, disabled.
-                                let cast_button_enabled = matches!(
-                                    vote.1,
-                                    ScheduledVoteCastingStatus::NotStarted
-                                        | ScheduledVoteCastingStatus::Failed
-                                ) && !self
-                                    .scheduled_vote_cast_in_progress;
+                                let cast_button_enabled =
+                                    vote.1.is_pending() && !self.scheduled_vote_cast_in_progress;
 
                                 let cast_button = if cast_button_enabled {
                                     Button::new("Cast Now")
@@ -1112,6 +3152,34 @@ impl DPNSScreen {
                                     Button::new("Cast Now").sense(egui::Sense::hover())
                                 };
 
+                                if vote.1 == ScheduledVoteCastingStatus::InProgress
+                                    && ui
+                                        .button("Cancel")
+                                        .on_hover_text(
+                                            "Stop waiting for this vote to be cast. A vote that \
+                                             already reached the network may still count.",
+                                        )
+                                        .clicked()
+                                {
+                                    self.scheduled_vote_cast_in_progress = false;
+                                    vote.1 = ScheduledVoteCastingStatus::NotStarted;
+                                    if let Ok(mut sched_guard) = self.scheduled_votes.lock() {
+                                        if let Some(t) = sched_guard.iter_mut().find(|(sv, _)| {
+                                            sv.voter_id == vote.0.voter_id
+                                                && sv.contested_name == vote.0.contested_name
+                                        }) {
+                                            t.1 = ScheduledVoteCastingStatus::NotStarted;
+                                        }
+                                    }
+                                    action =
+                                        AppAction::BackendTask(BackendTask::ContestedResourceTask(
+                                            ContestedResourceTask::CancelScheduledVoteCast(
+                                                vote.0.voter_id,
+                                                vote.0.contested_name.clone(),
+                                            ),
+                                        ));
+                                }
+
                                 if ui.add(cast_button).clicked() && cast_button_enabled {
                                     self.scheduled_vote_cast_in_progress = true;
                                     vote.1 = ScheduledVoteCastingStatus::InProgress;
@@ -1154,9 +3222,114 @@ impl DPNSScreen {
                 });
         });
 
+        action |= self.render_scheduled_vote_time_edit_window(ui);
+        action
+    }
+
+    /// Renders the popup editing the time of a scheduled vote, while one is being edited
+    fn render_scheduled_vote_time_edit_window(&mut self, ui: &mut Ui) -> AppAction {
+        let Some(mut edit) = self.scheduled_vote_time_edit.take() else {
+            return AppAction::None;
+        };
+        let contest_end_time = self
+            .contested_names
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|contest| contest.normalized_contested_name == edit.contested_name)
+            .and_then(|contest| contest.end_time);
+
+        let mut action = AppAction::None;
+        let mut is_open = true;
+        let mut close = false;
+        egui::Window::new("Edit Scheduled Time")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
+                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                ui.label(
+                    RichText::new(format!("Vote on \"{}\"", edit.contested_name))
+                        .color(DashColors::text_primary(dark_mode)),
+                );
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Cast In:").color(DashColors::text_primary(dark_mode)));
+                    ui.add(
+                        egui::DragValue::new(&mut edit.days)
+                            .prefix("Days: ")
+                            .range(0..=14),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut edit.hours)
+                            .prefix("Hours: ")
+                            .range(0..=23),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut edit.minutes)
+                            .prefix("Min: ")
+                            .range(0..=59),
+                    );
+                });
+                render_scheduled_vote_time(ui, edit.offset(), contest_end_time);
+
+                ui.add_space(10.0);
+                let new_time = validate_scheduled_vote_time(edit.offset(), contest_end_time).ok();
+                ui.horizontal(|ui| {
+                    let save_clicked = ui
+                        .add_enabled(new_time.is_some(), Button::new("Save"))
+                        .clicked();
+                    if let Some(new_time) =
+                        new_time.filter(|_| save_clicked || key_action == DialogKeyAction::Confirm)
+                    {
+                        action = AppAction::BackendTask(BackendTask::ContestedResourceTask(
+                            ContestedResourceTask::RescheduleVote(
+                                edit.voter_id,
+                                edit.contested_name.clone(),
+                                new_time.timestamp_millis() as u64,
+                            ),
+                        ));
+                        close = true;
+                    }
+                    if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
+                        close = true;
+                    }
+                });
+            });
+
+        if is_open && !close {
+            self.scheduled_vote_time_edit = Some(edit);
+        }
         action
     }
 
+    /// Selects `choice` for the contest, replacing any other choice. Selecting the choice that
+    /// is already selected clears it.
+    fn toggle_vote_selection(
+        &mut self,
+        contested_name: &ContestedName,
+        choice: ResourceVoteChoice,
+    ) {
+        if let Some(existing_index) = self
+            .selected_votes
+            .iter()
+            .position(|sv| sv.contested_name == contested_name.normalized_contested_name)
+        {
+            if self.selected_votes[existing_index].vote_choice == choice {
+                self.selected_votes.remove(existing_index);
+            } else {
+                self.selected_votes[existing_index].vote_choice = choice;
+            }
+        } else {
+            self.selected_votes.push(SelectedVote {
+                contested_name: contested_name.normalized_contested_name.clone(),
+                vote_choice: choice,
+                end_time: contested_name.end_time,
+            });
+        }
+    }
+
     /// For each contested name row, show the possible contestants. This is the old `show_contested_name_details` function.
     fn show_contestants_for_contested_name(
         &mut self,
@@ -1167,13 +3340,14 @@ impl DPNSScreen {
     ) {
         if let Some(contestants) = &contested_name.contestants {
             for contestant in contestants {
-                let first_6_chars: String = contestant
-                    .id
-                    .to_string(Encoding::Base58)
-                    .chars()
-                    .take(6)
-                    .collect();
-                let button_text = format!("{}... - {} votes", first_6_chars, contestant.votes);
+                let contestant_id = contestant.id.to_string(Encoding::Base58);
+                let copy_id = ui.id().with(("copy_contestant", &contestant_id));
+                let first_6_chars: String = contestant_id.chars().take(6).collect();
+                let button_text = if recently_copied(ui.ctx(), copy_id) {
+                    "✔ Copied".to_string()
+                } else {
+                    format!("{}... - {} votes", first_6_chars, contestant.votes)
+                };
 
                 // Bold if highest
                 let text = if contestant.votes == max_contestant_votes && !is_locked_votes_bold {
@@ -1195,31 +3369,20 @@ impl DPNSScreen {
                 } else {
                     Button::new(text)
                 };
-                let resp = ui.add(button);
-                if resp.clicked() {
-                    // Is there already a selection for this contested name?
-                    if let Some(existing_index) = self.selected_votes.iter().position(|sv| {
-                        sv.contested_name == contested_name.normalized_contested_name
-                    }) {
-                        // If the user clicked the same choice, that toggles it off (unselect).
-                        if self.selected_votes[existing_index].vote_choice
-                            == ResourceVoteChoice::TowardsIdentity(contestant.id)
-                        {
-                            // Remove it entirely -> no selection
-                            self.selected_votes.remove(existing_index);
-                        } else {
-                            // Otherwise replace the old choice with TowardsIdentity
-                            self.selected_votes[existing_index].vote_choice =
-                                ResourceVoteChoice::TowardsIdentity(contestant.id);
-                        }
-                    } else {
-                        // No existing selection for this name, so add this new TowardsIdentity
-                        self.selected_votes.push(SelectedVote {
-                            contested_name: contested_name.normalized_contested_name.clone(),
-                            vote_choice: ResourceVoteChoice::TowardsIdentity(contestant.id),
-                            end_time: contested_name.end_time,
-                        });
+                let resp = ui
+                    .add(button)
+                    .on_hover_text(format!("{}\n\nRight-click to copy", contestant_id));
+                resp.context_menu(|ui| {
+                    if ui.button("Copy identity ID").clicked() {
+                        copy_with_confirmation(ui.ctx(), copy_id, contestant_id.clone());
+                        ui.close_menu();
                     }
+                });
+                if resp.clicked() {
+                    self.toggle_vote_selection(
+                        contested_name,
+                        ResourceVoteChoice::TowardsIdentity(contestant.id),
+                    );
                 }
             }
         }
@@ -1229,7 +3392,9 @@ impl DPNSScreen {
     // Bulk scheduling ephemeral UI
     // ---------------------------
     fn show_bulk_schedule_popup_window(&mut self, ui: &mut Ui) -> AppAction {
+        let developer_mode = self.app_context.is_developer_mode();
         let mut action = AppAction::None;
+        let key_action = dialog_key_action(ui.ctx());
 
         let dark_mode = ui.ctx().style().visuals.dark_mode;
         ui.heading(
@@ -1248,7 +3413,7 @@ impl DPNSScreen {
             ui.add_space(5.0);
             ui.colored_label(Color32::DARK_RED, "No masternode identities loaded. Please go to the Identities screen to load your masternodes.");
             ui.add_space(10.0);
-            if ui.button("Close").clicked() {
+            if ui.button("Close").clicked() || key_action != DialogKeyAction::None {
                 self.show_bulk_schedule_popup = false;
             }
             return action;
@@ -1259,12 +3424,20 @@ impl DPNSScreen {
             ui.add_space(5.0);
             ui.colored_label(Color32::DARK_RED, "No votes selected. Please click the votes you want to cast or schedule in the Active Contests screen.");
             ui.add_space(10.0);
-            if ui.button("Close").clicked() {
+            if ui.button("Close").clicked() || key_action != DialogKeyAction::None {
                 self.show_bulk_schedule_popup = false;
             }
             return action;
         }
 
+        // Scheduled votes must be cast before their contests end
+        let contest_end_times: Vec<Option<u64>> =
+            self.selected_votes.iter().map(|sv| sv.end_time).collect();
+        let schedule_label = match self.schedule_anchor {
+            ScheduleAnchor::FromNow => "Schedule In:",
+            ScheduleAnchor::BeforeContestEnd => "Before End:",
+        };
+
         egui::ScrollArea::vertical().show(ui, |ui| {
             // Show which votes were clicked
             ui.group(|ui| {
@@ -1276,13 +3449,11 @@ impl DPNSScreen {
                 for sv in &self.selected_votes {
                     // Convert end_time -> readable
                     let end_str = if let Some(e) = sv.end_time {
-                        if let LocalResult::Single(dt) = Utc.timestamp_millis_opt(e as i64) {
-                            let iso = dt.format("%Y-%m-%d %H:%M:%S").to_string();
-                            let rel = HumanTime::from(dt).to_string();
-                            format!("{} ({})", iso, rel)
-                        } else {
-                            "Invalid timestamp".to_string()
-                        }
+                        format_timestamp_millis(
+                            e,
+                            TimestampStyle::AbsoluteAndRelative,
+                            developer_mode,
+                        )
                     } else {
                         "N/A".to_string()
                     };
@@ -1356,7 +3527,7 @@ impl DPNSScreen {
                                         hours,
                                         minutes,
                                     } => (*days, *hours, *minutes),
-                                    _ => (0, 0, 0),
+                                    _ => self.default_schedule,
                                 };
                                 self.set_all_option = VoteOption::Scheduled {
                                     days: d,
@@ -1375,13 +3546,21 @@ impl DPNSScreen {
                     {
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
                         ui.label(
-                            RichText::new("Schedule In:")
+                            RichText::new(schedule_label)
                                 .color(DashColors::text_primary(dark_mode)),
                         );
                         ui.add(egui::DragValue::new(days).prefix("Days: ").range(0..=14));
                         ui.add(egui::DragValue::new(hours).prefix("Hours: ").range(0..=23));
                         ui.add(egui::DragValue::new(minutes).prefix("Min: ").range(0..=59));
                     }
+                    if let Some(offset) = self.set_all_option.schedule_offset() {
+                        render_bulk_scheduled_vote_time(
+                            ui,
+                            self.schedule_anchor,
+                            offset,
+                            &contest_end_times,
+                        );
+                    }
 
                     // Button to apply the "Set all" choice to each identity in bulk_identity_options
                     if ui.button("Apply to All").clicked() {
@@ -1390,84 +3569,144 @@ impl DPNSScreen {
                         }
                     }
                 });
+                ui.horizontal(|ui| {
+                    let dark_mode = ui.ctx().style().visuals.dark_mode;
+                    ui.label(
+                        RichText::new("Schedule relative to:")
+                            .color(DashColors::text_primary(dark_mode)),
+                    );
+                    let anchor_changed = ui
+                        .radio_value(&mut self.schedule_anchor, ScheduleAnchor::FromNow, "Now")
+                        .changed()
+                        | ui.radio_value(
+                            &mut self.schedule_anchor,
+                            ScheduleAnchor::BeforeContestEnd,
+                            "Each contest's end",
+                        )
+                        .on_hover_text(
+                            "Cast each vote the delay before its contest ends, to vote with the latest tallies",
+                        )
+                        .changed();
+                    if anchor_changed {
+                        self.save_schedule_anchor();
+                    }
+                    let schedule = match self.set_all_option {
+                        VoteOption::Scheduled {
+                            days,
+                            hours,
+                            minutes,
+                        } => Some((days, hours, minutes)),
+                        _ => None,
+                    };
+                    let save_default = ui
+                        .add_enabled(schedule.is_some(), egui::Button::new("Save as Default"))
+                        .on_hover_text("Start the Schedule option with this delay")
+                        .on_disabled_hover_text("Pick Schedule in \"Set all\" to save its delay")
+                        .clicked();
+                    if let Some(schedule) = schedule.filter(|_| save_default) {
+                        self.save_default_schedule(schedule);
+                    }
+                });
             });
             ui.add_space(10.0);
-            for (i, identity) in self.voting_identities.iter().enumerate() {
+            let mut renamed_voter = None;
+            for (i, (identity, current_option)) in self
+                .voting_identities
+                .iter()
+                .zip(self.bulk_identity_options.iter_mut())
+                .enumerate()
+            {
+                // Identities that can't vote are kept out of the votes, so they don't fail later
+                let voting_issue = identity.voting_issue();
+                if voting_issue.is_some() {
+                    *current_option = VoteOption::NoVote;
+                }
                 ui.group(|ui| {
                     ui.horizontal(|ui| {
-                        let label = identity
-                            .alias
-                            .clone()
-                            .unwrap_or_else(|| identity.identity.id().to_string(Encoding::Base58));
                         let dark_mode = ui.ctx().style().visuals.dark_mode;
+                        render_identity_tag_dot(ui, &self.app_context, &identity.identity.id());
                         ui.label(
-                            RichText::new(format!("Identity: {}", label))
-                                .color(DashColors::text_primary(dark_mode)),
+                            RichText::new("Identity:").color(DashColors::text_primary(dark_mode)),
                         );
-
-                        // This is a hack
-                        // I'm seeing a panic if I load the app in mainnet context where I have no voting identities,
-                        // and then switch to testnet and pressed "Vote".
-                        if self.bulk_identity_options.len() <= i {
-                            let voting_identities = self
-                                .app_context
-                                .db
-                                .get_local_voting_identities(&self.app_context)
-                                .unwrap_or_default();
-                            // Initialize ephemeral bulk-schedule state to hidden
-                            let identity_count = voting_identities.len();
-                            self.bulk_identity_options = vec![VoteOption::CastNow; identity_count];
+                        if let Some(new_alias) = render_inline_identity_alias(
+                            ui,
+                            &self.app_context,
+                            &identity.identity.id(),
+                            identity.alias.as_deref(),
+                            &mut self.voter_alias_edit,
+                        ) {
+                            renamed_voter = Some((identity.identity.id(), new_alias));
+                        }
+                        ui.label(
+                            RichText::new(format!(
+                                "{} | {:.6} DASH",
+                                identity.identity_type,
+                                identity.identity.balance() as f64 * 1e-11
+                            ))
+                            .color(DashColors::text_secondary(dark_mode)),
+                        );
+                        match voting_issue {
+                            None => {
+                                ui.colored_label(DashColors::SUCCESS, "Can vote");
+                            }
+                            Some(issue) => {
+                                ui.colored_label(DashColors::ERROR, "Can't vote")
+                                    .on_hover_text(issue);
+                            }
                         }
 
-                        let current_option = &mut self.bulk_identity_options[i];
-                        ComboBox::from_id_salt(format!("combo_bulk_identity_{}", i))
-                            .width(120.0)
-                            .selected_text(match current_option {
-                                VoteOption::NoVote => "No Vote".to_string(),
-                                VoteOption::CastNow => "Cast Now".to_string(),
-                                VoteOption::Scheduled { .. } => "Schedule".to_string(),
-                            })
-                            .show_ui(ui, |ui| {
-                                if ui
-                                    .selectable_label(
-                                        matches!(current_option, VoteOption::NoVote),
-                                        "No Vote",
-                                    )
-                                    .clicked()
-                                {
-                                    *current_option = VoteOption::NoVote;
-                                }
-                                if ui
-                                    .selectable_label(
-                                        matches!(current_option, VoteOption::CastNow),
-                                        "Cast Now",
-                                    )
-                                    .clicked()
-                                {
-                                    *current_option = VoteOption::CastNow;
-                                }
-                                if ui
-                                    .selectable_label(
-                                        matches!(current_option, VoteOption::Scheduled { .. }),
-                                        "Schedule",
-                                    )
-                                    .clicked()
-                                {
-                                    let (d, h, m) = match current_option {
-                                        VoteOption::Scheduled {
-                                            days,
-                                            hours,
-                                            minutes,
-                                        } => (*days, *hours, *minutes),
-                                        _ => (0, 0, 0),
-                                    };
-                                    *current_option = VoteOption::Scheduled {
-                                        days: d,
-                                        hours: h,
-                                        minutes: m,
-                                    };
-                                }
-                            });
+                        ui.add_enabled_ui(voting_issue.is_none(), |ui| {
+                            ComboBox::from_id_salt(format!("combo_bulk_identity_{}", i))
+                                .width(120.0)
+                                .selected_text(match current_option {
+                                    VoteOption::NoVote => "No Vote".to_string(),
+                                    VoteOption::CastNow => "Cast Now".to_string(),
+                                    VoteOption::Scheduled { .. } => "Schedule".to_string(),
+                                })
+                                .show_ui(ui, |ui| {
+                                    if ui
+                                        .selectable_label(
+                                            matches!(current_option, VoteOption::NoVote),
+                                            "No Vote",
+                                        )
+                                        .clicked()
+                                    {
+                                        *current_option = VoteOption::NoVote;
+                                    }
+                                    if ui
+                                        .selectable_label(
+                                            matches!(current_option, VoteOption::CastNow),
+                                            "Cast Now",
+                                        )
+                                        .clicked()
+                                    {
+                                        *current_option = VoteOption::CastNow;
+                                    }
+                                    if ui
+                                        .selectable_label(
+                                            matches!(current_option, VoteOption::Scheduled { .. }),
+                                            "Schedule",
+                                        )
+                                        .clicked()
+                                    {
+                                        let (d, h, m) = match current_option {
+                                            VoteOption::Scheduled {
+                                                days,
+                                                hours,
+                                                minutes,
+                                            } => (*days, *hours, *minutes),
+                                            _ => self.default_schedule,
+                                        };
+                                        *current_option = VoteOption::Scheduled {
+                                            days: d,
+                                            hours: h,
+                                            minutes: m,
+                                        };
+                                    }
+                                });
+                        })
+                        .response
+                        .on_disabled_hover_text(voting_issue.unwrap_or_default());
 
                         if let VoteOption::Scheduled {
                             days,
@@ -1477,17 +3716,34 @@ impl DPNSScreen {
                         {
                             let dark_mode = ui.ctx().style().visuals.dark_mode;
                             ui.label(
-                                RichText::new("Schedule In:")
+                                RichText::new(schedule_label)
                                     .color(DashColors::text_primary(dark_mode)),
                             );
                             ui.add(egui::DragValue::new(days).prefix("Days: ").range(0..=14));
                             ui.add(egui::DragValue::new(hours).prefix("Hours: ").range(0..=23));
                             ui.add(egui::DragValue::new(minutes).prefix("Min: ").range(0..=59));
                         }
+                        if let Some(offset) = current_option.schedule_offset() {
+                            render_bulk_scheduled_vote_time(
+                                ui,
+                                self.schedule_anchor,
+                                offset,
+                                &contest_end_times,
+                            );
+                        }
                     });
                 });
                 ui.add_space(10.0);
             }
+            if let Some((identity_id, new_alias)) = renamed_voter {
+                if let Some(identity) = self
+                    .voting_identities
+                    .iter_mut()
+                    .find(|identity| identity.identity.id() == identity_id)
+                {
+                    identity.alias = new_alias;
+                }
+            }
         });
 
         // If any selected votes are scheduled, show a warning
@@ -1500,16 +3756,31 @@ impl DPNSScreen {
             ui.add_space(10.0);
         }
 
-        // "Apply Votes" button
+        // "Apply Votes" button, also triggered by Enter unless votes are already in flight
+        let can_submit_with_key = matches!(
+            self.bulk_vote_handling_status,
+            VoteHandlingStatus::NotStarted | VoteHandlingStatus::Failed(_)
+        );
         let button = egui::Button::new(RichText::new("Apply Votes").color(Color32::WHITE))
             .fill(Color32::from_rgb(0, 128, 255))
             .corner_radius(3.0);
-        if ui.add(button).clicked() {
-            action = self.bulk_apply_votes();
+        if self.pending_bulk_votes.as_ref().is_some_and(|pending| {
+            pending.selected_votes != self.selected_votes
+                || pending.options != self.bulk_identity_options
+        }) {
+            self.pending_bulk_votes = None;
+        }
+        if self.pending_bulk_votes.is_some() {
+            action |= self.render_bulk_vote_confirmation(ui);
+        } else if ui.add(button).clicked()
+            || (key_action == DialogKeyAction::Confirm && can_submit_with_key)
+        {
+            self.prepare_bulk_votes();
         }
 
         ui.add_space(5.0);
-        if ui.button("Cancel").clicked() {
+        if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
+            self.pending_bulk_votes = None;
             self.selected_votes.clear();
             self.show_bulk_schedule_popup = false;
             self.bulk_schedule_message = None;
@@ -1524,10 +3795,30 @@ impl DPNSScreen {
                 let now = Utc::now().timestamp() as u64;
                 let elapsed = now - start_time;
                 let dark_mode = ui.ctx().style().visuals.dark_mode;
-                ui.label(
-                    RichText::new(format!("Casting votes... Time taken so far: {}", elapsed))
-                        .color(DashColors::text_primary(dark_mode)),
-                );
+                match self.bulk_vote_progress {
+                    Some((completed, total)) if total > 0 => {
+                        ui.add(
+                            egui::ProgressBar::new(completed as f32 / total as f32)
+                                .text(format!("{} of {} cast", completed, total)),
+                        );
+                        let eta = bulk_vote_eta_secs(elapsed, completed, total)
+                            .map(|secs| format!(", about {}s left", secs))
+                            .unwrap_or_default();
+                        ui.label(
+                            RichText::new(format!("Time taken so far: {}s{}", elapsed, eta))
+                                .color(DashColors::text_primary(dark_mode)),
+                        );
+                    }
+                    _ => {
+                        ui.label(
+                            RichText::new(format!(
+                                "Casting votes... Time taken so far: {}",
+                                elapsed
+                            ))
+                            .color(DashColors::text_primary(dark_mode)),
+                        );
+                    }
+                }
             }
             VoteHandlingStatus::SchedulingVotes => {
                 let dark_mode = ui.ctx().style().visuals.dark_mode;
@@ -1549,8 +3840,123 @@ impl DPNSScreen {
         action
     }
 
+    /// Votes already cast or scheduled by the local voting identities, by voter and contested
+    /// name
+    fn recorded_votes(&self) -> BTreeMap<(Identifier, String), &'static str> {
+        let mut recorded = BTreeMap::new();
+        for vote in self.app_context.get_scheduled_votes().unwrap_or_default() {
+            let state = if vote.executed_successfully {
+                "already cast"
+            } else {
+                "already scheduled"
+            };
+            recorded.insert((vote.voter_id, vote.contested_name), state);
+        }
+        for contested_name in self.contested_names.lock().unwrap().iter() {
+            for (voter_id, _, _) in contested_name.my_votes.keys() {
+                recorded.insert(
+                    (*voter_id, contested_name.normalized_contested_name.clone()),
+                    "already cast",
+                );
+            }
+        }
+        recorded
+    }
+
+    /// Shows what "Apply Votes" is about to do and dispatches the votes once confirmed
+    fn render_bulk_vote_confirmation(&mut self, ui: &mut Ui) -> AppAction {
+        let Some(pending) = &self.pending_bulk_votes else {
+            return AppAction::None;
+        };
+
+        let immediate_count = pending.immediate_voters.len() * pending.selected_votes.len();
+        let summary = format!(
+            "{} votes will be cast now and {} scheduled, across {} identities.",
+            immediate_count,
+            pending.scheduled_votes.len(),
+            pending.voter_count
+        );
+        let cost = format!(
+            "Estimated cost: {:.8} DASH ({} credits), paid from the voting identities' balances.",
+            pending.total_cost as f64 / 100_000_000_000.0,
+            pending.total_cost
+        );
+        let warnings = pending.warnings.clone();
+        let shortfalls = pending.shortfalls.clone();
+
+        let mut action = AppAction::None;
+        egui::Frame::new()
+            .stroke(egui::Stroke::new(1.0, DashColors::WARNING))
+            .corner_radius(4)
+            .inner_margin(egui::Margin::same(8))
+            .show(ui, |ui| {
+                ui.label(RichText::new("Confirm votes").strong());
+                ui.label(summary);
+                ui.label(cost);
+                for warning in &warnings {
+                    ui.colored_label(DashColors::WARNING, format!("⚠ {}", warning));
+                }
+                for shortfall in &shortfalls {
+                    ui.colored_label(DashColors::ERROR, format!("✖ {}", shortfall));
+                }
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(shortfalls.is_empty(), Button::new("Confirm"))
+                        .on_disabled_hover_text(format!(
+                            "Top up the voters casting now first:\n{}",
+                            shortfalls.join("\n")
+                        ))
+                        .clicked()
+                    {
+                        action = self.bulk_apply_votes();
+                    }
+                    if ui.button("Back").clicked() {
+                        self.pending_bulk_votes = None;
+                    }
+                });
+            });
+        action
+    }
+
+    /// Saves the delay the Schedule option of bulk votes starts with
+    fn save_default_schedule(&mut self, (days, hours, minutes): (u32, u32, u32)) {
+        let total_minutes = days * 24 * 60 + hours * 60 + minutes;
+        match self
+            .app_context
+            .db
+            .update_default_vote_schedule_minutes(total_minutes)
+        {
+            Ok(()) => self.default_schedule = (days, hours, minutes),
+            Err(e) => {
+                self.message = Some((
+                    format!("Error saving default vote schedule: {}", e),
+                    MessageType::Error,
+                    Utc::now(),
+                ));
+            }
+        }
+    }
+
+    /// Saves what the delays of scheduled bulk votes count from, so it's the mode next time
+    fn save_schedule_anchor(&mut self) {
+        let before_end = self.schedule_anchor == ScheduleAnchor::BeforeContestEnd;
+        if let Err(e) = self
+            .app_context
+            .db
+            .update_default_vote_schedule_before_end(before_end)
+        {
+            self.message = Some((
+                format!("Error saving the vote scheduling mode: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ));
+        }
+    }
+
+    /// Works out the votes of the selected cast methods and puts them up for confirmation.
     /// The logic that was in BulkScheduleVoteScreen::schedule_votes
-    fn bulk_apply_votes(&mut self) -> AppAction {
+    fn prepare_bulk_votes(&mut self) {
         // Partition immediate vs scheduled
         let mut immediate_list = Vec::new();
         let mut scheduled_list = Vec::new();
@@ -1565,18 +3971,24 @@ impl DPNSScreen {
                 VoteOption::CastNow => {
                     immediate_list.push(identity.clone());
                 }
-                VoteOption::Scheduled {
-                    days,
-                    hours,
-                    minutes,
-                } => {
-                    let now = Utc::now();
-                    let offset = chrono::Duration::days(*days as i64)
-                        + chrono::Duration::hours(*hours as i64)
-                        + chrono::Duration::minutes(*minutes as i64);
-                    let scheduled_time = (now + offset).timestamp_millis() as u64;
+                VoteOption::Scheduled { .. } => {
+                    let offset = option
+                        .schedule_offset()
+                        .unwrap_or_else(chrono::Duration::zero);
 
                     for sv in &self.selected_votes {
+                        let scheduled_time = match self
+                            .schedule_anchor
+                            .scheduled_vote_time(offset, sv.end_time)
+                        {
+                            Ok(scheduled_time) => scheduled_time.timestamp_millis() as u64,
+                            Err(e) => {
+                                self.bulk_vote_handling_status = VoteHandlingStatus::Failed(
+                                    format!("\"{}\": {}", sv.contested_name, e),
+                                );
+                                return;
+                            }
+                        };
                         let new_vote = ScheduledDPNSVote {
                             contested_name: sv.contested_name.clone(),
                             voter_id: identity.identity.id(),
@@ -1594,9 +4006,97 @@ impl DPNSScreen {
             self.bulk_vote_handling_status = VoteHandlingStatus::Failed(
                 "No votes selected. Please select votes to cast or schedule.".to_string(),
             );
-            return AppAction::None;
+            return;
         }
 
+        let vote_cost = self
+            .app_context
+            .platform_version()
+            .fee_version
+            .vote_resolution_fund_fees
+            .contested_document_single_vote_cost;
+        let recorded_votes = self.recorded_votes();
+        let mut warnings = Vec::new();
+        let mut shortfalls = Vec::new();
+        let mut voter_count = 0;
+        let mut total_cost: Credits = 0;
+        for (identity, option) in self
+            .voting_identities
+            .iter()
+            .zip(&self.bulk_identity_options)
+        {
+            if *option == VoteOption::NoVote {
+                continue;
+            }
+            voter_count += 1;
+            let label = identity
+                .alias
+                .clone()
+                .unwrap_or_else(|| identity.identity.id().to_string(Encoding::Base58));
+            for sv in &self.selected_votes {
+                if let Some(state) =
+                    recorded_votes.get(&(identity.identity.id(), sv.contested_name.clone()))
+                {
+                    warnings.push(format!(
+                        "{} has {} a vote on \"{}\"",
+                        label, state, sv.contested_name
+                    ));
+                }
+            }
+            let cost = vote_cost.saturating_mul(self.selected_votes.len() as Credits);
+            total_cost = total_cost.saturating_add(cost);
+            let balance = identity.identity.balance();
+            if balance < cost && *option == VoteOption::CastNow {
+                shortfalls.push(format!(
+                    "{} is {} credits short of the {} credits its votes cost",
+                    label,
+                    cost - balance,
+                    cost
+                ));
+            } else if balance < cost {
+                warnings.push(format!(
+                    "{} has {} credits, less than the {} credits its votes cost",
+                    label, balance, cost
+                ));
+            }
+        }
+
+        if self.schedule_anchor == ScheduleAnchor::BeforeContestEnd && !scheduled_list.is_empty() {
+            for sv in self
+                .selected_votes
+                .iter()
+                .filter(|sv| sv.end_time.is_none())
+            {
+                warnings.push(format!(
+                    "The end of \"{}\" isn't known yet, so its scheduled votes are cast the delay from now",
+                    sv.contested_name
+                ));
+            }
+        }
+
+        self.pending_bulk_votes = Some(PendingBulkVotes {
+            selected_votes: self.selected_votes.clone(),
+            options: self.bulk_identity_options.clone(),
+            immediate_voters: immediate_list,
+            scheduled_votes: scheduled_list,
+            voter_count,
+            warnings,
+            shortfalls,
+            total_cost,
+        });
+    }
+
+    /// Dispatches the votes the user confirmed
+    fn bulk_apply_votes(&mut self) -> AppAction {
+        let Some(PendingBulkVotes {
+            immediate_voters: immediate_list,
+            scheduled_votes: scheduled_list,
+            ..
+        }) = self.pending_bulk_votes.take()
+        else {
+            return AppAction::None;
+        };
+
         // 1) If immediate_list is not empty, vote now, possibly scheduling votes as well
         if !immediate_list.is_empty() {
             let votes_for_all: Vec<(String, ResourceVoteChoice)> = self
@@ -1606,6 +4106,7 @@ impl DPNSScreen {
                 .collect();
             let now = Utc::now().timestamp() as u64;
             self.bulk_vote_handling_status = VoteHandlingStatus::CastingVotes(now);
+            self.bulk_vote_progress = None;
             if !scheduled_list.is_empty() {
                 AppAction::BackendTasks(
                     vec![
@@ -1633,6 +4134,82 @@ impl DPNSScreen {
         }
     }
 
+    /// Offers to resume an immediate bulk vote that was interrupted, for example by a crash,
+    /// casting only the votes that hadn't gone through yet.
+    fn render_unfinished_bulk_votes_prompt(&mut self, ui: &mut Ui) -> AppAction {
+        let remaining = remaining_bulk_votes(&self.unfinished_bulk_votes);
+        if remaining.is_empty() {
+            return AppAction::None;
+        }
+
+        let mut action = AppAction::None;
+        let total = self.unfinished_bulk_votes.len();
+        let cast = self
+            .unfinished_bulk_votes
+            .iter()
+            .filter(|vote| vote.cast)
+            .count();
+        egui::Frame::new()
+            .stroke(egui::Stroke::new(1.0, DashColors::WARNING))
+            .corner_radius(4)
+            .inner_margin(egui::Margin::same(8))
+            .show(ui, |ui| {
+                ui.label(RichText::new("Unfinished bulk vote").strong());
+                ui.label(format!(
+                    "A bulk vote was interrupted before it completed: {} of {} votes were cast. \
+                     Votes that were being submitted at that moment may have gone through \
+                     without being recorded.",
+                    cast, total
+                ));
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(format!("Resume remaining {} votes", total - cast))
+                        .clicked()
+                    {
+                        self.reload_voting_identities();
+                        let votes = remaining
+                            .into_iter()
+                            .filter_map(|(name, choice, voter_ids)| {
+                                let voters = self
+                                    .voting_identities
+                                    .iter()
+                                    .filter(|identity| voter_ids.contains(&identity.identity.id()))
+                                    .cloned()
+                                    .collect::<Vec<_>>();
+                                (!voters.is_empty()).then_some((name, choice, voters))
+                            })
+                            .collect::<Vec<_>>();
+                        if votes.is_empty() {
+                            self.message = Some((
+                                "The identities of the unfinished votes are no longer loaded"
+                                    .to_string(),
+                                MessageType::Error,
+                                Utc::now(),
+                            ));
+                        } else {
+                            self.resuming_bulk_votes = true;
+                            action = AppAction::BackendTask(BackendTask::ContestedResourceTask(
+                                ContestedResourceTask::ResumeDPNSVotes(votes),
+                            ));
+                        }
+                        self.unfinished_bulk_votes.clear();
+                    }
+                    if ui.button("Discard").clicked() {
+                        if let Err(e) = self.app_context.clear_bulk_vote_progress() {
+                            self.message = Some((
+                                format!("Error discarding the unfinished bulk vote: {}", e),
+                                MessageType::Error,
+                                Utc::now(),
+                            ));
+                        }
+                        self.unfinished_bulk_votes.clear();
+                    }
+                });
+            });
+        ui.add_space(10.0);
+        action
+    }
+
     /// If voting/scheduling is successful, show success message
     fn show_bulk_vote_handling_complete(&mut self, ui: &mut Ui) -> AppAction {
         let mut action = AppAction::None;
@@ -1730,59 +4307,17 @@ impl DPNSScreen {
 impl ScreenLike for DPNSScreen {
     fn refresh(&mut self) {
         self.scheduled_vote_cast_in_progress = false;
-        let mut contested_names = self.contested_names.lock().unwrap();
-        let mut dpns_names = self.local_dpns_names.lock().unwrap();
-        let mut scheduled_votes = self.scheduled_votes.lock().unwrap();
-
-        match self.dpns_subscreen {
-            DPNSSubscreen::Active => {
-                *contested_names = self
-                    .app_context
-                    .ongoing_contested_names()
-                    .unwrap_or_default();
-            }
-            DPNSSubscreen::Past => {
-                *contested_names = self.app_context.all_contested_names().unwrap_or_default();
-            }
-            DPNSSubscreen::Owned => {
-                *dpns_names = self.app_context.local_dpns_names().unwrap_or_default();
-            }
-            DPNSSubscreen::ScheduledVotes => {
-                let new_scheduled = self.app_context.get_scheduled_votes().unwrap_or_default();
-                *scheduled_votes = new_scheduled
-                    .iter()
-                    .map(|newv| {
-                        if newv.executed_successfully {
-                            (newv.clone(), ScheduledVoteCastingStatus::Completed)
-                        } else if let Some(existing) = scheduled_votes.iter().find(|(old, _)| {
-                            old.contested_name == newv.contested_name
-                                && old.voter_id == newv.voter_id
-                        }) {
-                            // preserve old status if InProgress/Failed
-                            match existing.1 {
-                                ScheduledVoteCastingStatus::InProgress => {
-                                    (newv.clone(), ScheduledVoteCastingStatus::InProgress)
-                                }
-                                ScheduledVoteCastingStatus::Failed => {
-                                    (newv.clone(), ScheduledVoteCastingStatus::Failed)
-                                }
-                                _ => (newv.clone(), ScheduledVoteCastingStatus::NotStarted),
-                            }
-                        } else {
-                            (newv.clone(), ScheduledVoteCastingStatus::NotStarted)
-                        }
-                    })
-                    .collect();
-            }
+        if let Some(popup) = self.contest_details_popup.as_mut() {
+            popup.fetching = false;
         }
+        self.load_error = self
+            .reload_subscreen_data()
+            .err()
+            .map(|e| format!("Error loading data from the local database: {}", e));
     }
 
     fn refresh_on_arrival(&mut self) {
-        self.voting_identities = self
-            .app_context
-            .db
-            .get_local_voting_identities(&self.app_context)
-            .unwrap_or_default();
+        self.reload_voting_identities();
         self.user_identities = self
             .app_context
             .load_local_user_identities()
@@ -1805,6 +4340,20 @@ impl ScreenLike for DPNSScreen {
         if message.contains("Successfully cast scheduled vote") {
             self.scheduled_vote_cast_in_progress = false;
         }
+        if message.contains("Error verifying contest outcome") {
+            for (name, verification) in self.contest_verifications.iter_mut() {
+                if matches!(verification, ContestVerification::Verifying(_))
+                    && message.contains(&format!("outcome of {}:", name))
+                {
+                    *verification = ContestVerification::Failed(message.to_string());
+                }
+            }
+        }
+        if message.contains("Error fetching contest details") {
+            if let Some(popup) = self.contest_details_popup.as_mut() {
+                popup.fetching = false;
+            }
+        }
         // If it's from a DPNS query or identity refresh, remove refreshing state
         if message.contains("Successfully refreshed DPNS contests")
             || message.contains("Successfully refreshed loaded identities dpns names")
@@ -1819,6 +4368,12 @@ impl ScreenLike for DPNSScreen {
         {
             self.bulk_vote_handling_status = VoteHandlingStatus::Completed;
         }
+        if message.contains("Votes scheduled")
+            && self.dpns_subscreen == DPNSSubscreen::ScheduledVotes
+        {
+            // Show votes imported from a voting plan in the table
+            self.refresh();
+        }
 
         // Save into general error_message for top-of-screen
         self.message = Some((message.to_string(), message_type, Utc::now()));
@@ -1826,8 +4381,12 @@ impl ScreenLike for DPNSScreen {
 
     fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
         match backend_task_success_result {
+            BackendTaskSuccessResult::DPNSVoteProgress { completed, total } => {
+                self.bulk_vote_progress = Some((completed, total));
+            }
             // If immediate cast finished, see if we have pending to schedule next
             BackendTaskSuccessResult::DPNSVoteResults(results) => {
+                self.bulk_vote_progress = None;
                 let errors: Vec<String> = results
                     .iter()
                     .filter_map(|(_, _, r)| r.as_ref().err().cloned())
@@ -1837,6 +4396,30 @@ impl ScreenLike for DPNSScreen {
                     .filter_map(|(name, _, r)| r.as_ref().ok().map(|_| name.clone()))
                     .collect();
 
+                // A resumed bulk vote has no popup open, so report on the screen instead
+                if self.resuming_bulk_votes {
+                    self.resuming_bulk_votes = false;
+                    self.message = Some(if errors.is_empty() {
+                        (
+                            format!("Resumed bulk vote: {} votes cast", successes.len()),
+                            MessageType::Success,
+                            Utc::now(),
+                        )
+                    } else {
+                        (
+                            format!(
+                                "Resumed bulk vote: {} of {} votes cast. Errors:\n\n{}",
+                                successes.len(),
+                                successes.len() + errors.len(),
+                                errors.join("\n\n")
+                            ),
+                            MessageType::Error,
+                            Utc::now(),
+                        )
+                    });
+                    return;
+                }
+
                 if !errors.is_empty() {
                     let errors_string = errors.join("\n\n");
                     if !successes.is_empty() {
@@ -1875,6 +4458,61 @@ impl ScreenLike for DPNSScreen {
                         Some((MessageType::Success, "Votes scheduled".to_string()));
                 }
             }
+            BackendTaskSuccessResult::CastScheduledVotes(results) => {
+                self.scheduled_vote_cast_in_progress = false;
+                let errors: Vec<String> = results
+                    .iter()
+                    .filter_map(|(vote, result)| {
+                        result
+                            .as_ref()
+                            .err()
+                            .map(|e| format!("{}: {}", vote.contested_name, e))
+                    })
+                    .collect();
+                if let Ok(mut guard) = self.scheduled_votes.lock() {
+                    for (vote, result) in &results {
+                        if let Some((_, status)) = guard.iter_mut().find(|(v, _)| {
+                            v.contested_name == vote.contested_name && v.voter_id == vote.voter_id
+                        }) {
+                            *status = if result.is_ok() {
+                                ScheduledVoteCastingStatus::Completed
+                            } else {
+                                ScheduledVoteCastingStatus::Failed
+                            };
+                        }
+                    }
+                }
+                self.message = Some(if errors.is_empty() {
+                    (
+                        format!("{} scheduled votes cast", results.len()),
+                        MessageType::Success,
+                        Utc::now(),
+                    )
+                } else {
+                    (
+                        format!(
+                            "{} of {} scheduled votes cast. Errors:\n\n{}",
+                            results.len() - errors.len(),
+                            results.len(),
+                            errors.join("\n\n")
+                        ),
+                        MessageType::Error,
+                        Utc::now(),
+                    )
+                });
+            }
+            BackendTaskSuccessResult::DPNSContestOutcome { name, outcome } => {
+                if let Some(ContestVerification::Verifying(cached)) =
+                    self.contest_verifications.remove(&name)
+                {
+                    self.contest_verifications.insert(
+                        name,
+                        ContestVerification::of_outcome(cached, outcome.as_ref()),
+                    );
+                }
+                // Show the outcome Platform returned in the table
+                self.refresh();
+            }
             BackendTaskSuccessResult::CastScheduledVote(vote) => {
                 if let Ok(mut guard) = self.scheduled_votes.lock() {
                     if let Some((_, status)) = guard.iter_mut().find(|(v, _)| {
@@ -1896,9 +4534,50 @@ impl ScreenLike for DPNSScreen {
             !guard.is_empty()
         };
 
+        let clear_selections_label = format!("Clear Selections ({})", self.selected_votes.len());
+        let (has_pending_scheduled_votes, has_expired_scheduled_votes) = {
+            let guard = self.scheduled_votes.lock().unwrap();
+            (
+                guard.iter().any(|(_, status)| status.is_pending()),
+                guard
+                    .iter()
+                    .any(|(_, status)| *status == ScheduledVoteCastingStatus::ContestEnded),
+            )
+        };
+
         // Build top-right buttons
         let mut right_buttons = match self.dpns_subscreen {
             DPNSSubscreen::Active => {
+                let refresh_button = (
+                    "Refresh",
+                    DesiredAppAction::BackendTask(Box::new(BackendTask::ContestedResourceTask(
+                        ContestedResourceTask::QueryDPNSContests,
+                    ))),
+                );
+                if has_active_contests {
+                    let mut buttons = vec![
+                        refresh_button,
+                        (
+                            "Export CSV",
+                            DesiredAppAction::Custom("ExportCsv".to_string()),
+                        ),
+                    ];
+                    if !self.selected_votes.is_empty() {
+                        buttons.push((
+                            clear_selections_label.as_str(),
+                            DesiredAppAction::Custom("ClearSelections".to_string()),
+                        ));
+                    }
+                    buttons.push((
+                        "Cast/Schedule Votes",
+                        DesiredAppAction::Custom("Vote".to_string()),
+                    ));
+                    buttons
+                } else {
+                    vec![refresh_button]
+                }
+            }
+            DPNSSubscreen::Past => {
                 let refresh_button = (
                     "Refresh",
                     DesiredAppAction::BackendTask(Box::new(BackendTask::ContestedResourceTask(
@@ -1909,15 +4588,15 @@ impl ScreenLike for DPNSScreen {
                     vec![
                         refresh_button,
                         (
-                            "Cast/Schedule Votes",
-                            DesiredAppAction::Custom("Vote".to_string()),
+                            "Export CSV",
+                            DesiredAppAction::Custom("ExportCsv".to_string()),
                         ),
                     ]
                 } else {
                     vec![refresh_button]
                 }
             }
-            DPNSSubscreen::Past => {
+            DPNSSubscreen::Watchlist => {
                 let refresh_button = (
                     "Refresh",
                     DesiredAppAction::BackendTask(Box::new(BackendTask::ContestedResourceTask(
@@ -1936,7 +4615,28 @@ impl ScreenLike for DPNSScreen {
                 vec![refresh_button]
             }
             DPNSSubscreen::ScheduledVotes => {
-                vec![
+                let mut buttons = vec![];
+                if has_pending_scheduled_votes && !self.scheduled_vote_cast_in_progress {
+                    buttons.push((
+                        "Cast All Pending Now",
+                        DesiredAppAction::Custom("CastAllPending".to_string()),
+                    ));
+                }
+                if has_expired_scheduled_votes {
+                    buttons.push((
+                        "Clear Expired",
+                        DesiredAppAction::BackendTask(Box::new(
+                            BackendTask::ContestedResourceTask(
+                                ContestedResourceTask::ClearExpiredScheduledVotes,
+                            ),
+                        )),
+                    ));
+                }
+                buttons.extend([
+                    (
+                        "Import Votes",
+                        DesiredAppAction::Custom("ImportVotes".to_string()),
+                    ),
                     (
                         "Clear All",
                         DesiredAppAction::BackendTask(Box::new(
@@ -1953,7 +4653,8 @@ impl ScreenLike for DPNSScreen {
                             ),
                         )),
                     ),
-                ]
+                ]);
+                buttons
             }
         };
 
@@ -1981,6 +4682,20 @@ impl ScreenLike for DPNSScreen {
             self.show_bulk_schedule_popup = true;
             action = AppAction::None; // clear it out so we don't re-trigger
         }
+        if action == AppAction::Custom("ExportCsv".to_string()) {
+            self.export_contested_names_csv();
+            action = AppAction::None;
+        }
+        if action == AppAction::Custom("ClearSelections".to_string()) {
+            self.selected_votes.clear();
+            action = AppAction::None;
+        }
+        if action == AppAction::Custom("CastAllPending".to_string()) {
+            action = self.cast_all_pending_scheduled_votes();
+        }
+        if action == AppAction::Custom("ImportVotes".to_string()) {
+            action = self.import_scheduled_votes();
+        }
 
         // Left panel
         match self.dpns_subscreen {
@@ -2012,6 +4727,13 @@ impl ScreenLike for DPNSScreen {
                     RootScreenType::RootScreenDPNSScheduledVotes,
                 );
             }
+            DPNSSubscreen::Watchlist => {
+                action |= add_left_panel(
+                    ctx,
+                    &self.app_context,
+                    RootScreenType::RootScreenDPNSWatchlist,
+                );
+            }
         }
 
         // Subscreen chooser
@@ -2031,56 +4753,70 @@ impl ScreenLike for DPNSScreen {
                     });
             }
 
-            // Render sub-screen
-            match self.dpns_subscreen {
-                DPNSSubscreen::Active => {
-                    let has_any = {
-                        let guard = self.contested_names.lock().unwrap();
-                        !guard.is_empty()
-                    };
-                    if has_any {
-                        self.render_table_active_contests(ui);
-                    } else {
-                        inner_action |= self.render_no_active_contests_or_owned_names(ui);
+            inner_action |= self.render_unfinished_bulk_votes_prompt(ui);
+
+            // Render sub-screen, or the load failure instead of an empty state
+            if let Some(error) = self.load_error.clone() {
+                self.render_load_error(ui, &error);
+            } else {
+                match self.dpns_subscreen {
+                    DPNSSubscreen::Active => {
+                        let has_any = {
+                            let guard = self.contested_names.lock().unwrap();
+                            !guard.is_empty()
+                        };
+                        if has_any {
+                            inner_action |= self.render_table_active_contests(ui);
+                        } else {
+                            inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                        }
                     }
-                }
-                DPNSSubscreen::Past => {
-                    let has_any = {
-                        let guard = self.contested_names.lock().unwrap();
-                        !guard.is_empty()
-                    };
-                    if has_any {
-                        self.render_table_past_contests(ui);
-                    } else {
-                        inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                    DPNSSubscreen::Past => {
+                        let has_any = {
+                            let guard = self.contested_names.lock().unwrap();
+                            !guard.is_empty()
+                        };
+                        if has_any {
+                            inner_action |= self.render_table_past_contests(ui);
+                        } else {
+                            inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                        }
                     }
-                }
-                DPNSSubscreen::Owned => {
-                    let has_any = {
-                        let guard = self.local_dpns_names.lock().unwrap();
-                        !guard.is_empty()
-                    };
-                    if has_any {
-                        self.render_table_local_dpns_names(ui);
-                    } else {
-                        inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                    DPNSSubscreen::Owned => {
+                        let has_any = {
+                            let guard = self.local_dpns_names.lock().unwrap();
+                            !guard.is_empty()
+                        };
+                        if has_any {
+                            self.render_table_local_dpns_names(ui);
+                        } else {
+                            inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                        }
                     }
-                }
-                DPNSSubscreen::ScheduledVotes => {
-                    let has_any = {
-                        let guard = self.scheduled_votes.lock().unwrap();
-                        !guard.is_empty()
-                    };
-                    if has_any {
-                        inner_action |= self.render_table_scheduled_votes(ui);
-                    } else {
-                        inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                    DPNSSubscreen::ScheduledVotes => {
+                        let has_any = {
+                            let guard = self.scheduled_votes.lock().unwrap();
+                            !guard.is_empty()
+                        };
+                        if has_any {
+                            inner_action |= self.render_table_scheduled_votes(ui);
+                        } else {
+                            inner_action |= self.render_no_active_contests_or_owned_names(ui);
+                        }
+                        self.render_scheduled_votes_import_report(ui);
+                    }
+                    DPNSSubscreen::Watchlist => {
+                        inner_action |= self.render_table_watchlist(ui);
                     }
                 }
             }
 
             // Show either refreshing indicator or message, but not both
-            if let RefreshingStatus::Refreshing(start_time) = self.refreshing_status {
+            let refreshing_since = match self.refreshing_status {
+                RefreshingStatus::Refreshing(start_time) => Some(start_time),
+                RefreshingStatus::NotRefreshing => self.refresh_all_pending_since(),
+            };
+            if let Some(start_time) = refreshing_since {
                 ui.add_space(25.0); // Space above
                 let now = Utc::now().timestamp() as u64;
                 let elapsed = now - start_time;
@@ -2149,6 +4885,437 @@ impl ScreenLike for DPNSScreen {
                 action = AppAction::BackendTask(bt);
             }
         }
+        if action == AppAction::None {
+            action = self.auto_refresh_ending_soon_contests(ctx);
+        }
         action
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use crate::model::contested_name::Contestant;
+    use crate::model::qualified_identity::IdentityType;
+    use dash_sdk::dpp::dashcore::Network;
+    use dash_sdk::platform::Identity;
+
+    #[test]
+    fn test_contest_verification_of_outcome() {
+        let winner = ContestState::WonBy(Identifier::new([1; 32]));
+        assert_eq!(
+            ContestVerification::of_outcome(winner.clone(), Some(&winner)),
+            ContestVerification::Matched
+        );
+        assert_eq!(
+            ContestVerification::of_outcome(winner.clone(), Some(&ContestState::Locked)),
+            ContestVerification::Stale { cached: winner }
+        );
+        assert!(matches!(
+            ContestVerification::of_outcome(ContestState::Locked, None),
+            ContestVerification::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn test_is_ending_soon() {
+        let hour_ms = 3_600_000;
+        assert!(is_ending_soon(Some(1_000 + hour_ms), 1_000, hour_ms));
+        assert!(!is_ending_soon(Some(1_001 + hour_ms), 1_000, hour_ms));
+        // Ended contests and contests without a known end aren't ending soon
+        assert!(!is_ending_soon(Some(1_000), 1_000, hour_ms));
+        assert!(!is_ending_soon(None, 1_000, hour_ms));
+        // A window of 0 turns the highlight off
+        assert!(!is_ending_soon(Some(1_001), 1_000, 0));
+    }
+
+    #[test]
+    fn test_acquired_within() {
+        let day = |d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+        // 2025-03-10 00:00:00 UTC and 23:59:59.999 UTC
+        let start_of_day = 1_741_564_800_000;
+        let end_of_day = start_of_day + 86_399_999;
+
+        assert!(acquired_within(start_of_day, None, None));
+        assert!(acquired_within(start_of_day, Some(day(10)), Some(day(10))));
+        assert!(acquired_within(end_of_day, Some(day(10)), Some(day(10))));
+        assert!(!acquired_within(end_of_day + 1, None, Some(day(10))));
+        assert!(!acquired_within(start_of_day - 1, Some(day(10)), None));
+        assert!(!acquired_within(start_of_day, Some(day(11)), Some(day(9))));
+    }
+
+    #[test]
+    fn test_contested_names_csv() {
+        let contestant = |i: u8, votes| Contestant {
+            id: Identifier::new([i; 32]),
+            name: "alice".to_string(),
+            info: String::new(),
+            votes,
+            created_at: None,
+            created_at_block_height: None,
+            created_at_core_block_height: None,
+            document_id: Identifier::new([i; 32]),
+        };
+        let contest = |contestants| ContestedName {
+            normalized_contested_name: "a11ce".to_string(),
+            contestants,
+            locked_votes: None,
+            abstain_votes: Some(2),
+            awarded_to: None,
+            end_time: Some(1_700_000_000_000),
+            state: ContestState::Ongoing,
+            last_updated: None,
+            my_votes: Default::default(),
+        };
+
+        let csv = contested_names_csv(&[
+            contest(Some(vec![contestant(1, 5), contestant(2, 3)])),
+            contest(None),
+        ]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "contested_name,locked_votes,abstain_votes,end_time,last_updated,\
+             contestant_1_id,contestant_1_votes,contestant_2_id,contestant_2_votes"
+        );
+        let first_id = Identifier::new([1; 32]).to_string(Encoding::Base58);
+        assert!(lines[1].starts_with(&format!(
+            "a11ce,,2,2023-11-14T22:13:20+00:00,,{},5,",
+            first_id
+        )));
+        // Unknown values and missing contestants are empty cells
+        assert_eq!(lines[2], "a11ce,,2,2023-11-14T22:13:20+00:00,,,,,");
+    }
+
+    #[test]
+    fn test_compare_contested_names_breaks_ties() {
+        let contest = |name: &str, end_time| ContestedName {
+            normalized_contested_name: name.to_string(),
+            contestants: None,
+            locked_votes: None,
+            abstain_votes: None,
+            awarded_to: None,
+            end_time: Some(end_time),
+            state: ContestState::Ongoing,
+            last_updated: None,
+            my_votes: Default::default(),
+        };
+        let mut contests = vec![contest("b0b", 2), contest("carol", 1), contest("a11ce", 2)];
+        let names = |contests: &[ContestedName]| {
+            contests
+                .iter()
+                .map(|c| c.normalized_contested_name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        contests.sort_by(|a, b| {
+            compare_contested_names(
+                a,
+                b,
+                (SortColumn::EndingTime, SortOrder::Ascending),
+                Some((SortColumn::ContestedName, SortOrder::Ascending)),
+            )
+        });
+        assert_eq!(names(&contests), vec!["carol", "a11ce", "b0b"]);
+
+        contests.sort_by(|a, b| {
+            compare_contested_names(
+                a,
+                b,
+                (SortColumn::EndingTime, SortOrder::Descending),
+                Some((SortColumn::ContestedName, SortOrder::Descending)),
+            )
+        });
+        assert_eq!(names(&contests), vec!["b0b", "a11ce", "carol"]);
+    }
+
+    #[test]
+    fn test_leading_choice() {
+        let contestant = |i: u8, votes| Contestant {
+            id: Identifier::new([i; 32]),
+            name: "alice".to_string(),
+            info: String::new(),
+            votes,
+            created_at: None,
+            created_at_block_height: None,
+            created_at_core_block_height: None,
+            document_id: Identifier::new([i; 32]),
+        };
+        let contest = |contestants, locked_votes| ContestedName {
+            normalized_contested_name: "a11ce".to_string(),
+            contestants: Some(contestants),
+            locked_votes: Some(locked_votes),
+            abstain_votes: Some(1),
+            awarded_to: None,
+            end_time: None,
+            state: ContestState::Ongoing,
+            last_updated: None,
+            my_votes: Default::default(),
+        };
+
+        assert_eq!(
+            leading_choice(&contest(vec![contestant(1, 5), contestant(2, 3)], 2)),
+            Some((
+                ResourceVoteChoice::TowardsIdentity(Identifier::new([1; 32])),
+                5
+            ))
+        );
+        assert_eq!(
+            leading_choice(&contest(vec![contestant(1, 5)], 7)),
+            Some((ResourceVoteChoice::Lock, 7))
+        );
+        assert_eq!(
+            leading_choice(&contest(vec![contestant(1, 4), contestant(2, 4)], 0)),
+            None
+        );
+        assert_eq!(
+            leading_choice(&contest(vec![], 0)),
+            Some((ResourceVoteChoice::Abstain, 1))
+        );
+    }
+
+    #[test]
+    fn test_contest_matches_filter() {
+        let contestant_id = Identifier::new([3; 32]);
+        let contest = ContestedName {
+            normalized_contested_name: "a11ce".to_string(),
+            contestants: Some(vec![Contestant {
+                id: contestant_id,
+                name: "alice".to_string(),
+                info: String::new(),
+                votes: 0,
+                created_at: None,
+                created_at_block_height: None,
+                created_at_core_block_height: None,
+                document_id: contestant_id,
+            }]),
+            locked_votes: None,
+            abstain_votes: None,
+            awarded_to: None,
+            end_time: None,
+            state: ContestState::Ongoing,
+            last_updated: None,
+            my_votes: Default::default(),
+        };
+        let id = contestant_id.to_string(Encoding::Base58);
+
+        assert!(contest_matches_filter(&contest, "Alice"));
+        assert!(contest_matches_filter(&contest, &id));
+        assert!(contest_matches_filter(&contest, &id[5..15]));
+        // IDs are matched as typed, without the name normalization or lowercasing
+        assert!(!contest_matches_filter(&contest, &id[5..15].to_lowercase()));
+        assert!(!contest_matches_filter(&contest, &id[..4]));
+        assert!(!contest_matches_filter(&contest, "bob"));
+        assert!(!looks_like_partial_identifier("abc"));
+        assert!(!looks_like_partial_identifier("a11ce0"));
+        assert!(looks_like_partial_identifier("8vJ3kX"));
+    }
+
+    #[test]
+    fn test_schedule_offset_parts() {
+        let parts = |minutes| schedule_offset_parts(chrono::Duration::minutes(minutes));
+        assert_eq!(parts(0), (0, 0, 0));
+        assert_eq!(parts(-30), (0, 0, 0));
+        assert_eq!(parts(90), (0, 1, 30));
+        assert_eq!(parts(2 * 24 * 60 + 3 * 60 + 4), (2, 3, 4));
+        assert_eq!(parts(30 * 24 * 60), (14, 23, 59));
+        // Part of a minute left still counts as a minute
+        assert_eq!(
+            schedule_offset_parts(chrono::Duration::seconds(61)),
+            (0, 0, 2)
+        );
+    }
+
+    #[test]
+    fn test_validate_vote_time_before_end() {
+        let now = DateTime::from_timestamp_millis(1_000_000_000_000).unwrap();
+        let end_time = 1_000_000_000_000 + 3 * 24 * 60 * 60 * 1000;
+        let two_days = chrono::Duration::days(2);
+        assert_eq!(
+            validate_vote_time_before_end(two_days, end_time, now),
+            Ok(now + chrono::Duration::days(1))
+        );
+        // Too long before the end to be cast in the future
+        assert!(validate_vote_time_before_end(chrono::Duration::days(3), end_time, now).is_err());
+        assert!(validate_vote_time_before_end(chrono::Duration::zero(), end_time, now).is_err());
+    }
+
+    #[test]
+    fn test_bulk_vote_eta_secs() {
+        assert_eq!(bulk_vote_eta_secs(10, 0, 8), None);
+        assert_eq!(bulk_vote_eta_secs(10, 2, 8), Some(30));
+        assert_eq!(bulk_vote_eta_secs(10, 8, 8), Some(0));
+    }
+
+    #[test]
+    fn test_move_highlight() {
+        assert_eq!(move_highlight(None, true, 3), 0);
+        assert_eq!(move_highlight(None, false, 3), 0);
+        assert_eq!(move_highlight(Some(0), true, 3), 1);
+        assert_eq!(move_highlight(Some(2), true, 3), 2);
+        assert_eq!(move_highlight(Some(1), false, 3), 0);
+        assert_eq!(move_highlight(Some(0), false, 3), 0);
+    }
+
+    #[test]
+    fn test_remaining_bulk_votes_skip_cast_votes() {
+        let voter = |i| Identifier::new([i; 32]);
+        let vote = |i, name: &str, choice, cast| BulkVoteProgress {
+            voter_id: voter(i),
+            contested_name: name.to_string(),
+            choice,
+            cast,
+        };
+        let progress = vec![
+            vote(1, "alice", ResourceVoteChoice::Lock, true),
+            vote(2, "alice", ResourceVoteChoice::Lock, false),
+            vote(3, "alice", ResourceVoteChoice::Lock, false),
+            vote(1, "bob", ResourceVoteChoice::Abstain, false),
+            vote(2, "bob", ResourceVoteChoice::Abstain, true),
+        ];
+
+        assert_eq!(
+            remaining_bulk_votes(&progress),
+            vec![
+                (
+                    "alice".to_string(),
+                    ResourceVoteChoice::Lock,
+                    vec![voter(2), voter(3)]
+                ),
+                (
+                    "bob".to_string(),
+                    ResourceVoteChoice::Abstain,
+                    vec![voter(1)]
+                ),
+            ]
+        );
+        assert!(remaining_bulk_votes(&progress[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_bulk_identity_options_follow_network_switches() {
+        // Mainnet has no voting identities, testnet has three
+        let mainnet_ids: Vec<Identifier> = Vec::new();
+        let testnet_ids: Vec<Identifier> = (1..=3).map(|i| Identifier::new([i; 32])).collect();
+
+        let options = reconcile_bulk_identity_options(&[], &mainnet_ids);
+        assert!(options.is_empty());
+
+        // Switching to testnet gives every identity an option
+        let options = reconcile_bulk_identity_options(&[], &testnet_ids);
+        assert_eq!(options, vec![VoteOption::CastNow; 3]);
+
+        // Choices made on testnet are kept when its identities are reloaded
+        let mut previous: Vec<(Identifier, VoteOption)> =
+            testnet_ids.iter().copied().zip(options).collect();
+        previous[1].1 = VoteOption::NoVote;
+        let options = reconcile_bulk_identity_options(&previous, &testnet_ids);
+        assert_eq!(
+            options,
+            vec![VoteOption::CastNow, VoteOption::NoVote, VoteOption::CastNow]
+        );
+
+        // Switching back to mainnet drops them all
+        let previous: Vec<(Identifier, VoteOption)> =
+            testnet_ids.iter().copied().zip(options).collect();
+        let options = reconcile_bulk_identity_options(&previous, &mainnet_ids);
+        assert!(options.is_empty());
+    }
+
+    /// Checks the voting identities and bulk vote options of the screen are in step, and walks
+    /// them by rendering the bulk vote popup and preparing the votes.
+    fn check_bulk_vote_identities(ctx: &egui::Context, screen: &mut Screen, expected: usize) {
+        let Screen::DPNSScreen(dpns) = screen else {
+            panic!("expected the DPNS screen");
+        };
+        assert_eq!(dpns.voting_identities.len(), expected);
+        assert_eq!(dpns.bulk_identity_options.len(), expected);
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                dpns.show_bulk_schedule_popup_window(ui);
+            });
+        });
+        dpns.prepare_bulk_votes();
+    }
+
+    #[test]
+    fn test_network_switch_rebuilds_bulk_vote_identities() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_file_path = temp_dir.path().join("test_data.db");
+        let db = Arc::new(Database::new(&db_file_path).unwrap());
+        db.initialize(&db_file_path).unwrap();
+        let mainnet = AppContext::new(Network::Dash, db.clone(), None, Default::default())
+            .expect("Expected to create AppContext");
+        let local = AppContext::new(Network::Regtest, db.clone(), None, Default::default())
+            .expect("Expected to create AppContext");
+
+        // Mainnet has no voting identities, the local network has three masternodes
+        for i in 1..=3 {
+            let identity =
+                Identity::create_basic_identity(Identifier::new([i; 32]), local.platform_version())
+                    .expect("Expected to create Identity");
+            let mut masternode = QualifiedIdentity::from(identity);
+            masternode.identity_type = IdentityType::Masternode;
+            db.insert_local_qualified_identity(&masternode, &None, &local)
+                .unwrap();
+        }
+
+        let ctx = egui::Context::default();
+        let mut screen = Screen::DPNSScreen(DPNSScreen::new(&mainnet, DPNSSubscreen::Active));
+        check_bulk_vote_identities(&ctx, &mut screen, 0);
+
+        screen.change_context(local.clone());
+        screen.refresh_on_arrival();
+        check_bulk_vote_identities(&ctx, &mut screen, 3);
+
+        // Back to fewer identities than options were made for
+        screen.change_context(mainnet.clone());
+        screen.refresh_on_arrival();
+        check_bulk_vote_identities(&ctx, &mut screen, 0);
+    }
+
+    #[test]
+    fn test_newly_contested_watched_names() {
+        let watched = |name: &str, contest_seen: bool| WatchedName {
+            normalized_name: name.to_string(),
+            contest_seen,
+        };
+        let contested = |name: &str| ContestedName {
+            normalized_contested_name: name.to_string(),
+            contestants: None,
+            locked_votes: None,
+            abstain_votes: None,
+            awarded_to: None,
+            end_time: None,
+            state: ContestState::Ongoing,
+            last_updated: None,
+            my_votes: Default::default(),
+        };
+
+        let watchlist = vec![
+            watched("a11ce", false),
+            watched("b0b", true),
+            watched("car01", false),
+        ];
+        let contested_names = vec![contested("a11ce"), contested("b0b"), contested("dave")];
+
+        // Only unseen watched names with a contest are announced
+        assert_eq!(
+            newly_contested_watched_names(&watchlist, &contested_names),
+            vec!["a11ce".to_string()]
+        );
+        assert!(newly_contested_watched_names(&watchlist, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_normalize_watchlist_input() {
+        assert_eq!(
+            normalize_watchlist_input("  Alice.dash "),
+            Some("a11ce".to_string())
+        );
+        assert_eq!(normalize_watchlist_input("bob"), Some("b0b".to_string()));
+        assert_eq!(normalize_watchlist_input(" "), None);
+        assert_eq!(normalize_watchlist_input(".dash"), None);
+    }
+}
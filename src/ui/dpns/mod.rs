@@ -1 +1,3 @@
+pub mod contest_details_screen;
 pub mod dpns_contested_names_screen;
+pub mod scheduled_votes_import;
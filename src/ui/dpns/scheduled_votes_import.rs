@@ -0,0 +1,250 @@
+//! Parsing of voting plans prepared offline, imported as scheduled votes.
+//!
+//! A plan is either a JSON array of objects or a CSV file, both with the fields
+//! `contested_name`, `voter_id`, `choice` and `scheduled_time`. The choice is `lock`,
+//! `abstain` or the base58 ID of a contestant, and the time is RFC 3339 or unix milliseconds.
+
+use chrono::DateTime;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use serde::Deserialize;
+
+use crate::backend_task::contested_names::ScheduledDPNSVote;
+use crate::model::contested_name::{ContestState, ContestedName};
+
+#[derive(Debug, Deserialize)]
+struct ImportedVote {
+    contested_name: String,
+    voter_id: String,
+    choice: String,
+    scheduled_time: String,
+}
+
+/// Outcome of importing one entry of a plan. `line` is the line of a CSV file, or the
+/// position of the entry in a JSON array, counting from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedVoteRow {
+    pub line: usize,
+    pub result: Result<ScheduledDPNSVote, String>,
+}
+
+/// Parses a voting plan and checks every entry against the identities that can vote and the
+/// contests that are still ongoing. A plan that can't be read at all is an error, while
+/// entries that don't pass the checks are rows with an error.
+pub fn parse_scheduled_votes_import(
+    contents: &str,
+    is_json: bool,
+    voter_ids: &[Identifier],
+    contested_names: &[ContestedName],
+) -> Result<Vec<ImportedVoteRow>, String> {
+    let entries: Vec<(usize, Result<ImportedVote, String>)> = if is_json {
+        serde_json::from_str::<Vec<ImportedVote>>(contents)
+            .map_err(|e| format!("Invalid JSON voting plan: {}", e))?
+            .into_iter()
+            .enumerate()
+            .map(|(i, vote)| (i + 1, Ok(vote)))
+            .collect()
+    } else {
+        csv_entries(contents)
+    };
+
+    Ok(entries
+        .into_iter()
+        .map(|(line, entry)| ImportedVoteRow {
+            line,
+            result: entry.and_then(|vote| validate_vote(vote, voter_ids, contested_names)),
+        })
+        .collect())
+}
+
+/// Splits a CSV plan into entries, skipping blank lines and a header line
+fn csv_entries(contents: &str) -> Vec<(usize, Result<ImportedVote, String>)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(_, line)| !line.trim_start().starts_with("contested_name"))
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let entry = match fields.as_slice() {
+                [contested_name, voter_id, choice, scheduled_time] => Ok(ImportedVote {
+                    contested_name: contested_name.to_string(),
+                    voter_id: voter_id.to_string(),
+                    choice: choice.to_string(),
+                    scheduled_time: scheduled_time.to_string(),
+                }),
+                _ => Err(format!("expected 4 fields, found {}", fields.len())),
+            };
+            (i + 1, entry)
+        })
+        .collect()
+}
+
+fn validate_vote(
+    vote: ImportedVote,
+    voter_ids: &[Identifier],
+    contested_names: &[ContestedName],
+) -> Result<ScheduledDPNSVote, String> {
+    let contested_name = vote.contested_name.trim().to_lowercase();
+    let contest = contested_names
+        .iter()
+        .find(|contest| contest.normalized_contested_name == contested_name)
+        .ok_or_else(|| format!("\"{}\" is not a known contest", contested_name))?;
+    if contest.state != ContestState::Ongoing {
+        return Err(format!("the contest for \"{}\" has ended", contested_name));
+    }
+
+    let voter_id = Identifier::from_string(vote.voter_id.trim(), Encoding::Base58)
+        .map_err(|_| format!("invalid voter identity \"{}\"", vote.voter_id))?;
+    if !voter_ids.contains(&voter_id) {
+        return Err(format!(
+            "{} is not a loaded voting identity",
+            vote.voter_id.trim()
+        ));
+    }
+
+    let choice = match vote.choice.trim().to_lowercase().as_str() {
+        "lock" => ResourceVoteChoice::Lock,
+        "abstain" => ResourceVoteChoice::Abstain,
+        _ => {
+            let contestant_id = Identifier::from_string(vote.choice.trim(), Encoding::Base58)
+                .map_err(|_| format!("invalid choice \"{}\"", vote.choice))?;
+            if !contest
+                .contestants
+                .iter()
+                .flatten()
+                .any(|contestant| contestant.id == contestant_id)
+            {
+                return Err(format!(
+                    "{} is not a contestant for \"{}\"",
+                    vote.choice.trim(),
+                    contested_name
+                ));
+            }
+            ResourceVoteChoice::TowardsIdentity(contestant_id)
+        }
+    };
+
+    let unix_timestamp = parse_scheduled_time(&vote.scheduled_time)?;
+    if contest
+        .end_time
+        .is_some_and(|end_time| unix_timestamp >= end_time)
+    {
+        return Err(format!(
+            "the time is after the contest for \"{}\" ends",
+            contested_name
+        ));
+    }
+
+    Ok(ScheduledDPNSVote {
+        contested_name,
+        voter_id,
+        choice,
+        unix_timestamp,
+        executed_successfully: false,
+    })
+}
+
+/// Parses an RFC 3339 time, or unix milliseconds, into unix milliseconds
+fn parse_scheduled_time(scheduled_time: &str) -> Result<u64, String> {
+    let scheduled_time = scheduled_time.trim();
+    if let Ok(millis) = scheduled_time.parse::<u64>() {
+        return Ok(millis);
+    }
+    DateTime::parse_from_rfc3339(scheduled_time)
+        .ok()
+        .and_then(|time| u64::try_from(time.timestamp_millis()).ok())
+        .ok_or_else(|| format!("invalid time \"{}\"", scheduled_time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::contested_name::Contestant;
+
+    #[test]
+    fn test_parse_scheduled_votes_import() {
+        let voter = Identifier::new([7; 32]);
+        let contestant = Identifier::new([1; 32]);
+        let contest = |name: &str, state| ContestedName {
+            normalized_contested_name: name.to_string(),
+            contestants: Some(vec![Contestant {
+                id: contestant,
+                name: name.to_string(),
+                info: String::new(),
+                votes: 0,
+                created_at: None,
+                created_at_block_height: None,
+                created_at_core_block_height: None,
+                document_id: contestant,
+            }]),
+            locked_votes: None,
+            abstain_votes: None,
+            awarded_to: None,
+            end_time: Some(2_000_000_000_000),
+            state,
+            last_updated: None,
+            my_votes: Default::default(),
+        };
+        let contests = [
+            contest("a11ce", ContestState::Ongoing),
+            contest("b0b", ContestState::Locked),
+        ];
+        let voter_b58 = voter.to_string(Encoding::Base58);
+        let contestant_b58 = contestant.to_string(Encoding::Base58);
+
+        let csv = format!(
+            "contested_name,voter_id,choice,scheduled_time\n\
+             a11ce,{voter_b58},{contestant_b58},2030-01-01T00:00:00Z\n\
+             \n\
+             a11ce,{voter_b58},Lock,1900000000000\n\
+             b0b,{voter_b58},abstain,1900000000000\n\
+             a11ce,{contestant_b58},abstain,1900000000000\n\
+             a11ce,{voter_b58},{voter_b58},1900000000000\n\
+             a11ce,{voter_b58},abstain,2100000000000\n\
+             a11ce,{voter_b58}\n"
+        );
+        let rows = parse_scheduled_votes_import(&csv, false, &[voter], &contests).unwrap();
+        let lines: Vec<usize> = rows.iter().map(|row| row.line).collect();
+        assert_eq!(lines, vec![2, 4, 5, 6, 7, 8, 9]);
+
+        let first = rows[0].result.as_ref().unwrap();
+        assert_eq!(
+            first.choice,
+            ResourceVoteChoice::TowardsIdentity(contestant)
+        );
+        assert_eq!(first.unix_timestamp, 1_893_456_000_000);
+        assert_eq!(
+            rows[1].result.as_ref().unwrap().choice,
+            ResourceVoteChoice::Lock
+        );
+        assert!(rows[2].result.as_ref().unwrap_err().contains("has ended"));
+        assert!(
+            rows[3]
+                .result
+                .as_ref()
+                .unwrap_err()
+                .contains("voting identity")
+        );
+        assert!(
+            rows[4]
+                .result
+                .as_ref()
+                .unwrap_err()
+                .contains("not a contestant")
+        );
+        assert!(rows[5].result.as_ref().unwrap_err().contains("contest for"));
+        assert!(rows[6].result.as_ref().unwrap_err().contains("4 fields"));
+
+        let json = format!(
+            r#"[{{"contested_name": "A11CE", "voter_id": "{voter_b58}", "choice": "abstain",
+                 "scheduled_time": "1900000000000"}}]"#
+        );
+        let rows = parse_scheduled_votes_import(&json, true, &[voter], &contests).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].result.as_ref().unwrap().contested_name, "a11ce");
+
+        assert!(parse_scheduled_votes_import("{", true, &[voter], &contests).is_err());
+    }
+}
@@ -3,8 +3,16 @@ use std::sync::Arc;
 use crate::{
     app::AppAction,
     context::AppContext,
-    model::{qualified_contract::QualifiedContract, qualified_identity::QualifiedIdentity},
+    model::{
+        identity_tag::IdentityTag, qualified_contract::QualifiedContract,
+        qualified_identity::QualifiedIdentity,
+    },
+    utils::json_validation::JsonValidationError,
 };
+use chrono::{LocalResult, TimeZone, Utc};
+use chrono_humanize::HumanTime;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::version::PlatformVersion;
 use dash_sdk::{
     dpp::{
         data_contract::{
@@ -20,8 +28,9 @@ use dash_sdk::{
     },
     platform::{Identifier, IdentityPublicKey},
 };
-use egui::{Color32, ComboBox, Response, Ui};
+use egui::{Color32, ComboBox, Response, RichText, Ui};
 
+use super::theme::DashColors;
 use super::tokens::tokens_screen::IdentityTokenInfo;
 
 /// Helper function to create a styled info icon button
@@ -54,9 +63,28 @@ pub fn info_icon_button(ui: &mut egui::Ui, hover_text: &str) -> Response {
     response.on_hover_text(hover_text)
 }
 
+/// Paints the colored dot of an identity tag, with the tag name on hover
+pub fn render_tag_dot(ui: &mut Ui, tag: &IdentityTag) -> Response {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let [r, g, b] = tag.color;
+        ui.painter()
+            .circle_filled(rect.center(), 5.0, Color32::from_rgb(r, g, b));
+    }
+    response.on_hover_text(&tag.name)
+}
+
+/// Paints the dot of the tag assigned to an identity, if it has one
+pub fn render_identity_tag_dot(ui: &mut Ui, app_context: &AppContext, identity_id: &Identifier) {
+    if let Some(tag) = app_context.identity_tag(identity_id) {
+        render_tag_dot(ui, &tag);
+    }
+}
+
 /// Returns the newly selected identity (if changed), otherwise the existing one.
 pub fn render_identity_selector(
     ui: &mut Ui,
+    app_context: &AppContext,
     qualified_identities: &[QualifiedIdentity],
     selected_identity: &Option<QualifiedIdentity>,
 ) -> Option<QualifiedIdentity> {
@@ -64,6 +92,9 @@ pub fn render_identity_selector(
 
     ui.horizontal(|ui| {
         ui.label("Identity:");
+        if let Some(selected_identity) = selected_identity {
+            render_identity_tag_dot(ui, app_context, &selected_identity.identity.id());
+        }
         ComboBox::from_id_salt("identity_selector")
             .selected_text(
                 selected_identity
@@ -84,12 +115,15 @@ pub fn render_identity_selector(
                         .unwrap_or(&qi.identity.id().to_string(Encoding::Base58))
                         .clone();
 
-                    if cb
-                        .selectable_label(selected_identity.as_ref() == Some(qi), label)
-                        .clicked()
-                    {
-                        new_selected_identity = Some(qi.clone());
-                    }
+                    cb.horizontal(|ui| {
+                        render_identity_tag_dot(ui, app_context, &qi.identity.id());
+                        if ui
+                            .selectable_label(selected_identity.as_ref() == Some(qi), label)
+                            .clicked()
+                        {
+                            new_selected_identity = Some(qi.clone());
+                        }
+                    });
                 }
             });
     });
@@ -135,7 +169,7 @@ pub fn render_key_selector(
 }
 
 /// Transaction types that require specific key filtering
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TransactionType {
     /// Register a new data contract - requires Authentication keys with High or Critical security level
     RegisterContract,
@@ -204,6 +238,130 @@ impl TransactionType {
             TransactionType::TokenClaim => "Token Claim",
         }
     }
+
+    /// Returns the identifier used for this transaction type in the database
+    pub fn storage_key(&self) -> &'static str {
+        match self {
+            TransactionType::RegisterContract => "register_contract",
+            TransactionType::UpdateContract => "update_contract",
+            TransactionType::Transfer => "transfer",
+            TransactionType::Withdraw => "withdraw",
+            TransactionType::DocumentAction => "document_action",
+            TransactionType::TokenAction => "token_action",
+            TransactionType::TokenTransfer => "token_transfer",
+            TransactionType::TokenClaim => "token_claim",
+        }
+    }
+
+    /// The minimum fee Platform charges for a transition of this type. The actual fee also
+    /// depends on the processing and storage the transition causes, so it can be higher.
+    pub fn estimated_fee(&self, platform_version: &PlatformVersion) -> Credits {
+        let min_fees = &platform_version.fee_version.state_transition_min_fees;
+        match self {
+            TransactionType::RegisterContract => min_fees.contract_create,
+            TransactionType::UpdateContract => min_fees.contract_update,
+            TransactionType::Transfer => min_fees.credit_transfer,
+            TransactionType::Withdraw => min_fees.credit_withdrawal,
+            TransactionType::DocumentAction
+            | TransactionType::TokenAction
+            | TransactionType::TokenTransfer
+            | TransactionType::TokenClaim => min_fees.document_batch_sub_transition,
+        }
+    }
+
+    /// Parses an identifier returned by [`TransactionType::storage_key`]
+    pub fn from_storage_key(key: &str) -> Option<Self> {
+        match key {
+            "register_contract" => Some(TransactionType::RegisterContract),
+            "update_contract" => Some(TransactionType::UpdateContract),
+            "transfer" => Some(TransactionType::Transfer),
+            "withdraw" => Some(TransactionType::Withdraw),
+            "document_action" => Some(TransactionType::DocumentAction),
+            "token_action" => Some(TransactionType::TokenAction),
+            "token_transfer" => Some(TransactionType::TokenTransfer),
+            "token_claim" => Some(TransactionType::TokenClaim),
+            _ => None,
+        }
+    }
+
+    /// Checks that `key` is set and can sign this transaction type.
+    ///
+    /// In developer mode any key is accepted, matching what the key chooser offers.
+    pub fn validate_signing_key(
+        &self,
+        key: Option<&IdentityPublicKey>,
+        is_dev_mode: bool,
+    ) -> Result<(), String> {
+        let Some(key) = key else {
+            return Err("Please select a key to sign the transaction with".to_string());
+        };
+        if is_dev_mode {
+            return Ok(());
+        }
+        if !self.allowed_purposes().contains(&key.purpose()) {
+            return Err(format!(
+                "Key {} has purpose {:?}, which cannot sign a {} transaction",
+                key.id(),
+                key.purpose(),
+                self.label()
+            ));
+        }
+        if !self
+            .allowed_security_levels()
+            .contains(&key.security_level())
+        {
+            return Err(format!(
+                "Key {} has security level {}, which cannot sign a {} transaction",
+                key.id(),
+                key.security_level(),
+                self.label()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Adds a submit button, followed by the estimated fee of the transition in credits and Dash.
+/// The button is only enabled when `signing_key` can sign `transaction_type` and `signer` can
+/// afford the fee. Hovering the disabled button explains what is wrong.
+pub fn add_signing_key_guarded_button(
+    ui: &mut Ui,
+    button: egui::Button,
+    signing_key: Option<&IdentityPublicKey>,
+    signer: &QualifiedIdentity,
+    transaction_type: TransactionType,
+    app_context: &AppContext,
+) -> Response {
+    let fee = transaction_type.estimated_fee(app_context.platform_version());
+    let balance = signer.identity.balance();
+    ui.horizontal(|ui| {
+        let response = match transaction_type
+            .validate_signing_key(signing_key, app_context.is_developer_mode())
+        {
+            Ok(()) if balance < fee => {
+                ui.add_enabled(false, button)
+                    .on_disabled_hover_text(format!(
+                        "The identity has {} credits, {} short of the estimated fee",
+                        balance,
+                        fee - balance
+                    ))
+            }
+            Ok(()) => ui.add(button),
+            Err(e) => ui.add_enabled(false, button).on_disabled_hover_text(e),
+        };
+        ui.label(
+            RichText::new(format!(
+                "Estimated fee: {} credits (~{:.8} DASH)",
+                fee,
+                fee as f64 / 100_000_000_000.0
+            ))
+            .small()
+            .color(DashColors::TEXT_SECONDARY),
+        )
+        .on_hover_text("The minimum fee for this transition, the final fee can be higher");
+        response
+    })
+    .inner
 }
 
 /// Identity key chooser that filters keys based on transaction type and dev mode
@@ -241,6 +399,50 @@ pub fn add_identity_key_chooser_with_doc_type<'a, T>(
     T: Iterator<Item = &'a QualifiedIdentity>,
 {
     let is_dev_mode = app_context.is_developer_mode();
+    let allowed_purposes = transaction_type.allowed_purposes();
+    let allowed_security_levels =
+        if transaction_type == TransactionType::DocumentAction && document_type.is_some() {
+            // For document actions with a specific document type, use its security requirement
+            let required_level = document_type.unwrap().security_level_requirement();
+            let allowed_levels = SecurityLevel::CRITICAL as u8..=required_level as u8;
+            let allowed_levels: Vec<SecurityLevel> = [
+                SecurityLevel::CRITICAL,
+                SecurityLevel::HIGH,
+                SecurityLevel::MEDIUM,
+            ]
+            .iter()
+            .cloned()
+            .filter(|level| allowed_levels.contains(&(*level as u8)))
+            .collect();
+            allowed_levels
+        } else {
+            transaction_type.allowed_security_levels()
+        };
+
+    // Pre-select the key this identity last signed this kind of transaction with. If that key
+    // is no longer usable, the key chosen by the screen is kept.
+    if let Some(qi) = selected_identity.as_ref() {
+        if let Some(key_id) =
+            app_context.remembered_signing_key_id(&qi.identity.id(), transaction_type)
+        {
+            if selected_key.as_ref().map(|key| key.id()) != Some(key_id) {
+                let remembered_key = qi
+                    .private_keys
+                    .identity_public_keys()
+                    .into_iter()
+                    .map(|key_ref| &key_ref.1.identity_public_key)
+                    .find(|key| {
+                        key.id() == key_id
+                            && (is_dev_mode
+                                || (allowed_purposes.contains(&key.purpose())
+                                    && allowed_security_levels.contains(&key.security_level())))
+                    });
+                if let Some(key) = remembered_key {
+                    *selected_key = Some(key.clone());
+                }
+            }
+        }
+    }
 
     egui::Grid::new("identity_key_chooser_grid")
         .num_columns(2)
@@ -300,30 +502,6 @@ pub fn add_identity_key_chooser_with_doc_type<'a, T>(
                     )
                     .show_ui(ui, |kui| {
                         if let Some(qi) = selected_identity {
-                            let allowed_purposes = transaction_type.allowed_purposes();
-                            let allowed_security_levels = if transaction_type
-                                == TransactionType::DocumentAction
-                                && document_type.is_some()
-                            {
-                                // For document actions with a specific document type, use its security requirement
-                                let required_level =
-                                    document_type.unwrap().security_level_requirement();
-                                let allowed_levels =
-                                    SecurityLevel::CRITICAL as u8..=required_level as u8;
-                                let allowed_levels: Vec<SecurityLevel> = [
-                                    SecurityLevel::CRITICAL,
-                                    SecurityLevel::HIGH,
-                                    SecurityLevel::MEDIUM,
-                                ]
-                                .iter()
-                                .cloned()
-                                .filter(|level| allowed_levels.contains(&(*level as u8)))
-                                .collect();
-                                allowed_levels
-                            } else {
-                                transaction_type.allowed_security_levels()
-                            };
-
                             for key_ref in qi.private_keys.identity_public_keys() {
                                 let key = &key_ref.1.identity_public_key;
 
@@ -361,6 +539,16 @@ pub fn add_identity_key_chooser_with_doc_type<'a, T>(
                                         .clicked()
                                     {
                                         *selected_key = Some(key.clone());
+                                        if let Err(e) = app_context.remember_signing_key(
+                                            qi.identity.id(),
+                                            transaction_type,
+                                            key.id(),
+                                        ) {
+                                            tracing::error!(
+                                                "Failed to remember signing key: {}",
+                                                e
+                                            );
+                                        }
                                     }
                                 }
                             }
@@ -658,3 +846,314 @@ pub fn show_success_screen(
     });
     action
 }
+
+/// Maximum number of JSON validation errors rendered at once.
+const MAX_DISPLAYED_JSON_ERRORS: usize = 10;
+
+/// Renders field-level JSON validation errors in a scrollable panel.
+///
+/// Only the first few errors are shown, with a note about how many were omitted.
+pub fn render_json_validation_errors(ui: &mut Ui, errors: &[JsonValidationError]) {
+    if errors.is_empty() {
+        return;
+    }
+
+    ui.colored_label(
+        Color32::RED,
+        format!("Found {} validation error(s):", errors.len()),
+    );
+    ui.add_space(5.0);
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        egui::ScrollArea::vertical()
+            .id_salt("json_validation_errors")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                for error in errors.iter().take(MAX_DISPLAYED_JSON_ERRORS) {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(egui::RichText::new(&error.path).monospace().strong());
+                        ui.colored_label(Color32::RED, &error.reason);
+                    });
+                }
+                if errors.len() > MAX_DISPLAYED_JSON_ERRORS {
+                    ui.label(format!(
+                        "…and {} more",
+                        errors.len() - MAX_DISPLAYED_JSON_ERRORS
+                    ));
+                }
+            });
+    });
+}
+
+/// Shortens `text` to at most `max_chars` characters by replacing its middle with an ellipsis,
+/// e.g. `abc123…xyz789`. A few characters are always kept on each side so the result stays
+/// recognizable even in very narrow columns.
+pub fn middle_ellipsize(text: &str, max_chars: usize) -> String {
+    const MIN_KEPT_CHARS: usize = 6;
+
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return text.to_string();
+    }
+    // One character is taken by the ellipsis itself
+    let kept = max_chars.saturating_sub(1).max(MIN_KEPT_CHARS);
+    if kept + 1 >= char_count {
+        return text.to_string();
+    }
+    let head = kept.div_ceil(2);
+    let tail = kept / 2;
+    let prefix: String = text.chars().take(head).collect();
+    let suffix: String = text.chars().skip(char_count - tail).collect();
+    format!("{}…{}", prefix, suffix)
+}
+
+/// Width reserved for the copy button next to an identifier in a table cell.
+const IDENTIFIER_COPY_BUTTON_WIDTH: f32 = 24.0;
+
+/// How long a copy control keeps showing its confirmation, in seconds.
+const COPY_CONFIRMATION_SECS: f64 = 1.5;
+
+/// Copies `text` to the clipboard and remembers when, so the copy control identified by `id`
+/// can confirm it with [`recently_copied`].
+pub fn copy_with_confirmation(ctx: &egui::Context, id: egui::Id, text: String) {
+    ctx.copy_text(text);
+    let now = ctx.input(|i| i.time);
+    ctx.data_mut(|data| data.insert_temp(id, now));
+}
+
+/// Whether the copy control identified by `id` copied something moments ago. Keeps repainting
+/// until the confirmation expires.
+pub fn recently_copied(ctx: &egui::Context, id: egui::Id) -> bool {
+    let Some(copied_at) = ctx.data(|data| data.get_temp::<f64>(id)) else {
+        return false;
+    };
+    let remaining = COPY_CONFIRMATION_SECS - (ctx.input(|i| i.time) - copied_at);
+    if remaining <= 0.0 {
+        return false;
+    }
+    ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+    true
+}
+
+/// Renders an identifier in a table cell, middle-ellipsized to fit the column width, with the
+/// full value on hover and a button to copy it, which briefly turns into a check mark once
+/// clicked.
+///
+/// Returns the label's response so callers can attach additional hover text.
+pub fn render_identifier_cell(
+    ui: &mut Ui,
+    identifier: &Identifier,
+    color: Option<Color32>,
+) -> Response {
+    let full_text = identifier.to_string(Encoding::Base58);
+    ui.horizontal(|ui| {
+        let available_width =
+            (ui.available_width() - IDENTIFIER_COPY_BUTTON_WIDTH - ui.spacing().item_spacing.x)
+                .max(0.0);
+        let font_id = egui::TextStyle::Body.resolve(ui.style());
+        let full_width = ui.fonts(|fonts| {
+            fonts
+                .layout_no_wrap(full_text.clone(), font_id, Color32::PLACEHOLDER)
+                .size()
+                .x
+        });
+        let display_text = if full_width <= available_width {
+            full_text.clone()
+        } else {
+            let max_chars =
+                (full_text.chars().count() as f32 * available_width / full_width) as usize;
+            middle_ellipsize(&full_text, max_chars)
+        };
+
+        let mut text = RichText::new(display_text);
+        if let Some(color) = color {
+            text = text.color(color);
+        }
+        let response = ui
+            .add(egui::Label::new(text).sense(egui::Sense::hover()))
+            .on_hover_text(&full_text);
+        let copy_id = ui.id().with(("copy_identifier", &full_text));
+        let (icon, hover_text) = if recently_copied(ui.ctx(), copy_id) {
+            ("✔", "Copied")
+        } else {
+            ("📋", "Copy to clipboard")
+        };
+        if ui.small_button(icon).on_hover_text(hover_text).clicked() {
+            copy_with_confirmation(ui.ctx(), copy_id, full_text.clone());
+        }
+        response
+    })
+    .inner
+}
+
+/// Keyboard shortcut pressed while a confirmation dialog is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogKeyAction {
+    None,
+    Confirm,
+    Cancel,
+}
+
+/// Checks for Enter (confirm) and Esc (cancel) while a dialog is open.
+///
+/// Call this from inside the dialog's window so the shortcuts only apply while it is shown.
+/// Enter is ignored while a text field has keyboard focus, so multiline editors keep inserting
+/// newlines instead of submitting the dialog.
+pub fn dialog_key_action(ctx: &egui::Context) -> DialogKeyAction {
+    if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape)) {
+        return DialogKeyAction::Cancel;
+    }
+    if !ctx.wants_keyboard_input()
+        && ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Enter))
+    {
+        return DialogKeyAction::Confirm;
+    }
+    DialogKeyAction::None
+}
+
+/// How [`format_timestamp_millis`] writes out a valid timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle {
+    /// `2025-01-31 12:00:00`
+    Absolute,
+    /// `3 hours ago`, or `now` within the last minute
+    Relative,
+    /// `2025-01-31 12:00:00 (3 hours ago)`
+    AbsoluteAndRelative,
+}
+
+/// Formats a Unix timestamp in milliseconds for display in tables.
+///
+/// Values chrono can't represent are shown as "Invalid timestamp". In developer mode the raw
+/// value is appended so bad stored data can be tracked down.
+pub fn format_timestamp_millis(
+    timestamp_ms: u64,
+    style: TimestampStyle,
+    developer_mode: bool,
+) -> String {
+    let LocalResult::Single(dt) = i64::try_from(timestamp_ms)
+        .map(|ms| Utc.timestamp_millis_opt(ms))
+        .unwrap_or(LocalResult::None)
+    else {
+        return if developer_mode {
+            format!("Invalid timestamp ({} ms)", timestamp_ms)
+        } else {
+            "Invalid timestamp".to_string()
+        };
+    };
+
+    let absolute = || dt.format("%Y-%m-%d %H:%M:%S").to_string();
+    let relative = || {
+        let relative = HumanTime::from(dt).to_string();
+        if relative.contains("seconds") {
+            "now".to_string()
+        } else {
+            relative
+        }
+    };
+    match style {
+        TimestampStyle::Absolute => absolute(),
+        TimestampStyle::Relative => relative(),
+        TimestampStyle::AbsoluteAndRelative => format!("{} ({})", absolute(), relative()),
+    }
+}
+
+/// Quotes a CSV field when it contains a separator, quote or line break.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats a raw token amount with the token's decimals, e.g. 150000000 with 8 decimals as
+/// "1.5". Trailing zeros of the fraction are left out.
+pub fn format_token_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+
+    let divisor = 10u64.pow(decimals as u32);
+    let whole = amount / divisor;
+    let fraction = amount % divisor;
+
+    if fraction == 0 {
+        whole.to_string()
+    } else {
+        // Format with the appropriate number of decimal places, removing trailing zeros
+        let fraction_str = format!("{:0width$}", fraction, width = decimals as usize);
+        let trimmed = fraction_str.trim_end_matches('0');
+        format!("{}.{}", whole, trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(100_000_000, 8), "1");
+        assert_eq!(format_token_amount(150_000_000, 8), "1.5");
+        assert_eq!(format_token_amount(1, 8), "0.00000001");
+        assert_eq!(format_token_amount(123_456, 2), "1234.56");
+        assert_eq!(format_token_amount(0, 8), "0");
+
+        // Without decimals the raw amount is the token amount
+        assert_eq!(format_token_amount(100_000_000, 0), "100000000");
+        assert_eq!(format_token_amount(0, 0), "0");
+    }
+
+    #[test]
+    fn test_transaction_type_storage_key_round_trip() {
+        for transaction_type in [
+            TransactionType::RegisterContract,
+            TransactionType::UpdateContract,
+            TransactionType::Transfer,
+            TransactionType::Withdraw,
+            TransactionType::DocumentAction,
+            TransactionType::TokenAction,
+            TransactionType::TokenTransfer,
+            TransactionType::TokenClaim,
+        ] {
+            assert_eq!(
+                TransactionType::from_storage_key(transaction_type.storage_key()),
+                Some(transaction_type)
+            );
+        }
+        assert_eq!(TransactionType::from_storage_key("unknown"), None);
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("alice"), "alice");
+        assert_eq!(csv_field("alice, bob"), "\"alice, bob\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_format_timestamp_millis() {
+        let ms = 1_700_000_000_000;
+        assert_eq!(
+            format_timestamp_millis(ms, TimestampStyle::Absolute, false),
+            "2023-11-14 22:13:20"
+        );
+        assert_eq!(
+            format_timestamp_millis(u64::MAX, TimestampStyle::Absolute, false),
+            "Invalid timestamp"
+        );
+        assert_eq!(
+            format_timestamp_millis(u64::MAX, TimestampStyle::Relative, true),
+            format!("Invalid timestamp ({} ms)", u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_middle_ellipsize() {
+        let id = "GWRSAVFMjXx8HpQFaNJMqBV7MBgMK4br5UESsB4S31Ec";
+        assert_eq!(middle_ellipsize(id, 100), id);
+        assert_eq!(middle_ellipsize(id, 13), "GWRSAV…4S31Ec");
+        assert_eq!(middle_ellipsize(id, 0), "GWR…1Ec");
+        assert_eq!(middle_ellipsize("abcdefg", 3), "abcdefg");
+    }
+}
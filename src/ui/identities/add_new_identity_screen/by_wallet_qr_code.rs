@@ -3,12 +3,12 @@ use crate::backend_task::BackendTask;
 use crate::backend_task::identity::{
     IdentityRegistrationInfo, IdentityTask, RegisterIdentityFundingMethod,
 };
+use crate::ui::components::qr_code::qr_code;
 use crate::ui::identities::add_new_identity_screen::{
     AddNewIdentityScreen, WalletFundedScreenStep,
 };
-use crate::ui::identities::funding_common::{copy_to_clipboard, generate_qr_code_image};
+use crate::ui::identities::funding_common::copy_to_clipboard;
 use dash_sdk::dashcore_rpc::RpcApi;
-use eframe::epaint::TextureHandle;
 use egui::{Color32, Ui};
 use std::sync::Arc;
 
@@ -83,15 +83,7 @@ impl AddNewIdentityScreen {
 
         let pay_uri = format!("{}?amount={:.4}", address.to_qr_uri(), amount);
 
-        // Generate the QR code image
-        if let Ok(qr_image) = generate_qr_code_image(&pay_uri) {
-            let texture: TextureHandle =
-                ui.ctx()
-                    .load_texture("qr_code", qr_image, egui::TextureOptions::LINEAR);
-            ui.image(&texture);
-        } else {
-            ui.label("Failed to generate QR code.");
-        }
+        qr_code(ui, &pay_uri, 4.0);
 
         ui.add_space(10.0);
 
@@ -15,7 +15,7 @@ use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::identities::funding_common::WalletFundedScreenStep;
+use crate::ui::identities::funding_common::{WalletFundedScreenStep, funding_wallet_label};
 use crate::ui::{MessageType, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Address;
 use dash_sdk::dashcore_rpc::dashcore::transaction::special_transaction::TransactionPayload;
@@ -343,11 +343,11 @@ impl AddNewIdentityScreen {
         let rendered = if self.app_context.has_wallet.load(Ordering::Relaxed) {
             let wallets = &self.app_context.wallets.read().unwrap();
             if wallets.len() > 1 {
-                // Retrieve the alias of the currently selected wallet, if any
-                let selected_wallet_alias = self
+                // Retrieve the label of the currently selected wallet, if any
+                let selected_wallet_label = self
                     .selected_wallet
                     .as_ref()
-                    .and_then(|wallet| wallet.read().ok()?.alias.clone())
+                    .and_then(|wallet| wallet.read().ok().map(|w| funding_wallet_label(&w)))
                     .unwrap_or_else(|| "Select".to_string());
 
                 ui.heading(
@@ -356,21 +356,20 @@ impl AddNewIdentityScreen {
 
                 // Display the ComboBox for wallet selection
                 ComboBox::from_id_salt("select_wallet")
-                    .selected_text(selected_wallet_alias)
+                    .selected_text(selected_wallet_label)
                     .show_ui(ui, |ui| {
                         for wallet in wallets.values() {
-                            let wallet_alias = wallet
+                            let wallet_label = wallet
                                 .read()
-                                .ok()
-                                .and_then(|w| w.alias.clone())
-                                .unwrap_or_else(|| "Unnamed Wallet".to_string());
+                                .map(|w| funding_wallet_label(&w))
+                                .unwrap_or_else(|_| "Unnamed Wallet".to_string());
 
                             let is_selected = self
                                 .selected_wallet
                                 .as_ref()
                                 .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
 
-                            if ui.selectable_label(is_selected, wallet_alias).clicked() {
+                            if ui.selectable_label(is_selected, wallet_label).clicked() {
                                 // Update the selected wallet
                                 selected_wallet = Some(wallet.clone());
                             }
@@ -1,7 +1,5 @@
+use crate::model::wallet::Wallet;
 use arboard::Clipboard;
-use eframe::epaint::{Color32, ColorImage};
-use image::Luma;
-use qrcode::QrCode;
 
 #[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
 pub enum WalletFundedScreenStep {
@@ -14,26 +12,13 @@ pub enum WalletFundedScreenStep {
     Success,
 }
 
-// Function to generate a QR code image from the address
-pub fn generate_qr_code_image(pay_uri: &str) -> Result<ColorImage, qrcode::types::QrError> {
-    // Generate the QR code
-    let code = QrCode::new(pay_uri.as_bytes())?;
-
-    // Render the QR code into an image buffer
-    let image = code.render::<Luma<u8>>().build();
-
-    // Convert the image buffer to ColorImage
-    let size = [image.width() as usize, image.height() as usize];
-    let pixels = image.into_raw();
-    let pixels: Vec<Color32> = pixels
-        .into_iter()
-        .map(|p| {
-            let color = 255 - p; // Invert colors for better visibility
-            Color32::from_rgba_unmultiplied(color, color, color, 255)
-        })
-        .collect();
-
-    Ok(ColorImage { size, pixels })
+/// Label of a wallet in funding wallet selectors: its alias and spendable balance
+pub fn funding_wallet_label(wallet: &Wallet) -> String {
+    format!(
+        "{} ({:.8} DASH)",
+        wallet.alias.as_deref().unwrap_or("Unnamed Wallet"),
+        wallet.max_balance() as f64 * 1e-8
+    )
 }
 
 pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
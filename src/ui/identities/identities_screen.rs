@@ -3,6 +3,7 @@ use crate::app::{AppAction, BackendTasksExecutionMode, DesiredAppAction};
 use crate::backend_task::BackendTask;
 use crate::backend_task::identity::IdentityTask;
 use crate::context::AppContext;
+use crate::model::identity_tag::IdentityTag;
 use crate::model::qualified_identity::PrivateKeyTarget::{
     PrivateKeyOnMainIdentity, PrivateKeyOnVoterIdentity,
 };
@@ -10,11 +11,14 @@ use crate::model::qualified_identity::{IdentityStatus, IdentityType, QualifiedId
 use crate::model::wallet::WalletSeedHash;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::table_column_widths::TableColumnWidths;
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::{DialogKeyAction, dialog_key_action, render_tag_dot};
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
 use crate::ui::identities::top_up_identity_screen::TopUpIdentityScreen;
 use crate::ui::identities::transfer_screen::TransferScreen;
+use crate::ui::theme::DashColors;
 use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike, ScreenType};
 use chrono::{DateTime, Utc};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
@@ -63,6 +67,9 @@ pub struct IdentitiesScreen {
     use_custom_order: bool,
     refreshing_status: IdentitiesRefreshingStatus,
     backend_message: Option<(String, MessageType, DateTime<Utc>)>,
+    new_tag_name: String,
+    new_tag_color: [u8; 3],
+    column_widths: TableColumnWidths,
 }
 
 impl IdentitiesScreen {
@@ -86,6 +93,9 @@ impl IdentitiesScreen {
             use_custom_order: true,
             refreshing_status: IdentitiesRefreshingStatus::NotRefreshing,
             backend_message: None,
+            new_tag_name: String::new(),
+            new_tag_color: [0, 141, 228],
+            column_widths: TableColumnWidths::load(app_context, "identities"),
         };
 
         if let Ok(saved_ids) = screen.app_context.db.load_identity_order() {
@@ -253,6 +263,161 @@ impl IdentitiesScreen {
         }
     }
 
+    /// Renders a warning badge when the identity can't sign platform transactions, which
+    /// opens the add key screen when the master key is available.
+    fn show_signing_warning(
+        &self,
+        ui: &mut Ui,
+        qualified_identity: &QualifiedIdentity,
+    ) -> AppAction {
+        let issues = qualified_identity.platform_signing_issues();
+        if issues.is_empty() {
+            return AppAction::None;
+        }
+
+        let can_add_key = qualified_identity.can_sign_with_master_key().is_some();
+        let mut hover_text = String::from("Cannot sign platform transactions:");
+        for issue in &issues {
+            hover_text.push_str(&format!("\n• {}", issue));
+        }
+        hover_text.push_str(if can_add_key {
+            "\n\nClick to add a key."
+        } else {
+            "\n\nLoad the master key's private key to add a new key."
+        });
+
+        let badge = egui::Button::new(RichText::new("⚠").color(DashColors::WARNING)).frame(false);
+        if ui.add(badge).on_hover_text(hover_text).clicked() && can_add_key {
+            return AppAction::AddScreen(Screen::AddKeyScreen(AddKeyScreen::new(
+                qualified_identity.clone(),
+                &self.app_context,
+            )));
+        }
+        AppAction::None
+    }
+
+    /// Renders the tag dot of an identity, which opens a menu to change its tag
+    fn show_tag_picker(&mut self, ui: &mut Ui, qualified_identity: &QualifiedIdentity) {
+        let identity_id = qualified_identity.identity.id();
+        let current_tag = self.app_context.identity_tag(&identity_id);
+        let dot = match &current_tag {
+            Some(tag) => {
+                let [r, g, b] = tag.color;
+                RichText::new("●").color(Color32::from_rgb(r, g, b))
+            }
+            None => RichText::new("○").weak(),
+        };
+
+        let mut new_tag = None;
+        ui.menu_button(dot, |ui| {
+            let tags = self.app_context.identity_tags();
+            if tags.is_empty() {
+                ui.label("No tags yet. Add some under Identity Tags.");
+            }
+            for tag in tags {
+                ui.horizontal(|ui| {
+                    render_tag_dot(ui, &tag);
+                    let is_selected = current_tag.as_ref() == Some(&tag);
+                    if ui.selectable_label(is_selected, &tag.name).clicked() {
+                        new_tag = Some(Some(tag.name.clone()));
+                        ui.close_menu();
+                    }
+                });
+            }
+            if current_tag.is_some() && ui.button("Remove tag").clicked() {
+                new_tag = Some(None);
+                ui.close_menu();
+            }
+        })
+        .response
+        .on_hover_text(
+            current_tag
+                .as_ref()
+                .map_or("Tag this identity", |tag| tag.name.as_str()),
+        );
+
+        if let Some(new_tag) = new_tag {
+            if let Err(e) = self.app_context.set_identity_tag(identity_id, new_tag) {
+                self.backend_message = Some((
+                    format!("Error tagging identity: {}", e),
+                    MessageType::Error,
+                    Utc::now(),
+                ));
+            }
+        }
+    }
+
+    /// Renders the list of identity tags with controls to add and delete them
+    fn render_identity_tags(&mut self, ui: &mut Ui) {
+        let tags = self.app_context.identity_tags();
+        egui::CollapsingHeader::new(format!("Identity Tags ({})", tags.len()))
+            .id_salt("identity_tags")
+            .show(ui, |ui| {
+                ui.label(
+                    RichText::new(
+                        "Tag identities, e.g. masternodes and user identities, to tell them apart \
+                         in identity selectors.",
+                    )
+                    .weak(),
+                );
+                ui.add_space(5.0);
+
+                for tag in &tags {
+                    ui.horizontal(|ui| {
+                        render_tag_dot(ui, tag);
+                        ui.label(&tag.name);
+                        let mut color = tag.color;
+                        if egui::color_picker::color_edit_button_srgb(ui, &mut color).changed() {
+                            self.save_identity_tag(IdentityTag {
+                                name: tag.name.clone(),
+                                color,
+                            });
+                        }
+                        if ui.small_button("Delete").clicked() {
+                            if let Err(e) = self.app_context.delete_identity_tag(&tag.name) {
+                                self.backend_message = Some((
+                                    format!("Error deleting tag: {}", e),
+                                    MessageType::Error,
+                                    Utc::now(),
+                                ));
+                            }
+                        }
+                    });
+                }
+
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.new_tag_name)
+                            .hint_text("New tag")
+                            .desired_width(120.0),
+                    );
+                    egui::color_picker::color_edit_button_srgb(ui, &mut self.new_tag_color);
+                    let name = self.new_tag_name.trim().to_string();
+                    if ui
+                        .add_enabled(!name.is_empty(), egui::Button::new("Add Tag"))
+                        .clicked()
+                    {
+                        self.save_identity_tag(IdentityTag {
+                            name,
+                            color: self.new_tag_color,
+                        });
+                        self.new_tag_name.clear();
+                    }
+                });
+            });
+        ui.add_space(5.0);
+    }
+
+    fn save_identity_tag(&mut self, tag: IdentityTag) {
+        if let Err(e) = self.app_context.save_identity_tag(tag) {
+            self.backend_message = Some((
+                format!("Error saving tag: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ));
+        }
+    }
+
     fn show_identity_id(ui: &mut Ui, qualified_identity: &QualifiedIdentity) {
         let (encoding, helper) = match qualified_identity.identity_type {
             IdentityType::User => (Encoding::Base58, "UserId".to_string()),
@@ -388,7 +553,118 @@ impl IdentitiesScreen {
         format!("{} - {} - {}", key.id(), purpose_letter, security_level)
     }
 
-    fn render_no_identities_view(&self, ui: &mut Ui) {
+    /// Asks for a file name and writes the public data of `qualified_identity` to it, for
+    /// sharing. Its private keys are left out.
+    fn export_public_identity(&mut self, qualified_identity: &QualifiedIdentity) {
+        let identity_id = qualified_identity.identity.id();
+        let tracked_tokens: Vec<Identifier> = self
+            .app_context
+            .identity_token_balances()
+            .unwrap_or_default()
+            .keys()
+            .filter(|id| id.identity_id == identity_id)
+            .map(|id| id.token_id)
+            .collect();
+        let export = qualified_identity.public_export(self.app_context.network, &tracked_tokens);
+
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!(
+                "identity-{}-public.json",
+                identity_id.to_string(Encoding::Base58)
+            ))
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match std::fs::write(&path, export) {
+            Ok(()) => self.display_message(
+                &format!("Exported the public identity to {}", path.display()),
+                MessageType::Success,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to export the identity: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    /// Asks for a file written by "Export" and loads the identity in it as a watch-only
+    /// identity, which can be viewed but not used to sign until its keys are added
+    fn import_public_identity(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+
+        let imported = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|export| {
+                QualifiedIdentity::from_public_export(&export, self.app_context.network)
+            })
+            .and_then(|(qualified_identity, tracked_tokens)| {
+                // Never replace a loaded identity, which may hold private keys
+                if self
+                    .identities
+                    .lock()
+                    .unwrap()
+                    .contains_key(&qualified_identity.identity.id())
+                {
+                    return Err("This identity is already loaded".to_string());
+                }
+                self.app_context
+                    .insert_local_qualified_identity(&qualified_identity, &None)
+                    .map_err(|e| e.to_string())?;
+                Ok((qualified_identity, tracked_tokens))
+            });
+
+        match imported {
+            Ok((qualified_identity, tracked_tokens)) => {
+                let known_tokens = self
+                    .app_context
+                    .db
+                    .get_all_known_tokens(&self.app_context)
+                    .unwrap_or_default();
+                let untracked = tracked_tokens
+                    .iter()
+                    .filter(|token_id| !known_tokens.contains_key(*token_id))
+                    .count();
+                self.refresh();
+                let mut message = format!(
+                    "Imported {} as a watch-only identity",
+                    qualified_identity.display_string()
+                );
+                if untracked > 0 {
+                    message.push_str(&format!(
+                        ". {} of its tokens aren't tracked yet, add them by contract ID on the Tokens screen",
+                        untracked
+                    ));
+                }
+                self.display_message(&message, MessageType::Success);
+            }
+            Err(e) => self.display_message(
+                &format!("Failed to import the identity: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn render_import_public_identity_button(&mut self, ui: &mut Ui) {
+        if ui
+            .button("Import Identity (public)")
+            .on_hover_text(
+                "Load an identity exported by someone else as watch-only, without its private keys",
+            )
+            .clicked()
+        {
+            self.import_public_identity();
+        }
+    }
+
+    fn render_no_identities_view(&mut self, ui: &mut Ui) {
         let dark_mode = ui.ctx().style().visuals.dark_mode;
 
         // Optionally put everything in a framed "card"-like container
@@ -436,7 +712,11 @@ impl IdentitiesScreen {
                          on \"Load Identity\" at the top right, or",
                     );
                     ui.add_space(1.0);
-                    ui.label("• REGISTER an Identity after creating or importing a wallet.");
+                    ui.label("• REGISTER an Identity after creating or importing a wallet, or");
+                    ui.add_space(1.0);
+                    ui.label("• IMPORT the public data of an identity someone shared with you:");
+                    ui.add_space(5.0);
+                    self.render_import_public_identity_button(ui);
 
                     ui.add_space(10.0);
                     ui.separator();
@@ -505,48 +785,69 @@ impl IdentitiesScreen {
 
         // Space allocation for UI elements is handled by the layout system
 
+        self.render_import_public_identity_button(ui);
+        self.render_identity_tags(ui);
+
         egui::ScrollArea::both().show(ui, |ui| {
+            let ctx = ui.ctx().clone();
+            let id_salt = self.column_widths.id_salt();
+            let widths = self
+                .column_widths
+                .initial_widths(&[110.0, 330.0, 60.0, 80.0, 140.0, 160.0]);
             TableBuilder::new(ui)
+                        .id_salt(id_salt)
                         .striped(false)
                         .resizable(true)
                         .cell_layout(egui::Layout::left_to_right(Align::Center))
-                        .column(Column::initial(80.0).resizable(true))   // Name
-                        .column(Column::initial(330.0).resizable(true))  // Identity ID
-                        .column(Column::initial(60.0).resizable(true))   // In Wallet
-                        .column(Column::initial(80.0).resizable(true))   // Type
-                        .column(Column::initial(140.0).resizable(true))  // Balance
-                        .column(Column::initial(160.0).resizable(true))  // Actions (wider for more buttons)
+                        .column(Column::initial(widths[0]).resizable(true))  // Name
+                        .column(Column::initial(widths[1]).resizable(true))  // Identity ID
+                        .column(Column::initial(widths[2]).resizable(true))   // In Wallet
+                        .column(Column::initial(widths[3]).resizable(true))   // Type
+                        .column(Column::initial(widths[4]).resizable(true))  // Balance
+                        .column(Column::initial(widths[5]).resizable(true))  // Actions (wider for more buttons)
                         .header(30.0, |mut header| {
                             header.col(|ui| {
-                                if ui.button("Name").clicked() {
+                                let response = ui.button("Name");
+                                self.column_widths.header_context_menu(&response, &self.app_context);
+                                if response.clicked() {
                                     self.toggle_sort(IdentitiesSortColumn::Alias);
                                 }
                             });
                             header.col(|ui| {
-                                if ui.button("Identity ID").clicked() {
+                                let response = ui.button("Identity ID");
+                                self.column_widths.header_context_menu(&response, &self.app_context);
+                                if response.clicked() {
                                     self.toggle_sort(IdentitiesSortColumn::IdentityID);
                                 }
                             });
                             header.col(|ui| {
-                                if ui.button("In Wallet").clicked() {
+                                let response = ui.button("In Wallet");
+                                self.column_widths.header_context_menu(&response, &self.app_context);
+                                if response.clicked() {
                                     self.toggle_sort(IdentitiesSortColumn::InWallet);
                                 }
                             });
                             header.col(|ui| {
-                                if ui.button("Type").clicked() {
+                                let response = ui.button("Type");
+                                self.column_widths.header_context_menu(&response, &self.app_context);
+                                if response.clicked() {
                                     self.toggle_sort(IdentitiesSortColumn::Type);
                                 }
                             });
                             header.col(|ui| {
-                                if ui.button("Balance").clicked() {
+                                let response = ui.button("Balance");
+                                self.column_widths.header_context_menu(&response, &self.app_context);
+                                if response.clicked() {
                                     self.toggle_sort(IdentitiesSortColumn::Balance);
                                 }
                             });
                             header.col(|ui| {
-                                ui.heading("");
+                                let response = ui.heading("");
+                                self.column_widths.header_context_menu(&response, &self.app_context);
                             });
                         })
                         .body(|mut body| {
+                            self.column_widths.track(&ctx, &self.app_context, body.widths());
                             for qualified_identity in &local_identities {
                                 let identity = &qualified_identity.identity;
                                 let public_keys = identity.public_keys();
@@ -564,7 +865,9 @@ impl IdentitiesScreen {
                                             ui.horizontal_centered(|ui| {
                                                 // Disable UI elements if identity is not active
                                                 ui.add_enabled_ui(is_active, |ui| {
+                                                    self.show_tag_picker(ui, qualified_identity);
                                                     self.show_alias(ui, qualified_identity);
+                                                    action |= self.show_signing_warning(ui, qualified_identity);
                                                 });
                                             });
                                         });
@@ -816,6 +1119,10 @@ impl IdentitiesScreen {
                                                     );
                                                 }
 
+                                                if ui.button("Export").on_hover_text("Save the public data of this identity (ID, keys, names and tracked tokens) to a file to share it. Private keys are never exported.").clicked() {
+                                                    self.export_public_identity(qualified_identity);
+                                                }
+
                                                 // Remove
                                                 if ui.button("Remove").on_hover_text("Remove this identity from Dash Evo Tool (it'll still exist on Dash Platform)").clicked() {
                                                     self.identity_to_remove =
@@ -862,6 +1169,7 @@ impl IdentitiesScreen {
                 .collapsible(false)
                 .resizable(false)
                 .show(ctx, |ui| {
+                    let key_action = dialog_key_action(ui.ctx());
                     ui.label(format!(
                         "Are you sure you want to no longer track this {} identity?",
                         identity_to_remove.identity_type
@@ -874,7 +1182,7 @@ impl IdentitiesScreen {
                             .to_string(identity_to_remove.identity_type.default_encoding())
                     ));
                     ui.horizontal(|ui| {
-                        if ui.button("Yes").clicked() {
+                        if ui.button("Yes").clicked() || key_action == DialogKeyAction::Confirm {
                             let identity_id = identity_to_remove.identity.id();
                             let mut lock = self.identities.lock().unwrap();
                             lock.shift_remove(&identity_id);
@@ -899,7 +1207,7 @@ impl IdentitiesScreen {
 
                             self.identity_to_remove = None;
                         }
-                        if ui.button("No").clicked() {
+                        if ui.button("No").clicked() || key_action == DialogKeyAction::Cancel {
                             self.identity_to_remove = None;
                         }
                     });
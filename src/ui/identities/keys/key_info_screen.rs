@@ -1,33 +1,43 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
-use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::qualified_identity::encrypted_key_storage::{
     PrivateKeyData, WalletDerivationPath,
 };
+use crate::model::qualified_identity::{PrivateKeyTarget, QualifiedIdentity};
 use crate::model::wallet::Wallet;
 use crate::ui::ScreenLike;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::qr_code::address_with_qr_toggle;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::{DialogKeyAction, dialog_key_action};
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use dash_sdk::dashcore_rpc::dashcore::PrivateKey as RPCPrivateKey;
 use dash_sdk::dpp::dashcore::address::Payload;
 use dash_sdk::dpp::dashcore::hashes::Hash;
-use dash_sdk::dpp::dashcore::secp256k1::{Message, Secp256k1, SecretKey};
+use dash_sdk::dpp::dashcore::secp256k1::ecdsa::{RecoverableSignature, RecoveryId, Signature};
+use dash_sdk::dpp::dashcore::secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
 use dash_sdk::dpp::dashcore::sign_message::signed_msg_hash;
 use dash_sdk::dpp::dashcore::{Address, PrivateKey, PubkeyHash, ScriptHash};
-use dash_sdk::dpp::identity::KeyType;
 use dash_sdk::dpp::identity::KeyType::BIP13_SCRIPT_HASH;
 use dash_sdk::dpp::identity::hash::IdentityPublicKeyHashMethodsV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::contract_bounds::ContractBounds;
+use dash_sdk::dpp::identity::{KeyID, KeyType, Purpose, SecurityLevel};
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::IdentityPublicKey;
 use eframe::egui::{self, Context};
 use egui::{Color32, RichText, ScrollArea, TextEdit};
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use zeroize::Zeroize;
+
+/// Seconds a revealed private key stays visible without interaction, unless changed
+const DEFAULT_PRIVATE_KEY_REVEAL_SECS: u64 = 30;
+/// Seconds after which a copied private key is cleared from the clipboard
+const CLIPBOARD_CLEAR_SECS: u64 = 30;
 
 pub struct KeyInfoScreen {
     pub identity: QualifiedIdentity,
@@ -43,11 +53,20 @@ pub struct KeyInfoScreen {
     message_input: String,
     signed_message: Option<String>,
     sign_error_message: Option<String>,
+    verify_message_input: String,
+    verify_signature_input: String,
+    verify_result: Option<Result<(), String>>,
     view_wallet_unlock: bool,
     wallet_open: bool,
     view_private_key_even_if_encrypted_or_in_wallet: bool,
     show_pop_up_info: Option<String>,
     show_confirm_remove_private_key: bool,
+    /// Whether the user acknowledged losing the actions only this key can sign
+    acknowledge_last_key_removal: bool,
+    /// Seconds the private key stays revealed without interaction
+    private_key_reveal_secs: u64,
+    /// When the revealed private key gets masked again, `None` while it is masked
+    private_key_hidden_at: Option<Instant>,
 }
 
 // /// The prefix for signed messages using Dash's message signing protocol.
@@ -62,6 +81,76 @@ pub struct KeyInfoScreen {
 //     sha256d::Hash::from_engine(engine)
 // }
 
+/// Signs a message with Dash's message signing protocol, returning the base64 signature
+fn sign_dash_message(message: &str, secret_key: &SecretKey) -> String {
+    let secp = Secp256k1::new();
+    let message_hash = signed_msg_hash(message);
+    let message = Message::from_digest(*message_hash.as_byte_array());
+
+    let signature = secp.sign_ecdsa(&message, secret_key);
+
+    // Serialize the signature
+    let mut serialized_signature = signature.serialize_compact().to_vec();
+    serialized_signature.insert(0, 32);
+
+    // Encode to Base64
+    STANDARD.encode(serialized_signature)
+}
+
+/// Checks a base64 signature made with Dash's message signing protocol against the data of
+/// an ECDSA key: the public key itself, or its hash for `ECDSA_HASH160` keys.
+fn verify_dash_message(
+    key_type: KeyType,
+    key_data: &[u8],
+    message: &str,
+    signature_base64: &str,
+) -> Result<(), String> {
+    let signature_bytes = STANDARD
+        .decode(signature_base64.trim())
+        .map_err(|_| "Signature is not valid base64.".to_string())?;
+    // Signatures are the 64-byte compact form, optionally preceded by a header byte
+    let compact = match signature_bytes.len() {
+        64 => signature_bytes.as_slice(),
+        65 => &signature_bytes[1..],
+        _ => return Err("Signature must be 64 or 65 bytes long.".to_string()),
+    };
+
+    let secp = Secp256k1::new();
+    let message_hash = signed_msg_hash(message);
+    let message = Message::from_digest(*message_hash.as_byte_array());
+
+    match key_type {
+        KeyType::ECDSA_SECP256K1 => {
+            let public_key = PublicKey::from_slice(key_data)
+                .map_err(|e| format!("Invalid public key: {}", e))?;
+            let mut signature = Signature::from_compact(compact)
+                .map_err(|e| format!("Invalid signature: {}", e))?;
+            signature.normalize_s();
+            secp.verify_ecdsa(&message, &signature, &public_key)
+                .map_err(|_| "Signature does not match this key.".to_string())
+        }
+        KeyType::ECDSA_HASH160 => {
+            // Only the key hash is known, so recover the signing key and compare its hash
+            let matches = (0..4).any(|recovery_id| {
+                RecoveryId::try_from(recovery_id)
+                    .and_then(|recovery_id| {
+                        RecoverableSignature::from_compact(compact, recovery_id)
+                    })
+                    .and_then(|signature| secp.recover_ecdsa(&message, &signature))
+                    .is_ok_and(|public_key| {
+                        PubkeyHash::hash(&public_key.serialize()).as_byte_array() == key_data
+                    })
+            });
+            if matches {
+                Ok(())
+            } else {
+                Err("Signature does not match this key.".to_string())
+            }
+        }
+        _ => Err("Unsupported key type for verification.".to_string()),
+    }
+}
+
 impl ScreenLike for KeyInfoScreen {
     fn refresh(&mut self) {}
 
@@ -82,6 +171,21 @@ impl ScreenLike for KeyInfoScreen {
             crate::ui::RootScreenType::RootScreenIdentities,
         );
 
+        // The wallet may have been locked again after inactivity, forget what it revealed
+        if self.wallet_open
+            && self
+                .selected_wallet
+                .as_ref()
+                .is_some_and(|wallet| !wallet.read().unwrap().is_open())
+        {
+            self.wallet_open = false;
+            self.decrypted_private_key = None;
+            self.private_key_hidden_at = None;
+            if self.view_private_key_even_if_encrypted_or_in_wallet {
+                self.view_wallet_unlock = true;
+            }
+        }
+
         action |= island_central_panel(ctx, |ui| {
             let inner_action = AppAction::None;
 
@@ -157,14 +261,74 @@ impl ScreenLike for KeyInfoScreen {
                                     .strong()
                                     .color(Color32::BLACK),
                             );
+                            ui.horizontal(|ui| {
+                                let derivation_path =
+                                    wallet_derivation_path.derivation_path.to_string();
+                                ui.label(
+                                    RichText::new(format!(
+                                        "At derivation path {}",
+                                        derivation_path
+                                    ))
+                                    .strong()
+                                    .color(Color32::BLACK),
+                                );
+                                if ui
+                                    .small_button("📋")
+                                    .on_hover_text("Copy derivation path")
+                                    .clicked()
+                                {
+                                    ui.ctx().copy_text(derivation_path);
+                                }
+                            });
+                            ui.end_row();
+
+                            // The account xpub is public, but deriving it needs the seed
                             ui.label(
-                                RichText::new(format!(
-                                    "At derivation path {}",
-                                    wallet_derivation_path.derivation_path
-                                ))
-                                .strong()
-                                .color(Color32::BLACK),
+                                RichText::new("Account xpub:")
+                                    .strong()
+                                    .color(Color32::BLACK),
                             );
+                            match self.selected_wallet.as_ref() {
+                                Some(wallet) if wallet.read().unwrap().is_open() => {
+                                    let account_key =
+                                        wallet.read().unwrap().parent_extended_public_key(
+                                            &wallet_derivation_path.derivation_path,
+                                            self.app_context.network,
+                                        );
+                                    match account_key {
+                                        Ok((account_path, xpub)) => {
+                                            ui.vertical(|ui| {
+                                                let xpub = xpub.to_string();
+                                                ui.horizontal(|ui| {
+                                                    ui.add(
+                                                        TextEdit::singleline(&mut xpub.as_str())
+                                                            .desired_width(400.0),
+                                                    );
+                                                    if ui
+                                                        .small_button("📋")
+                                                        .on_hover_text("Copy xpub")
+                                                        .clicked()
+                                                    {
+                                                        ui.ctx().copy_text(xpub.clone());
+                                                    }
+                                                });
+                                                ui.label(
+                                                    RichText::new(format!("At {}", account_path))
+                                                        .color(Color32::BLACK),
+                                                );
+                                            });
+                                        }
+                                        Err(e) => {
+                                            ui.colored_label(Color32::DARK_RED, e);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    if ui.button("Unlock wallet to view").clicked() {
+                                        self.view_wallet_unlock = true;
+                                    }
+                                }
+                            }
                             ui.end_row();
                         }
 
@@ -275,8 +439,12 @@ impl ScreenLike for KeyInfoScreen {
                                             Payload::PubkeyHash(PubkeyHash::from_byte_array(hash)),
                                         )
                                     };
-                                    ui.label(
-                                        RichText::new(address.to_string()).color(Color32::BLACK),
+                                    address_with_qr_toggle(
+                                        ui,
+                                        "key_info_address",
+                                        &address.to_string(),
+                                        &address.to_qr_uri(),
+                                        Color32::BLACK,
                                     );
                                 }
                                 Err(e) => {
@@ -300,10 +468,7 @@ impl ScreenLike for KeyInfoScreen {
                     match private_key {
                         PrivateKeyData::Clear(clear) | PrivateKeyData::AlwaysClear(clear) => {
                             let private_key_hex = hex::encode(clear);
-                            ui.add(
-                                TextEdit::singleline(&mut private_key_hex.as_str().to_owned())
-                                    .desired_width(f32::INFINITY),
-                            );
+                            self.render_private_key(ui, private_key_hex);
                             ui.add_space(10.0);
                             if ui.button("Remove private key from DET").clicked() {
                                 self.show_confirm_remove_private_key = true;
@@ -321,36 +486,24 @@ impl ScreenLike for KeyInfoScreen {
                                 && self.view_private_key_even_if_encrypted_or_in_wallet
                                 && self.selected_wallet.is_some()
                             {
-                                if let Some(private_key) = self.decrypted_private_key {
-                                    let private_key_wif = private_key.to_wif();
-                                    ui.add(
-                                        TextEdit::multiline(
-                                            &mut private_key_wif.as_str().to_owned(),
-                                        )
-                                        .desired_width(f32::INFINITY),
-                                    );
-                                } else {
-                                    let wallet =
-                                        self.selected_wallet.as_ref().unwrap().read().unwrap();
-                                    match wallet.private_key_at_derivation_path(
-                                        &derivation_path.derivation_path,
-                                    ) {
-                                        Ok(private_key) => {
-                                            let private_key_wif = private_key.to_wif();
-                                            ui.add(
-                                                TextEdit::multiline(
-                                                    &mut private_key_wif.as_str().to_owned(),
-                                                )
-                                                .desired_width(f32::INFINITY),
-                                            );
-                                            self.decrypted_private_key = Some(private_key);
-                                        }
-                                        Err(e) => {
-                                            ui.label(format!("Error: {}", e));
-                                            return;
+                                let private_key = match self.decrypted_private_key {
+                                    Some(private_key) => private_key,
+                                    None => {
+                                        let wallet =
+                                            self.selected_wallet.as_ref().unwrap().read().unwrap();
+                                        match wallet.private_key_at_derivation_path(
+                                            &derivation_path.derivation_path,
+                                        ) {
+                                            Ok(private_key) => private_key,
+                                            Err(e) => {
+                                                ui.label(format!("Error: {}", e));
+                                                return;
+                                            }
                                         }
                                     }
-                                }
+                                };
+                                self.decrypted_private_key = Some(private_key);
+                                self.render_private_key(ui, private_key.to_wif());
                                 self.render_sign_input(ui);
                             } else if self.wallet_open {
                                 ui.colored_label(Color32::DARK_RED, "Key is in encrypted wallet");
@@ -359,6 +512,7 @@ impl ScreenLike for KeyInfoScreen {
                                 if ui.button("View Private Key").clicked() {
                                     self.view_private_key_even_if_encrypted_or_in_wallet = true;
                                     self.view_wallet_unlock = true;
+                                    self.reveal_private_key();
                                 }
                                 if self.decrypted_private_key.is_none() {
                                     let wallet =
@@ -367,13 +521,6 @@ impl ScreenLike for KeyInfoScreen {
                                         &derivation_path.derivation_path,
                                     ) {
                                         Ok(private_key) => {
-                                            let private_key_wif = private_key.to_wif();
-                                            ui.add(
-                                                TextEdit::multiline(
-                                                    &mut private_key_wif.as_str().to_owned(),
-                                                )
-                                                .desired_width(f32::INFINITY),
-                                            );
                                             self.decrypted_private_key = Some(private_key);
                                         }
                                         Err(e) => {
@@ -390,6 +537,7 @@ impl ScreenLike for KeyInfoScreen {
                                 if ui.button("View Private Key").clicked() {
                                     self.view_private_key_even_if_encrypted_or_in_wallet = true;
                                     self.view_wallet_unlock = true;
+                                    self.reveal_private_key();
                                 }
 
                                 if ui.button("Sign Message").clicked() {
@@ -412,6 +560,8 @@ impl ScreenLike for KeyInfoScreen {
                     }
                 }
 
+                self.render_verify_input(ui);
+
                 if self.view_wallet_unlock {
                     let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
                     if !needed_unlock || just_unlocked {
@@ -479,14 +629,96 @@ impl KeyInfoScreen {
             message_input: "".to_string(),
             signed_message: None,
             sign_error_message: None,
+            verify_message_input: String::new(),
+            verify_signature_input: String::new(),
+            verify_result: None,
             view_wallet_unlock: false,
             wallet_open: false,
             view_private_key_even_if_encrypted_or_in_wallet: false,
             show_pop_up_info: None,
             show_confirm_remove_private_key: false,
+            acknowledge_last_key_removal: false,
+            private_key_reveal_secs: DEFAULT_PRIVATE_KEY_REVEAL_SECS,
+            private_key_hidden_at: None,
         }
     }
 
+    fn reveal_private_key(&mut self) {
+        self.private_key_hidden_at =
+            Some(Instant::now() + Duration::from_secs(self.private_key_reveal_secs));
+    }
+
+    /// Renders the private key masked until revealed. Once revealed it is masked again after
+    /// `private_key_reveal_secs` unless the user keeps interacting with it.
+    fn render_private_key(&mut self, ui: &mut egui::Ui, mut private_key: String) {
+        let now = Instant::now();
+        let hides_in = self
+            .private_key_hidden_at
+            .and_then(|hidden_at| hidden_at.checked_duration_since(now))
+            .filter(|remaining| !remaining.is_zero());
+
+        if hides_in.is_some() {
+            let response = ui.add(
+                TextEdit::multiline(&mut private_key.as_str().to_owned())
+                    .desired_width(f32::INFINITY),
+            );
+            if response.hovered() || response.has_focus() {
+                self.reveal_private_key();
+            }
+        } else {
+            self.private_key_hidden_at = None;
+            ui.add_enabled(
+                false,
+                TextEdit::singleline(&mut "•".repeat(private_key.len()))
+                    .desired_width(f32::INFINITY),
+            );
+        }
+
+        ui.horizontal(|ui| {
+            if hides_in.is_some() {
+                if ui.button("Hide").clicked() {
+                    self.private_key_hidden_at = None;
+                }
+            } else if ui.button("Reveal").clicked() {
+                self.reveal_private_key();
+            }
+
+            if ui
+                .button("Copy")
+                .on_hover_text(format!(
+                    "The clipboard is cleared after {} seconds",
+                    CLIPBOARD_CLEAR_SECS
+                ))
+                .clicked()
+            {
+                ui.ctx().copy_text(private_key.clone());
+                self.app_context.schedule_clipboard_clear(
+                    private_key.clone(),
+                    Duration::from_secs(CLIPBOARD_CLEAR_SECS),
+                );
+            }
+
+            ui.add_space(10.0);
+            ui.label("Hide after:");
+            ui.add(
+                egui::DragValue::new(&mut self.private_key_reveal_secs)
+                    .range(5..=600)
+                    .suffix(" s"),
+            );
+
+            if let Some(hides_in) = hides_in {
+                ui.label(
+                    RichText::new(format!("Hiding in {}s", hides_in.as_secs() + 1))
+                        .color(Color32::GRAY),
+                );
+                ui.ctx()
+                    .request_repaint_after(hides_in.min(Duration::from_secs(1)));
+            }
+        });
+
+        private_key.zeroize();
+    }
+
     fn validate_and_store_private_key(&mut self) {
         // Convert the input string to bytes (hex decoding)
         let private_key_bytes = match hex::decode(&self.private_key_input) {
@@ -586,6 +818,71 @@ impl KeyInfoScreen {
         }
     }
 
+    fn render_verify_input(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(10.0);
+        ui.separator();
+        ui.add_space(10.0);
+
+        ui.horizontal(|ui| {
+            ui.heading(RichText::new("Verify").color(Color32::BLACK));
+            crate::ui::helpers::info_icon_button(
+                ui,
+                "Enter a message and the base64 signature someone gave you for it to check that \
+                 it was signed with the private key of this key.",
+            );
+        });
+        ui.add_space(5.0);
+
+        ui.label(RichText::new("Message:").color(Color32::BLACK));
+        ui.add_space(5.0);
+        if ui
+            .add(
+                egui::TextEdit::multiline(&mut self.verify_message_input)
+                    .desired_width(f32::INFINITY)
+                    .desired_rows(3),
+            )
+            .changed()
+        {
+            self.verify_result = None;
+        }
+        ui.add_space(5.0);
+
+        ui.label(RichText::new("Signature (Base64):").color(Color32::BLACK));
+        ui.add_space(5.0);
+        if ui
+            .add(
+                egui::TextEdit::singleline(&mut self.verify_signature_input)
+                    .desired_width(f32::INFINITY),
+            )
+            .changed()
+        {
+            self.verify_result = None;
+        }
+        ui.add_space(5.0);
+
+        if ui.button("Verify Signature").clicked() {
+            self.verify_result = Some(verify_dash_message(
+                self.key.key_type(),
+                self.key.data().as_slice(),
+                &self.verify_message_input,
+                &self.verify_signature_input,
+            ));
+        }
+
+        match &self.verify_result {
+            Some(Ok(())) => {
+                ui.colored_label(
+                    Color32::DARK_GREEN,
+                    "✔ The signature is valid for this key.",
+                );
+            }
+            Some(Err(error_message)) => {
+                ui.colored_label(Color32::RED, format!("✖ {}", error_message));
+            }
+            None => {}
+        }
+    }
+
     fn sign_message(&mut self) {
         // Check that we have a private key
         if let Some((private_key_data, _)) = &self.private_key_data {
@@ -605,23 +902,10 @@ impl KeyInfoScreen {
             match self.key.key_type() {
                 KeyType::ECDSA_SECP256K1 | KeyType::ECDSA_HASH160 => {
                     // Sign the message using ECDSA
-                    let secp = Secp256k1::new();
-
-                    let message_hash = signed_msg_hash(self.message_input.as_str());
-                    let message = Message::from_digest(*message_hash.as_byte_array());
-
                     let secret_key = SecretKey::from_byte_array(&private_key_bytes).unwrap();
 
-                    let signature = secp.sign_ecdsa(&message, &secret_key);
-
-                    // Serialize the signature
-                    let mut serialized_signature = signature.serialize_compact().to_vec();
-                    serialized_signature.insert(0, 32);
-
-                    // Encode to Base64
-                    let signature_base64 = STANDARD.encode(serialized_signature);
-
-                    self.signed_message = Some(signature_base64);
+                    self.signed_message =
+                        Some(sign_dash_message(self.message_input.as_str(), &secret_key));
                     self.sign_error_message = None;
                 }
                 _ => {
@@ -634,27 +918,60 @@ impl KeyInfoScreen {
     }
 
     fn render_remove_private_key_confirm(&mut self, ui: &mut egui::Ui) {
+        let removed_entry: (PrivateKeyTarget, KeyID) = (self.key.purpose().into(), self.key.id());
+        let lost_actions = actions_only_signable_by(
+            &self.key,
+            self.identity
+                .private_keys
+                .private_keys
+                .iter()
+                .filter(|(entry, _)| **entry != removed_entry)
+                .map(|(_, (public_key, _))| &public_key.identity_public_key),
+        );
+
         egui::Window::new("Remove Private Key")
             .collapsible(false) // Prevent collapsing
             .resizable(false) // Prevent resizing
             .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
                 ui.label(
                     RichText::new("Are you sure you want to remove the private key?")
                         .color(Color32::BLACK),
                 );
                 ui.add_space(10.0);
 
+                if !lost_actions.is_empty() {
+                    ui.label(
+                        RichText::new(format!(
+                            "This is the only key that can sign {}. Without it this identity \
+                             can't do so in DET until a private key for such a key is added again.",
+                            lost_actions.join(", ")
+                        ))
+                        .color(Color32::DARK_RED),
+                    );
+                    ui.checkbox(
+                        &mut self.acknowledge_last_key_removal,
+                        "I understand and still want to remove it",
+                    );
+                    ui.add_space(10.0);
+                }
+                let can_remove = lost_actions.is_empty() || self.acknowledge_last_key_removal;
+
                 ui.horizontal(|ui| {
-                    if ui.button("Cancel").clicked() {
+                    if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
                         self.show_confirm_remove_private_key = false;
+                        self.acknowledge_last_key_removal = false;
                     }
                     ui.add_space(3.0);
-                    if ui.button("Remove").clicked() {
+                    let remove_clicked = ui
+                        .add_enabled(can_remove, egui::Button::new("Remove"))
+                        .clicked();
+                    if remove_clicked || (can_remove && key_action == DialogKeyAction::Confirm) {
                         self.private_key_data = None;
                         self.identity
                             .private_keys
                             .private_keys
-                            .remove(&(self.key.purpose().into(), self.key.id()));
+                            .remove(&removed_entry);
                         match self
                             .app_context
                             .insert_local_qualified_identity(&self.identity, &None)
@@ -667,12 +984,48 @@ impl KeyInfoScreen {
                             }
                         }
                         self.show_confirm_remove_private_key = false;
+                        self.acknowledge_last_key_removal = false;
                     }
                 });
             });
     }
 }
 
+/// Common actions an enabled key can sign
+fn signable_actions(key: &IdentityPublicKey) -> Vec<&'static str> {
+    if key.disabled_at().is_some() {
+        return vec![];
+    }
+    match (key.purpose(), key.security_level()) {
+        (Purpose::AUTHENTICATION, SecurityLevel::MASTER) => vec!["identity key updates"],
+        (Purpose::AUTHENTICATION, SecurityLevel::CRITICAL) => vec![
+            "contract and token config updates",
+            "documents, names and token actions",
+        ],
+        (Purpose::AUTHENTICATION, SecurityLevel::HIGH) => {
+            vec!["documents, names and token actions"]
+        }
+        (Purpose::TRANSFER, _) => vec!["credit transfers", "withdrawals"],
+        (Purpose::OWNER, _) => vec!["withdrawals"],
+        (Purpose::VOTING, _) => vec!["votes"],
+        _ => vec![],
+    }
+}
+
+/// Common actions `key` can sign that none of the `other_keys` with a stored private key can,
+/// which the identity loses in DET once the private key of `key` is removed
+fn actions_only_signable_by<'a>(
+    key: &IdentityPublicKey,
+    other_keys: impl IntoIterator<Item = &'a IdentityPublicKey>,
+) -> Vec<&'static str> {
+    let mut actions = signable_actions(key);
+    for other_key in other_keys {
+        let other_actions = signable_actions(other_key);
+        actions.retain(|action| !other_actions.contains(action));
+    }
+    actions
+}
+
 impl ScreenWithWalletUnlock for KeyInfoScreen {
     fn selected_wallet_ref(&self) -> &Option<Arc<RwLock<Wallet>>> {
         &self.selected_wallet
@@ -702,3 +1055,85 @@ impl ScreenWithWalletUnlock for KeyInfoScreen {
         self.error_message.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+
+    #[test]
+    fn test_verify_dash_message_round_trip() {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_byte_array(&[7; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key).serialize();
+        let public_key_hash = PubkeyHash::hash(&public_key);
+        let signature = sign_dash_message("hello", &secret_key);
+
+        assert_eq!(
+            verify_dash_message(KeyType::ECDSA_SECP256K1, &public_key, "hello", &signature),
+            Ok(())
+        );
+        assert_eq!(
+            verify_dash_message(
+                KeyType::ECDSA_HASH160,
+                public_key_hash.as_byte_array(),
+                "hello",
+                &signature
+            ),
+            Ok(())
+        );
+        assert!(
+            verify_dash_message(KeyType::ECDSA_SECP256K1, &public_key, "goodbye", &signature)
+                .is_err()
+        );
+        assert!(verify_dash_message(KeyType::ECDSA_SECP256K1, &public_key, "hello", "%%").is_err());
+        assert_eq!(
+            verify_dash_message(KeyType::BLS12_381, &public_key, "hello", &signature),
+            Err("Unsupported key type for verification.".to_string())
+        );
+    }
+
+    fn test_key(id: KeyID, purpose: Purpose, security_level: SecurityLevel) -> IdentityPublicKeyV0 {
+        IdentityPublicKeyV0 {
+            id,
+            purpose,
+            security_level,
+            contract_bounds: None,
+            key_type: KeyType::ECDSA_HASH160,
+            read_only: false,
+            data: vec![0; 20].into(),
+            disabled_at: None,
+        }
+    }
+
+    #[test]
+    fn test_actions_only_signable_by() {
+        let critical = IdentityPublicKey::V0(test_key(
+            1,
+            Purpose::AUTHENTICATION,
+            SecurityLevel::CRITICAL,
+        ));
+        let high = IdentityPublicKey::V0(test_key(2, Purpose::AUTHENTICATION, SecurityLevel::HIGH));
+        let transfer =
+            IdentityPublicKey::V0(test_key(3, Purpose::TRANSFER, SecurityLevel::CRITICAL));
+
+        assert_eq!(
+            actions_only_signable_by(&critical, [&high, &transfer]),
+            vec!["contract and token config updates"]
+        );
+        assert!(actions_only_signable_by(&high, [&critical]).is_empty());
+        assert_eq!(
+            actions_only_signable_by(&transfer, [&critical]),
+            vec!["credit transfers", "withdrawals"]
+        );
+
+        let disabled_critical = IdentityPublicKey::V0(IdentityPublicKeyV0 {
+            disabled_at: Some(1),
+            ..test_key(4, Purpose::AUTHENTICATION, SecurityLevel::CRITICAL)
+        });
+        assert_eq!(
+            actions_only_signable_by(&high, [&disabled_critical]),
+            vec!["documents, names and token actions"]
+        );
+    }
+}
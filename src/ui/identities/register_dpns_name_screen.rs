@@ -5,6 +5,7 @@ use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
@@ -237,6 +238,8 @@ impl ScreenLike for RegisterDpnsNameScreen {
             ui.add_space(5.0);
             if let Some(identity) = &self.selected_qualified_identity {
                 ui.label(format!("Identity balance: {:.6}", identity.identity.balance() as f64 * 1e-11));
+                ui.add_space(5.0);
+                inner_action |= render_low_balance_banner(ui, &self.app_context, identity);
             }
 
             ui.add_space(10.0);
@@ -14,21 +14,66 @@ use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::format_token_amount;
 use crate::ui::identities::add_new_identity_screen::FundingMethod;
-use crate::ui::identities::funding_common::WalletFundedScreenStep;
-use crate::ui::{MessageType, ScreenLike};
+use crate::ui::identities::funding_common::{WalletFundedScreenStep, funding_wallet_label};
+use crate::ui::{MessageType, Screen, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Address;
 use dash_sdk::dashcore_rpc::dashcore::transaction::special_transaction::TransactionPayload;
-use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::balances::credits::{Credits, Duffs};
 use dash_sdk::dpp::dashcore::{OutPoint, Transaction, TxOut};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::prelude::AssetLockProof;
 use eframe::egui::Context;
-use egui::{ComboBox, ScrollArea, Ui};
+use egui::{Color32, ComboBox, RichText, ScrollArea, Ui};
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, RwLock};
 
+const CREDITS_PER_DUFF: Credits = 1000;
+
+/// Extra Dash suggested on top of a shortfall to cover the top up's processing fees
+const TOP_UP_FEE_MARGIN_DUFFS: Duffs = 100_000;
+
+/// Duffs to top up with so that an identity can afford an action costing `required_credits`
+pub fn suggested_top_up_duffs(balance: Credits, required_credits: Credits) -> Duffs {
+    required_credits
+        .saturating_sub(balance)
+        .div_ceil(CREDITS_PER_DUFF)
+        .saturating_add(TOP_UP_FEE_MARGIN_DUFFS)
+}
+
+/// Renders a note that `identity` can't afford an action costing `required_credits`, with a
+/// button opening a top up. The top up returns to the current screen once it is done.
+pub fn render_low_balance_prompt(
+    ui: &mut Ui,
+    app_context: &Arc<AppContext>,
+    identity: &QualifiedIdentity,
+    required_credits: Credits,
+) -> AppAction {
+    let mut action = AppAction::None;
+    ui.horizontal(|ui| {
+        ui.colored_label(
+            Color32::DARK_RED,
+            format!(
+                "Insufficient credits: this needs {} credits, the identity has {}.",
+                required_credits,
+                identity.identity.balance()
+            ),
+        );
+        if ui.button("Top up credits").clicked() {
+            action = AppAction::AddScreen(Screen::TopUpIdentityScreen(
+                TopUpIdentityScreen::new_for_shortfall(
+                    identity.clone(),
+                    app_context,
+                    required_credits,
+                ),
+            ));
+        }
+    });
+    action
+}
+
 pub struct TopUpIdentityScreen {
     pub identity: QualifiedIdentity,
     step: Arc<RwLock<WalletFundedScreenStep>>,
@@ -45,6 +90,8 @@ pub struct TopUpIdentityScreen {
     show_password: bool,
     wallet_password: String,
     show_pop_up_info: Option<String>,
+    /// Credits needed by the action this top up was started from, if any
+    required_credits: Option<Credits>,
     pub app_context: Arc<AppContext>,
 }
 
@@ -66,10 +113,43 @@ impl TopUpIdentityScreen {
             show_password: false,
             wallet_password: "".to_string(),
             show_pop_up_info: None,
+            required_credits: None,
             app_context: app_context.clone(),
         }
     }
 
+    /// Top up started because an action costing `required_credits` couldn't be afforded.
+    /// Prefills the amount needed and funds from the identity's wallet balance if possible.
+    pub fn new_for_shortfall(
+        qualified_identity: QualifiedIdentity,
+        app_context: &Arc<AppContext>,
+        required_credits: Credits,
+    ) -> Self {
+        let duffs = suggested_top_up_duffs(qualified_identity.identity.balance(), required_credits);
+        let can_use_wallet_balance = {
+            let wallets = app_context.wallets.read().unwrap();
+            let associated_wallet_has_balance = qualified_identity
+                .associated_wallets
+                .keys()
+                .filter_map(|seed_hash| wallets.get(seed_hash))
+                .any(|wallet| wallet.read().unwrap().has_balance());
+            associated_wallet_has_balance
+                || wallets
+                    .values()
+                    .any(|wallet| wallet.read().unwrap().has_balance())
+        };
+
+        let mut screen = Self::new(qualified_identity, app_context);
+        screen.required_credits = Some(required_credits);
+        screen.funding_amount = format_token_amount(duffs, 8);
+        screen.funding_amount_exact = Some(duffs);
+        if can_use_wallet_balance {
+            *screen.funding_method.write().unwrap() = FundingMethod::UseWalletBalance;
+            *screen.step.write().unwrap() = WalletFundedScreenStep::ReadyToCreate;
+        }
+        screen
+    }
+
     fn render_wallet_selection(&mut self, ui: &mut Ui) -> bool {
         if self.app_context.has_wallet.load(Ordering::Relaxed) {
             let wallets = self.app_context.wallets.read().unwrap();
@@ -77,24 +157,39 @@ impl TopUpIdentityScreen {
                 // Get the current funding method
                 let funding_method = *self.funding_method.read().unwrap();
 
-                // Retrieve the alias of the currently selected wallet, if any
-                let selected_wallet_alias = self
+                // Default to the wallet the identity was created from, if it can fund this
+                if self.wallet.is_none() {
+                    self.wallet = self
+                        .identity
+                        .associated_wallets
+                        .keys()
+                        .filter_map(|seed_hash| wallets.get(seed_hash))
+                        .find(|wallet| {
+                            let wallet = wallet.read().unwrap();
+                            match funding_method {
+                                FundingMethod::UseWalletBalance => wallet.has_balance(),
+                                FundingMethod::UseUnusedAssetLock => wallet.has_unused_asset_lock(),
+                                _ => true,
+                            }
+                        })
+                        .cloned();
+                }
+
+                // Retrieve the label of the currently selected wallet, if any
+                let selected_wallet_label = self
                     .wallet
                     .as_ref()
-                    .and_then(|wallet| wallet.read().ok()?.alias.clone())
+                    .and_then(|wallet| wallet.read().ok().map(|w| funding_wallet_label(&w)))
                     .unwrap_or_else(|| "Select".to_string());
 
                 // Display the ComboBox for wallet selection
                 ComboBox::from_id_salt("select_wallet")
-                    .selected_text(selected_wallet_alias)
+                    .selected_text(selected_wallet_label)
                     .show_ui(ui, |ui| {
                         for wallet in wallets.values() {
-                            let (wallet_alias, has_required_resources) = {
+                            let (wallet_label, has_required_resources) = {
                                 let wallet_read = wallet.read().unwrap();
-                                let alias = wallet_read
-                                    .alias
-                                    .clone()
-                                    .unwrap_or_else(|| "Unnamed Wallet".to_string());
+                                let label = funding_wallet_label(&wallet_read);
 
                                 let has_resources = match funding_method {
                                     FundingMethod::UseWalletBalance => wallet_read.has_balance(),
@@ -104,7 +199,7 @@ impl TopUpIdentityScreen {
                                     _ => true,
                                 };
 
-                                (alias, has_resources)
+                                (label, has_resources)
                             };
 
                             let is_selected = self
@@ -113,7 +208,7 @@ impl TopUpIdentityScreen {
                                 .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
 
                             ui.add_enabled_ui(has_required_resources, |ui| {
-                                if ui.selectable_label(is_selected, wallet_alias).clicked() {
+                                if ui.selectable_label(is_selected, wallet_label).clicked() {
                                     // Update the selected wallet from app_context
                                     self.wallet = Some(wallet.clone());
                                 }
@@ -316,6 +411,27 @@ impl TopUpIdentityScreen {
             }
         });
 
+        if let Some(duffs) = self.funding_amount_exact.filter(|duffs| *duffs > 0) {
+            let estimated_balance = self
+                .identity
+                .identity
+                .balance()
+                .saturating_add(duffs.saturating_mul(CREDITS_PER_DUFF));
+            ui.label(format!(
+                "Estimated balance after top up: ~{} credits ({:.4} DASH), before fees",
+                estimated_balance,
+                estimated_balance as f64 * 1e-11
+            ));
+            if let Some(required_credits) = self.required_credits {
+                if estimated_balance < required_credits {
+                    ui.colored_label(
+                        Color32::DARK_RED,
+                        "This is not enough for the action you started the top up from.",
+                    );
+                }
+            }
+        }
+
         ui.add_space(10.0);
     }
 }
@@ -464,6 +580,17 @@ impl ScreenLike for TopUpIdentityScreen {
                     ui.label(format!("{:.4} DASH", balance_dash));
                 });
 
+                if let Some(required_credits) = self.required_credits {
+                    ui.label(
+                        RichText::new(format!(
+                            "The action you came from needs {} credits. Once the top up is done \
+                             you will be returned to it.",
+                            required_credits
+                        ))
+                        .color(Color32::DARK_RED),
+                    );
+                }
+
                 ui.add_space(10.0);
                 ui.separator();
                 ui.add_space(10.0);
@@ -551,3 +678,25 @@ impl ScreenLike for TopUpIdentityScreen {
         action
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggested_top_up_duffs() {
+        // Shortfall rounded up to whole duffs, plus the fee margin
+        assert_eq!(
+            suggested_top_up_duffs(1_000, 2_001),
+            2 + TOP_UP_FEE_MARGIN_DUFFS
+        );
+        assert_eq!(
+            suggested_top_up_duffs(0, 100_000_000_000),
+            100_000_000 + TOP_UP_FEE_MARGIN_DUFFS
+        );
+        assert_eq!(
+            suggested_top_up_duffs(5_000, 1_000),
+            TOP_UP_FEE_MARGIN_DUFFS
+        );
+    }
+}
@@ -15,9 +15,13 @@ impl TopUpIdentityScreen {
 
             ui.add_space(20.0);
 
-            // Display the "Back to Identities" button
-            if ui.button("Back to Identities").clicked() {
-                // Handle navigation back to the identities screen
+            // Return to the screen the top up was started from
+            let back_label = if self.required_credits.is_some() {
+                "Return to Action"
+            } else {
+                "Back to Identities"
+            };
+            if ui.button(back_label).clicked() {
                 action = AppAction::PopScreenAndRefresh;
             }
         });
@@ -5,6 +5,9 @@ use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::mainnet_confirmation::{
+    IrreversibleAction, LARGE_CREDIT_TRANSFER_THRESHOLD, MainnetConfirmation,
+};
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
@@ -45,6 +48,7 @@ pub struct TransferScreen {
     max_amount: u64,
     pub app_context: Arc<AppContext>,
     confirmation_popup: bool,
+    mainnet_confirmation: MainnetConfirmation,
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
     wallet_password: String,
     show_password: bool,
@@ -73,6 +77,7 @@ impl TransferScreen {
             max_amount,
             app_context: app_context.clone(),
             confirmation_popup: false,
+            mainnet_confirmation: MainnetConfirmation::default(),
             selected_wallet,
             wallet_password: String::new(),
             show_password: false,
@@ -173,7 +178,18 @@ impl TransferScreen {
                     credits += fraction_number * multiplier; // Fractional Dash to credits
                 }
 
-                if ui.button("Confirm").clicked() {
+                let confirmed = (credits as Credits) < LARGE_CREDIT_TRANSFER_THRESHOLD
+                    || self.mainnet_confirmation.show(
+                        ui,
+                        &self.app_context,
+                        IrreversibleAction::LargeTransfer,
+                        self.identity.alias.as_deref(),
+                    );
+
+                if ui
+                    .add_enabled(confirmed, egui::Button::new("Confirm"))
+                    .clicked()
+                {
                     self.confirmation_popup = false;
                     let now = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -378,6 +394,7 @@ impl ScreenLike for TransferScreen {
                     .corner_radius(3.0);
                 if ui.add(button).clicked() {
                     self.confirmation_popup = true;
+                    self.mainnet_confirmation.reset();
                 }
 
                 if self.confirmation_popup {
@@ -9,6 +9,7 @@ use crate::ui::contracts_documents::contracts_documents_screen::DocumentQueryScr
 use crate::ui::contracts_documents::document_action_screen::{
     DocumentActionScreen, DocumentActionType,
 };
+use crate::ui::dpns::contest_details_screen::ContestDetailsScreen;
 use crate::ui::dpns::dpns_contested_names_screen::DPNSScreen;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
@@ -21,8 +22,10 @@ use crate::ui::tokens::add_token_by_id_screen::AddTokenByIdScreen;
 use crate::ui::tokens::tokens_screen::{IdentityTokenBasicInfo, IdentityTokenInfo};
 use crate::ui::tokens::transfer_tokens_screen::TransferTokensScreen;
 use crate::ui::tokens::view_token_claims_screen::ViewTokenClaimsScreen;
+use crate::ui::tools::activity_log_screen::ActivityLogScreen;
 use crate::ui::tools::contract_visualizer_screen::ContractVisualizerScreen;
 use crate::ui::tools::document_visualizer_screen::DocumentVisualizerScreen;
+use crate::ui::tools::log_viewer_screen::LogViewerScreen;
 use crate::ui::tools::platform_info_screen::PlatformInfoScreen;
 use crate::ui::tools::proof_log_screen::ProofLogScreen;
 use crate::ui::tools::proof_visualizer_screen::ProofVisualizerScreen;
@@ -47,6 +50,7 @@ use tokens::burn_tokens_screen::BurnTokensScreen;
 use tokens::claim_tokens_screen::ClaimTokensScreen;
 use tokens::destroy_frozen_funds_screen::DestroyFrozenFundsScreen;
 use tokens::direct_token_purchase_screen::PurchaseTokenScreen;
+use tokens::distribute_tokens_screen::DistributeTokensScreen;
 use tokens::freeze_tokens_screen::FreezeTokensScreen;
 use tokens::mint_tokens_screen::MintTokensScreen;
 use tokens::pause_tokens_screen::PauseTokensScreen;
@@ -77,6 +81,7 @@ pub enum RootScreenType {
     RootScreenDPNSPastContests,
     RootScreenDPNSOwnedNames,
     RootScreenDPNSScheduledVotes,
+    RootScreenDPNSWatchlist,
     RootScreenDocumentQuery,
     RootScreenWalletsBalances,
     RootScreenToolsProofLogScreen,
@@ -89,6 +94,8 @@ pub enum RootScreenType {
     RootScreenTokenCreator,
     RootScreenToolsContractVisualizerScreen,
     RootScreenToolsPlatformInfoScreen,
+    RootScreenToolsLogViewerScreen,
+    RootScreenToolsActivityLogScreen,
 }
 
 impl RootScreenType {
@@ -113,6 +120,9 @@ impl RootScreenType {
             RootScreenType::RootScreenToolsDocumentVisualizerScreen => 15,
             RootScreenType::RootScreenToolsContractVisualizerScreen => 16,
             RootScreenType::RootScreenToolsPlatformInfoScreen => 17,
+            RootScreenType::RootScreenDPNSWatchlist => 18,
+            RootScreenType::RootScreenToolsLogViewerScreen => 19,
+            RootScreenType::RootScreenToolsActivityLogScreen => 20,
         }
     }
 
@@ -137,6 +147,9 @@ impl RootScreenType {
             15 => Some(RootScreenType::RootScreenToolsDocumentVisualizerScreen),
             16 => Some(RootScreenType::RootScreenToolsContractVisualizerScreen),
             17 => Some(RootScreenType::RootScreenToolsPlatformInfoScreen),
+            18 => Some(RootScreenType::RootScreenDPNSWatchlist),
+            19 => Some(RootScreenType::RootScreenToolsLogViewerScreen),
+            20 => Some(RootScreenType::RootScreenToolsActivityLogScreen),
             _ => None,
         }
     }
@@ -157,6 +170,7 @@ impl From<RootScreenType> for ScreenType {
             RootScreenType::RootScreenWalletsBalances => ScreenType::WalletsBalances,
             RootScreenType::RootScreenToolsProofLogScreen => ScreenType::ProofLog,
             RootScreenType::RootScreenDPNSScheduledVotes => ScreenType::ScheduledVotes,
+            RootScreenType::RootScreenDPNSWatchlist => ScreenType::DPNSWatchlist,
             RootScreenType::RootScreenToolsProofVisualizerScreen => ScreenType::ProofVisualizer,
             RootScreenType::RootScreenMyTokenBalances => ScreenType::TokenBalances,
             RootScreenType::RootScreenTokenSearch => ScreenType::TokenSearch,
@@ -168,6 +182,8 @@ impl From<RootScreenType> for ScreenType {
                 ScreenType::ContractsVisualizer
             }
             RootScreenType::RootScreenToolsPlatformInfoScreen => ScreenType::PlatformInfo,
+            RootScreenType::RootScreenToolsLogViewerScreen => ScreenType::LogViewer,
+            RootScreenType::RootScreenToolsActivityLogScreen => ScreenType::ActivityLog,
         }
     }
 }
@@ -179,6 +195,7 @@ pub enum ScreenType {
     DPNSActiveContests,
     DPNSPastContests,
     DPNSMyUsernames,
+    ContestDetails(String),
     AddNewIdentity,
     WalletsBalances,
     ImportWallet,
@@ -202,11 +219,14 @@ pub enum ScreenType {
     ProofLog,
     TopUpIdentity(QualifiedIdentity),
     ScheduledVotes,
+    DPNSWatchlist,
     AddContracts,
     ProofVisualizer,
     DocumentsVisualizer,
     ContractsVisualizer,
     PlatformInfo,
+    LogViewer,
+    ActivityLog,
     CreateDocument,
     DeleteDocument,
     ReplaceDocument,
@@ -222,6 +242,7 @@ pub enum ScreenType {
     AddTokenById,
     TransferTokensScreen(IdentityTokenBalance),
     MintTokensScreen(IdentityTokenInfo),
+    DistributeTokensScreen(IdentityTokenInfo),
     BurnTokensScreen(IdentityTokenInfo),
     DestroyFrozenFundsScreen(IdentityTokenInfo),
     FreezeTokensScreen(IdentityTokenInfo),
@@ -248,6 +269,9 @@ impl ScreenType {
             ScreenType::DPNSMyUsernames => {
                 Screen::DPNSScreen(DPNSScreen::new(app_context, DPNSSubscreen::Owned))
             }
+            ScreenType::ContestDetails(contested_name) => Screen::ContestDetailsScreen(
+                ContestDetailsScreen::new(contested_name.clone(), app_context),
+            ),
             ScreenType::AddNewIdentity => {
                 Screen::AddNewIdentityScreen(AddNewIdentityScreen::new(app_context))
             }
@@ -308,6 +332,9 @@ impl ScreenType {
             ScreenType::ScheduledVotes => {
                 Screen::DPNSScreen(DPNSScreen::new(app_context, DPNSSubscreen::ScheduledVotes))
             }
+            ScreenType::DPNSWatchlist => {
+                Screen::DPNSScreen(DPNSScreen::new(app_context, DPNSSubscreen::Watchlist))
+            }
             ScreenType::AddContracts => {
                 Screen::AddContractsScreen(AddContractsScreen::new(app_context))
             }
@@ -323,6 +350,10 @@ impl ScreenType {
             ScreenType::PlatformInfo => {
                 Screen::PlatformInfoScreen(PlatformInfoScreen::new(app_context))
             }
+            ScreenType::LogViewer => Screen::LogViewerScreen(LogViewerScreen::new(app_context)),
+            ScreenType::ActivityLog => {
+                Screen::ActivityLogScreen(ActivityLogScreen::new(app_context))
+            }
             ScreenType::CreateDocument => Screen::DocumentActionScreen(DocumentActionScreen::new(
                 app_context.clone(),
                 None,
@@ -373,6 +404,12 @@ impl ScreenType {
             ScreenType::MintTokensScreen(identity_token_info) => Screen::MintTokensScreen(
                 MintTokensScreen::new(identity_token_info.clone(), app_context),
             ),
+            ScreenType::DistributeTokensScreen(identity_token_info) => {
+                Screen::DistributeTokensScreen(DistributeTokensScreen::new(
+                    identity_token_info.clone(),
+                    app_context,
+                ))
+            }
             ScreenType::BurnTokensScreen(identity_token_info) => Screen::BurnTokensScreen(
                 BurnTokensScreen::new(identity_token_info.clone(), app_context),
             ),
@@ -421,6 +458,7 @@ impl ScreenType {
 pub enum Screen {
     IdentitiesScreen(IdentitiesScreen),
     DPNSScreen(DPNSScreen),
+    ContestDetailsScreen(ContestDetailsScreen),
     DocumentQueryScreen(DocumentQueryScreen),
     AddNewWalletScreen(AddNewWalletScreen),
     ImportWalletScreen(ImportWalletScreen),
@@ -446,11 +484,14 @@ pub enum Screen {
     AddContractsScreen(AddContractsScreen),
     ProofVisualizerScreen(ProofVisualizerScreen),
     PlatformInfoScreen(PlatformInfoScreen),
+    LogViewerScreen(LogViewerScreen),
+    ActivityLogScreen(ActivityLogScreen),
 
     // Token Screens
     TokensScreen(Box<TokensScreen>),
     TransferTokensScreen(TransferTokensScreen),
     MintTokensScreen(MintTokensScreen),
+    DistributeTokensScreen(DistributeTokensScreen),
     BurnTokensScreen(BurnTokensScreen),
     DestroyFrozenFundsScreen(DestroyFrozenFundsScreen),
     FreezeTokensScreen(FreezeTokensScreen),
@@ -469,7 +510,14 @@ impl Screen {
     pub fn change_context(&mut self, app_context: Arc<AppContext>) {
         match self {
             Screen::IdentitiesScreen(screen) => screen.app_context = app_context,
-            Screen::DPNSScreen(screen) => screen.app_context = app_context,
+            Screen::DPNSScreen(screen) => {
+                screen.app_context = app_context;
+                screen.reload_voting_identities();
+            }
+            Screen::ContestDetailsScreen(screen) => {
+                screen.app_context = app_context;
+                screen.refresh();
+            }
             Screen::AddExistingIdentityScreen(screen) => screen.app_context = app_context,
             Screen::KeyInfoScreen(screen) => screen.app_context = app_context,
             Screen::KeysScreen(screen) => screen.app_context = app_context,
@@ -495,11 +543,17 @@ impl Screen {
             Screen::ProofVisualizerScreen(screen) => screen.app_context = app_context,
             Screen::DocumentVisualizerScreen(screen) => screen.app_context = app_context,
             Screen::PlatformInfoScreen(screen) => screen.app_context = app_context,
+            Screen::LogViewerScreen(screen) => screen.app_context = app_context,
+            Screen::ActivityLogScreen(screen) => {
+                // Entries, identity names and filters all belong to the previous network
+                *screen = ActivityLogScreen::new(&app_context);
+            }
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.app_context = app_context,
             Screen::TransferTokensScreen(screen) => screen.app_context = app_context,
             Screen::MintTokensScreen(screen) => screen.app_context = app_context,
+            Screen::DistributeTokensScreen(screen) => screen.app_context = app_context,
             Screen::BurnTokensScreen(screen) => screen.app_context = app_context,
             Screen::DestroyFrozenFundsScreen(screen) => screen.app_context = app_context,
             Screen::FreezeTokensScreen(screen) => screen.app_context = app_context,
@@ -578,6 +632,13 @@ impl Screen {
                 dpns_subscreen: DPNSSubscreen::ScheduledVotes,
                 ..
             }) => ScreenType::ScheduledVotes,
+            Screen::DPNSScreen(DPNSScreen {
+                dpns_subscreen: DPNSSubscreen::Watchlist,
+                ..
+            }) => ScreenType::DPNSWatchlist,
+            Screen::ContestDetailsScreen(screen) => {
+                ScreenType::ContestDetails(screen.contested_name.clone())
+            }
             Screen::TransitionVisualizerScreen(_) => ScreenType::TransitionVisualizer,
             Screen::ContractVisualizerScreen(_) => ScreenType::ContractsVisualizer,
             Screen::WithdrawalScreen(screen) => {
@@ -610,6 +671,8 @@ impl Screen {
             Screen::ProofVisualizerScreen(_) => ScreenType::ProofVisualizer,
             Screen::DocumentVisualizerScreen(_) => ScreenType::DocumentsVisualizer,
             Screen::PlatformInfoScreen(_) => ScreenType::PlatformInfo,
+            Screen::LogViewerScreen(_) => ScreenType::LogViewer,
+            Screen::ActivityLogScreen(_) => ScreenType::ActivityLog,
 
             // Token Screens
             Screen::TokensScreen(screen)
@@ -634,6 +697,9 @@ impl Screen {
             Screen::MintTokensScreen(screen) => {
                 ScreenType::MintTokensScreen(screen.identity_token_info.clone())
             }
+            Screen::DistributeTokensScreen(screen) => {
+                ScreenType::DistributeTokensScreen(screen.identity_token_info.clone())
+            }
             Screen::BurnTokensScreen(screen) => {
                 ScreenType::BurnTokensScreen(screen.identity_token_info.clone())
             }
@@ -681,6 +747,7 @@ impl ScreenLike for Screen {
         match self {
             Screen::IdentitiesScreen(screen) => screen.refresh(),
             Screen::DPNSScreen(screen) => screen.refresh(),
+            Screen::ContestDetailsScreen(screen) => screen.refresh(),
             Screen::DocumentQueryScreen(screen) => screen.refresh(),
             Screen::AddNewWalletScreen(screen) => screen.refresh(),
             Screen::ImportWalletScreen(screen) => screen.refresh(),
@@ -706,11 +773,14 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.refresh(),
             Screen::ContractVisualizerScreen(screen) => screen.refresh(),
             Screen::PlatformInfoScreen(screen) => screen.refresh(),
+            Screen::LogViewerScreen(screen) => screen.refresh(),
+            Screen::ActivityLogScreen(screen) => screen.refresh(),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.refresh(),
             Screen::TransferTokensScreen(screen) => screen.refresh(),
             Screen::MintTokensScreen(screen) => screen.refresh(),
+            Screen::DistributeTokensScreen(screen) => screen.refresh(),
             Screen::BurnTokensScreen(screen) => screen.refresh(),
             Screen::DestroyFrozenFundsScreen(screen) => screen.refresh(),
             Screen::FreezeTokensScreen(screen) => screen.refresh(),
@@ -730,6 +800,7 @@ impl ScreenLike for Screen {
         match self {
             Screen::IdentitiesScreen(screen) => screen.refresh_on_arrival(),
             Screen::DPNSScreen(screen) => screen.refresh_on_arrival(),
+            Screen::ContestDetailsScreen(screen) => screen.refresh_on_arrival(),
             Screen::DocumentQueryScreen(screen) => screen.refresh_on_arrival(),
             Screen::AddNewWalletScreen(screen) => screen.refresh_on_arrival(),
             Screen::ImportWalletScreen(screen) => screen.refresh_on_arrival(),
@@ -755,11 +826,14 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.refresh_on_arrival(),
             Screen::ContractVisualizerScreen(screen) => screen.refresh_on_arrival(),
             Screen::PlatformInfoScreen(screen) => screen.refresh_on_arrival(),
+            Screen::LogViewerScreen(screen) => screen.refresh_on_arrival(),
+            Screen::ActivityLogScreen(screen) => screen.refresh_on_arrival(),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.refresh_on_arrival(),
             Screen::TransferTokensScreen(screen) => screen.refresh_on_arrival(),
             Screen::MintTokensScreen(screen) => screen.refresh_on_arrival(),
+            Screen::DistributeTokensScreen(screen) => screen.refresh_on_arrival(),
             Screen::BurnTokensScreen(screen) => screen.refresh_on_arrival(),
             Screen::DestroyFrozenFundsScreen(screen) => screen.refresh_on_arrival(),
             Screen::FreezeTokensScreen(screen) => screen.refresh_on_arrival(),
@@ -779,6 +853,7 @@ impl ScreenLike for Screen {
         match self {
             Screen::IdentitiesScreen(screen) => screen.ui(ctx),
             Screen::DPNSScreen(screen) => screen.ui(ctx),
+            Screen::ContestDetailsScreen(screen) => screen.ui(ctx),
             Screen::DocumentQueryScreen(screen) => screen.ui(ctx),
             Screen::AddNewWalletScreen(screen) => screen.ui(ctx),
             Screen::ImportWalletScreen(screen) => screen.ui(ctx),
@@ -804,11 +879,14 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.ui(ctx),
             Screen::ContractVisualizerScreen(screen) => screen.ui(ctx),
             Screen::PlatformInfoScreen(screen) => screen.ui(ctx),
+            Screen::LogViewerScreen(screen) => screen.ui(ctx),
+            Screen::ActivityLogScreen(screen) => screen.ui(ctx),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.ui(ctx),
             Screen::TransferTokensScreen(screen) => screen.ui(ctx),
             Screen::MintTokensScreen(screen) => screen.ui(ctx),
+            Screen::DistributeTokensScreen(screen) => screen.ui(ctx),
             Screen::BurnTokensScreen(screen) => screen.ui(ctx),
             Screen::DestroyFrozenFundsScreen(screen) => screen.ui(ctx),
             Screen::FreezeTokensScreen(screen) => screen.ui(ctx),
@@ -828,6 +906,7 @@ impl ScreenLike for Screen {
         match self {
             Screen::IdentitiesScreen(screen) => screen.display_message(message, message_type),
             Screen::DPNSScreen(screen) => screen.display_message(message, message_type),
+            Screen::ContestDetailsScreen(screen) => screen.display_message(message, message_type),
             Screen::DocumentQueryScreen(screen) => screen.display_message(message, message_type),
             Screen::AddNewWalletScreen(screen) => screen.display_message(message, message_type),
             Screen::ImportWalletScreen(screen) => screen.display_message(message, message_type),
@@ -865,11 +944,14 @@ impl ScreenLike for Screen {
                 screen.display_message(message, message_type)
             }
             Screen::PlatformInfoScreen(screen) => screen.display_message(message, message_type),
+            Screen::LogViewerScreen(screen) => screen.display_message(message, message_type),
+            Screen::ActivityLogScreen(screen) => screen.display_message(message, message_type),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.display_message(message, message_type),
             Screen::TransferTokensScreen(screen) => screen.display_message(message, message_type),
             Screen::MintTokensScreen(screen) => screen.display_message(message, message_type),
+            Screen::DistributeTokensScreen(screen) => screen.display_message(message, message_type),
             Screen::BurnTokensScreen(screen) => screen.display_message(message, message_type),
             Screen::DestroyFrozenFundsScreen(screen) => {
                 screen.display_message(message, message_type)
@@ -895,6 +977,9 @@ impl ScreenLike for Screen {
                 screen.display_task_result(backend_task_success_result)
             }
             Screen::DPNSScreen(screen) => screen.display_task_result(backend_task_success_result),
+            Screen::ContestDetailsScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
             Screen::DocumentQueryScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
@@ -966,6 +1051,12 @@ impl ScreenLike for Screen {
             Screen::PlatformInfoScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
+            Screen::LogViewerScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
+            Screen::ActivityLogScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.display_task_result(backend_task_success_result),
@@ -975,6 +1066,9 @@ impl ScreenLike for Screen {
             Screen::MintTokensScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
+            Screen::DistributeTokensScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
             Screen::BurnTokensScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
@@ -1016,6 +1110,7 @@ impl ScreenLike for Screen {
         match self {
             Screen::IdentitiesScreen(screen) => screen.pop_on_success(),
             Screen::DPNSScreen(screen) => screen.pop_on_success(),
+            Screen::ContestDetailsScreen(screen) => screen.pop_on_success(),
             Screen::DocumentQueryScreen(screen) => screen.pop_on_success(),
             Screen::AddNewWalletScreen(screen) => screen.pop_on_success(),
             Screen::ImportWalletScreen(screen) => screen.pop_on_success(),
@@ -1041,11 +1136,14 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.pop_on_success(),
             Screen::ContractVisualizerScreen(screen) => screen.pop_on_success(),
             Screen::PlatformInfoScreen(screen) => screen.pop_on_success(),
+            Screen::LogViewerScreen(screen) => screen.pop_on_success(),
+            Screen::ActivityLogScreen(screen) => screen.pop_on_success(),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.pop_on_success(),
             Screen::TransferTokensScreen(screen) => screen.pop_on_success(),
             Screen::MintTokensScreen(screen) => screen.pop_on_success(),
+            Screen::DistributeTokensScreen(screen) => screen.pop_on_success(),
             Screen::BurnTokensScreen(screen) => screen.pop_on_success(),
             Screen::DestroyFrozenFundsScreen(screen) => screen.pop_on_success(),
             Screen::FreezeTokensScreen(screen) => screen.pop_on_success(),
@@ -1,24 +1,46 @@
 use crate::app::AppAction;
 use crate::backend_task::core::{CoreItem, CoreTask};
-use crate::backend_task::system_task::SystemTask;
-use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
+use crate::backend_task::system_task::{DapiEndpointTest, SystemTask, parse_dapi_addresses};
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult, MAX_BACKEND_TASK_RETRIES};
 use crate::config::Config;
 use crate::context::AppContext;
+use crate::model::wallet::{DEFAULT_WALLET_AUTO_LOCK_MINUTES, MAX_WALLET_AUTO_LOCK_MINUTES};
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::DEFAULT_LOW_BALANCE_WARNING_CREDITS;
+use crate::ui::components::mainnet_confirmation::{
+    IrreversibleAction, MainnetConfirmationSettings,
+};
 use crate::ui::components::styled::{
     ClickableCollapsingHeader, StyledCard, StyledCheckbox, island_central_panel,
 };
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::dpns::dpns_contested_names_screen::{
+    DEFAULT_CONTEST_ENDING_SOON_MINUTES, MAX_CONTEST_ENDING_SOON_MINUTES,
+};
 use crate::ui::theme::{DashColors, ThemeMode};
-use crate::ui::{RootScreenType, ScreenLike};
+use crate::ui::{MessageType, RootScreenType, ScreenLike};
 use crate::utils::path::format_path_for_display;
 use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::fee::Credits;
 use dash_sdk::dpp::identity::TimestampMillis;
 use eframe::egui::{self, Context, Ui};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+/// Where the editing of the custom DAPI addresses of a network stands
+#[derive(Debug, Clone, PartialEq)]
+enum DapiEndpointsEdit {
+    Editing,
+    /// The addresses are being tested, then saved if `save` is set and any of them answers
+    Testing {
+        addresses: Vec<String>,
+        save: bool,
+    },
+    /// None of the addresses answered, waiting for the user to save them anyway or not
+    ConfirmUnreachable(Vec<String>),
+}
+
 pub struct NetworkChooserScreen {
     pub mainnet_app_context: Arc<AppContext>,
     pub testnet_app_context: Option<Arc<AppContext>>,
@@ -36,6 +58,26 @@ pub struct NetworkChooserScreen {
     overwrite_dash_conf: bool,
     developer_mode: bool,
     theme_preference: ThemeMode,
+    mainnet_confirmation: MainnetConfirmationSettings,
+    backend_task_retries: u8,
+    /// Idle minutes before unlocked wallets are locked, 0 when disabled
+    wallet_auto_lock_minutes: u16,
+    /// Credit balance under which action screens warn about the signing identity, 0 when
+    /// disabled
+    low_balance_warning_credits: Credits,
+    /// Minutes before their end active contests are highlighted, 0 when disabled
+    contest_ending_soon_minutes: u16,
+    contest_auto_refresh: bool,
+    /// Network whose DAPI addresses are edited
+    dapi_network: Network,
+    /// DAPI addresses being edited, one per line
+    dapi_addresses_input: String,
+    dapi_endpoints_edit: DapiEndpointsEdit,
+    /// Results of the last test of the DAPI addresses
+    dapi_endpoint_tests: Vec<DapiEndpointTest>,
+    dapi_endpoints_message: Option<(MessageType, String)>,
+    /// Set once the DAPI addresses of the current network changed, to check the new connection
+    check_platform_connection: bool,
     should_reset_collapsing_states: bool,
 }
 
@@ -93,6 +135,18 @@ impl NetworkChooserScreen {
             overwrite_dash_conf,
             developer_mode,
             theme_preference,
+            mainnet_confirmation: mainnet_app_context.mainnet_confirmation_settings(),
+            backend_task_retries: current_context.backend_task_retries(),
+            wallet_auto_lock_minutes: current_context.wallet_auto_lock_minutes(),
+            low_balance_warning_credits: current_context.low_balance_warning_credits(),
+            contest_ending_soon_minutes: current_context.contest_ending_soon_minutes(),
+            contest_auto_refresh: current_context.contest_auto_refresh(),
+            dapi_network: current_network,
+            dapi_addresses_input: current_context.dapi_addresses().join("\n"),
+            dapi_endpoints_edit: DapiEndpointsEdit::Editing,
+            dapi_endpoint_tests: Vec::new(),
+            dapi_endpoints_message: None,
+            check_platform_connection: false,
             should_reset_collapsing_states: true, // Start with collapsed state
         }
     }
@@ -446,6 +500,90 @@ impl NetworkChooserScreen {
                             });
                         });
 
+                        // Mainnet Safety Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Mainnet Safety")
+                                        .strong()
+                                        .color(DashColors::text_primary(dark_mode)),
+                                );
+                                ui.add_space(8.0);
+                                self.render_mainnet_confirmation_settings(ui);
+                            });
+                        });
+
+                        // Network Requests Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Network Requests")
+                                        .strong()
+                                        .color(DashColors::text_primary(dark_mode)),
+                                );
+                                ui.add_space(8.0);
+                                self.render_backend_task_retries_setting(ui);
+                            });
+                        });
+
+                        // DAPI Endpoints Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new("DAPI Endpoints")
+                                        .strong()
+                                        .color(DashColors::text_primary(dark_mode)),
+                                );
+                                ui.add_space(8.0);
+                                app_action |= self.render_dapi_endpoints_setting(ui);
+                            });
+                        });
+
+                        // Security Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Security")
+                                        .strong()
+                                        .color(DashColors::text_primary(dark_mode)),
+                                );
+                                ui.add_space(8.0);
+                                self.render_wallet_auto_lock_setting(ui);
+                            });
+                        });
+
+                        // Identities Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Identities")
+                                        .strong()
+                                        .color(DashColors::text_primary(dark_mode)),
+                                );
+                                ui.add_space(8.0);
+                                self.render_low_balance_warning_setting(ui);
+                            });
+                        });
+
+                        // DPNS Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new("DPNS")
+                                        .strong()
+                                        .color(DashColors::text_primary(dark_mode)),
+                                );
+                                ui.add_space(8.0);
+                                self.render_contest_ending_soon_setting(ui);
+                            });
+                        });
+
                         // Configuration Requirements Section (only show if not overwriting dash.conf)
                         if !self.overwrite_dash_conf {
                             ui.add_space(16.0);
@@ -515,6 +653,457 @@ impl NetworkChooserScreen {
         app_action
     }
 
+    /// Renders the double confirmation toggle and the actions it applies to. The settings only
+    /// matter on mainnet, so they are kept in the mainnet context.
+    fn render_mainnet_confirmation_settings(&mut self, ui: &mut Ui) {
+        let mut changed = StyledCheckbox::new(
+            &mut self.mainnet_confirmation.enabled,
+            "Double confirm irreversible actions on mainnet",
+        )
+        .show(ui)
+        .clicked();
+        ui.label(
+            egui::RichText::new(
+                "Asks you to type the token or identity name before these actions go through",
+            )
+            .color(DashColors::TEXT_SECONDARY),
+        );
+
+        ui.add_enabled_ui(self.mainnet_confirmation.enabled, |ui| {
+            for action in IrreversibleAction::ALL {
+                let mut selected = self.mainnet_confirmation.actions.contains(&action);
+                if ui.checkbox(&mut selected, action.label()).changed() {
+                    if selected {
+                        self.mainnet_confirmation.actions.insert(action);
+                    } else {
+                        self.mainnet_confirmation.actions.remove(&action);
+                    }
+                    changed = true;
+                }
+            }
+        });
+
+        if changed {
+            if let Err(e) = self
+                .mainnet_app_context
+                .update_mainnet_confirmation_settings(self.mainnet_confirmation.clone())
+            {
+                eprintln!("Failed to save mainnet confirmation settings: {e}");
+            }
+        }
+    }
+
+    /// Renders the number of retries for queries failing with a network error or timeout. The
+    /// setting is shared by all networks, so every loaded context picks it up.
+    fn render_backend_task_retries_setting(&mut self, ui: &mut Ui) {
+        let changed = ui
+            .horizontal(|ui| {
+                ui.label("Retries on network errors:");
+                ui.add(egui::Slider::new(
+                    &mut self.backend_task_retries,
+                    0..=MAX_BACKEND_TASK_RETRIES,
+                ))
+                .changed()
+            })
+            .inner;
+        ui.label(
+            egui::RichText::new(
+                "Queries failing with a timeout or connection error are retried with increasing \
+                 delays. Transactions are never retried.",
+            )
+            .color(DashColors::TEXT_SECONDARY),
+        );
+
+        if changed {
+            let contexts = [
+                Some(&self.mainnet_app_context),
+                self.testnet_app_context.as_ref(),
+                self.devnet_app_context.as_ref(),
+                self.local_app_context.as_ref(),
+            ];
+            for context in contexts.into_iter().flatten() {
+                if let Err(e) = context.update_backend_task_retries(self.backend_task_retries) {
+                    eprintln!("Failed to save backend task retries: {e}");
+                }
+            }
+        }
+    }
+
+    /// Networks that are loaded, so their DAPI addresses can be edited
+    fn dapi_networks(&self) -> Vec<(Network, &'static str)> {
+        [
+            (Network::Dash, "Mainnet", true),
+            (
+                Network::Testnet,
+                "Testnet",
+                self.testnet_app_context.is_some(),
+            ),
+            (Network::Devnet, "Devnet", self.devnet_app_context.is_some()),
+            (Network::Regtest, "Local", self.local_app_context.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, _, loaded)| *loaded)
+        .map(|(network, name, _)| (network, name))
+        .collect()
+    }
+
+    /// Starts editing the DAPI addresses of `network`, forgetting the tests of the previous ones
+    fn select_dapi_network(&mut self, network: Network) {
+        self.dapi_network = network;
+        self.dapi_addresses_input = self
+            .context_for_network(network)
+            .dapi_addresses()
+            .join("\n");
+        self.dapi_endpoints_edit = DapiEndpointsEdit::Editing;
+        self.dapi_endpoint_tests.clear();
+        self.dapi_endpoints_message = None;
+    }
+
+    /// Saves `addresses` as the DAPI addresses of the edited network, or goes back to the ones
+    /// from the config file when `None`, and reconnects to them
+    fn save_dapi_addresses(&mut self, addresses: Option<Vec<String>>) {
+        self.dapi_endpoints_edit = DapiEndpointsEdit::Editing;
+        let context = self.context_for_network(self.dapi_network).clone();
+        match context.update_custom_dapi_addresses(addresses.as_deref()) {
+            Ok(()) => {
+                self.dapi_addresses_input = context.dapi_addresses().join("\n");
+                self.dapi_endpoints_message = Some((
+                    MessageType::Success,
+                    if addresses.is_some() {
+                        "Custom DAPI endpoints saved, reconnected to them".to_string()
+                    } else {
+                        "Back to the DAPI endpoints of the config file, reconnected to them"
+                            .to_string()
+                    },
+                ));
+                self.check_platform_connection |= self.dapi_network == self.current_network;
+            }
+            Err(e) => self.dapi_endpoints_message = Some((MessageType::Error, e)),
+        }
+    }
+
+    /// Renders the DAPI addresses of a network, with buttons to test them and to save them
+    /// once tested. Saving warns first when none of them answered.
+    fn render_dapi_endpoints_setting(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let testing = matches!(self.dapi_endpoints_edit, DapiEndpointsEdit::Testing { .. });
+
+        ui.horizontal(|ui| {
+            ui.label("Network:");
+            let networks = self.dapi_networks();
+            let selected_name = networks
+                .iter()
+                .find(|(network, _)| *network == self.dapi_network)
+                .map_or("Mainnet", |(_, name)| *name);
+            let mut selected = self.dapi_network;
+            egui::ComboBox::from_id_salt("dapi_network")
+                .selected_text(selected_name)
+                .show_ui(ui, |ui| {
+                    for (network, name) in &networks {
+                        ui.selectable_value(&mut selected, *network, *name);
+                    }
+                });
+            if selected != self.dapi_network && !testing {
+                self.select_dapi_network(selected);
+            }
+        });
+        ui.add_space(4.0);
+        ui.add_enabled(
+            !testing,
+            egui::TextEdit::multiline(&mut self.dapi_addresses_input)
+                .hint_text("https://127.0.0.1:1443")
+                .desired_rows(4)
+                .desired_width(f32::INFINITY),
+        );
+        let has_custom_addresses = self
+            .context_for_network(self.dapi_network)
+            .has_custom_dapi_addresses();
+        ui.label(
+            egui::RichText::new(if has_custom_addresses {
+                "Using custom endpoints. One address per line, with its http:// or https:// scheme."
+            } else {
+                "Using the endpoints of the config file. One address per line, with its \
+                 http:// or https:// scheme."
+            })
+            .color(DashColors::TEXT_SECONDARY),
+        );
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            let test_clicked = ui
+                .add_enabled(!testing, egui::Button::new("Test"))
+                .on_hover_text("Ask each endpoint for its status, reporting latency and height")
+                .clicked();
+            let save_clicked = ui
+                .add_enabled(!testing, egui::Button::new("Save"))
+                .on_hover_text("Test the endpoints, then connect to them")
+                .clicked();
+            if test_clicked || save_clicked {
+                match parse_dapi_addresses(&self.dapi_addresses_input) {
+                    Ok(addresses) => {
+                        self.dapi_endpoints_message = None;
+                        self.dapi_endpoint_tests.clear();
+                        self.dapi_endpoints_edit = DapiEndpointsEdit::Testing {
+                            addresses: addresses.clone(),
+                            save: save_clicked,
+                        };
+                        action = AppAction::BackendTask(BackendTask::SystemTask(
+                            SystemTask::TestDapiEndpoints(addresses),
+                        ));
+                    }
+                    Err(e) => self.dapi_endpoints_message = Some((MessageType::Error, e)),
+                }
+            }
+            if ui
+                .add_enabled(
+                    !testing && has_custom_addresses,
+                    egui::Button::new("Use Config File Endpoints"),
+                )
+                .clicked()
+            {
+                self.dapi_endpoint_tests.clear();
+                self.save_dapi_addresses(None);
+            }
+            if testing {
+                ui.add(egui::widgets::Spinner::default().color(DashColors::DASH_BLUE));
+                ui.label("Testing endpoints...");
+            }
+        });
+
+        if let DapiEndpointsEdit::ConfirmUnreachable(addresses) = self.dapi_endpoints_edit.clone() {
+            ui.add_space(4.0);
+            ui.colored_label(
+                DashColors::WARNING,
+                "None of the endpoints answered. Save them anyway?",
+            );
+            ui.horizontal(|ui| {
+                if ui.button("Save Anyway").clicked() {
+                    self.save_dapi_addresses(Some(addresses));
+                }
+                if ui.button("Cancel").clicked() {
+                    self.dapi_endpoints_edit = DapiEndpointsEdit::Editing;
+                }
+            });
+        }
+
+        if let Some((message_type, message)) = &self.dapi_endpoints_message {
+            let color = match message_type {
+                MessageType::Success => DashColors::success_color(dark_mode),
+                MessageType::Info => DashColors::text_primary(dark_mode),
+                MessageType::Error => DashColors::error_color(dark_mode),
+            };
+            ui.add_space(4.0);
+            ui.colored_label(color, message.as_str());
+        }
+
+        if !self.dapi_endpoint_tests.is_empty() {
+            ui.add_space(8.0);
+            egui::Grid::new("dapi_endpoint_tests")
+                .striped(false)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new("Address").strong());
+                    ui.label(egui::RichText::new("Latency").strong());
+                    ui.label(egui::RichText::new("Platform Height").strong());
+                    ui.end_row();
+                    for test in &self.dapi_endpoint_tests {
+                        ui.label(test.address.as_str());
+                        match &test.result {
+                            Ok((latency, height)) => {
+                                ui.label(format!("{} ms", latency.as_millis()));
+                                ui.label(height.to_string());
+                            }
+                            Err(e) => {
+                                ui.colored_label(DashColors::error_color(dark_mode), "Unreachable")
+                                    .on_hover_text(e.as_str());
+                                ui.label("-");
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+
+        action
+    }
+
+    /// Renders after how many idle minutes unlocked wallets are locked again. Like the retries,
+    /// the setting is shared by all networks.
+    fn render_wallet_auto_lock_setting(&mut self, ui: &mut Ui) {
+        let mut enabled = self.wallet_auto_lock_minutes > 0;
+        let mut changed = ui
+            .checkbox(&mut enabled, "Lock wallets after inactivity")
+            .changed();
+        if changed {
+            self.wallet_auto_lock_minutes = if enabled {
+                DEFAULT_WALLET_AUTO_LOCK_MINUTES
+            } else {
+                0
+            };
+        }
+        if enabled {
+            changed |= ui
+                .horizontal(|ui| {
+                    ui.label("Lock after (minutes):");
+                    ui.add(egui::Slider::new(
+                        &mut self.wallet_auto_lock_minutes,
+                        1..=MAX_WALLET_AUTO_LOCK_MINUTES,
+                    ))
+                    .changed()
+                })
+                .inner;
+        }
+        ui.label(
+            egui::RichText::new(
+                "Unlocked wallets are locked again and their decrypted keys erased from memory \
+                 when the app is not used for this long. Wallets without a password stay open.",
+            )
+            .color(DashColors::TEXT_SECONDARY),
+        );
+
+        if changed {
+            let contexts = [
+                Some(&self.mainnet_app_context),
+                self.testnet_app_context.as_ref(),
+                self.devnet_app_context.as_ref(),
+                self.local_app_context.as_ref(),
+            ];
+            for context in contexts.into_iter().flatten() {
+                if let Err(e) =
+                    context.update_wallet_auto_lock_minutes(self.wallet_auto_lock_minutes)
+                {
+                    eprintln!("Failed to save wallet auto-lock timeout: {e}");
+                }
+            }
+        }
+    }
+
+    /// Renders the credit balance under which token and DPNS action screens warn about the
+    /// signing identity. Like the retries, the setting is shared by all networks.
+    fn render_low_balance_warning_setting(&mut self, ui: &mut Ui) {
+        let mut enabled = self.low_balance_warning_credits > 0;
+        let mut changed = ui
+            .checkbox(
+                &mut enabled,
+                "Warn when the signing identity runs low on credits",
+            )
+            .changed();
+        if changed {
+            self.low_balance_warning_credits = if enabled {
+                DEFAULT_LOW_BALANCE_WARNING_CREDITS
+            } else {
+                0
+            };
+        }
+        if enabled {
+            let mut dash = self.low_balance_warning_credits as f64 / 100_000_000_000.0;
+            let dash_changed = ui
+                .horizontal(|ui| {
+                    ui.label("Warn under (DASH):");
+                    ui.add(
+                        egui::DragValue::new(&mut dash)
+                            .speed(0.001)
+                            .range(0.00001..=10.0)
+                            .max_decimals(5),
+                    )
+                    .changed()
+                })
+                .inner;
+            if dash_changed {
+                self.low_balance_warning_credits = (dash * 100_000_000_000.0).round() as Credits;
+                changed = true;
+            }
+        }
+        ui.label(
+            egui::RichText::new(
+                "Token and DPNS action screens show a banner with a shortcut to top up the \
+                 signing identity when its balance is under this amount.",
+            )
+            .color(DashColors::TEXT_SECONDARY),
+        );
+
+        if changed {
+            let contexts = [
+                Some(&self.mainnet_app_context),
+                self.testnet_app_context.as_ref(),
+                self.devnet_app_context.as_ref(),
+                self.local_app_context.as_ref(),
+            ];
+            for context in contexts.into_iter().flatten() {
+                if let Err(e) =
+                    context.update_low_balance_warning_credits(self.low_balance_warning_credits)
+                {
+                    eprintln!("Failed to save low balance warning threshold: {e}");
+                }
+            }
+        }
+    }
+
+    /// Renders how long before their end active contests are highlighted, and whether they
+    /// are refreshed automatically meanwhile. Like the retries, the settings are shared by all
+    /// networks.
+    fn render_contest_ending_soon_setting(&mut self, ui: &mut Ui) {
+        let mut enabled = self.contest_ending_soon_minutes > 0;
+        let mut minutes_changed = ui
+            .checkbox(&mut enabled, "Highlight contests ending soon")
+            .changed();
+        if minutes_changed {
+            self.contest_ending_soon_minutes = if enabled {
+                DEFAULT_CONTEST_ENDING_SOON_MINUTES
+            } else {
+                0
+            };
+        }
+        let mut auto_refresh_changed = false;
+        if enabled {
+            minutes_changed |= ui
+                .horizontal(|ui| {
+                    ui.label("Highlight within (minutes):");
+                    ui.add(egui::Slider::new(
+                        &mut self.contest_ending_soon_minutes,
+                        1..=MAX_CONTEST_ENDING_SOON_MINUTES,
+                    ))
+                    .changed()
+                })
+                .inner;
+            auto_refresh_changed = ui
+                .checkbox(
+                    &mut self.contest_auto_refresh,
+                    "Refresh the contests every minute while one of them ends soon",
+                )
+                .changed();
+        }
+        ui.label(
+            egui::RichText::new(
+                "Active contests ending within this window get a highlighted row, and can be \
+                 refreshed automatically so their vote tallies stay current.",
+            )
+            .color(DashColors::TEXT_SECONDARY),
+        );
+
+        let contexts = [
+            Some(&self.mainnet_app_context),
+            self.testnet_app_context.as_ref(),
+            self.devnet_app_context.as_ref(),
+            self.local_app_context.as_ref(),
+        ];
+        for context in contexts.into_iter().flatten() {
+            if minutes_changed {
+                if let Err(e) =
+                    context.update_contest_ending_soon_minutes(self.contest_ending_soon_minutes)
+                {
+                    eprintln!("Failed to save contest ending soon window: {e}");
+                }
+            }
+            if auto_refresh_changed {
+                if let Err(e) = context.update_contest_auto_refresh(self.contest_auto_refresh) {
+                    eprintln!("Failed to save contest auto-refresh setting: {e}");
+                }
+            }
+        }
+    }
+
     /// Render a single row for the network table
     fn render_network_row(&mut self, ui: &mut Ui, network: Network, name: &str) -> AppAction {
         let mut app_action = AppAction::None;
@@ -612,9 +1201,12 @@ impl NetworkChooserScreen {
                         // 5) Update our local AppContext in memory
                         if let Some(local_app_context) = &self.local_app_context {
                             {
-                                // Overwrite the config field with the new password
+                                // Overwrite the config field with the new password, keeping
+                                // the custom DAPI addresses, which aren't in the config file
                                 let mut cfg_lock = local_app_context.config.write().unwrap();
+                                let dapi_addresses = std::mem::take(&mut cfg_lock.dapi_addresses);
                                 *cfg_lock = updated_local_config;
+                                cfg_lock.dapi_addresses = dapi_addresses;
                             }
 
                             // 6) Re-init the client & sdk from the updated config
@@ -665,6 +1257,11 @@ impl ScreenLike for NetworkChooserScreen {
         // This ensures dropdowns are closed when navigating back
         self.should_reset_collapsing_states = true;
 
+        // A test that finished on another screen never reported back
+        if matches!(self.dapi_endpoints_edit, DapiEndpointsEdit::Testing { .. }) {
+            self.dapi_endpoints_edit = DapiEndpointsEdit::Editing;
+        }
+
         // Reload settings from database to ensure we have the latest values
         if let Ok(Some((_, _, _, custom_dash_qt_path, overwrite_dash_conf, theme_preference))) =
             self.current_app_context().get_settings()
@@ -686,6 +1283,20 @@ impl ScreenLike for NetworkChooserScreen {
     }
 
     fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::DapiEndpointTests(tests) = backend_task_success_result {
+            let any_reachable = tests.iter().any(|test| test.result.is_ok());
+            self.dapi_endpoint_tests = tests;
+            if let DapiEndpointsEdit::Testing { addresses, save } =
+                std::mem::replace(&mut self.dapi_endpoints_edit, DapiEndpointsEdit::Editing)
+            {
+                if save && any_reachable {
+                    self.save_dapi_addresses(Some(addresses));
+                } else if save {
+                    self.dapi_endpoints_edit = DapiEndpointsEdit::ConfirmUnreachable(addresses);
+                }
+            }
+            return;
+        }
         if let BackendTaskSuccessResult::CoreItem(CoreItem::ChainLocks(
             mainnet_chainlock,
             testnet_chainlock,
@@ -733,6 +1344,14 @@ impl ScreenLike for NetworkChooserScreen {
                 .inner
         });
 
+        // Check the connection right away once the DAPI addresses changed
+        if self.check_platform_connection && action == AppAction::None {
+            self.check_platform_connection = false;
+            action = AppAction::BackendTask(BackendTask::SystemTask(
+                SystemTask::CheckPlatformConnection,
+            ));
+        }
+
         // Recheck both network status every 3 seconds
         let recheck_time = Duration::from_secs(3);
         if action == AppAction::None {
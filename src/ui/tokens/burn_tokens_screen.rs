@@ -1,13 +1,21 @@
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
+use crate::ui::components::mainnet_confirmation::{IrreversibleAction, MainnetConfirmation};
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::token_action_simulation::TokenActionSimulation;
+use crate::ui::components::token_amount_input::{parse_token_amount, render_token_amount_input};
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::contracts_documents::group_actions_screen::GroupActionsScreen;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser, render_group_action_text};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+    render_group_action_text,
+};
 use crate::ui::theme::DashColors;
 use dash_sdk::dpp::data_contract::GroupContractPosition;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use dash_sdk::dpp::data_contract::group::Group;
 use dash_sdk::dpp::data_contract::group::accessors::v0::GroupV0Getters;
@@ -22,8 +30,8 @@ use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::app::{AppAction, BackendTasksExecutionMode};
-use crate::backend_task::BackendTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
 use crate::ui::components::top_panel::add_top_panel;
@@ -63,6 +71,8 @@ pub struct BurnTokensScreen {
 
     // Confirmation popup
     show_confirmation_popup: bool,
+    simulation: TokenActionSimulation,
+    mainnet_confirmation: MainnetConfirmation,
 
     // For password-based wallet unlocking, if needed
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
@@ -185,17 +195,27 @@ impl BurnTokensScreen {
             error_message,
             app_context: app_context.clone(),
             show_confirmation_popup: false,
+            mainnet_confirmation: MainnetConfirmation::default(),
             selected_wallet,
             wallet_password: String::new(),
             show_password: false,
+            simulation: TokenActionSimulation::default(),
         }
     }
 
+    fn token_decimals(&self) -> u8 {
+        self.identity_token_info
+            .token_config
+            .conventions()
+            .decimals()
+    }
+
     /// Renders a text input for the user to specify an amount to burn
     fn render_amount_input(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
             ui.label("Amount to Burn:");
-            ui.text_edit_singleline(&mut self.amount_to_burn);
+            let decimals = self.token_decimals();
+            let _ = render_token_amount_input(ui, &mut self.amount_to_burn, decimals, None);
         });
     }
 
@@ -208,13 +228,15 @@ impl BurnTokensScreen {
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
                 // Validate user input
-                let amount_ok = self.amount_to_burn.parse::<u64>().ok();
-                if amount_ok.is_none() {
-                    self.error_message = Some("Please enter a valid integer amount.".into());
-                    self.status = BurnTokensStatus::ErrorMessage("Invalid amount".into());
-                    self.show_confirmation_popup = false;
-                    return;
-                }
+                let amount = match parse_token_amount(&self.amount_to_burn, self.token_decimals()) {
+                    Ok(amount) => amount,
+                    Err(e) => {
+                        self.error_message = Some(e.clone());
+                        self.status = BurnTokensStatus::ErrorMessage(e);
+                        self.show_confirmation_popup = false;
+                        return;
+                    }
+                };
 
                 ui.label(format!(
                     "Are you sure you want to burn {} tokens?",
@@ -223,8 +245,18 @@ impl BurnTokensScreen {
 
                 ui.add_space(10.0);
 
+                let confirmed = self.mainnet_confirmation.show(
+                    ui,
+                    &self.app_context,
+                    IrreversibleAction::BurnTokens,
+                    Some(&self.identity_token_info.token_alias),
+                );
+
                 // Confirm button
-                if ui.button("Confirm").clicked() {
+                if ui
+                    .add_enabled(confirmed, egui::Button::new("Confirm"))
+                    .clicked()
+                {
                     self.show_confirmation_popup = false;
                     let now = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -253,25 +285,30 @@ impl BurnTokensScreen {
                     };
 
                     // Dispatch the actual backend burn action
-                    action = AppAction::BackendTasks(
-                        vec![
-                            BackendTask::TokenTask(Box::new(TokenTask::BurnTokens {
-                                owner_identity: self.identity_token_info.identity.clone(),
-                                data_contract,
-                                token_position: self.identity_token_info.token_position,
-                                signing_key: self.selected_key.clone().expect("Expected a key"),
-                                public_note: if self.group_action_id.is_some() {
-                                    None
-                                } else {
-                                    self.public_note.clone()
-                                },
-                                amount: amount_ok.unwrap(),
-                                group_info,
-                            })),
-                            BackendTask::TokenTask(Box::new(TokenTask::QueryMyTokenBalances)),
-                        ],
-                        BackendTasksExecutionMode::Sequential,
-                    );
+                    let burn = self.simulation.task(TokenTask::BurnTokens {
+                        owner_identity: self.identity_token_info.identity.clone(),
+                        data_contract,
+                        token_position: self.identity_token_info.token_position,
+                        signing_key: self.selected_key.clone().expect("Expected a key"),
+                        public_note: if self.group_action_id.is_some() {
+                            None
+                        } else {
+                            self.public_note.clone()
+                        },
+                        amount,
+                        group_info,
+                    });
+                    action = if self.simulation.enabled {
+                        AppAction::BackendTask(burn)
+                    } else {
+                        AppAction::BackendTasks(
+                            vec![
+                                burn,
+                                BackendTask::TokenTask(Box::new(TokenTask::QueryMyTokenBalances)),
+                            ],
+                            BackendTasksExecutionMode::Sequential,
+                        )
+                    };
                 }
 
                 // Cancel button
@@ -333,6 +370,19 @@ impl BurnTokensScreen {
 }
 
 impl ScreenLike for BurnTokensScreen {
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::SimulationResult {
+            transition,
+            size_bytes,
+            minimum_fee,
+        } = backend_task_success_result
+        {
+            self.simulation
+                .set_result(&transition, size_bytes, minimum_fee);
+            self.status = BurnTokensStatus::NotStarted;
+        }
+    }
+
     fn display_message(&mut self, message: &str, message_type: MessageType) {
         match message_type {
             MessageType::Success => {
@@ -410,6 +460,11 @@ impl ScreenLike for BurnTokensScreen {
 
             ui.heading("Burn Tokens");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(
+                ui,
+                &self.app_context,
+                &self.identity_token_info.identity,
+            );
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -558,9 +613,20 @@ impl ScreenLike for BurnTokensScreen {
                             .fill(Color32::from_rgb(0, 128, 255))
                             .corner_radius(3.0);
 
-                    if ui.add(button).clicked() {
+                    if add_signing_key_guarded_button(
+                        ui,
+                        button,
+                        self.selected_key.as_ref(),
+                        &self.identity_token_info.identity,
+                        TransactionType::TokenAction,
+                        &self.app_context,
+                    )
+                    .clicked()
+                    {
                         self.show_confirmation_popup = true;
+                        self.mainnet_confirmation.reset();
                     }
+                    self.simulation.checkbox(ui);
                 }
 
                 // If user pressed "Burn," show a popup
@@ -572,7 +638,7 @@ impl ScreenLike for BurnTokensScreen {
                 ui.add_space(10.0);
                 match &self.status {
                     BurnTokensStatus::NotStarted => {
-                        // no-op
+                        self.simulation.show_result(ui);
                     }
                     BurnTokensStatus::WaitingForResult(start_time) => {
                         let now = SystemTime::now()
@@ -1,7 +1,10 @@
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+};
 use std::collections::HashSet;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -14,9 +17,10 @@ use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::distribution_recipient::TokenDistributionRecipient;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::methods::v0::TokenPerpetualDistributionV0Accessors;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::reward_distribution_type::RewardDistributionType;
-use dash_sdk::dpp::data_contract::TokenConfiguration;
+use dash_sdk::dpp::data_contract::{DataContract, TokenConfiguration};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
-use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
+use dash_sdk::dpp::identity::{Identity, KeyType, Purpose, SecurityLevel};
+use dash_sdk::platform::{Identifier, IdentityPublicKey};
 use eframe::egui::{self, Color32, Context, Ui};
 use egui::RichText;
 use crate::app::{AppAction, BackendTasksExecutionMode};
@@ -34,6 +38,57 @@ use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
 use super::tokens_screen::IdentityTokenBasicInfo;
 
+/// Key used to sign claims: the first critical authentication key, or the first critical
+/// transfer key for masternodes and evonodes
+pub(crate) fn claim_signing_key(identity: &Identity) -> Option<&IdentityPublicKey> {
+    identity
+        .get_first_public_key_matching(
+            Purpose::AUTHENTICATION,
+            HashSet::from([SecurityLevel::CRITICAL]),
+            KeyType::all_key_types().into(),
+            false,
+        )
+        .or_else(|| {
+            identity.get_first_public_key_matching(
+                Purpose::TRANSFER,
+                HashSet::from([SecurityLevel::CRITICAL]),
+                KeyType::all_key_types().into(),
+                false,
+            )
+        })
+}
+
+/// Distributions of the token `identity_id` may claim from: the perpetual one when the
+/// identity is one of its recipients, and the pre-programmed one
+pub(crate) fn claimable_distribution_types(
+    token_configuration: &TokenConfiguration,
+    contract: &DataContract,
+    identity_id: Identifier,
+) -> Vec<TokenDistributionType> {
+    let mut types = Vec::new();
+    if let Some(perpetual_distribution) = token_configuration
+        .distribution_rules()
+        .perpetual_distribution()
+    {
+        let is_recipient = match perpetual_distribution.distribution_recipient() {
+            TokenDistributionRecipient::ContractOwner => contract.owner_id() == identity_id,
+            TokenDistributionRecipient::Identity(id) => identity_id == id,
+            TokenDistributionRecipient::EvonodesByParticipation => true,
+        };
+        if is_recipient {
+            types.push(TokenDistributionType::Perpetual);
+        }
+    }
+    if token_configuration
+        .distribution_rules()
+        .pre_programmed_distribution()
+        .is_some()
+    {
+        types.push(TokenDistributionType::PreProgrammed);
+    }
+    types
+}
+
 /// States for the claim flow
 #[derive(PartialEq)]
 pub enum ClaimTokensStatus {
@@ -75,21 +130,7 @@ impl ClaimTokensScreen {
             .expect("No local qualified identity found for this token’s identity.");
 
         let identity_clone = identity.identity.clone();
-        let mut possible_key = identity_clone.get_first_public_key_matching(
-            Purpose::AUTHENTICATION,
-            HashSet::from([SecurityLevel::CRITICAL]),
-            KeyType::all_key_types().into(),
-            false,
-        );
-
-        if possible_key.is_none() {
-            possible_key = identity_clone.get_first_public_key_matching(
-                Purpose::TRANSFER,
-                HashSet::from([SecurityLevel::CRITICAL]),
-                KeyType::all_key_types().into(),
-                false,
-            );
-        }
+        let possible_key = claim_signing_key(&identity_clone);
 
         let mut error_message = None;
         let selected_wallet =
@@ -130,26 +171,13 @@ impl ClaimTokensScreen {
     }
 
     fn render_token_distribution_type_selector(&mut self, ui: &mut Ui) {
-        let show_perpetual = if let Some(perpetual_distribution) = self
-            .token_configuration
-            .distribution_rules()
-            .perpetual_distribution()
-        {
-            match perpetual_distribution.distribution_recipient() {
-                TokenDistributionRecipient::ContractOwner => {
-                    self.token_contract.contract.owner_id() == self.identity.identity.id()
-                }
-                TokenDistributionRecipient::Identity(id) => self.identity.identity.id() == id,
-                TokenDistributionRecipient::EvonodesByParticipation => true,
-            }
-        } else {
-            false
-        };
-        let show_pre_programmed = self
-            .token_configuration
-            .distribution_rules()
-            .pre_programmed_distribution()
-            .is_some();
+        let claimable = claimable_distribution_types(
+            &self.token_configuration,
+            &self.token_contract.contract,
+            self.identity.identity.id(),
+        );
+        let show_perpetual = claimable.contains(&TokenDistributionType::Perpetual);
+        let show_pre_programmed = claimable.contains(&TokenDistributionType::PreProgrammed);
         ui.horizontal(|ui| {
             ui.label("Select Distribution Type:");
             egui::ComboBox::from_id_salt("claim_distribution_type_selector")
@@ -309,6 +337,7 @@ impl ScreenLike for ClaimTokensScreen {
 
             ui.heading("Claim Tokens");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(ui, &self.app_context, &self.identity);
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -505,7 +534,16 @@ impl ScreenLike for ClaimTokensScreen {
                     .fill(Color32::from_rgb(0, 128, 0))
                     .corner_radius(3.0);
 
-                if ui.add(button).clicked() {
+                if add_signing_key_guarded_button(
+                    ui,
+                    button,
+                    self.selected_key.as_ref(),
+                    &self.identity,
+                    TransactionType::TokenClaim,
+                    &self.app_context,
+                )
+                .clicked()
+                {
                     if self.distribution_type.is_none() {
                         self.status = ClaimTokensStatus::ErrorMessage(
                             "Please select a distribution type.".to_string(),
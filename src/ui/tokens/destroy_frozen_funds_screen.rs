@@ -6,12 +6,17 @@ use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
+use crate::ui::components::mainnet_confirmation::{IrreversibleAction, MainnetConfirmation};
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
 use crate::ui::contracts_documents::group_actions_screen::GroupActionsScreen;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser, render_group_action_text};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+    render_group_action_text,
+};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
@@ -73,6 +78,7 @@ pub struct DestroyFrozenFundsScreen {
 
     /// Confirmation popup
     show_confirmation_popup: bool,
+    mainnet_confirmation: MainnetConfirmation,
 
     /// If password-based wallet unlocking is needed
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
@@ -196,6 +202,7 @@ impl DestroyFrozenFundsScreen {
             error_message,
             app_context: app_context.clone(),
             show_confirmation_popup: false,
+            mainnet_confirmation: MainnetConfirmation::default(),
             selected_wallet,
             wallet_password: String::new(),
             show_password: false,
@@ -243,8 +250,18 @@ impl DestroyFrozenFundsScreen {
 
                 ui.add_space(10.0);
 
+                let confirmed = self.mainnet_confirmation.show(
+                    ui,
+                    &self.app_context,
+                    IrreversibleAction::DestroyFrozenFunds,
+                    Some(&self.identity_token_info.token_alias),
+                );
+
                 // Confirm button
-                if ui.button("Confirm").clicked() {
+                if ui
+                    .add_enabled(confirmed, egui::Button::new("Confirm"))
+                    .clicked()
+                {
                     self.show_confirmation_popup = false;
                     let now = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -434,6 +451,7 @@ impl ScreenLike for DestroyFrozenFundsScreen {
 
             ui.heading("Destroy Frozen Funds");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(ui, &self.app_context, &self.identity);
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -570,8 +588,18 @@ impl ScreenLike for DestroyFrozenFundsScreen {
                             .fill(Color32::from_rgb(0, 128, 255))
                             .corner_radius(3.0);
 
-                    if ui.add(button).clicked() {
+                    if add_signing_key_guarded_button(
+                        ui,
+                        button,
+                        self.selected_key.as_ref(),
+                        &self.identity,
+                        TransactionType::TokenAction,
+                        &self.app_context,
+                    )
+                    .clicked()
+                    {
                         self.show_confirmation_popup = true;
+                        self.mainnet_confirmation.reset();
                     }
                 }
 
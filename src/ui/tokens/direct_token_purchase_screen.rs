@@ -15,14 +15,18 @@ use crate::backend_task::tokens::TokenTask;
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
+use crate::ui::identities::top_up_identity_screen::render_low_balance_prompt;
 use crate::ui::theme::DashColors;
 use crate::ui::{BackendTaskSuccessResult, MessageType, Screen, ScreenLike};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
@@ -256,7 +260,8 @@ impl PurchaseTokenScreen {
                             })),
                             BackendTask::TokenTask(Box::new(TokenTask::QueryMyTokenBalances)),
                         ],
-                        BackendTasksExecutionMode::Sequential,
+                        // No point refreshing balances when the purchase didn't go through
+                        BackendTasksExecutionMode::SequentialFailFast,
                     );
                 }
 
@@ -381,6 +386,11 @@ impl ScreenLike for PurchaseTokenScreen {
 
             ui.heading("Purchase Tokens");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(
+                ui,
+                &self.app_context,
+                &self.identity_token_info.identity,
+            );
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -475,12 +485,24 @@ impl ScreenLike for PurchaseTokenScreen {
                 ui.add_space(10.0);
 
                 // Display calculated price
+                let mut can_afford = true;
                 if let Some(calculated_price) = self.calculated_price {
                     ui.group(|ui| {
                         ui.heading("Calculated total price:");
                         ui.label(format!("{} credits", calculated_price));
                         ui.label("Note: This is the calculated price based on the current pricing schedule.");
                     });
+
+                    if self.identity_token_info.identity.identity.balance() < calculated_price {
+                        can_afford = false;
+                        ui.add_space(5.0);
+                        action |= render_low_balance_prompt(
+                            ui,
+                            &self.app_context,
+                            &self.identity_token_info.identity,
+                            calculated_price,
+                        );
+                    }
                 } else if self.fetched_pricing_schedule.is_some() {
                     ui.colored_label(
                         DashColors::error_color(dark_mode),
@@ -495,8 +517,9 @@ impl ScreenLike for PurchaseTokenScreen {
                 ui.add_space(10.0);
 
                 // Purchase button (disabled if no pricing is available)
-                let can_purchase =
-                    self.fetched_pricing_schedule.is_some() && self.calculated_price.is_some();
+                let can_purchase = self.fetched_pricing_schedule.is_some()
+                    && self.calculated_price.is_some()
+                    && can_afford;
                 let purchase_text = "Purchase".to_string();
 
                 if can_purchase {
@@ -505,7 +528,16 @@ impl ScreenLike for PurchaseTokenScreen {
                             .fill(Color32::from_rgb(0, 128, 255))
                             .corner_radius(3.0);
 
-                    if ui.add(button).clicked() {
+                    if add_signing_key_guarded_button(
+                        ui,
+                        button,
+                        self.selected_key.as_ref(),
+                        &self.identity_token_info.identity,
+                        TransactionType::TokenAction,
+                        &self.app_context,
+                    )
+                    .clicked()
+                    {
                         self.show_confirmation_popup = true;
                     }
                 } else {
@@ -518,6 +550,8 @@ impl ScreenLike for PurchaseTokenScreen {
                     ui.add_enabled(false, button).on_hover_text(
                         if self.pricing_fetch_attempted && self.fetched_pricing_schedule.is_none() {
                             "This token is not available for purchase"
+                        } else if !can_afford {
+                            "Insufficient credits for this purchase"
                         } else {
                             "Fetch token price and enter amount first"
                         },
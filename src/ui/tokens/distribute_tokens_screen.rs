@@ -0,0 +1,892 @@
+use crate::app::{AppAction, BackendTasksExecutionMode};
+use crate::backend_task::BackendTask;
+use crate::backend_task::tokens::TokenTask;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
+use crate::ui::components::mainnet_confirmation::{
+    IrreversibleAction, MainnetConfirmation, is_large_token_transfer,
+};
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::token_amount_input::parse_token_amount;
+use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, format_token_amount, render_identifier_cell,
+};
+use crate::ui::identities::get_selected_wallet;
+use crate::ui::theme::DashColors;
+use crate::ui::tokens::tokens_screen::IdentityTokenInfo;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::balances::credits::{Credits, TokenAmount};
+use dash_sdk::dpp::data_contract::GroupContractPosition;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
+use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
+use dash_sdk::dpp::data_contract::group::accessors::v0::GroupV0Getters;
+use dash_sdk::dpp::group::GroupStateTransitionInfoStatus;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::{Identifier, IdentityPublicKey};
+use eframe::egui::{self, Context, Ui};
+use egui::{Color32, RichText};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+/// How the tokens reach the recipients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistributionMode {
+    /// Transferred out of the balance of the distributing identity
+    Transfer,
+    /// Newly minted straight to each recipient
+    Mint,
+}
+
+impl DistributionMode {
+    fn transaction_type(&self) -> TransactionType {
+        match self {
+            DistributionMode::Transfer => TransactionType::TokenTransfer,
+            DistributionMode::Mint => TransactionType::TokenAction,
+        }
+    }
+
+    /// Message the backend reports each successful transition of this mode with
+    fn success_message(&self) -> &'static str {
+        match self {
+            DistributionMode::Transfer => "TransferTokens",
+            DistributionMode::Mint => "MintTokens",
+        }
+    }
+
+    /// Start of the error the backend reports each failed transition of this mode with
+    fn failure_prefix(&self) -> &'static str {
+        match self {
+            DistributionMode::Transfer => "Failed to transfer tokens",
+            DistributionMode::Mint => "Failed to mint tokens",
+        }
+    }
+}
+
+/// Parses a recipient list, one recipient per line as an identity ID in base58 followed by the
+/// amount, separated by a comma, a semicolon or spaces. Blank lines and lines starting with `#`
+/// are skipped. Every other line is returned with its line number and either the recipient or
+/// what is wrong with it.
+fn parse_recipients(
+    input: &str,
+    decimals: u8,
+    sender: Option<Identifier>,
+) -> Vec<(usize, Result<(Identifier, TokenAmount), String>)> {
+    let mut first_lines: HashMap<Identifier, usize> = HashMap::new();
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            let fields: Vec<&str> = line
+                .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+                .filter(|field| !field.is_empty())
+                .collect();
+            let recipient = match fields.as_slice() {
+                [id, amount] => Identifier::from_string(id, Encoding::Base58)
+                    .map_err(|_| format!("Invalid identity ID \"{}\"", id))
+                    .and_then(|id| {
+                        if Some(id) == sender {
+                            return Err("Tokens can't be sent to the sending identity".to_string());
+                        }
+                        if let Some(first_line) = first_lines.get(&id) {
+                            return Err(format!("Same recipient as line {}", first_line));
+                        }
+                        first_lines.insert(id, line_number);
+                        match parse_token_amount(amount, decimals)? {
+                            0 => Err("Amount must be greater than zero".to_string()),
+                            amount => Ok((id, amount)),
+                        }
+                    }),
+                _ => Err("Expected an identity ID and an amount".to_string()),
+            };
+            (line_number, recipient)
+        })
+        .collect()
+}
+
+/// Group the identity proposes mints in on its own, or why it can't mint to chosen recipients
+/// without the approval of others
+fn unilateral_mint_group(
+    identity_token_info: &IdentityTokenInfo,
+) -> Result<Option<GroupContractPosition>, String> {
+    let token_config = &identity_token_info.token_config;
+    let identity_id = identity_token_info.identity.identity.id();
+    if !token_config
+        .distribution_rules()
+        .minting_allow_choosing_destination()
+    {
+        return Err("The token doesn't allow choosing who minted tokens go to".to_string());
+    }
+    let group_position = match token_config
+        .manual_minting_rules()
+        .authorized_to_make_change_action_takers()
+    {
+        AuthorizedActionTakers::NoOne => {
+            return Err("Minting is not allowed on this token".to_string());
+        }
+        AuthorizedActionTakers::ContractOwner => {
+            if identity_token_info.data_contract.contract.owner_id() != identity_id {
+                return Err("Only the contract owner can mint this token".to_string());
+            }
+            return Ok(None);
+        }
+        AuthorizedActionTakers::Identity(authorized_id) => {
+            if *authorized_id != identity_id {
+                return Err("This identity is not allowed to mint this token".to_string());
+            }
+            return Ok(None);
+        }
+        AuthorizedActionTakers::MainGroup => token_config
+            .main_control_group()
+            .ok_or_else(|| "Invalid contract: No main control group".to_string())?,
+        AuthorizedActionTakers::Group(group_position) => *group_position,
+    };
+    let group = identity_token_info
+        .data_contract
+        .contract
+        .expected_group(group_position)
+        .map_err(|e| format!("Invalid contract: {}", e))?;
+    match group.members().get(&identity_id) {
+        Some(power) if *power >= group.required_power() => Ok(Some(group_position)),
+        _ => Err(
+            "Minting needs other members of the group to sign, which a batch can't wait for"
+                .to_string(),
+        ),
+    }
+}
+
+fn format_credits(credits: Credits) -> String {
+    format!(
+        "{} credits (~{:.8} DASH)",
+        credits,
+        credits as f64 / 100_000_000_000.0
+    )
+}
+
+/// A recipient of a running or finished distribution, and how its transition went
+struct RecipientOutcome {
+    recipient: Identifier,
+    amount: TokenAmount,
+    result: Option<Result<(), String>>,
+}
+
+#[derive(PartialEq)]
+pub enum DistributeTokensStatus {
+    NotStarted,
+    Distributing,
+    ErrorMessage(String),
+    Complete,
+}
+
+/// Sends a token to a list of recipients, one transition per recipient
+pub struct DistributeTokensScreen {
+    pub identity_token_info: IdentityTokenInfo,
+    selected_key: Option<IdentityPublicKey>,
+    mode: DistributionMode,
+    /// Group to propose mints in, or why the identity can't mint in a batch
+    mint_group: Result<Option<GroupContractPosition>, String>,
+    recipients_input: String,
+    public_note: String,
+    token_balance: TokenAmount,
+    outcomes: Vec<RecipientOutcome>,
+    status: DistributeTokensStatus,
+    pub app_context: Arc<AppContext>,
+    confirmation_popup: bool,
+    mainnet_confirmation: MainnetConfirmation,
+    selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    wallet_password: String,
+    show_password: bool,
+}
+
+impl DistributeTokensScreen {
+    pub fn new(identity_token_info: IdentityTokenInfo, app_context: &Arc<AppContext>) -> Self {
+        let selected_key = identity_token_info
+            .identity
+            .identity
+            .get_first_public_key_matching(
+                Purpose::AUTHENTICATION,
+                HashSet::from([SecurityLevel::CRITICAL]),
+                KeyType::all_key_types().into(),
+                false,
+            )
+            .cloned();
+        let mut error_message = None;
+        let selected_wallet = get_selected_wallet(
+            &identity_token_info.identity,
+            None,
+            selected_key.as_ref(),
+            &mut error_message,
+        );
+        let mint_group = unilateral_mint_group(&identity_token_info);
+
+        let mut screen = Self {
+            identity_token_info,
+            selected_key,
+            mode: DistributionMode::Transfer,
+            mint_group,
+            recipients_input: String::new(),
+            public_note: String::new(),
+            token_balance: 0,
+            outcomes: Vec::new(),
+            status: error_message
+                .map(DistributeTokensStatus::ErrorMessage)
+                .unwrap_or(DistributeTokensStatus::NotStarted),
+            app_context: app_context.clone(),
+            confirmation_popup: false,
+            mainnet_confirmation: MainnetConfirmation::default(),
+            selected_wallet,
+            wallet_password: String::new(),
+            show_password: false,
+        };
+        screen.token_balance = screen.load_token_balance();
+        screen
+    }
+
+    fn load_token_balance(&self) -> TokenAmount {
+        self.app_context
+            .db
+            .get_identity_token_balances(&self.app_context)
+            .map(|balances| {
+                balances
+                    .values()
+                    .find(|balance| {
+                        balance.identity_id == self.identity_token_info.identity.identity.id()
+                            && balance.token_id == self.identity_token_info.token_id
+                    })
+                    .map(|balance| balance.balance)
+                    .unwrap_or(0)
+            })
+            .unwrap_or(0)
+    }
+
+    fn decimals(&self) -> u8 {
+        self.identity_token_info
+            .token_config
+            .conventions()
+            .decimals()
+    }
+
+    fn parsed_recipients(&self) -> Vec<(usize, Result<(Identifier, TokenAmount), String>)> {
+        let sender = match self.mode {
+            DistributionMode::Transfer => Some(self.identity_token_info.identity.identity.id()),
+            DistributionMode::Mint => None,
+        };
+        parse_recipients(&self.recipients_input, self.decimals(), sender)
+    }
+
+    /// Loads the recipient list from a text or CSV file
+    fn load_recipients_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Recipient list", &["csv", "txt"])
+            .pick_file()
+        else {
+            return;
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => self.recipients_input = contents,
+            Err(e) => {
+                self.status = DistributeTokensStatus::ErrorMessage(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                ))
+            }
+        }
+    }
+
+    /// One transition per recipient, run one after the other, then a balance refresh
+    fn distribution_tasks(&self, recipients: &[(Identifier, TokenAmount)]) -> Vec<BackendTask> {
+        let data_contract = Arc::new(self.identity_token_info.data_contract.contract.clone());
+        let signing_key = self.selected_key.clone().expect("Expected a key");
+        let public_note = Some(self.public_note.trim().to_string()).filter(|note| !note.is_empty());
+        let mut tasks: Vec<BackendTask> = recipients
+            .iter()
+            .map(|(recipient_id, amount)| {
+                let task =
+                    match self.mode {
+                        DistributionMode::Transfer => TokenTask::TransferTokens {
+                            sending_identity: self.identity_token_info.identity.clone(),
+                            recipient_id: *recipient_id,
+                            amount: *amount,
+                            data_contract: data_contract.clone(),
+                            token_position: self.identity_token_info.token_position,
+                            signing_key: signing_key.clone(),
+                            public_note: public_note.clone(),
+                        },
+                        DistributionMode::Mint => TokenTask::MintTokens {
+                            sending_identity: self.identity_token_info.identity.clone(),
+                            data_contract: data_contract.clone(),
+                            token_position: self.identity_token_info.token_position,
+                            signing_key: signing_key.clone(),
+                            public_note: public_note.clone(),
+                            amount: *amount,
+                            recipient_id: Some(*recipient_id),
+                            group_info: self.mint_group.clone().ok().flatten().map(
+                                GroupStateTransitionInfoStatus::GroupStateTransitionInfoProposer,
+                            ),
+                        },
+                    };
+                BackendTask::TokenTask(Box::new(task))
+            })
+            .collect();
+        tasks.push(BackendTask::TokenTask(Box::new(
+            TokenTask::QueryMyTokenBalances,
+        )));
+        tasks
+    }
+
+    /// Records the outcome of the next recipient still waiting for one
+    fn record_outcome(&mut self, result: Result<(), String>) {
+        let Some(outcome) = self
+            .outcomes
+            .iter_mut()
+            .find(|outcome| outcome.result.is_none())
+        else {
+            return;
+        };
+        outcome.result = Some(result);
+        if self.outcomes.iter().all(|outcome| outcome.result.is_some()) {
+            self.status = DistributeTokensStatus::Complete;
+        }
+    }
+
+    /// Puts the recipients whose transition failed back into the list, to run them again
+    fn retry_failed(&mut self) {
+        let decimals = self.decimals();
+        self.recipients_input = self
+            .outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome.result, Some(Err(_))))
+            .map(|outcome| {
+                format!(
+                    "{}, {}",
+                    outcome.recipient.to_string(Encoding::Base58),
+                    format_token_amount(outcome.amount, decimals)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.outcomes.clear();
+        self.status = DistributeTokensStatus::NotStarted;
+    }
+
+    fn show_confirmation_popup(&mut self, ui: &mut Ui, total: TokenAmount) -> AppAction {
+        let mut app_action = AppAction::None;
+        let mut is_open = true;
+        let recipients: Vec<(Identifier, TokenAmount)> = self
+            .parsed_recipients()
+            .into_iter()
+            .filter_map(|(_, recipient)| recipient.ok())
+            .collect();
+        let alias = self.identity_token_info.token_alias.clone();
+        egui::Window::new("Confirm Distribution")
+            .collapsible(false)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                let verb = match self.mode {
+                    DistributionMode::Transfer => "transfer",
+                    DistributionMode::Mint => "mint",
+                };
+                ui.label(format!(
+                    "Are you sure you want to {} {} {} to {} recipients?",
+                    verb,
+                    format_token_amount(total, self.decimals()),
+                    alias,
+                    recipients.len()
+                ));
+                ui.label("Each recipient gets its own transition, paid for separately.");
+
+                let confirmed = self.mode == DistributionMode::Mint
+                    || !is_large_token_transfer(total, self.token_balance)
+                    || self.mainnet_confirmation.show(
+                        ui,
+                        &self.app_context,
+                        IrreversibleAction::LargeTransfer,
+                        Some(&alias),
+                    );
+
+                if ui
+                    .add_enabled(confirmed, egui::Button::new("Confirm"))
+                    .clicked()
+                {
+                    self.confirmation_popup = false;
+                    self.status = DistributeTokensStatus::Distributing;
+                    self.outcomes = recipients
+                        .iter()
+                        .map(|(recipient, amount)| RecipientOutcome {
+                            recipient: *recipient,
+                            amount: *amount,
+                            result: None,
+                        })
+                        .collect();
+                    app_action = AppAction::BackendTasks(
+                        self.distribution_tasks(&recipients),
+                        BackendTasksExecutionMode::Sequential,
+                    );
+                }
+                if ui.button("Cancel").clicked() {
+                    self.confirmation_popup = false;
+                }
+            });
+        if !is_open {
+            self.confirmation_popup = false;
+        }
+        app_action
+    }
+
+    /// Renders the list of recipients, the summary of the distribution and the button starting it
+    fn render_distribution_form(&mut self, ui: &mut Ui) -> AppAction {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let decimals = self.decimals();
+        let transaction_type = self.mode.transaction_type();
+
+        ui.heading("1. Select the key to sign the transitions with");
+        ui.add_space(10.0);
+        let mut selected_identity = Some(self.identity_token_info.identity.clone());
+        add_identity_key_chooser(
+            ui,
+            &self.app_context,
+            std::iter::once(&self.identity_token_info.identity),
+            &mut selected_identity,
+            &mut self.selected_key,
+            transaction_type,
+        );
+
+        ui.add_space(10.0);
+        ui.separator();
+        ui.add_space(10.0);
+
+        ui.heading("2. Choose how the recipients get the tokens");
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            ui.radio_value(
+                &mut self.mode,
+                DistributionMode::Transfer,
+                "Transfer from balance",
+            );
+            let mint_response = ui.add_enabled_ui(self.mint_group.is_ok(), |ui| {
+                ui.radio_value(&mut self.mode, DistributionMode::Mint, "Mint to recipients")
+            });
+            if let Err(e) = &self.mint_group {
+                mint_response.inner.on_disabled_hover_text(e);
+            }
+        });
+
+        ui.add_space(10.0);
+        ui.separator();
+        ui.add_space(10.0);
+
+        ui.heading("3. Recipients");
+        ui.add_space(5.0);
+        ui.label(
+            "One recipient per line: the identity ID in base58 and the amount, separated by a \
+             comma, a semicolon or spaces. Lines starting with # are ignored.",
+        );
+        ui.add_space(5.0);
+        if ui.button("Load from File").clicked() {
+            self.load_recipients_file();
+        }
+        ui.add_space(5.0);
+        ui.add(
+            egui::TextEdit::multiline(&mut self.recipients_input)
+                .hint_text("IdentityIdInBase58, 100")
+                .desired_rows(8)
+                .desired_width(f32::INFINITY)
+                .font(egui::TextStyle::Monospace),
+        );
+
+        let parsed = self.parsed_recipients();
+        let invalid: Vec<(usize, &String)> = parsed
+            .iter()
+            .filter_map(|(line_number, recipient)| {
+                recipient.as_ref().err().map(|e| (*line_number, e))
+            })
+            .collect();
+        for (line_number, e) in &invalid {
+            ui.colored_label(
+                DashColors::error_color(dark_mode),
+                format!("Line {}: {}", line_number, e),
+            );
+        }
+
+        ui.add_space(10.0);
+        ui.separator();
+        ui.add_space(10.0);
+
+        ui.heading("4. Public note (optional)");
+        ui.add_space(5.0);
+        ui.text_edit_singleline(&mut self.public_note)
+            .on_hover_text("A note added to every transition that can be seen by the public.");
+
+        ui.add_space(10.0);
+        ui.separator();
+        ui.add_space(10.0);
+
+        // Dry run of what the distribution will cost, before anything is broadcast
+        ui.heading("5. Summary");
+        ui.add_space(5.0);
+        let recipient_count = parsed.len() - invalid.len();
+        let total: TokenAmount = parsed
+            .iter()
+            .filter_map(|(_, recipient)| recipient.as_ref().ok())
+            .fold(0, |total: TokenAmount, (_, amount)| {
+                total.saturating_add(*amount)
+            });
+        let total_fee = transaction_type
+            .estimated_fee(self.app_context.platform_version())
+            .saturating_mul(recipient_count as Credits);
+        let credits = self.identity_token_info.identity.identity.balance();
+        let alias = &self.identity_token_info.token_alias;
+
+        let mut problems = Vec::new();
+        egui::Grid::new("distribution_summary")
+            .num_columns(2)
+            .spacing([20.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Recipients:");
+                ui.label(recipient_count.to_string());
+                ui.end_row();
+
+                ui.label("Total tokens:");
+                ui.label(format!(
+                    "{} {}",
+                    format_token_amount(total, decimals),
+                    alias
+                ));
+                ui.end_row();
+
+                if self.mode == DistributionMode::Transfer {
+                    ui.label("Token balance:");
+                    ui.label(format!(
+                        "{} {}",
+                        format_token_amount(self.token_balance, decimals),
+                        alias
+                    ));
+                    ui.end_row();
+                }
+
+                ui.label("Estimated total fee:");
+                ui.label(format_credits(total_fee)).on_hover_text(
+                    "The minimum fee of every transition, the final fees can be higher",
+                );
+                ui.end_row();
+
+                ui.label("Identity credits:");
+                ui.label(format_credits(credits));
+                ui.end_row();
+            });
+
+        if recipient_count == 0 {
+            problems.push("Add at least one recipient".to_string());
+        }
+        if !invalid.is_empty() {
+            problems.push(format!("Fix the {} invalid lines first", invalid.len()));
+        }
+        if self.mode == DistributionMode::Transfer && total > self.token_balance {
+            problems.push(format!(
+                "The total exceeds the balance by {} {}",
+                format_token_amount(total - self.token_balance, decimals),
+                alias
+            ));
+        }
+        if total_fee > credits {
+            problems.push(format!(
+                "The identity is {} short of the estimated fees",
+                format_credits(total_fee - credits)
+            ));
+        }
+        if let Err(e) = transaction_type.validate_signing_key(
+            self.selected_key.as_ref(),
+            self.app_context.is_developer_mode(),
+        ) {
+            problems.push(e);
+        }
+
+        ui.add_space(10.0);
+        let button = egui::Button::new(RichText::new("Distribute").color(Color32::WHITE))
+            .fill(Color32::from_rgb(0, 128, 255))
+            .frame(true)
+            .corner_radius(3.0);
+        let response = ui.add_enabled(problems.is_empty(), button);
+        if !problems.is_empty() {
+            response.on_disabled_hover_text(problems.join("\n"));
+        } else if response.clicked() {
+            self.confirmation_popup = true;
+            self.mainnet_confirmation.reset();
+        }
+
+        if self.confirmation_popup {
+            return self.show_confirmation_popup(ui, total);
+        }
+        AppAction::None
+    }
+
+    /// Renders how each recipient of the running or finished distribution fared
+    fn render_outcomes(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let decimals = self.decimals();
+        let done = self
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.result.is_some())
+            .count();
+        let failed = self
+            .outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome.result, Some(Err(_))))
+            .count();
+
+        if self.status == DistributeTokensStatus::Complete {
+            let summary = format!(
+                "{} of {} recipients received their tokens",
+                done - failed,
+                self.outcomes.len()
+            );
+            if failed == 0 {
+                ui.colored_label(DashColors::success_color(dark_mode), summary);
+            } else {
+                ui.colored_label(DashColors::error_color(dark_mode), summary);
+            }
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                if failed > 0 && ui.button(format!("Retry Failed ({})", failed)).clicked() {
+                    self.retry_failed();
+                }
+                if ui.button("Back to Tokens").clicked() {
+                    action = AppAction::PopScreenAndRefresh;
+                }
+            });
+        } else {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(format!(
+                    "Distributing... {} of {} recipients done",
+                    done,
+                    self.outcomes.len()
+                ));
+            });
+        }
+        ui.add_space(10.0);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("distribution_outcomes")
+                .num_columns(3)
+                .striped(true)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(RichText::new("Recipient").strong());
+                    ui.label(RichText::new("Amount").strong());
+                    ui.label(RichText::new("Result").strong());
+                    ui.end_row();
+
+                    for outcome in &self.outcomes {
+                        render_identifier_cell(ui, &outcome.recipient, None);
+                        ui.label(format_token_amount(outcome.amount, decimals));
+                        match &outcome.result {
+                            None => ui.label("Pending"),
+                            Some(Ok(())) => {
+                                ui.colored_label(DashColors::success_color(dark_mode), "✔ Sent")
+                            }
+                            Some(Err(e)) => ui.colored_label(
+                                DashColors::error_color(dark_mode),
+                                format!("Failed: {}", e),
+                            ),
+                        };
+                        ui.end_row();
+                    }
+                });
+        });
+        action
+    }
+}
+
+impl ScreenLike for DistributeTokensScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        match message_type {
+            MessageType::Success => {
+                if self.status == DistributeTokensStatus::Distributing
+                    && message == self.mode.success_message()
+                {
+                    self.record_outcome(Ok(()));
+                }
+            }
+            MessageType::Info => {}
+            MessageType::Error => {
+                // Transitions run one after the other, so a failed one is the next recipient's.
+                // Errors of other tasks, like a scheduled vote, don't belong to any recipient
+                if self.status == DistributeTokensStatus::Distributing {
+                    if message.starts_with(self.mode.failure_prefix()) {
+                        self.record_outcome(Err(message.to_string()));
+                    }
+                } else {
+                    self.status = DistributeTokensStatus::ErrorMessage(message.to_string());
+                }
+            }
+        }
+    }
+
+    fn refresh(&mut self) {
+        if let Ok(identities) = self.app_context.load_local_qualified_identities() {
+            if let Some(identity) = identities.into_iter().find(|identity| {
+                identity.identity.id() == self.identity_token_info.identity.identity.id()
+            }) {
+                self.identity_token_info.identity = identity;
+            }
+        }
+        self.token_balance = self.load_token_balance();
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Tokens", AppAction::GoToMainScreen),
+                (&self.identity_token_info.token_alias, AppAction::PopScreen),
+                ("Distribute", AppAction::None),
+            ],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            crate::ui::RootScreenType::RootScreenMyTokenBalances,
+        );
+
+        action |= add_tokens_subscreen_chooser_panel(ctx, &self.app_context);
+
+        let central_panel_action = island_central_panel(ctx, |ui| {
+            let dark_mode = ui.ctx().style().visuals.dark_mode;
+
+            ui.heading(format!(
+                "Distribute {}",
+                self.identity_token_info.token_alias
+            ));
+            ui.add_space(10.0);
+            let mut inner_action = render_low_balance_banner(
+                ui,
+                &self.app_context,
+                &self.identity_token_info.identity,
+            );
+
+            if !self.outcomes.is_empty() {
+                inner_action |= self.render_outcomes(ui);
+                return inner_action;
+            }
+
+            if self.selected_wallet.is_some() {
+                let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
+                if needed_unlock && !just_unlocked {
+                    return inner_action;
+                }
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                inner_action |= self.render_distribution_form(ui);
+
+                if let DistributeTokensStatus::ErrorMessage(msg) = &self.status {
+                    ui.add_space(5.0);
+                    ui.colored_label(
+                        DashColors::error_color(dark_mode),
+                        format!("Error: {}", msg),
+                    );
+                }
+            });
+            inner_action
+        });
+        action |= central_panel_action;
+        action
+    }
+}
+
+impl ScreenWithWalletUnlock for DistributeTokensScreen {
+    fn selected_wallet_ref(&self) -> &Option<Arc<RwLock<Wallet>>> {
+        &self.selected_wallet
+    }
+
+    fn wallet_password_ref(&self) -> &String {
+        &self.wallet_password
+    }
+
+    fn wallet_password_mut(&mut self) -> &mut String {
+        &mut self.wallet_password
+    }
+
+    fn show_password(&self) -> bool {
+        self.show_password
+    }
+
+    fn show_password_mut(&mut self) -> &mut bool {
+        &mut self.show_password
+    }
+
+    fn set_error_message(&mut self, error_message: Option<String>) {
+        if let Some(error_message) = error_message {
+            self.status = DistributeTokensStatus::ErrorMessage(error_message);
+        }
+    }
+
+    fn error_message(&self) -> Option<&String> {
+        if let DistributeTokensStatus::ErrorMessage(error_message) = &self.status {
+            Some(error_message)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recipients() {
+        let alice = Identifier::new([1; 32]);
+        let bob = Identifier::new([2; 32]);
+        let sender = Identifier::new([3; 32]);
+        let input = format!(
+            "# identity, amount\n{}, 1.5\n\n{} 2\n{};1\nnot-an-id, 4\n{}\n{}, 0\n{}, 3",
+            alice.to_string(Encoding::Base58),
+            bob.to_string(Encoding::Base58),
+            alice.to_string(Encoding::Base58),
+            bob.to_string(Encoding::Base58),
+            sender.to_string(Encoding::Base58),
+            sender.to_string(Encoding::Base58),
+        );
+
+        let parsed = parse_recipients(&input, 2, Some(sender));
+        assert_eq!(parsed[0], (2, Ok((alice, 150))));
+        assert_eq!(parsed[1], (4, Ok((bob, 200))));
+        assert_eq!(parsed[2], (5, Err("Same recipient as line 2".to_string())));
+        assert_eq!(
+            parsed[3],
+            (6, Err("Invalid identity ID \"not-an-id\"".to_string()))
+        );
+        assert_eq!(
+            parsed[4],
+            (7, Err("Expected an identity ID and an amount".to_string()))
+        );
+        assert_eq!(
+            parsed[5],
+            (
+                8,
+                Err("Tokens can't be sent to the sending identity".to_string())
+            )
+        );
+        assert_eq!(parsed.len(), 7);
+    }
+}
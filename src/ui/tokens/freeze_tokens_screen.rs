@@ -1,17 +1,22 @@
 use super::tokens_screen::IdentityTokenInfo;
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
+use crate::backend_task::BackendTaskSuccessResult;
 use crate::backend_task::tokens::TokenTask;
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::token_action_simulation::TokenActionSimulation;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
 use crate::ui::contracts_documents::group_actions_screen::GroupActionsScreen;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser, render_group_action_text};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+    render_group_action_text,
+};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
@@ -64,6 +69,7 @@ pub struct FreezeTokensScreen {
 
     // Confirmation popup
     show_confirmation_popup: bool,
+    simulation: TokenActionSimulation,
 
     // If password-based wallet unlocking is needed
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
@@ -190,6 +196,7 @@ impl FreezeTokensScreen {
             selected_wallet,
             wallet_password: String::new(),
             show_password: false,
+            simulation: TokenActionSimulation::default(),
         }
     }
 
@@ -262,8 +269,8 @@ impl FreezeTokensScreen {
                     };
 
                     // Dispatch to backend
-                    action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(
-                        TokenTask::FreezeTokens {
+                    action =
+                        AppAction::BackendTask(self.simulation.task(TokenTask::FreezeTokens {
                             actor_identity: self.identity.clone(),
                             data_contract,
                             token_position: self.identity_token_info.token_position,
@@ -275,8 +282,7 @@ impl FreezeTokensScreen {
                             },
                             freeze_identity: freeze_id,
                             group_info,
-                        },
-                    )));
+                        }));
                 }
 
                 // Cancel
@@ -338,6 +344,19 @@ impl FreezeTokensScreen {
 }
 
 impl ScreenLike for FreezeTokensScreen {
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::SimulationResult {
+            transition,
+            size_bytes,
+            minimum_fee,
+        } = backend_task_success_result
+        {
+            self.simulation
+                .set_result(&transition, size_bytes, minimum_fee);
+            self.status = FreezeTokensStatus::NotStarted;
+        }
+    }
+
     fn display_message(&mut self, message: &str, message_type: MessageType) {
         match message_type {
             MessageType::Success => {
@@ -411,6 +430,7 @@ impl ScreenLike for FreezeTokensScreen {
 
             ui.heading("Freeze Identity’s Tokens");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(ui, &self.app_context, &self.identity);
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -548,9 +568,19 @@ impl ScreenLike for FreezeTokensScreen {
                             .fill(Color32::from_rgb(0, 128, 255))
                             .corner_radius(3.0);
 
-                    if ui.add(button).clicked() {
+                    if add_signing_key_guarded_button(
+                        ui,
+                        button,
+                        self.selected_key.as_ref(),
+                        &self.identity,
+                        TransactionType::TokenAction,
+                        &self.app_context,
+                    )
+                    .clicked()
+                    {
                         self.show_confirmation_popup = true;
                     }
+                    self.simulation.checkbox(ui);
                 }
 
                 // If user pressed "Freeze," show popup
@@ -562,7 +592,7 @@ impl ScreenLike for FreezeTokensScreen {
                 ui.add_space(10.0);
                 match &self.status {
                     FreezeTokensStatus::NotStarted => {
-                        // no-op
+                        self.simulation.show_result(ui);
                     }
                     FreezeTokensStatus::WaitingForResult(start_time) => {
                         let now = SystemTime::now()
@@ -1,25 +1,33 @@
 use super::tokens_screen::IdentityTokenInfo;
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
+use crate::backend_task::BackendTaskSuccessResult;
 use crate::backend_task::tokens::TokenTask;
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::token_action_simulation::TokenActionSimulation;
+use crate::ui::components::token_amount_input::{check_token_amount, render_token_amount_input};
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
 use crate::ui::contracts_documents::group_actions_screen::GroupActionsScreen;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser, render_group_action_text};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+    render_group_action_text,
+};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
 use crate::ui::theme::DashColors;
 use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike};
+use dash_sdk::dpp::balances::credits::TokenAmount;
 use dash_sdk::dpp::data_contract::GroupContractPosition;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
 use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use dash_sdk::dpp::data_contract::group::Group;
@@ -64,6 +72,7 @@ pub struct MintTokensScreen {
 
     /// Confirmation popup
     show_confirmation_popup: bool,
+    simulation: TokenActionSimulation,
 
     // If needed for password-based wallet unlocking:
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
@@ -190,6 +199,7 @@ impl MintTokensScreen {
             selected_wallet,
             wallet_password: String::new(),
             show_password: false,
+            simulation: TokenActionSimulation::default(),
         }
     }
 
@@ -197,13 +207,20 @@ impl MintTokensScreen {
     fn render_amount_input(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
             ui.label("Amount to Mint:");
-            ui.text_edit_singleline(&mut self.amount_to_mint);
-
-            // Since it's minting, we often don't do "Max."
-            // But you could show a help text or put constraints if needed.
+            let (decimals, max_supply) = self.amount_constraints();
+            let _ = render_token_amount_input(ui, &mut self.amount_to_mint, decimals, max_supply);
         });
     }
 
+    /// Decimals of the token and its max supply, which no single mint can exceed
+    fn amount_constraints(&self) -> (u8, Option<TokenAmount>) {
+        let token_config = &self.identity_token_info.token_config;
+        (
+            token_config.conventions().decimals(),
+            token_config.max_supply(),
+        )
+    }
+
     /// Renders an optional text input for the user to specify a "Recipient Identity"
     fn render_recipient_input(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
@@ -223,13 +240,16 @@ impl MintTokensScreen {
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
                 // Validate user input
-                let amount_ok = self.amount_to_mint.parse::<u64>().ok();
-                if amount_ok.is_none() {
-                    self.error_message = Some("Please enter a valid amount.".into());
-                    self.status = MintTokensStatus::ErrorMessage("Invalid amount".into());
-                    self.show_confirmation_popup = false;
-                    return;
-                }
+                let (decimals, max_supply) = self.amount_constraints();
+                let amount = match check_token_amount(&self.amount_to_mint, decimals, max_supply) {
+                    Ok(amount) => amount,
+                    Err(e) => {
+                        self.error_message = Some(e.clone());
+                        self.status = MintTokensStatus::ErrorMessage(e);
+                        self.show_confirmation_popup = false;
+                        return;
+                    }
+                };
 
                 let maybe_identifier = if self.recipient_identity_id.trim().is_empty() {
                     None
@@ -293,24 +313,22 @@ impl MintTokensScreen {
                     };
 
                     // Dispatch the actual backend mint action
-                    action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(
-                        TokenTask::MintTokens {
-                            sending_identity: self.identity_token_info.identity.clone(),
-                            data_contract: Arc::new(
-                                self.identity_token_info.data_contract.contract.clone(),
-                            ),
-                            token_position: self.identity_token_info.token_position,
-                            signing_key: self.selected_key.clone().expect("Expected a key"),
-                            public_note: if self.group_action_id.is_some() {
-                                None
-                            } else {
-                                self.public_note.clone()
-                            },
-                            amount: amount_ok.unwrap(),
-                            recipient_id: maybe_identifier,
-                            group_info,
+                    action = AppAction::BackendTask(self.simulation.task(TokenTask::MintTokens {
+                        sending_identity: self.identity_token_info.identity.clone(),
+                        data_contract: Arc::new(
+                            self.identity_token_info.data_contract.contract.clone(),
+                        ),
+                        token_position: self.identity_token_info.token_position,
+                        signing_key: self.selected_key.clone().expect("Expected a key"),
+                        public_note: if self.group_action_id.is_some() {
+                            None
+                        } else {
+                            self.public_note.clone()
                         },
-                    )));
+                        amount,
+                        recipient_id: maybe_identifier,
+                        group_info,
+                    }));
                 }
 
                 // Cancel button
@@ -372,6 +390,19 @@ impl MintTokensScreen {
 }
 
 impl ScreenLike for MintTokensScreen {
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::SimulationResult {
+            transition,
+            size_bytes,
+            minimum_fee,
+        } = backend_task_success_result
+        {
+            self.simulation
+                .set_result(&transition, size_bytes, minimum_fee);
+            self.status = MintTokensStatus::NotStarted;
+        }
+    }
+
     fn display_message(&mut self, message: &str, message_type: MessageType) {
         match message_type {
             MessageType::Success => {
@@ -449,6 +480,11 @@ impl ScreenLike for MintTokensScreen {
 
             ui.heading("Mint Tokens");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(
+                ui,
+                &self.app_context,
+                &self.identity_token_info.identity,
+            );
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -623,9 +659,19 @@ impl ScreenLike for MintTokensScreen {
                             .fill(Color32::from_rgb(0, 128, 255))
                             .corner_radius(3.0);
 
-                    if ui.add(button).clicked() {
+                    if add_signing_key_guarded_button(
+                        ui,
+                        button,
+                        self.selected_key.as_ref(),
+                        &self.identity_token_info.identity,
+                        TransactionType::TokenAction,
+                        &self.app_context,
+                    )
+                    .clicked()
+                    {
                         self.show_confirmation_popup = true;
                     }
+                    self.simulation.checkbox(ui);
                 }
 
                 // If the user pressed "Mint," show a popup
@@ -637,7 +683,7 @@ impl ScreenLike for MintTokensScreen {
                 ui.add_space(10.0);
                 match &self.status {
                     MintTokensStatus::NotStarted => {
-                        // no-op
+                        self.simulation.show_result(ui);
                     }
                     MintTokensStatus::WaitingForResult(start_time) => {
                         let now = SystemTime::now()
@@ -3,6 +3,7 @@ pub mod burn_tokens_screen;
 pub mod claim_tokens_screen;
 pub mod destroy_frozen_funds_screen;
 pub mod direct_token_purchase_screen;
+pub mod distribute_tokens_screen;
 pub mod freeze_tokens_screen;
 pub mod mint_tokens_screen;
 pub mod pause_tokens_screen;
@@ -5,21 +5,23 @@ use crate::backend_task::tokens::TokenTask;
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
 use crate::ui::contracts_documents::group_actions_screen::GroupActionsScreen;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser};
+use crate::ui::helpers::{TransactionType, add_identity_key_chooser, format_token_amount};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
-use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike};
-use dash_sdk::dpp::balances::credits::Credits;
+use crate::ui::{BackendTaskSuccessResult, MessageType, RootScreenType, Screen, ScreenLike};
+use dash_sdk::dpp::balances::credits::{Credits, TokenAmount};
 use dash_sdk::dpp::data_contract::GroupContractPosition;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
 use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use dash_sdk::dpp::data_contract::group::Group;
@@ -32,10 +34,16 @@ use dash_sdk::platform::{Identifier, IdentityPublicKey};
 use eframe::egui::{self, Color32, Context, Ui};
 use egui::RichText;
 use egui_extras::{Column, TableBuilder};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Whole token quantities the cost preview is shown for
+const PREVIEW_QUANTITIES: [TokenAmount; 3] = [1, 10, 100];
+
+/// Number of decimal places of a Dash amount expressed in credits
+const DASH_DECIMALS: u8 = 11;
+
 /// Pricing type selection
 #[derive(PartialEq, Clone)]
 pub enum PricingType {
@@ -66,6 +74,10 @@ pub struct SetTokenPriceScreen {
     pricing_type: PricingType,
     single_price: String,
     tiered_prices: Vec<(String, String)>,
+    /// Whether the schedule currently set on Platform is being fetched to edit it
+    loading_current_pricing: bool,
+    /// Outcome of the last fetch of the current schedule
+    current_pricing_note: Option<String>,
     status: SetTokenPriceStatus,
     error_message: Option<String>,
 
@@ -82,11 +94,6 @@ pub struct SetTokenPriceScreen {
 }
 
 impl SetTokenPriceScreen {
-    /// Converts Dash amount to credits (1 Dash = 100,000,000,000 credits)
-    fn dash_to_credits(dash_amount: f64) -> Credits {
-        (dash_amount * 100_000_000_000.0) as Credits
-    }
-
     pub fn new(identity_token_info: IdentityTokenInfo, app_context: &Arc<AppContext>) -> Self {
         let possible_key = identity_token_info
             .identity
@@ -202,6 +209,8 @@ impl SetTokenPriceScreen {
             pricing_type: PricingType::SinglePrice,
             single_price: "".to_string(),
             tiered_prices: vec![("1".to_string(), "".to_string())],
+            loading_current_pricing: false,
+            current_pricing_note: None,
             status: SetTokenPriceStatus::NotStarted,
             error_message: None,
             app_context: app_context.clone(),
@@ -213,7 +222,32 @@ impl SetTokenPriceScreen {
     }
 
     /// Renders the pricing input UI
-    fn render_pricing_input(&mut self, ui: &mut Ui) {
+    fn render_pricing_input(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !self.loading_current_pricing,
+                    egui::Button::new("Load Current Pricing"),
+                )
+                .on_hover_text("Fill in the pricing schedule currently set on Platform to edit it")
+                .clicked()
+            {
+                self.loading_current_pricing = true;
+                self.current_pricing_note = None;
+                action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(
+                    TokenTask::QueryTokenPricing(self.identity_token_info.token_id),
+                )));
+            }
+            if self.loading_current_pricing {
+                ui.add(egui::Spinner::new());
+            } else if let Some(note) = &self.current_pricing_note {
+                ui.label(RichText::new(note).color(Color32::GRAY));
+            }
+        });
+        ui.add_space(5.0);
+
         // Radio buttons for pricing type
         ui.horizontal(|ui| {
             ui.radio_value(
@@ -244,24 +278,9 @@ impl SetTokenPriceScreen {
                 });
 
                 // Show preview
-                if !self.single_price.is_empty() {
-                    if let Ok(price) = self.single_price.parse::<f64>() {
-                        if price > 0.0 {
-                            ui.add_space(5.0);
-                            let credits = Self::dash_to_credits(price);
-                            ui.colored_label(
-                                Color32::DARK_GREEN,
-                                format!("Price: {} Dash per token ({} credits)", price, credits),
-                            );
-                        } else {
-                            ui.colored_label(Color32::DARK_RED, "X Price must be greater than 0");
-                        }
-                    } else {
-                        ui.colored_label(
-                            Color32::DARK_RED,
-                            "X Invalid price - must be a positive number",
-                        );
-                    }
+                if !self.single_price.trim().is_empty() {
+                    ui.add_space(5.0);
+                    self.render_pricing_preview(ui);
                 }
             }
             PricingType::TieredPricing => {
@@ -312,30 +331,24 @@ impl SetTokenPriceScreen {
                         for (i, (amount, price)) in self.tiered_prices.iter_mut().enumerate() {
                             body.row(25.0, |mut row| {
                                 row.col(|ui| {
-                                    if i == 0 {
-                                        // First tier is hardcoded to 1 token
-                                        ui.label("1");
-                                        *amount = "1".to_string(); // Ensure it's always 1
-                                    } else {
-                                        let dark_mode = ui.ctx().style().visuals.dark_mode;
-                                        ui.add(
-                                            egui::TextEdit::singleline(amount)
-                                                .hint_text(
-                                                    RichText::new("100").color(Color32::GRAY),
-                                                )
-                                                .desired_width(100.0)
-                                                .text_color(
-                                                    crate::ui::theme::DashColors::text_primary(
-                                                        dark_mode,
-                                                    ),
-                                                )
-                                                .background_color(
-                                                    crate::ui::theme::DashColors::input_background(
-                                                        dark_mode,
-                                                    ),
+                                    // Amounts below the first tier can't be bought
+                                    let dark_mode = ui.ctx().style().visuals.dark_mode;
+                                    ui.add(
+                                        egui::TextEdit::singleline(amount)
+                                            .hint_text(
+                                                RichText::new(if i == 0 { "1" } else { "100" })
+                                                    .color(Color32::GRAY),
+                                            )
+                                            .desired_width(100.0)
+                                            .text_color(crate::ui::theme::DashColors::text_primary(
+                                                dark_mode,
+                                            ))
+                                            .background_color(
+                                                crate::ui::theme::DashColors::input_background(
+                                                    dark_mode,
                                                 ),
-                                        );
-                                    }
+                                            ),
+                                    );
                                 });
                                 row.col(|ui| {
                                     let dark_mode = ui.ctx().style().visuals.dark_mode;
@@ -355,7 +368,7 @@ impl SetTokenPriceScreen {
                                     ui.label(" Dash");
                                 });
                                 row.col(|ui| {
-                                    if can_remove && i > 0 && ui.small_button("X").clicked() {
+                                    if can_remove && ui.small_button("X").clicked() {
                                         to_remove = Some(i);
                                     }
                                 });
@@ -377,58 +390,109 @@ impl SetTokenPriceScreen {
 
                 // Show preview
                 ui.add_space(10.0);
-                self.render_tiered_pricing_preview(ui);
+                self.render_pricing_preview(ui);
             }
             PricingType::RemovePricing => {
                 ui.colored_label(Color32::from_rgb(180, 100, 0), "WARNING: This will remove the pricing schedule, making the token unavailable for direct purchase.");
                 ui.label("Users will no longer be able to buy this token directly.");
             }
         }
-    }
-
-    fn render_tiered_pricing_preview(&self, ui: &mut Ui) {
-        let mut valid_tiers = Vec::new();
-        let mut has_errors = false;
 
-        for (amount_str, price_str) in &self.tiered_prices {
-            if amount_str.trim().is_empty() || price_str.trim().is_empty() {
-                continue;
-            }
+        action
+    }
 
-            match (amount_str.parse::<u64>(), price_str.parse::<f64>()) {
-                (Ok(amount), Ok(price)) if price > 0.0 => {
-                    valid_tiers.push((amount, price));
-                }
-                _ => {
-                    has_errors = true;
-                }
+    /// Shows the entered schedule, or why it is invalid, with the cost of buying a few
+    /// representative quantities
+    fn render_pricing_preview(&self, ui: &mut Ui) {
+        let schedule = match self.create_pricing_schedule() {
+            Ok(Some(schedule)) => schedule,
+            Ok(None) => return,
+            Err(error) => {
+                ui.colored_label(Color32::DARK_RED, format!("X {}", error));
+                return;
             }
-        }
-
-        // Only show preview if there are valid tiers or errors
-        if !valid_tiers.is_empty() || has_errors {
-            ui.group(|ui| {
-                // Sort tiers by amount
-                if !valid_tiers.is_empty() {
-                    valid_tiers.sort_by_key(|(amount, _)| *amount);
-                }
+        };
 
-                if has_errors {
-                    ui.colored_label(Color32::DARK_RED, "X Some tiers have invalid values");
+        ui.group(|ui| {
+            ui.colored_label(Color32::DARK_GREEN, "Pricing Structure:");
+            match &schedule {
+                TokenPricingSchedule::SinglePrice(price) => {
+                    ui.label(format!(
+                        "  - {} Dash per token ({} credits)",
+                        format_token_amount(*price, DASH_DECIMALS),
+                        price
+                    ));
                 }
-
-                if !valid_tiers.is_empty() {
-                    ui.colored_label(Color32::DARK_GREEN, "Pricing Structure:");
-                    for (amount, price) in &valid_tiers {
-                        let credits = Self::dash_to_credits(*price);
+                TokenPricingSchedule::SetPrices(tiers) => {
+                    let mut previous_price = None;
+                    for (amount, price) in tiers {
                         ui.label(format!(
                             "  - {} or more tokens: {} Dash each ({} credits)",
-                            amount, price, credits
+                            amount,
+                            format_token_amount(*price, DASH_DECIMALS),
+                            price
                         ));
+                        if previous_price.is_some_and(|previous| *price > previous) {
+                            ui.colored_label(
+                                Color32::from_rgb(180, 100, 0),
+                                format!(
+                                    "    Buyers of {} or more tokens pay more per token than \
+                                     in the previous tier",
+                                    amount
+                                ),
+                            );
+                        }
+                        previous_price = Some(*price);
                     }
                 }
-            });
-        }
+            }
+
+            ui.add_space(5.0);
+            ui.label(RichText::new("Cost preview:").strong());
+            egui::Grid::new("set_price_cost_preview")
+                .num_columns(3)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(RichText::new("Tokens").underline());
+                    ui.label(RichText::new("Price per Token").underline());
+                    ui.label(RichText::new("Total Cost").underline());
+                    ui.end_row();
+
+                    let decimals = self
+                        .identity_token_info
+                        .token_config
+                        .conventions()
+                        .decimals();
+                    for whole_tokens in PREVIEW_QUANTITIES {
+                        let quantity = base_units(whole_tokens, decimals);
+                        ui.label(format_token_amount(quantity, decimals));
+                        match (
+                            unit_price(&schedule, quantity),
+                            purchase_cost(&schedule, quantity),
+                        ) {
+                            (Some(price), Some(cost)) => {
+                                ui.label(format!(
+                                    "{} Dash",
+                                    format_token_amount(price, DASH_DECIMALS)
+                                ));
+                                ui.label(format!(
+                                    "{} Dash",
+                                    format_token_amount(cost, DASH_DECIMALS)
+                                ));
+                            }
+                            (Some(_), None) => {
+                                ui.label("-");
+                                ui.colored_label(Color32::DARK_RED, "Too large");
+                            }
+                            _ => {
+                                ui.label("-");
+                                ui.label("Not for sale");
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
     }
 
     /// Validates and creates the pricing schedule from the UI inputs
@@ -439,52 +503,12 @@ impl SetTokenPriceScreen {
                 if self.single_price.trim().is_empty() {
                     return Err("Please enter a price".to_string());
                 }
-                match self.single_price.trim().parse::<f64>() {
-                    Ok(dash_price) if dash_price > 0.0 => {
-                        let credits_price = Self::dash_to_credits(dash_price);
-                        Ok(Some(TokenPricingSchedule::SinglePrice(credits_price)))
-                    }
-                    Ok(_) => Err("Price must be greater than 0".to_string()),
-                    Err(_) => Err("Invalid price - must be a positive number".to_string()),
-                }
+                let price = parse_dash_price(&self.single_price)?;
+                Ok(Some(TokenPricingSchedule::SinglePrice(price)))
             }
             PricingType::TieredPricing => {
-                let mut map = std::collections::BTreeMap::new();
-
-                for (amount_str, price_str) in &self.tiered_prices {
-                    if amount_str.trim().is_empty() || price_str.trim().is_empty() {
-                        continue;
-                    }
-
-                    let amount = amount_str.trim().parse::<u64>().map_err(|_| {
-                        format!(
-                            "Invalid amount '{}' - must be a positive number",
-                            amount_str.trim()
-                        )
-                    })?;
-                    let dash_price = price_str.trim().parse::<f64>().map_err(|_| {
-                        format!(
-                            "Invalid price '{}' - must be a positive number",
-                            price_str.trim()
-                        )
-                    })?;
-
-                    if dash_price <= 0.0 {
-                        return Err(format!(
-                            "Price '{}' must be greater than 0",
-                            price_str.trim()
-                        ));
-                    }
-
-                    let credits_price = Self::dash_to_credits(dash_price);
-                    map.insert(amount, credits_price);
-                }
-
-                if map.is_empty() {
-                    return Err("Please add at least one pricing tier".to_string());
-                }
-
-                Ok(Some(TokenPricingSchedule::SetPrices(map)))
+                let tiers = validate_tiers(&self.tiered_prices)?;
+                Ok(Some(TokenPricingSchedule::SetPrices(tiers)))
             }
         }
     }
@@ -526,27 +550,26 @@ impl SetTokenPriceScreen {
                         ui.label("This will make the token unavailable for direct purchase.");
                     }
                     PricingType::SinglePrice => {
-                        if let Ok(dash_price) = self.single_price.trim().parse::<f64>() {
+                        if let Some(TokenPricingSchedule::SinglePrice(price)) =
+                            &token_pricing_schedule_opt
+                        {
                             ui.label(format!(
                                 "Are you sure you want to set a fixed price of {} Dash per token?",
-                                dash_price
+                                format_token_amount(*price, DASH_DECIMALS)
                             ));
                         }
                     }
                     PricingType::TieredPricing => {
                         ui.label("Are you sure you want to set the following tiered pricing?");
                         ui.add_space(5.0);
-                        for (amount_str, price_str) in &self.tiered_prices {
-                            if amount_str.trim().is_empty() || price_str.trim().is_empty() {
-                                continue;
-                            }
-                            if let (Ok(amount), Ok(dash_price)) = (
-                                amount_str.trim().parse::<u64>(),
-                                price_str.trim().parse::<f64>(),
-                            ) {
+                        if let Some(TokenPricingSchedule::SetPrices(tiers)) =
+                            &token_pricing_schedule_opt
+                        {
+                            for (amount, price) in tiers {
                                 ui.label(format!(
                                     "  - {} or more tokens: {} Dash each",
-                                    amount, dash_price
+                                    amount,
+                                    format_token_amount(*price, DASH_DECIMALS)
                                 ));
                             }
                         }
@@ -658,7 +681,145 @@ impl SetTokenPriceScreen {
     }
 }
 
+/// Parses a price in Dash and converts it to credits (1 Dash = 100,000,000,000 credits)
+fn parse_dash_price(input: &str) -> Result<Credits, String> {
+    let input = input.trim();
+    let dash_price = input
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid price '{}' - must be a positive number", input))?;
+    if !dash_price.is_finite() || dash_price <= 0.0 {
+        return Err(format!("Price '{}' must be greater than 0", input));
+    }
+
+    let credits = (dash_price * 100_000_000_000.0).round();
+    if credits < 1.0 {
+        return Err(format!(
+            "Price '{}' is below the smallest possible price of 1 credit",
+            input
+        ));
+    }
+    if credits >= Credits::MAX as f64 {
+        return Err(format!("Price '{}' is too large", input));
+    }
+    Ok(credits as Credits)
+}
+
+/// Validates the entered tiers and converts them to a `SetPrices` map. Rows left entirely
+/// empty are ignored, minimum amounts must be strictly increasing in the order entered.
+fn validate_tiers(tiers: &[(String, String)]) -> Result<BTreeMap<TokenAmount, Credits>, String> {
+    let mut map = BTreeMap::new();
+    let mut previous_amount = None;
+
+    for (i, (amount_str, price_str)) in tiers.iter().enumerate() {
+        let tier = i + 1;
+        let (amount_str, price_str) = (amount_str.trim(), price_str.trim());
+        match (amount_str.is_empty(), price_str.is_empty()) {
+            (true, true) => continue,
+            (true, false) => return Err(format!("Tier {}: please enter a minimum amount", tier)),
+            (false, true) => return Err(format!("Tier {}: please enter a price", tier)),
+            (false, false) => {}
+        }
+
+        let amount = amount_str
+            .parse::<TokenAmount>()
+            .ok()
+            .filter(|amount| *amount > 0)
+            .ok_or_else(|| {
+                format!(
+                    "Tier {}: invalid amount '{}' - must be a positive whole number",
+                    tier, amount_str
+                )
+            })?;
+        if let Some(previous) = previous_amount {
+            if amount <= previous {
+                return Err(format!(
+                    "Tier {}: minimum amount {} must be greater than the previous tier's {}",
+                    tier, amount, previous
+                ));
+            }
+        }
+        let price = parse_dash_price(price_str).map_err(|e| format!("Tier {}: {}", tier, e))?;
+
+        map.insert(amount, price);
+        previous_amount = Some(amount);
+    }
+
+    if map.is_empty() {
+        return Err("Please add at least one pricing tier".to_string());
+    }
+    Ok(map)
+}
+
+/// Editor inputs holding `schedule`: its pricing type, single price and tier rows, with
+/// prices in Dash as they are entered
+fn pricing_inputs(schedule: &TokenPricingSchedule) -> (PricingType, String, Vec<(String, String)>) {
+    match schedule {
+        TokenPricingSchedule::SinglePrice(price) => (
+            PricingType::SinglePrice,
+            format_token_amount(*price, DASH_DECIMALS),
+            vec![("1".to_string(), String::new())],
+        ),
+        TokenPricingSchedule::SetPrices(tiers) => (
+            PricingType::TieredPricing,
+            String::new(),
+            tiers
+                .iter()
+                .map(|(amount, price)| {
+                    (
+                        amount.to_string(),
+                        format_token_amount(*price, DASH_DECIMALS),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Price per token when buying `amount` tokens, or `None` if that amount can't be bought
+fn unit_price(schedule: &TokenPricingSchedule, amount: TokenAmount) -> Option<Credits> {
+    match schedule {
+        TokenPricingSchedule::SinglePrice(price) => Some(*price),
+        TokenPricingSchedule::SetPrices(tiers) => {
+            tiers.range(..=amount).next_back().map(|(_, price)| *price)
+        }
+    }
+}
+
+/// `whole_tokens` in the token's base units, capped at the largest amount
+fn base_units(whole_tokens: TokenAmount, decimals: u8) -> TokenAmount {
+    10u64
+        .checked_pow(decimals as u32)
+        .map_or(TokenAmount::MAX, |multiplier| {
+            whole_tokens.saturating_mul(multiplier)
+        })
+}
+
+/// Total cost of buying `amount` tokens, or `None` if it can't be bought or overflows
+fn purchase_cost(schedule: &TokenPricingSchedule, amount: TokenAmount) -> Option<Credits> {
+    unit_price(schedule, amount)?.checked_mul(amount)
+}
+
 impl ScreenLike for SetTokenPriceScreen {
+    fn display_task_result(&mut self, result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::TokenPricing { token_id, prices } = result {
+            if token_id != self.identity_token_info.token_id {
+                return;
+            }
+            self.loading_current_pricing = false;
+            match prices {
+                Some(schedule) => {
+                    (self.pricing_type, self.single_price, self.tiered_prices) =
+                        pricing_inputs(&schedule);
+                    self.current_pricing_note = Some("Loaded the current pricing".to_string());
+                }
+                None => {
+                    self.current_pricing_note =
+                        Some("No pricing is currently set for this token".to_string());
+                }
+            }
+        }
+    }
+
     fn display_message(&mut self, message: &str, message_type: MessageType) {
         match message_type {
             MessageType::Success => {
@@ -669,6 +830,12 @@ impl ScreenLike for SetTokenPriceScreen {
                 }
             }
             MessageType::Error => {
+                if self.loading_current_pricing {
+                    // The fetch of the current schedule failed, not the transition
+                    self.loading_current_pricing = false;
+                    self.current_pricing_note = Some(message.to_string());
+                    return;
+                }
                 self.status = SetTokenPriceStatus::ErrorMessage(message.to_string());
                 self.error_message = Some(message.to_string());
             }
@@ -738,6 +905,7 @@ impl ScreenLike for SetTokenPriceScreen {
 
             ui.heading("Set Token Pricing Schedule");
             ui.add_space(10.0);
+            action |= render_low_balance_banner(ui, &self.app_context, &self.identity_token_info.identity);
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -834,7 +1002,7 @@ impl ScreenLike for SetTokenPriceScreen {
                     ui.add_space(5.0);
                     ui.label(format!("Schedule: {}", self.token_pricing_schedule));
                 } else {
-                    self.render_pricing_input(ui);
+                    action |= self.render_pricing_input(ui);
                 }
 
                 ui.add_space(10.0);
@@ -911,23 +1079,14 @@ impl ScreenLike for SetTokenPriceScreen {
                 };
 
                 // Set price button
-                let can_proceed = match self.pricing_type {
-                    PricingType::RemovePricing => true,
-                    PricingType::SinglePrice => {
-                        if let Ok(price) = self.single_price.trim().parse::<f64>() {
-                            price > 0.0
-                        } else {
-                            false
-                        }
-                    },
-                    PricingType::TieredPricing => {
-                        self.tiered_prices.iter().any(|(amount, price)| {
-                            !amount.trim().is_empty() && !price.trim().is_empty() &&
-                            amount.trim().parse::<u64>().is_ok() &&
-                            if let Ok(p) = price.trim().parse::<f64>() { p > 0.0 } else { false }
-                        })
-                    }
-                };
+                let schedule_check = self.create_pricing_schedule();
+                let can_proceed = schedule_check.is_ok();
+
+                let key_check = TransactionType::TokenAction.validate_signing_key(
+                    self.selected_key.as_ref(),
+                    self.app_context.is_developer_mode(),
+                );
+                let can_proceed = can_proceed && key_check.is_ok();
 
                 let button_color = if can_proceed {
                     Color32::from_rgb(0, 128, 255)
@@ -941,8 +1100,10 @@ impl ScreenLike for SetTokenPriceScreen {
 
                 let button_response = ui.add_enabled(can_proceed, button);
 
-                if !can_proceed {
-                    button_response.on_hover_text("Please enter valid pricing information");
+                if let Err(e) = key_check {
+                    button_response.on_disabled_hover_text(e);
+                } else if let Err(e) = schedule_check {
+                    button_response.on_disabled_hover_text(e);
                 } else if button_response.clicked() {
                     self.show_confirmation_popup = true;
                 }
@@ -1010,3 +1171,82 @@ impl ScreenWithWalletUnlock for SetTokenPriceScreen {
         self.error_message.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiers(rows: &[(&str, &str)]) -> Vec<(String, String)> {
+        rows.iter()
+            .map(|(amount, price)| (amount.to_string(), price.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_dash_price() {
+        assert_eq!(parse_dash_price(" 0.5 "), Ok(50_000_000_000));
+        assert_eq!(parse_dash_price("0.00000000001"), Ok(1));
+        assert!(parse_dash_price("0").is_err());
+        assert!(parse_dash_price("-1").is_err());
+        assert!(parse_dash_price("abc").is_err());
+        assert!(parse_dash_price("NaN").is_err());
+        assert!(parse_dash_price("0.000000000001").is_err());
+        assert!(parse_dash_price("1000000000").is_err());
+    }
+
+    #[test]
+    fn test_validate_tiers() {
+        let map = validate_tiers(&tiers(&[("1", "1"), ("10", "0.5"), ("", "")])).unwrap();
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            vec![(1, 100_000_000_000), (10, 50_000_000_000)]
+        );
+
+        // Amounts must be strictly increasing in the order entered
+        let error = validate_tiers(&tiers(&[("1", "1"), ("10", "0.5"), ("10", "0.4")]));
+        assert!(error.unwrap_err().starts_with("Tier 3:"));
+        assert!(validate_tiers(&tiers(&[("1", "1"), ("10", "0.5"), ("5", "0.4")])).is_err());
+
+        assert!(validate_tiers(&tiers(&[("1", "1"), ("10", "")])).is_err());
+        assert!(validate_tiers(&tiers(&[("1", "1"), ("0", "1")])).is_err());
+        assert!(validate_tiers(&tiers(&[("", "")])).is_err());
+    }
+
+    #[test]
+    fn test_base_units() {
+        assert_eq!(base_units(1, 0), 1);
+        assert_eq!(base_units(10, 8), 1_000_000_000);
+        assert_eq!(base_units(100, 18), TokenAmount::MAX);
+        assert_eq!(base_units(1, 30), TokenAmount::MAX);
+    }
+
+    #[test]
+    fn test_purchase_cost() {
+        let schedule =
+            TokenPricingSchedule::SetPrices(BTreeMap::from([(1, 10), (10, 8), (100, 5)]));
+        assert_eq!(purchase_cost(&schedule, 1), Some(10));
+        assert_eq!(purchase_cost(&schedule, 9), Some(90));
+        assert_eq!(purchase_cost(&schedule, 10), Some(80));
+        assert_eq!(purchase_cost(&schedule, 100), Some(500));
+
+        let schedule = TokenPricingSchedule::SetPrices(BTreeMap::from([(10, 8)]));
+        assert_eq!(purchase_cost(&schedule, 1), None);
+
+        let schedule = TokenPricingSchedule::SinglePrice(Credits::MAX);
+        assert_eq!(purchase_cost(&schedule, 2), None);
+    }
+
+    #[test]
+    fn test_pricing_inputs_round_trip() {
+        let tiers = BTreeMap::from([(5, 100_000_000_000), (50, 25_000_000_000)]);
+        let (pricing_type, _, rows) =
+            pricing_inputs(&TokenPricingSchedule::SetPrices(tiers.clone()));
+        assert!(pricing_type == PricingType::TieredPricing);
+        assert_eq!(validate_tiers(&rows), Ok(tiers));
+
+        let (pricing_type, single_price, _) =
+            pricing_inputs(&TokenPricingSchedule::SinglePrice(150_000_000_000));
+        assert!(pricing_type == PricingType::SinglePrice);
+        assert_eq!(parse_dash_price(&single_price), Ok(150_000_000_000));
+    }
+}
@@ -0,0 +1,194 @@
+use super::{TokenInfoWithDataContract, TokensScreen};
+use crate::app::{AppAction, BackendTasksExecutionMode};
+use crate::backend_task::BackendTask;
+use crate::backend_task::tokens::TokenTask;
+use crate::ui::MessageType;
+use crate::ui::theme::DashColors;
+use chrono::Utc;
+use dash_sdk::dpp::data_contract::TokenContractPosition;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, RichText, Ui};
+
+/// State of the "Add Token by Contract ID" window
+#[derive(Debug, Default)]
+pub(super) struct AddTokenByContractId {
+    contract_id_input: String,
+    token_position_input: String,
+    adding: bool,
+    error: Option<String>,
+}
+
+impl AddTokenByContractId {
+    fn contract_id(&self) -> Option<Identifier> {
+        Identifier::from_string(self.contract_id_input.trim(), Encoding::Base58).ok()
+    }
+
+    /// The entered token position, `Ok(None)` when left empty
+    fn token_position(&self) -> Result<Option<TokenContractPosition>, ()> {
+        parse_token_position(&self.token_position_input)
+    }
+}
+
+fn parse_token_position(input: &str) -> Result<Option<TokenContractPosition>, ()> {
+    let input = input.trim();
+    if input.is_empty() {
+        Ok(None)
+    } else {
+        input.parse().map(Some).map_err(|_| ())
+    }
+}
+
+/// Check mark or cross telling whether a non-empty input is valid
+fn render_validity(ui: &mut Ui, is_valid: bool) {
+    let (symbol, color) = if is_valid {
+        ("✔", Color32::DARK_GREEN)
+    } else {
+        ("×", Color32::RED)
+    };
+    ui.label(RichText::new(symbol).color(color).strong());
+}
+
+impl TokensScreen {
+    pub(super) fn open_add_token_by_contract_id(&mut self) {
+        self.add_token_by_contract_id = Some(AddTokenByContractId::default());
+    }
+
+    /// Records the error of a pending add, returns whether one was pending
+    pub(super) fn add_token_by_contract_id_failed(&mut self, msg: &str) -> bool {
+        match &mut self.add_token_by_contract_id {
+            Some(form) if form.adding => {
+                form.adding = false;
+                form.error = Some(msg.to_string());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(super) fn added_token_by_contract_id(&mut self, token: TokenInfoWithDataContract) {
+        self.add_token_by_contract_id = None;
+        self.backend_message = Some((
+            format!("Added token {}", token.token_name),
+            MessageType::Success,
+            Utc::now(),
+        ));
+        self.all_known_tokens.insert(token.token_id, token);
+    }
+
+    /// Renders the "Add Token by Contract ID" window when it is open
+    pub(super) fn render_add_token_by_contract_id_window(&mut self, ui: &mut Ui) -> AppAction {
+        let Some(mut form) = self.add_token_by_contract_id.take() else {
+            return AppAction::None;
+        };
+
+        let mut action = AppAction::None;
+        let mut is_open = true;
+        egui::Window::new("Add Token by Contract ID")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                let dark_mode = ui.ctx().style().visuals.dark_mode;
+
+                egui::Grid::new("add_token_by_contract_id_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label("Contract ID:");
+                        ui.horizontal(|ui| {
+                            ui.add_sized(
+                                [350.0, 22.0],
+                                egui::TextEdit::singleline(&mut form.contract_id_input)
+                                    .hint_text("Enter base58 contract ID"),
+                            );
+                            if !form.contract_id_input.trim().is_empty() {
+                                render_validity(ui, form.contract_id().is_some());
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Token position:");
+                        ui.horizontal(|ui| {
+                            ui.add_sized(
+                                [80.0, 22.0],
+                                egui::TextEdit::singleline(&mut form.token_position_input)
+                                    .hint_text("Optional"),
+                            );
+                            if !form.token_position_input.trim().is_empty() {
+                                render_validity(ui, form.token_position().is_ok());
+                            }
+                        });
+                        ui.end_row();
+                    });
+                ui.label(
+                    RichText::new(
+                        "Leave the position empty if the contract defines a single token.",
+                    )
+                    .small()
+                    .color(DashColors::text_secondary(dark_mode)),
+                );
+
+                ui.add_space(10.0);
+                let inputs = form
+                    .contract_id()
+                    .zip(form.token_position().ok())
+                    .filter(|_| !form.adding);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(inputs.is_some(), egui::Button::new("Add Token"))
+                        .clicked()
+                    {
+                        if let Some((contract_id, token_position)) = inputs {
+                            form.adding = true;
+                            form.error = None;
+                            action = AppAction::BackendTasks(
+                                vec![
+                                    BackendTask::TokenTask(Box::new(
+                                        TokenTask::AddTokenByContractId {
+                                            contract_id,
+                                            token_position,
+                                        },
+                                    )),
+                                    BackendTask::TokenTask(Box::new(
+                                        TokenTask::QueryMyTokenBalances,
+                                    )),
+                                ],
+                                BackendTasksExecutionMode::SequentialFailFast,
+                            );
+                        }
+                    }
+                    if form.adding {
+                        ui.spinner();
+                        ui.label("Fetching contract...");
+                    }
+                });
+
+                if let Some(error) = &form.error {
+                    ui.add_space(5.0);
+                    ui.colored_label(DashColors::ERROR, error);
+                }
+            });
+
+        if is_open {
+            self.add_token_by_contract_id = Some(form);
+        }
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_position() {
+        assert_eq!(parse_token_position(""), Ok(None));
+        assert_eq!(parse_token_position("  "), Ok(None));
+        assert_eq!(parse_token_position("2"), Ok(Some(2)));
+        assert_eq!(parse_token_position(" 0 "), Ok(Some(0)));
+        assert!(parse_token_position("-1").is_err());
+        assert!(parse_token_position("abc").is_err());
+        assert!(parse_token_position("70000").is_err());
+    }
+}
@@ -0,0 +1,300 @@
+use crate::app::{AppAction, BackendTasksExecutionMode};
+use crate::backend_task::BackendTask;
+use crate::backend_task::tokens::TokenTask;
+use crate::ui::MessageType;
+use crate::ui::helpers::{DialogKeyAction, dialog_key_action};
+use crate::ui::identities::get_selected_wallet;
+use crate::ui::tokens::claim_tokens_screen::{claim_signing_key, claimable_distribution_types};
+use crate::ui::tokens::tokens_screen::TokensScreen;
+use chrono::Utc;
+use dash_sdk::dpp::data_contract::associated_token::token_distribution_key::TokenDistributionType;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use egui::Ui;
+use std::sync::Arc;
+
+/// Claims "Claim All" is about to submit, waiting for confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ClaimAllPlan {
+    /// Description of each claim, such as "MyToken (Perpetual) for alice", with its task
+    claims: Vec<(String, BackendTask)>,
+    /// Tokens left out of the batch, with the reason
+    skipped: Vec<String>,
+}
+
+/// Claims submitted by "Claim All" whose results have not all arrived yet
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ClaimAllBatch {
+    labels: Vec<String>,
+    skipped: Vec<String>,
+    claimed: usize,
+}
+
+/// Report of a finished "Claim All" batch. `errors` are the errors of the failed claims as
+/// reported for a best effort batch, where claims are numbered in the order of `labels`.
+fn claim_all_report(
+    labels: &[String],
+    claimed: usize,
+    errors: Option<&str>,
+    skipped: &[String],
+) -> String {
+    let mut report = format!("Claimed {} of {} rewards.", claimed, labels.len());
+    if let Some(errors) = errors {
+        let errors =
+            labels
+                .iter()
+                .enumerate()
+                .rev()
+                .fold(errors.to_string(), |errors, (i, label)| {
+                    errors.replace(&format!("Task {}: ", i + 1), &format!("{}: ", label))
+                });
+        report.push_str(" Failed:\n\n");
+        report.push_str(&errors);
+    }
+    if !skipped.is_empty() {
+        report.push_str(if errors.is_some() { "\n\n" } else { " " });
+        report.push_str(&format!("Skipped: {}", skipped.join(", ")));
+    }
+    report
+}
+
+impl TokensScreen {
+    /// Number of tracked balances whose identity can claim rewards of the token
+    fn claimable_token_count(&self) -> usize {
+        self.my_tokens
+            .values()
+            .filter(|itb| itb.available_actions.can_claim)
+            .count()
+    }
+
+    /// Builds a claim for every distribution of each tracked balance whose identity can claim,
+    /// skipping the ones that can't be signed right now or whose contract isn't stored locally
+    fn plan_claim_all(&self) -> ClaimAllPlan {
+        let mut plan = ClaimAllPlan {
+            claims: Vec::new(),
+            skipped: Vec::new(),
+        };
+        for itb in self
+            .my_tokens
+            .values()
+            .filter(|itb| itb.available_actions.can_claim)
+        {
+            let Some(identity) = self.identities.get(&itb.identity_id) else {
+                continue;
+            };
+            let owner = identity
+                .alias
+                .clone()
+                .unwrap_or_else(|| itb.identity_id.to_string(Encoding::Base58));
+            let label = format!("{} for {}", itb.token_alias, owner);
+            let contract = match self.app_context.get_contract_by_token_id(&itb.token_id) {
+                Ok(Some(contract)) => contract,
+                Ok(None) => {
+                    plan.skipped
+                        .push(format!("{} (token contract not found)", label));
+                    continue;
+                }
+                Err(e) => {
+                    plan.skipped
+                        .push(format!("{} (error loading token contract: {})", label, e));
+                    continue;
+                }
+            };
+            let Some(signing_key) = claim_signing_key(&identity.identity) else {
+                plan.skipped
+                    .push(format!("{} (no critical key to sign the claim)", label));
+                continue;
+            };
+            let mut error_message = None;
+            if get_selected_wallet(identity, None, Some(signing_key), &mut error_message)
+                .is_some_and(|wallet| !wallet.read().unwrap().is_open())
+            {
+                plan.skipped.push(format!("{} (wallet is locked)", label));
+                continue;
+            }
+            let distribution_types = claimable_distribution_types(
+                &itb.token_config,
+                &contract.contract,
+                itb.identity_id,
+            );
+            if distribution_types.is_empty() {
+                plan.skipped
+                    .push(format!("{} (no distribution to claim from)", label));
+                continue;
+            }
+            let data_contract = Arc::new(contract.contract.clone());
+            for distribution_type in distribution_types {
+                let type_name = match distribution_type {
+                    TokenDistributionType::Perpetual => "Perpetual",
+                    TokenDistributionType::PreProgrammed => "PreProgrammed",
+                };
+                plan.claims.push((
+                    format!("{} ({}) for {}", itb.token_alias, type_name, owner),
+                    BackendTask::TokenTask(Box::new(TokenTask::ClaimTokens {
+                        data_contract: data_contract.clone(),
+                        token_position: itb.token_position,
+                        actor_identity: identity.clone(),
+                        distribution_type,
+                        signing_key: signing_key.clone(),
+                        public_note: None,
+                    })),
+                ));
+            }
+        }
+        plan
+    }
+
+    /// Renders a "Claim All" button for the tokens with claimable rewards, or the progress of
+    /// the claims it submitted
+    pub(super) fn render_claim_all_button(&mut self, ui: &mut Ui) {
+        if let Some(batch) = &self.claim_all_batch {
+            ui.add(egui::Spinner::new());
+            ui.label(format!("Claiming {} rewards...", batch.labels.len()));
+            return;
+        }
+        let count = self.claimable_token_count();
+        if count > 0
+            && ui
+                .button(format!("Claim All ({})", count))
+                .on_hover_text("Claim the rewards of every token your identities can claim")
+                .clicked()
+        {
+            self.claim_all_plan = Some(self.plan_claim_all());
+        }
+    }
+
+    /// Confirmation of the claims planned by "Claim All", which submits them one after another
+    pub(super) fn show_claim_all_popup(&mut self, ui: &mut Ui) -> AppAction {
+        let Some(plan) = self.claim_all_plan.clone() else {
+            return AppAction::None;
+        };
+        let mut action = AppAction::None;
+        let mut close = false;
+        let mut is_open = true;
+
+        egui::Window::new("Confirm Claim All")
+            .collapsible(false)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
+                if plan.claims.is_empty() {
+                    ui.label("None of the tokens with claimable rewards can be claimed right now.");
+                } else {
+                    ui.label(format!(
+                        "Are you sure you want to submit these {} claims? They are sent one after another.",
+                        plan.claims.len()
+                    ));
+                }
+                ui.add_space(5.0);
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for (label, _) in &plan.claims {
+                            ui.label(format!("• {}", label));
+                        }
+                        if !plan.skipped.is_empty() {
+                            ui.add_space(5.0);
+                            ui.label("Skipped:");
+                            for skipped in &plan.skipped {
+                                ui.label(format!("• {}", skipped));
+                            }
+                        }
+                    });
+                ui.add_space(5.0);
+
+                if !plan.claims.is_empty()
+                    && (ui.button("Confirm").clicked() || key_action == DialogKeyAction::Confirm)
+                {
+                    let (labels, tasks): (Vec<String>, Vec<BackendTask>) =
+                        plan.claims.iter().cloned().unzip();
+                    action = AppAction::BackendTasks(
+                        tasks,
+                        BackendTasksExecutionMode::SequentialBestEffort,
+                    );
+                    self.claim_all_batch = Some(ClaimAllBatch {
+                        labels,
+                        skipped: plan.skipped.clone(),
+                        claimed: 0,
+                    });
+                    close = true;
+                }
+                if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
+                    close = true;
+                }
+            });
+
+        if close || !is_open {
+            self.claim_all_plan = None;
+        }
+        action
+    }
+
+    /// Counts the result of a claim of the "Claim All" batch, reporting once the batch is
+    /// finished. Returns whether the message belonged to the batch.
+    pub(super) fn claim_all_message(&mut self, msg: &str, msg_type: MessageType) -> bool {
+        let Some(batch) = &mut self.claim_all_batch else {
+            return false;
+        };
+        let errors = match msg_type {
+            MessageType::Success if msg == "ClaimTokens" => {
+                batch.claimed += 1;
+                if batch.claimed < batch.labels.len() {
+                    return true;
+                }
+                None
+            }
+            // A best effort batch reports every failure at once, after its successes
+            MessageType::Error if msg.contains("tasks succeeded.") => Some(
+                msg.split_once(" Errors:\n\n")
+                    .map_or(msg, |(_, errors)| errors),
+            ),
+            _ => return false,
+        };
+        let report = claim_all_report(&batch.labels, batch.claimed, errors, &batch.skipped);
+        let report_type = if errors.is_some() {
+            MessageType::Error
+        } else {
+            MessageType::Success
+        };
+        self.backend_message = Some((report, report_type, Utc::now()));
+        self.claim_all_batch = None;
+        self.pending_backend_task = Some(BackendTask::TokenTask(Box::new(
+            TokenTask::QueryMyTokenBalances,
+        )));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_all_report() {
+        let labels = vec![
+            "A (Perpetual) for alice".to_string(),
+            "B (PreProgrammed) for bob".to_string(),
+        ];
+        assert_eq!(
+            claim_all_report(&labels, 2, None, &[]),
+            "Claimed 2 of 2 rewards."
+        );
+        assert_eq!(
+            claim_all_report(
+                &labels,
+                1,
+                Some("Task 2: Failed to claim tokens: nothing to claim"),
+                &["C for carol (token contract not found)".to_string()],
+            ),
+            "Claimed 1 of 2 rewards. Failed:\n\nB (PreProgrammed) for bob: Failed to claim tokens: nothing to claim\n\nSkipped: C for carol (token contract not found)"
+        );
+        assert_eq!(
+            claim_all_report(
+                &labels[..1],
+                1,
+                None,
+                &["C for carol (wallet is locked)".to_string()]
+            ),
+            "Claimed 1 of 1 rewards. Skipped: C for carol (wallet is locked)"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::app::AppAction;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::tokens::tokens_screen::TokensScreen;
+use crate::ui::tokens::tokens_screen::{TokenSearchListKind, TokensScreen};
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::Identifier;
 use egui::Ui;
@@ -42,6 +42,16 @@ impl TokensScreen {
             .filter(|token| token.data_contract_id == *contract_id)
             .cloned()
             .collect::<Vec<_>>();
+        let (token_infos, dismissed): (Vec<_>, Vec<_>) =
+            token_infos.into_iter().partition(|token| {
+                self.token_search_list_kind(&token.token_id) != Some(TokenSearchListKind::Denylist)
+            });
+        if !dismissed.is_empty() {
+            ui.label(format!(
+                "{} dismissed token(s) hidden. Manage them from the search screen.",
+                dismissed.len()
+            ));
+        }
         for token in token_infos {
             if token.data_contract_id == *contract_id {
                 ui.add_space(10.0);
@@ -75,6 +85,24 @@ impl TokensScreen {
                         }
                     }
                 }
+                if self.token_search_list_kind(&token.token_id)
+                    == Some(TokenSearchListKind::Allowlist)
+                {
+                    ui.label("Always tracked");
+                } else if ui
+                    .button("Always Track")
+                    .on_hover_text("Track this token now and whenever a search finds it again")
+                    .clicked()
+                {
+                    action |= self.set_token_search_list(&token, TokenSearchListKind::Allowlist);
+                }
+                if ui
+                    .button("Dismiss")
+                    .on_hover_text("Hide this token's contract from future search results")
+                    .clicked()
+                {
+                    action |= self.set_token_search_list(&token, TokenSearchListKind::Denylist);
+                }
                 if ui.button("View schema").clicked() {
                     // Show a popup window with the schema
                     match serde_json::to_string_pretty(&token.token_configuration) {
@@ -2,14 +2,14 @@ use crate::app::AppAction;
 use crate::backend_task::BackendTask;
 use crate::backend_task::contract::ContractTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::ui::helpers::render_identifier_cell;
 use crate::ui::tokens::tokens_screen::{
     ContractDescriptionInfo, ContractSearchStatus, TokensScreen,
 };
 use chrono::Utc;
-use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use eframe::emath::Align;
 use eframe::epaint::Color32;
-use egui::Ui;
+use egui::{RichText, Ui};
 use egui_extras::{Column, TableBuilder};
 
 impl TokensScreen {
@@ -99,13 +99,28 @@ impl TokensScreen {
                 });
             }
             ContractSearchStatus::Complete => {
-                // Show the results
-                let results = self.search_results.lock().unwrap().clone();
+                // Show the results, minus contracts with dismissed tokens
+                let (results, hidden): (Vec<_>, Vec<_>) = self
+                    .search_results
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .cloned()
+                    .partition(|contract| !self.is_contract_denylisted(&contract.data_contract_id));
                 if results.is_empty() {
                     ui.label("No tokens match your keyword.");
                 } else {
                     action |= self.render_search_results_table(ui, &results);
                 }
+                if !hidden.is_empty() {
+                    ui.label(
+                        RichText::new(format!(
+                            "{} result(s) hidden because you dismissed their tokens.",
+                            hidden.len()
+                        ))
+                        .weak(),
+                    );
+                }
 
                 // Pagination controls
                 ui.horizontal(|ui| {
@@ -129,6 +144,9 @@ impl TokensScreen {
             }
         }
 
+        ui.add_space(10.0);
+        self.render_token_search_lists(ui);
+
         action
     }
 
@@ -143,29 +161,43 @@ impl TokensScreen {
             ui.set_min_width(ui.available_width());
             ui.set_max_width(ui.available_width());
 
+            let ctx = ui.ctx().clone();
+            let id_salt = self.keyword_search_column_widths.id_salt();
+            let widths = self
+                .keyword_search_column_widths
+                .initial_widths(&[60.0, 200.0, 80.0]);
             TableBuilder::new(ui)
+                .id_salt(id_salt)
                 .striped(false)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(Align::Center))
-                .column(Column::initial(60.0).resizable(true)) // Contract ID
-                .column(Column::initial(200.0).resizable(true)) // Contract Description
-                .column(Column::initial(80.0).resizable(true)) // Action
+                .column(Column::initial(widths[0]).resizable(true)) // Contract ID
+                .column(Column::initial(widths[1]).resizable(true)) // Contract Description
+                .column(Column::initial(widths[2]).resizable(true)) // Action
                 .header(30.0, |mut header| {
                     header.col(|ui| {
-                        ui.label("Contract ID");
+                        let response = ui.label("Contract ID");
+                        self.keyword_search_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                     header.col(|ui| {
-                        ui.label("Contract Description");
+                        let response = ui.label("Contract Description");
+                        self.keyword_search_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                     header.col(|ui| {
-                        ui.label("Action");
+                        let response = ui.label("Action");
+                        self.keyword_search_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                 })
                 .body(|mut body| {
+                    self.keyword_search_column_widths
+                        .track(&ctx, &self.app_context, body.widths());
                     for contract in search_results {
                         body.row(25.0, |mut row| {
                             row.col(|ui| {
-                                ui.label(contract.data_contract_id.to_string(Encoding::Base58));
+                                render_identifier_cell(ui, &contract.data_contract_id, None);
                             });
                             row.col(|ui| {
                                 ui.label(contract.description.clone());
@@ -1,17 +1,26 @@
+mod add_token_by_contract_id;
+mod claim_all;
 mod contract_details;
 mod data_contract_json_pop_up;
 mod distributions;
 mod groups;
 mod keyword_search;
 mod my_tokens;
+mod price_history;
 mod structs;
+mod token_comparison;
 mod token_creator;
+mod token_search_lists;
 
 pub use structs::*;
 
 pub use groups::*;
 
-use std::collections::BTreeMap;
+use add_token_by_contract_id::AddTokenByContractId;
+use claim_all::{ClaimAllBatch, ClaimAllPlan};
+use token_comparison::TokenComparison;
+
+use std::collections::{BTreeMap, HashSet};
 use std::sync::{Arc, Mutex, RwLock};
 
 use serde_json;
@@ -25,7 +34,6 @@ use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::To
 use dash_sdk::dpp::data_contract::associated_token::token_keeps_history_rules::TokenKeepsHistoryRules;
 use dash_sdk::dpp::data_contract::associated_token::token_keeps_history_rules::v0::TokenKeepsHistoryRulesV0;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::distribution_function::DistributionFunction;
-use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::distribution_function::evaluate_interval::IntervalEvaluationExplanation;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::distribution_recipient::TokenDistributionRecipient;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::reward_distribution_type::RewardDistributionType;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::v0::TokenPerpetualDistributionV0;
@@ -57,13 +65,18 @@ use crate::backend_task::{BackendTask, NO_IDENTITIES_FOUND};
 use crate::app::{AppAction, DesiredAppAction};
 use crate::context::AppContext;
 use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
+use crate::model::token_price_history::TokenPriceHistoryEntry;
 use crate::model::wallet::Wallet;
+use crate::ui::components::inline_alias_edit::InlineAliasEdit;
 use crate::ui::components::left_panel::add_left_panel;
-use crate::ui::components::styled::{island_central_panel, ClickableCollapsingHeader};
+use crate::ui::components::styled::{ClickableCollapsingHeader, island_central_panel};
+use crate::ui::components::table_column_widths::TableColumnWidths;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::{DialogKeyAction, dialog_key_action};
 use crate::ui::{BackendTaskSuccessResult, MessageType, RootScreenType, ScreenLike, ScreenType};
+use crate::utils::json_validation::JsonValidationError;
 
 const EXP_FORMULA_PNG: &[u8] = include_bytes!("../../../../assets/exp_function.png");
 const INV_LOG_FORMULA_PNG: &[u8] = include_bytes!("../../../../assets/inv_log_function.png");
@@ -124,6 +137,40 @@ pub struct ContractDescriptionInfo {
     pub description: String,
 }
 
+/// The local list a token was put on from the Search Tokens subscreen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSearchListKind {
+    /// Tokens that are tracked automatically whenever a search turns them up
+    Allowlist,
+    /// Tokens that are hidden from search results
+    Denylist,
+}
+
+impl TokenSearchListKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenSearchListKind::Allowlist => "allow",
+            TokenSearchListKind::Denylist => "deny",
+        }
+    }
+
+    pub fn from_db_str(value: &str) -> Option<Self> {
+        match value {
+            "allow" => Some(TokenSearchListKind::Allowlist),
+            "deny" => Some(TokenSearchListKind::Denylist),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenSearchListEntry {
+    pub token_id: Identifier,
+    pub data_contract_id: Identifier,
+    pub token_name: String,
+    pub kind: TokenSearchListKind,
+}
+
 /* helper: tiny checkbox with no extra spacing */
 fn sub_checkbox(ui: &mut Ui, flag: &mut bool, label: &str) {
     ui.horizontal(|ui| {
@@ -211,6 +258,30 @@ enum SortColumn {
     Balance,
 }
 
+impl SortColumn {
+    /// Stable identifier used to persist the sort column
+    fn storage_key(&self) -> &'static str {
+        match self {
+            SortColumn::OwnerIdentity => "owner_identity",
+            SortColumn::OwnerIdentityAlias => "owner_identity_alias",
+            SortColumn::Balance => "balance",
+        }
+    }
+
+    /// Parses an identifier returned by [`SortColumn::storage_key`]
+    fn from_storage_key(key: &str) -> Option<Self> {
+        match key {
+            "owner_identity" => Some(SortColumn::OwnerIdentity),
+            "owner_identity_alias" => Some(SortColumn::OwnerIdentityAlias),
+            "balance" => Some(SortColumn::Balance),
+            _ => None,
+        }
+    }
+}
+
+/// Identifies the token balances table in the saved sort preferences
+const TOKEN_BALANCES_SORT_KEY: &str = "tokens_balances";
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum SortOrder {
     Ascending,
@@ -952,6 +1023,16 @@ pub struct TokensScreen {
         Option<dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule>,
     >,
     pricing_loading_state: IndexMap<Identifier, bool>,
+    // Cached paused state per token, fetched from Platform
+    token_paused_statuses: IndexMap<Identifier, bool>,
+    // Balances requested by "Check all" that are still being fetched, for progress display
+    balance_check_batch: Option<Vec<IdentityTokenIdentifier>>,
+    // Reward estimates requested by "Estimate all" that have not arrived yet
+    reward_estimation_batch: Option<Vec<IdentityTokenIdentifier>>,
+    // Claims planned by "Claim All", waiting for confirmation
+    claim_all_plan: Option<ClaimAllPlan>,
+    // Claims submitted by "Claim All" whose results have not all arrived yet
+    claim_all_batch: Option<ClaimAllBatch>,
     backend_message: Option<(String, MessageType, DateTime<Utc>)>,
     pending_backend_task: Option<BackendTask>,
     refreshing_status: RefreshingStatus,
@@ -977,18 +1058,53 @@ pub struct TokensScreen {
     sort_order: SortOrder,
     use_custom_order: bool,
 
+    // Pinned tokens, shown at the top of the token list
+    pinned_tokens: HashSet<Identifier>,
+    // Filter of the token list by token name or ID
+    token_list_filter: String,
+    // Page of the token list shown, and how many tokens a page holds
+    token_list_page: usize,
+    token_list_page_size: usize,
+    // Column widths of the token list, token holders and keyword search tables
+    token_list_column_widths: TableColumnWidths,
+    holder_column_widths: TableColumnWidths,
+    holder_rewards_column_widths: TableColumnWidths,
+    keyword_search_column_widths: TableColumnWidths,
+    // Developer option to show token amounts as raw integers instead of applying decimals
+    show_raw_token_amounts: bool,
+    // Alias of a token holder being edited in place
+    holder_alias_edit: Option<InlineAliasEdit>,
+
+    // Allowlisted and denylisted tokens for the Search Tokens subscreen
+    token_search_lists: IndexMap<Identifier, TokenSearchListEntry>,
+    // Allowlisted tokens found by a search that still have to be tracked
+    pending_auto_track_tokens: Vec<TokenInfo>,
+
     // Remove token
     confirm_remove_identity_token_balance_popup: bool,
     identity_token_balance_to_remove: Option<IdentityTokenBasicInfo>,
     confirm_remove_token_popup: bool,
     token_to_remove: Option<Identifier>,
+    // Tokens checked in the token list, to remove together
+    tokens_selected_for_removal: HashSet<Identifier>,
+    confirm_remove_selected_tokens_popup: bool,
 
     // Reward explanations
-    reward_explanations: IndexMap<IdentityTokenIdentifier, IntervalEvaluationExplanation>,
+    reward_explanations: IndexMap<IdentityTokenIdentifier, CachedRewardExplanation>,
     show_explanation_popup: Option<IdentityTokenIdentifier>,
+    // When token balances were last fetched from Platform
+    balances_refreshed_at: Option<DateTime<Utc>>,
 
     // Token info popup
     show_token_info_popup: Option<Identifier>,
+    // Private notes of the token shown in the info popup: (token, saved notes, edited notes)
+    token_notes: Option<(Identifier, String, String)>,
+    // Recorded pricing schedules of the token shown in the detail view
+    price_history: Option<(Identifier, Vec<TokenPriceHistoryEntry>)>,
+    // Two tokens compared side by side
+    token_comparison: Option<TokenComparison>,
+    // Form adding a token from its contract ID
+    add_token_by_contract_id: Option<AddTokenByContractId>,
 
     // ====================================
     //           Token Creator
@@ -1144,7 +1260,7 @@ pub struct TokensScreen {
     // Document Schemas
     document_schemas_input: String,
     parsed_document_schemas: Option<BTreeMap<String, serde_json::Value>>,
-    document_schemas_error: Option<String>,
+    document_schemas_errors: Vec<JsonValidationError>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1259,6 +1375,22 @@ fn my_tokens(
 }
 
 impl TokensScreen {
+    /// Reloads the tracked token balances and drops the reward explanations of identities whose
+    /// balance changed since the estimate, e.g. after a claim or transfer.
+    fn reload_my_tokens(&mut self) {
+        self.my_tokens = my_tokens(
+            &self.app_context,
+            &self.identities,
+            &self.all_known_tokens,
+            &self.token_pricing_data,
+        );
+        let my_tokens = &self.my_tokens;
+        self.reward_explanations
+            .retain(|identity_token_id, cached| {
+                my_tokens.get(identity_token_id).map(|itb| itb.balance) == cached.balance
+            });
+    }
+
     pub fn new(app_context: &Arc<AppContext>, tokens_subscreen: TokensSubscreen) -> Self {
         let identities = app_context
             .load_local_qualified_identities()
@@ -1270,6 +1402,28 @@ impl TokensScreen {
             .db
             .get_all_known_tokens_with_data_contract(app_context)
             .unwrap_or_default();
+        let pinned_tokens = app_context
+            .db
+            .get_pinned_tokens(app_context)
+            .unwrap_or_default();
+        let (sort_column, sort_order) = app_context
+            .db
+            .get_sort_preference(TOKEN_BALANCES_SORT_KEY)
+            .ok()
+            .flatten()
+            .and_then(|(column, ascending)| {
+                let order = if ascending {
+                    SortOrder::Ascending
+                } else {
+                    SortOrder::Descending
+                };
+                Some((SortColumn::from_storage_key(&column)?, order))
+            })
+            .unwrap_or((SortColumn::OwnerIdentityAlias, SortOrder::Ascending));
+        let token_search_lists = app_context
+            .db
+            .get_token_search_lists(app_context)
+            .unwrap_or_default();
 
         let my_tokens = my_tokens(
             app_context,
@@ -1309,6 +1463,11 @@ impl TokensScreen {
             selected_token: None,
             token_pricing_data: IndexMap::new(),
             pricing_loading_state: IndexMap::new(),
+            token_paused_statuses: IndexMap::new(),
+            balance_check_batch: None,
+            reward_estimation_batch: None,
+            claim_all_plan: None,
+            claim_all_batch: None,
             selected_contract_id: None,
             selected_contract_description: None,
             selected_token_infos: Vec::new(),
@@ -1321,9 +1480,27 @@ impl TokensScreen {
             previous_cursors: vec![],
             search_results: Arc::new(Mutex::new(Vec::new())),
             backend_message: None,
-            sort_column: SortColumn::OwnerIdentityAlias,
-            sort_order: SortOrder::Ascending,
+            sort_column,
+            sort_order,
             use_custom_order: false,
+            pinned_tokens,
+            token_list_filter: String::new(),
+            token_list_page: 0,
+            token_list_page_size: my_tokens::TOKEN_LIST_PAGE_SIZES[0],
+            token_list_column_widths: TableColumnWidths::load(app_context, "token_list"),
+            holder_column_widths: TableColumnWidths::load(app_context, "token_holders"),
+            holder_rewards_column_widths: TableColumnWidths::load(
+                app_context,
+                "token_holders_with_rewards",
+            ),
+            keyword_search_column_widths: TableColumnWidths::load(
+                app_context,
+                "token_keyword_search",
+            ),
+            show_raw_token_amounts: false,
+            holder_alias_edit: None,
+            token_search_lists,
+            pending_auto_track_tokens: Vec::new(),
             pending_backend_task: None,
             tokens_subscreen,
             refreshing_status: RefreshingStatus::NotRefreshing,
@@ -1333,11 +1510,18 @@ impl TokensScreen {
             identity_token_balance_to_remove: None,
             confirm_remove_token_popup: false,
             token_to_remove: None,
+            tokens_selected_for_removal: HashSet::new(),
+            confirm_remove_selected_tokens_popup: false,
 
             // Reward explanations
             reward_explanations: IndexMap::new(),
             show_explanation_popup: None,
+            balances_refreshed_at: None,
             show_token_info_popup: None,
+            token_notes: None,
+            price_history: None,
+            token_comparison: None,
+            add_token_by_contract_id: None,
 
             // Token Creator
             selected_token_preset: None,
@@ -1494,7 +1678,7 @@ impl TokensScreen {
             // Document Schemas
             document_schemas_input: String::new(),
             parsed_document_schemas: None,
-            document_schemas_error: None,
+            document_schemas_errors: Vec::new(),
         };
 
         if let Ok(saved_ids) = screen.app_context.db.load_token_order() {
@@ -1502,9 +1686,39 @@ impl TokensScreen {
             screen.use_custom_order = true;
         }
 
+        if screen.tokens_subscreen == TokensSubscreen::MyTokens {
+            screen.queue_token_statuses_query();
+        }
+
         screen
     }
 
+    /// Backend task that refreshes the paused state of all known tokens, if there are any.
+    fn query_token_statuses_task(&self) -> Option<BackendTask> {
+        if self.all_known_tokens.is_empty() {
+            return None;
+        }
+        Some(BackendTask::TokenTask(Box::new(
+            TokenTask::QueryTokenStatuses(self.all_known_tokens.keys().copied().collect()),
+        )))
+    }
+
+    /// Queues the refresh of the paused state of all known tokens, unless another task, such as
+    /// the balance refresh after "Claim All", is already waiting to be sent.
+    fn queue_token_statuses_query(&mut self) {
+        if self.pending_backend_task.is_none() {
+            self.pending_backend_task = self.query_token_statuses_task();
+        }
+    }
+
+    /// Whether the token is known to be paused. Returns `false` while the status is unknown.
+    fn is_token_paused(&self, token_id: &Identifier) -> bool {
+        self.token_paused_statuses
+            .get(token_id)
+            .copied()
+            .unwrap_or(false)
+    }
+
     // ─────────────────────────────────────────────────────────────────
     // Reordering
     // ─────────────────────────────────────────────────────────────────
@@ -1529,6 +1743,31 @@ impl TokensScreen {
         //self.my_tokens = reordered;
     }
 
+    /// Pin or unpin a token and persist the change.
+    fn toggle_token_pinned(&mut self, token_id: Identifier) {
+        let pinned = !self.pinned_tokens.contains(&token_id);
+        if let Err(e) = self
+            .app_context
+            .db
+            .set_token_pinned(&token_id, pinned, &self.app_context)
+        {
+            self.backend_message = Some((
+                format!("Error updating pinned tokens: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ));
+            return;
+        }
+
+        if pinned {
+            self.pinned_tokens.insert(token_id);
+        } else {
+            self.pinned_tokens.remove(&token_id);
+        }
+        // Pinning reorders the token list, so the current page no longer shows the same tokens
+        self.token_list_page = 0;
+    }
+
     /// Save the current map's order of token IDs to the DB
     fn save_current_order(&self) {
         let all_ids = self
@@ -1560,6 +1799,13 @@ impl TokensScreen {
             self.sort_order = SortOrder::Ascending;
             self.save_current_order();
         }
+        if let Err(e) = self.app_context.db.set_sort_preference(
+            TOKEN_BALANCES_SORT_KEY,
+            self.sort_column.storage_key(),
+            self.sort_order == SortOrder::Ascending,
+        ) {
+            tracing::error!("Error saving token sort preference: {}", e);
+        }
     }
 
     // ─────────────────────────────────────────────────────────────────
@@ -2208,7 +2454,7 @@ impl TokensScreen {
         // Reset document schemas
         self.document_schemas_input = String::new();
         self.parsed_document_schemas = None;
-        self.document_schemas_error = None;
+        self.document_schemas_errors.clear();
     }
 
     fn add_token_to_tracked_tokens(&mut self, token_info: TokenInfo) -> Result<AppAction, String> {
@@ -2300,6 +2546,7 @@ impl TokensScreen {
             .collapsible(false)
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
                 ui.label(format!(
                     "Are you sure you want to stop tracking the token \"{}\" for identity \"{}\"?",
                     token_to_remove.token_alias,
@@ -2307,7 +2554,7 @@ impl TokensScreen {
                 ));
 
                 // Confirm button
-                if ui.button("Confirm").clicked() {
+                if ui.button("Confirm").clicked() || key_action == DialogKeyAction::Confirm {
                     if let Err(e) = self
                         .app_context
                         .remove_token_balance(token_to_remove.token_id, token_to_remove.identity_id)
@@ -2327,7 +2574,7 @@ impl TokensScreen {
                 }
 
                 // Cancel button
-                if ui.button("Cancel").clicked() {
+                if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
                     self.confirm_remove_identity_token_balance_popup = false;
                     self.identity_token_balance_to_remove = None;
                 }
@@ -2363,13 +2610,14 @@ impl TokensScreen {
             .collapsible(false)
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
                 ui.label(format!(
                     "Are you sure you want to stop tracking the token \"{}\"? You can re-add it later. Your actual token balance will not change with this action.",
                     token_name,
                 ));
 
                 // Confirm button
-                if ui.button("Confirm").clicked() {
+                if ui.button("Confirm").clicked() || key_action == DialogKeyAction::Confirm {
                     if let Err(e) = self.app_context.db.remove_token(
                         &token_to_remove,
                         &self.app_context,
@@ -2389,7 +2637,7 @@ impl TokensScreen {
                 }
 
                 // Cancel button
-                if ui.button("Cancel").clicked() {
+                if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
                     self.confirm_remove_token_popup = false;
                     self.token_to_remove = None;
                 }
@@ -2401,6 +2649,80 @@ impl TokensScreen {
             self.token_to_remove = None;
         }
     }
+
+    fn show_remove_selected_tokens_popup(&mut self, ui: &mut Ui) {
+        // Listed in the order of the token list
+        let tokens_to_remove: Vec<(Identifier, String)> = self
+            .all_known_tokens
+            .values()
+            .filter(|t| self.tokens_selected_for_removal.contains(&t.token_id))
+            .map(|t| (t.token_id, t.token_name.clone()))
+            .collect();
+        if tokens_to_remove.is_empty() {
+            self.confirm_remove_selected_tokens_popup = false;
+            return;
+        }
+
+        let mut is_open = true;
+
+        egui::Window::new("Confirm Remove Tokens")
+            .collapsible(false)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                let key_action = dialog_key_action(ui.ctx());
+                ui.label(format!(
+                    "Are you sure you want to stop tracking these {} tokens? You can re-add them later. Your actual token balances will not change with this action.",
+                    tokens_to_remove.len(),
+                ));
+                ui.add_space(5.0);
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for (token_id, token_name) in &tokens_to_remove {
+                            ui.label(format!(
+                                "• {} ({})",
+                                token_name,
+                                token_id.to_string(Encoding::Base58)
+                            ));
+                        }
+                    });
+                ui.add_space(5.0);
+
+                // Confirm button
+                if ui.button("Confirm").clicked() || key_action == DialogKeyAction::Confirm {
+                    let failed: Vec<String> = tokens_to_remove
+                        .iter()
+                        .filter_map(|(token_id, token_name)| {
+                            self.app_context
+                                .db
+                                .remove_token(token_id, &self.app_context)
+                                .err()
+                                .map(|e| format!("{}: {}", token_name, e))
+                        })
+                        .collect();
+                    if !failed.is_empty() {
+                        self.backend_message = Some((
+                            format!("Error removing tokens: {}", failed.join(", ")),
+                            MessageType::Error,
+                            Utc::now(),
+                        ));
+                    }
+                    self.confirm_remove_selected_tokens_popup = false;
+                    self.tokens_selected_for_removal.clear();
+                    self.refresh();
+                }
+
+                // Cancel button
+                if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
+                    self.confirm_remove_selected_tokens_popup = false;
+                }
+            });
+
+        // If user closes the popup window (the [x] button), also reset state
+        if !is_open {
+            self.confirm_remove_selected_tokens_popup = false;
+        }
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────
@@ -2413,6 +2735,19 @@ impl ScreenLike for TokensScreen {
             .db
             .get_all_known_tokens_with_data_contract(&self.app_context)
             .unwrap_or_default();
+        let all_known_tokens = &self.all_known_tokens;
+        self.tokens_selected_for_removal
+            .retain(|token_id| all_known_tokens.contains_key(token_id));
+        self.pinned_tokens = self
+            .app_context
+            .db
+            .get_pinned_tokens(&self.app_context)
+            .unwrap_or_default();
+        self.token_search_lists = self
+            .app_context
+            .db
+            .get_token_search_lists(&self.app_context)
+            .unwrap_or_default();
 
         self.identities = self
             .app_context
@@ -2422,12 +2757,7 @@ impl ScreenLike for TokensScreen {
             .map(|qi| (qi.identity.id(), qi))
             .collect();
 
-        self.my_tokens = my_tokens(
-            &self.app_context,
-            &self.identities,
-            &self.all_known_tokens,
-            &self.token_pricing_data,
-        );
+        self.reload_my_tokens();
 
         match self.app_context.db.load_token_order() {
             Ok(saved_ids) => {
@@ -2450,6 +2780,16 @@ impl ScreenLike for TokensScreen {
             .db
             .get_all_known_tokens_with_data_contract(&self.app_context)
             .unwrap_or_default();
+        self.pinned_tokens = self
+            .app_context
+            .db
+            .get_pinned_tokens(&self.app_context)
+            .unwrap_or_default();
+        self.token_search_lists = self
+            .app_context
+            .db
+            .get_token_search_lists(&self.app_context)
+            .unwrap_or_default();
         self.identities = self
             .app_context
             .load_local_qualified_identities()
@@ -2458,12 +2798,11 @@ impl ScreenLike for TokensScreen {
             .map(|qi| (qi.identity.id(), qi))
             .collect();
 
-        self.my_tokens = my_tokens(
-            &self.app_context,
-            &self.identities,
-            &self.all_known_tokens,
-            &self.token_pricing_data,
-        );
+        self.reload_my_tokens();
+
+        if self.tokens_subscreen == TokensSubscreen::MyTokens {
+            self.queue_token_statuses_query();
+        }
     }
 
     fn ui(&mut self, ctx: &Context) -> AppAction {
@@ -2594,8 +2933,17 @@ impl ScreenLike for TokensScreen {
                         }
                     }
 
-                    // Show either refreshing indicator or message, but not both
-                    if let RefreshingStatus::Refreshing(start_time) = self.refreshing_status {
+                    // Show either refreshing indicator or message, but not both. A "Refresh All"
+                    // batch still to query the balances counts as refreshing too
+                    let refreshing_since = match self.refreshing_status {
+                        RefreshingStatus::Refreshing(start_time) => Some(start_time),
+                        RefreshingStatus::NotRefreshing => self
+                            .app_context
+                            .refresh_all_pending_since(&BackendTask::TokenTask(Box::new(
+                                TokenTask::QueryMyTokenBalances,
+                            ))),
+                    };
+                    if let Some(start_time) = refreshing_since {
                         ui.add_space(25.0); // Space above
                         let now = Utc::now().timestamp() as u64;
                         let elapsed = now - start_time;
@@ -2634,6 +2982,10 @@ impl ScreenLike for TokensScreen {
                     if self.confirm_remove_token_popup {
                         self.show_remove_token_popup(ui);
                     }
+                    if self.confirm_remove_selected_tokens_popup {
+                        self.show_remove_selected_tokens_popup(ui);
+                    }
+                    inner_action |= self.show_claim_all_popup(ui);
 
                     // If we have info text, open a pop-up window to show it
                     if let Some(info_text) = self.show_pop_up_info.clone() {
@@ -2696,6 +3048,8 @@ impl ScreenLike for TokensScreen {
         if action == AppAction::None {
             if let Some(bt) = self.pending_backend_task.take() {
                 action = AppAction::BackendTask(bt);
+            } else if !self.pending_auto_track_tokens.is_empty() {
+                action = self.auto_track_tokens_action();
             }
         }
         action
@@ -2706,6 +3060,12 @@ impl ScreenLike for TokensScreen {
         if msg_type == MessageType::Error && self.contract_details_loading {
             self.contract_details_loading = false;
         }
+        if msg_type == MessageType::Error && self.add_token_by_contract_id_failed(msg) {
+            return;
+        }
+        if self.claim_all_message(msg, msg_type) {
+            return;
+        }
 
         match self.tokens_subscreen {
             TokensSubscreen::TokenCreator => {
@@ -2722,10 +3082,16 @@ impl ScreenLike for TokensScreen {
             }
             TokensSubscreen::MyTokens => {
                 if msg.contains("Successfully fetched token balances")
-                    || msg.contains("Failed to fetch token balances")
+                    || msg.contains("Failed to fetch token balance")
+                    || msg.contains("token balances failed to fetch")
                     || msg.contains("Failed to get estimated rewards")
                     || msg.eq(NO_IDENTITIES_FOUND)
                 {
+                    // A "Check all" batch reports once every balance in it was fetched
+                    self.balance_check_batch = None;
+                    if msg.contains("Failed to get estimated rewards") {
+                        self.reward_estimation_batch = None;
+                    }
                     // Clear adding status on any error
                     if msg.contains("Failed") {
                         self.adding_token_start_time = None;
@@ -2733,7 +3099,13 @@ impl ScreenLike for TokensScreen {
                     }
                     self.backend_message = Some((msg.to_string(), msg_type, Utc::now()));
                     self.refreshing_status = RefreshingStatus::NotRefreshing;
-                } else if msg.contains("Failed to query token pricing") {
+                    if msg.contains("Successfully fetched token balances") {
+                        self.balances_refreshed_at = Some(Utc::now());
+                        self.queue_token_statuses_query();
+                    }
+                } else if msg.contains("Failed to query token pricing")
+                    || msg.contains("Failed to fetch token statuses")
+                {
                     self.backend_message = Some((msg.to_string(), MessageType::Error, Utc::now()));
                 } else {
                     tracing::debug!(
@@ -2790,6 +3162,7 @@ impl ScreenLike for TokensScreen {
 
                 self.selected_contract_description = info.0.clone();
                 self.selected_token_infos = info.1.clone();
+                self.queue_allowlisted_tokens_for_tracking();
                 self.refreshing_status = RefreshingStatus::NotRefreshing;
                 self.contract_details_loading = false;
             }
@@ -2802,23 +3175,49 @@ impl ScreenLike for TokensScreen {
                 if let Some(itb) = self.my_tokens.get_mut(&identity_token_id) {
                     itb.estimated_unclaimed_rewards = Some(amount);
                 }
-                self.reward_explanations.insert(identity_token_id, explanation);
+                let balance = self
+                    .my_tokens
+                    .get(&identity_token_id)
+                    .map(|itb| itb.balance);
+                self.reward_explanations.insert(
+                    identity_token_id,
+                    CachedRewardExplanation {
+                        explanation,
+                        calculated_at: Utc::now(),
+                        balance,
+                    },
+                );
+                if let Some(batch) = &mut self.reward_estimation_batch {
+                    batch.retain(|id| *id != identity_token_id);
+                    if batch.is_empty() {
+                        self.reward_estimation_batch = None;
+                    }
+                }
             }
             BackendTaskSuccessResult::TokenPricing { token_id, prices } => {
+                // The fetch recorded a new price history entry
+                if self
+                    .price_history
+                    .as_ref()
+                    .is_some_and(|(id, _)| *id == token_id)
+                {
+                    self.price_history = None;
+                }
                 // Store the pricing data
                 self.token_pricing_data.insert(token_id, prices);
                 // Clear loading state
                 self.pricing_loading_state.insert(token_id, false);
                 // Refresh my_tokens to update available actions with new pricing data
-                self.my_tokens = my_tokens(
-                    &self.app_context,
-                    &self.identities,
-                    &self.all_known_tokens,
-                    &self.token_pricing_data,
-                );
+                self.reload_my_tokens();
                 // Refresh display
                 self.refreshing_status = RefreshingStatus::NotRefreshing;
             }
+            BackendTaskSuccessResult::TokenPausedStatuses(statuses) => {
+                self.token_paused_statuses.extend(statuses);
+            }
+            BackendTaskSuccessResult::AddedTokenByContractId(token) => {
+                self.added_token_by_contract_id(token);
+            }
             _ => {}
         }
     }
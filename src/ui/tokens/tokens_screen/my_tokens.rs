@@ -1,43 +1,66 @@
-use crate::app::AppAction;
+use crate::app::{AppAction, BackendTasksExecutionMode};
 use crate::backend_task::BackendTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::ui::MessageType;
 use crate::ui::Screen;
+use crate::ui::components::inline_alias_edit::render_inline_identity_alias;
 use crate::ui::components::styled::{StyledButton, ClickableCollapsingHeader};
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::{csv_field, format_token_amount, render_identifier_cell};
+use crate::ui::identities::top_up_identity_screen::TopUpIdentityScreen;
 use crate::ui::theme::DashColors;
 use crate::ui::tokens::burn_tokens_screen::BurnTokensScreen;
 use crate::ui::tokens::claim_tokens_screen::ClaimTokensScreen;
 use crate::ui::tokens::destroy_frozen_funds_screen::DestroyFrozenFundsScreen;
 use crate::ui::tokens::direct_token_purchase_screen::PurchaseTokenScreen;
+use crate::ui::tokens::distribute_tokens_screen::DistributeTokensScreen;
 use crate::ui::tokens::freeze_tokens_screen::FreezeTokensScreen;
 use crate::ui::tokens::mint_tokens_screen::MintTokensScreen;
 use crate::ui::tokens::pause_tokens_screen::PauseTokensScreen;
 use crate::ui::tokens::resume_tokens_screen::ResumeTokensScreen;
 use crate::ui::tokens::set_token_price_screen::SetTokenPriceScreen;
 use crate::ui::tokens::tokens_screen::{
-    IdentityTokenIdentifier, IdentityTokenInfo, IdentityTokenMaybeBalanceWithActions,
-    RefreshingStatus, SortColumn, TokenInfoWithDataContract, TokensScreen, TokensSubscreen,
-    get_available_token_actions_for_identity,
+    CachedRewardExplanation, IdentityTokenIdentifier, IdentityTokenInfo,
+    IdentityTokenMaybeBalanceWithActions, RefreshingStatus, SortColumn, TokenInfoWithDataContract,
+    TokensScreen, TokensSubscreen, get_available_token_actions_for_identity,
+    unavailable_token_action_reasons,
 };
 use crate::ui::tokens::transfer_tokens_screen::TransferTokensScreen;
 use crate::ui::tokens::unfreeze_tokens_screen::UnfreezeTokensScreen;
 use crate::ui::tokens::update_token_config::UpdateTokenConfigScreen;
 use crate::ui::tokens::view_token_claims_screen::ViewTokenClaimsScreen;
 use chrono::{Local, Utc};
+use dash_sdk::dpp::balances::credits::TokenAmount;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
+use dash_sdk::dpp::version::PlatformVersion;
+use dash_sdk::platform::Identifier;
 use eframe::emath::Align;
 use eframe::epaint::Color32;
-use egui::{RichText, Ui};
+use egui::{Button, ComboBox, Response, RichText, Ui};
 use egui_extras::{Column, TableBuilder};
+use std::collections::HashSet;
 use std::ops::Range;
 
+const TOKEN_PAUSED_HOVER: &str = "This token is paused. Transfers and purchases are disabled until it is resumed via an emergency action.";
+
+/// Prominent "PAUSED" badge shown next to paused tokens.
+fn render_paused_badge(ui: &mut Ui) {
+    ui.label(
+        RichText::new(" PAUSED ")
+            .strong()
+            .color(Color32::WHITE)
+            .background_color(Color32::DARK_RED),
+    )
+    .on_hover_text(TOKEN_PAUSED_HOVER);
+}
+
 /// Get the minimum price for purchasing one token from a pricing schedule
-fn get_min_token_price(pricing_schedule: &TokenPricingSchedule) -> u64 {
+pub(super) fn get_min_token_price(pricing_schedule: &TokenPricingSchedule) -> u64 {
     match pricing_schedule {
         TokenPricingSchedule::SinglePrice(price) => *price,
         TokenPricingSchedule::SetPrices(price_map) => {
@@ -48,8 +71,19 @@ fn get_min_token_price(pricing_schedule: &TokenPricingSchedule) -> u64 {
 }
 
 impl TokensScreen {
+    /// Formats a token amount with the token's decimals, or as the raw integer when a developer
+    /// chose to see raw amounts.
+    fn display_token_amount(&self, amount: TokenAmount, decimals: u8) -> String {
+        if self.show_raw_token_amounts && self.app_context.is_developer_mode() {
+            amount.to_string()
+        } else {
+            format_token_amount(amount, decimals)
+        }
+    }
+
     fn render_token_info_popup_content(&self, ui: &mut Ui, token_info: &TokenInfoWithDataContract) {
         let config = &token_info.token_configuration;
+        let decimals = config.conventions().decimals();
 
         ui.heading(&token_info.token_name);
         ui.separator();
@@ -74,13 +108,13 @@ impl TokensScreen {
 
                     // Base Supply
                     ui.label("Base Supply:");
-                    ui.label(config.base_supply().to_string());
+                    ui.label(self.display_token_amount(config.base_supply(), decimals));
                     ui.end_row();
 
                     // Max Supply
                     ui.label("Max Supply:");
                     if let Some(max_supply) = config.max_supply() {
-                        ui.label(max_supply.to_string());
+                        ui.label(self.display_token_amount(max_supply, decimals));
                     } else {
                         ui.label("Unlimited");
                     }
@@ -116,20 +150,15 @@ impl TokensScreen {
                     ui.end_row();
 
                     ui.label("Token ID:");
-                    ui.label(token_info.token_id.to_string(Encoding::Base58));
+                    render_identifier_cell(ui, &token_info.token_id, None);
                     ui.end_row();
 
                     ui.label("Contract ID:");
-                    ui.label(token_info.data_contract.id().to_string(Encoding::Base58));
+                    render_identifier_cell(ui, &token_info.data_contract.id(), None);
                     ui.end_row();
 
                     ui.label("Contract Owner:");
-                    ui.label(
-                        token_info
-                            .data_contract
-                            .owner_id()
-                            .to_string(Encoding::Base58),
-                    );
+                    render_identifier_cell(ui, &token_info.data_contract.owner_id(), None);
                     ui.end_row();
                 });
         });
@@ -154,6 +183,65 @@ impl TokensScreen {
         });
     }
 
+    /// Renders the editable private notes of a token. Notes are stored locally only.
+    fn render_token_notes(&mut self, ui: &mut Ui, token_id: Identifier) {
+        if self
+            .token_notes
+            .as_ref()
+            .is_none_or(|(id, _, _)| *id != token_id)
+        {
+            let saved = self
+                .app_context
+                .db
+                .get_token_notes(&token_id, &self.app_context)
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+            self.token_notes = Some((token_id, saved.clone(), saved));
+        }
+        let Some((_, saved, edited)) = self.token_notes.as_mut() else {
+            return;
+        };
+
+        ui.label(RichText::new("Notes").strong());
+        ui.label(
+            RichText::new("Private notes, stored only on this device and never sent to Platform.")
+                .small()
+                .color(Color32::GRAY),
+        );
+        ui.add(
+            egui::TextEdit::multiline(edited)
+                .desired_rows(4)
+                .desired_width(f32::INFINITY)
+                .hint_text("Why you track this token, contacts, TODOs..."),
+        );
+
+        let changed = saved != edited;
+        let mut save_error = None;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(changed, egui::Button::new("Save Notes"))
+                .clicked()
+            {
+                match self
+                    .app_context
+                    .db
+                    .set_token_notes(&token_id, edited, &self.app_context)
+                {
+                    Ok(()) => *saved = edited.clone(),
+                    Err(e) => save_error = Some(format!("Error saving notes: {}", e)),
+                }
+            }
+            if changed {
+                ui.label(RichText::new("Unsaved changes").color(Color32::GRAY));
+            }
+        });
+
+        if let Some(e) = save_error {
+            self.backend_message = Some((e, MessageType::Error, Utc::now()));
+        }
+    }
+
     pub(super) fn render_my_tokens_subscreen(&mut self, ui: &mut Ui) -> AppAction {
         let mut action = AppAction::None;
         if self.all_known_tokens.is_empty() {
@@ -173,6 +261,9 @@ impl TokensScreen {
             }
         }
 
+        self.render_token_comparison_window(ui);
+        action |= self.render_add_token_by_contract_id_window(ui);
+
         // Show token info popup
         if let Some(token_id) = self.show_token_info_popup {
             if let Some(token_info) = self.all_known_tokens.get(&token_id).cloned() {
@@ -195,6 +286,9 @@ impl TokensScreen {
                                 egui::ScrollArea::vertical().show(ui, |ui| {
                                     self.render_token_info_popup_content(ui, &token_info);
 
+                                    ui.separator();
+                                    self.render_token_notes(ui, token_id);
+
                                     ui.separator();
                                     if ui.button("Close").clicked() {
                                         close_popup = true;
@@ -229,12 +323,7 @@ impl TokensScreen {
                     );
                 }
                 TokensSubscreen::SearchTokens => {
-                    ui.label(
-                        RichText::new("No matching tokens found.")
-                            .heading()
-                            .strong()
-                            .color(Color32::GRAY),
-                    );
+                    render_no_matching_tokens(ui);
                 }
                 TokensSubscreen::TokenCreator => {
                     ui.label(
@@ -274,6 +363,12 @@ impl TokensScreen {
                     }
                 }
             }
+            if self.tokens_subscreen == TokensSubscreen::MyTokens {
+                ui.add_space(10.0);
+                if ui.button("Add by Contract ID").clicked() {
+                    self.open_add_token_by_contract_id();
+                }
+            }
         });
 
         app_action
@@ -341,6 +436,36 @@ impl TokensScreen {
             detail_list.push(record);
         }
 
+        if self.is_token_paused(&token_id) {
+            ui.horizontal(|ui| {
+                render_paused_badge(ui);
+                ui.label(TOKEN_PAUSED_HOVER);
+            });
+            ui.add_space(5.0);
+        }
+
+        action |= self.render_check_all_balances(ui, &detail_list);
+
+        if ui
+            .button("Export holders CSV")
+            .on_hover_text("Save every row below, including unknown balances, to a CSV file")
+            .clicked()
+        {
+            self.export_token_holders_csv(&token_info, &detail_list);
+        }
+        if self.app_context.is_developer_mode() {
+            ui.checkbox(&mut self.show_raw_token_amounts, "Show raw amounts")
+                .on_hover_text(
+                    "Show balances and rewards as stored, without applying the token's decimals",
+                );
+        }
+
+        egui::CollapsingHeader::new("Price History")
+            .id_salt(("token_price_history", token_id))
+            .show(ui, |ui| {
+                self.render_price_history(ui, token_id);
+            });
+
         // Space allocation for UI elements is handled by the layout system
 
         let in_dev_mode = self.app_context.is_developer_mode();
@@ -352,79 +477,117 @@ impl TokensScreen {
                 .perpetual_distribution()
                 .is_some();
 
+        let decimals = token_info.token_configuration.conventions().decimals();
+
+        if shows_estimation_column {
+            action |= self.render_estimate_all_rewards(ui, &detail_list, decimals);
+        }
+
         // A simple table with columns: [Token Name | Token ID | Total Balance]
         egui::ScrollArea::both()
             .show(ui, |ui| {
                 ui.set_min_width(ui.available_width());
                 ui.set_max_width(ui.available_width());
+                let ctx = ui.ctx().clone();
+                // The rewards column changes the layout, so each variant keeps its own widths
+                let (column_widths, defaults): (_, &[f32]) = if shows_estimation_column {
+                    (&self.holder_rewards_column_widths, &[60.0, 200.0, 60.0, 85.0, 200.0])
+                } else {
+                    (&self.holder_column_widths, &[60.0, 200.0, 60.0, 200.0])
+                };
+                let id_salt = column_widths.id_salt();
+                let widths = column_widths.initial_widths(defaults);
                 let mut table = TableBuilder::new(ui)
+                            .id_salt(id_salt)
                             .striped(false)
                             .resizable(true)
                             .cell_layout(egui::Layout::left_to_right(Align::Center))
-                            .column(Column::initial(60.0).resizable(true)) // Identity Alias
-                            .column(Column::initial(200.0).resizable(true)) // Identity ID
-                            .column(Column::initial(60.0).resizable(true)); // Balance
+                            .column(Column::initial(widths[0]).resizable(true)) // Identity Alias
+                            .column(Column::initial(widths[1]).resizable(true)) // Identity ID
+                            .column(Column::initial(widths[2]).resizable(true)); // Balance
 
 
                         if shows_estimation_column {
-                            table = table.column(Column::initial(85.0).resizable(true)); // Estimated Rewards
+                            table = table.column(Column::initial(widths[3]).resizable(true)); // Estimated Rewards
                         }
 
-                        table = table.column(Column::initial(200.0).resizable(true));// Actions
+                        table = table.column(Column::initial(widths[widths.len() - 1]).resizable(true));// Actions
                         table.header(30.0, |mut header| {
                             header.col(|ui| {
-                                if ui.button("Identity Alias").clicked() {
+                                let response = ui.button("Identity Alias");
+                                self.holder_column_widths_menu(&response, shows_estimation_column);
+                                if response.clicked() {
                                     self.toggle_sort(SortColumn::OwnerIdentityAlias);
                                 }
                             });
                             header.col(|ui| {
-                                if ui.button("Identity ID").clicked() {
+                                let response = ui.button("Identity ID");
+                                self.holder_column_widths_menu(&response, shows_estimation_column);
+                                if response.clicked() {
                                     self.toggle_sort(SortColumn::OwnerIdentity);
                                 }
                             });
                             header.col(|ui| {
-                                if ui.button("Balance").clicked() {
+                                let response = ui.button("Balance");
+                                self.holder_column_widths_menu(&response, shows_estimation_column);
+                                if response.clicked() {
                                     self.toggle_sort(SortColumn::Balance);
                                 }
                             });
                             if shows_estimation_column {
                                 header.col(|ui| {
-                                    ui.label("Rewards");
+                                    let response = ui.label("Rewards");
+                                    self.holder_column_widths_menu(&response, shows_estimation_column);
                                 });
                             }
                             header.col(|ui| {
-                                ui.label("Actions");
+                                let response = ui.label("Actions");
+                                self.holder_column_widths_menu(&response, shows_estimation_column);
                             });
                         })
                             .body(|mut body| {
+                                if shows_estimation_column {
+                                    self.holder_rewards_column_widths.track(&ctx, &self.app_context, body.widths());
+                                } else {
+                                    self.holder_column_widths.track(&ctx, &self.app_context, body.widths());
+                                }
                                 for itb in &detail_list {
                                     body.row(30.0, |mut row| {
                                         row.col(|ui| {
-                                            // Show identity alias or ID
-                                            if let Some(alias) = self
+                                            // Show identity alias or ID, editable on double-click
+                                            let alias = self
                                                 .app_context
                                                 .get_identity_alias(&itb.identity_id)
-                                                .expect("Expected to get alias")
-                                            {
-                                                ui.label(alias);
-                                            } else {
-                                                ui.label("-");
+                                                .expect("Expected to get alias");
+                                            if let Some(new_alias) = render_inline_identity_alias(
+                                                ui,
+                                                &self.app_context,
+                                                &itb.identity_id,
+                                                alias.as_deref(),
+                                                &mut self.holder_alias_edit,
+                                            ) {
+                                                if let Some(identity) = self.identities.get_mut(&itb.identity_id) {
+                                                    identity.alias = new_alias;
+                                                }
                                             }
                                         });
                                         row.col(|ui| {
                                             if itb.identity_id == token_info.data_contract.owner_id() {
-                                                ui.label(
-                                                    RichText::new(itb.identity_id.to_string(Encoding::Base58))
-                                                        .color(Color32::from_rgb(0, 100, 0)) // Dark green
+                                                render_identifier_cell(
+                                                    ui,
+                                                    &itb.identity_id,
+                                                    Some(Color32::from_rgb(0, 100, 0)), // Dark green
                                                 ).on_hover_text("Owner of the contract");
                                             } else {
-                                                ui.label(itb.identity_id.to_string(Encoding::Base58));
+                                                render_identifier_cell(ui, &itb.identity_id, None);
                                             }
                                         });
                                         row.col(|ui| {
                                             if let Some(balance) = itb.balance {
-                                                let formatted_balance = balance.to_string();
+                                                let formatted_balance = self.display_token_amount(balance, decimals);
                                                 ui.label(formatted_balance);
+                                            } else if self.balance_check_batch.as_ref().is_some_and(|batch| batch.contains(&itb.clone().into())) {
+                                                ui.add(egui::Spinner::new());
                                             } else if ui.button("Check").clicked() {
                                                 action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(TokenTask::QueryIdentityTokenBalance(itb.clone().into()))));
                                             }
@@ -434,7 +597,7 @@ impl TokensScreen {
                                                 if itb.available_actions.can_estimate {
                                                         if let Some(known_rewards) = itb.estimated_unclaimed_rewards  {
                                                             ui.horizontal(|ui| {
-                                                                let formatted_rewards = known_rewards.to_string();
+                                                                let formatted_rewards = self.display_token_amount(known_rewards, decimals);
                                                                 ui.label(formatted_rewards);
 
                                                                 // Info button to show explanation
@@ -484,6 +647,24 @@ impl TokensScreen {
                                                     });
                                                 }
 
+                                                // Explain why the remaining actions are not offered
+                                                let unavailable = unavailable_token_action_reasons(
+                                                    &itb.available_actions,
+                                                    &token_info.token_configuration,
+                                                    &token_info.data_contract,
+                                                );
+                                                if !unavailable.is_empty() {
+                                                    let explanation = unavailable
+                                                        .iter()
+                                                        .map(|(name, reason)| format!("• {}: {}", name, reason))
+                                                        .collect::<Vec<_>>()
+                                                        .join("\n");
+                                                    crate::ui::helpers::info_icon_button(
+                                                        ui,
+                                                        &format!("Why are some actions unavailable?\n\n{}", explanation),
+                                                    );
+                                                }
+
                                                 // Remove
                                                 if ui
                                                     .button("X")
@@ -504,79 +685,142 @@ impl TokensScreen {
 
         // Show explanation popup if requested
         if let Some(identity_token_id) = self.show_explanation_popup {
-            if let Some(explanation) = self.reward_explanations.get(&identity_token_id) {
-                let mut is_open = true;
-                egui::Window::new("Reward Calculation Explanation")
-                    .resizable(true)
-                    .collapsible(false)
-                    .default_width(600.0)
-                    .default_height(400.0)
-                    .open(&mut is_open)
-                    .show(ui.ctx(), |ui| {
-                        egui::ScrollArea::vertical().show(ui, |ui| {
-                            ui.heading("Reward Estimation Details");
-                            ui.separator();
-
-                            let formatted_total = explanation.total_amount.to_string();
-                            ui.label(format!(
-                                "Total Estimated Rewards: {} tokens",
-                                formatted_total
-                            ));
+            let mut is_open = true;
+            let mut re_estimate = false;
+            let mut export_markdown = None;
+            egui::Window::new("Reward Calculation Explanation")
+                .resizable(true)
+                .collapsible(false)
+                .default_width(600.0)
+                .default_height(400.0)
+                .open(&mut is_open)
+                .show(ui.ctx(), |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        ui.heading("Reward Estimation Details");
+
+                        let Some(cached) = self.reward_explanations.get(&identity_token_id) else {
                             ui.separator();
+                            ui.label(
+                                "No up-to-date explanation is available. The balance may have \
+                                 changed since the last estimate.",
+                            );
+                            ui.add_space(5.0);
+                            re_estimate = ui.button("Re-estimate").clicked();
+                            return;
+                        };
+                        let explanation = &cached.explanation;
+
+                        ui.label(
+                            RichText::new(format!(
+                                "Recalculated at {}",
+                                cached
+                                    .calculated_at
+                                    .with_timezone(&Local)
+                                    .format("%Y-%m-%d %H:%M:%S")
+                            ))
+                            .weak(),
+                        );
+                        if cached.is_stale(self.balances_refreshed_at) {
+                            ui.horizontal(|ui| {
+                                ui.colored_label(
+                                    DashColors::WARNING,
+                                    "Balances were refreshed after this estimate, so it may be out of date.",
+                                );
+                                re_estimate = ui.button("Re-estimate").clicked();
+                            });
+                        }
+                        ui.separator();
+
+                        let formatted_total = format_token_amount(
+                            explanation.total_amount,
+                            token_info.token_configuration.conventions().decimals(),
+                        );
+                        ui.label(format!(
+                            "Total Estimated Rewards: {} tokens",
+                            formatted_total
+                        ));
+                        ui.separator();
+
+                        ClickableCollapsingHeader::new("Basic Explanation")
+                            .id_salt("basic_explanation_header")
+                            .show(ui, |ui| {
+                            let local_time = Local::now();
+                            let timezone = local_time.format("%Z").to_string();
 
-                            ClickableCollapsingHeader::new("Basic Explanation")
-                                .id_salt("basic_explanation_header")
-                                .show(ui, |ui| {
-                                let local_time = Local::now();
-                                let timezone = local_time.format("%Z").to_string();
+                            let short_explanation = explanation.short_explanation(
+                                token_info.token_configuration.conventions().decimals(),
+                                self.app_context.platform_version(),
+                                &timezone,
+                            );
 
-                                let short_explanation = explanation.short_explanation(
-                                    token_info.token_configuration.conventions().decimals(),
-                                    self.app_context.platform_version(),
-                                    &timezone,
-                                );
+                            ui.label(short_explanation);
+                        });
 
-                                ui.label(short_explanation);
-                            });
+                        ClickableCollapsingHeader::new("Detailed Explanation")
+                            .id_salt("detailed_explanation_header")
+                            .show(ui, |ui| {
+                            ui.label(explanation.detailed_explanation());
+                        });
 
-                            ClickableCollapsingHeader::new("Detailed Explanation")
-                                .id_salt("detailed_explanation_header")
+                        if !explanation.evaluation_steps.is_empty() {
+                            ClickableCollapsingHeader::new("Step-by-Step Breakdown")
+                                .id_salt("step_by_step_header")
                                 .show(ui, |ui| {
-                                ui.label(explanation.detailed_explanation());
+                                for (i, step) in explanation.evaluation_steps.iter().enumerate()
+                                {
+                                    ClickableCollapsingHeader::new(format!("Step {}", i + 1))
+                                        .id_salt(format!("step_{}_header", i))
+                                        .show(ui, |ui| {
+                                        if let Some(step_explanation) =
+                                            explanation.explanation_for_step(step.step_index)
+                                        {
+                                            ui.label(step_explanation);
+                                        }
+                                    });
+                                }
                             });
+                        }
 
-                            if !explanation.evaluation_steps.is_empty() {
-                                ClickableCollapsingHeader::new("Step-by-Step Breakdown")
-                                    .id_salt("step_by_step_header")
-                                    .show(ui, |ui| {
-                                    for (i, step) in explanation.evaluation_steps.iter().enumerate()
-                                    {
-                                        ClickableCollapsingHeader::new(format!("Step {}", i + 1))
-                                            .id_salt(format!("step_{}_header", i))
-                                            .show(ui, |ui| {
-                                            if let Some(step_explanation) =
-                                                explanation.explanation_for_step(step.step_index)
-                                            {
-                                                ui.label(step_explanation);
-                                            }
-                                        });
-                                    }
-                                });
+                        ui.separator();
+                        let markdown = || {
+                            reward_explanation_markdown(
+                                &token_info.token_name,
+                                cached,
+                                token_info.token_configuration.conventions().decimals(),
+                                self.app_context.platform_version(),
+                                &Local::now().format("%Z").to_string(),
+                            )
+                        };
+                        ui.horizontal(|ui| {
+                            if ui.button("Copy explanation").clicked() {
+                                ui.ctx().copy_text(markdown());
+                            }
+                            if ui.button("Export").clicked() {
+                                export_markdown = Some(markdown());
                             }
-
-                            ui.separator();
                             if ui.button("Close").clicked() {
                                 self.show_explanation_popup = None;
                             }
                         });
                     });
+                });
 
-                // If the window was closed via the X button
-                if !is_open {
-                    self.show_explanation_popup = None;
-                }
-            } else {
-                // No explanation available yet, close popup
+            if let Some(markdown) = export_markdown {
+                self.export_reward_explanation(&token_info.token_name, markdown);
+            }
+
+            if re_estimate {
+                action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(
+                    TokenTask::EstimatePerpetualTokenRewardsWithExplanation {
+                        identity_id: identity_token_id.identity_id,
+                        token_id: identity_token_id.token_id,
+                    },
+                )));
+                self.refreshing_status = RefreshingStatus::Refreshing(Utc::now().timestamp() as u64);
+            }
+
+            // If the window was closed via the X button
+            if !is_open {
                 self.show_explanation_popup = None;
             }
         }
@@ -584,6 +828,206 @@ impl TokensScreen {
         action
     }
 
+    /// Asks for a file name and writes a reward explanation to it as Markdown.
+    fn export_reward_explanation(&mut self, token_name: &str, markdown: String) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!("{}-reward-explanation.md", token_name))
+            .add_filter("Markdown", &["md", "txt"])
+            .save_file()
+        else {
+            return;
+        };
+
+        self.backend_message = Some(match std::fs::write(&path, markdown) {
+            Ok(()) => (
+                format!("Exported reward explanation to {}", path.display()),
+                MessageType::Success,
+                Utc::now(),
+            ),
+            Err(e) => (
+                format!("Failed to export reward explanation: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ),
+        });
+    }
+
+    /// Asks for a file name and writes the holders of a token to it as CSV.
+    fn export_token_holders_csv(
+        &mut self,
+        token_info: &TokenInfoWithDataContract,
+        detail_list: &[IdentityTokenMaybeBalanceWithActions],
+    ) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!("{}-holders.csv", token_info.token_name))
+            .add_filter("CSV", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+
+        let decimals = token_info.token_configuration.conventions().decimals();
+        let csv = token_holders_csv(detail_list, decimals);
+        self.backend_message = Some(match std::fs::write(&path, csv) {
+            Ok(()) => (
+                format!(
+                    "Exported {} holders to {}",
+                    detail_list.len(),
+                    path.display()
+                ),
+                MessageType::Success,
+                Utc::now(),
+            ),
+            Err(e) => (
+                format!("Failed to export holders: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ),
+        });
+    }
+
+    /// Adds the column widths menu of the token holders table variant shown
+    fn holder_column_widths_menu(&mut self, response: &Response, shows_estimation_column: bool) {
+        let column_widths = if shows_estimation_column {
+            &mut self.holder_rewards_column_widths
+        } else {
+            &mut self.holder_column_widths
+        };
+        column_widths.header_context_menu(response, &self.app_context);
+    }
+
+    /// Renders a "Check all" button that fetches every unknown balance in a single batched task,
+    /// or the progress of that batch while it runs.
+    fn render_check_all_balances(
+        &mut self,
+        ui: &mut Ui,
+        detail_list: &[IdentityTokenMaybeBalanceWithActions],
+    ) -> AppAction {
+        let mut action = AppAction::None;
+
+        if let Some(batch) = &self.balance_check_batch {
+            let fetched = batch
+                .iter()
+                .filter(|identity_token_id| self.my_tokens.contains_key(*identity_token_id))
+                .count();
+            ui.horizontal(|ui| {
+                ui.add(egui::Spinner::new());
+                ui.label(format!(
+                    "Checking balances: {} of {} fetched",
+                    fetched,
+                    batch.len()
+                ));
+            });
+            ui.add_space(5.0);
+            return action;
+        }
+
+        let unknown: Vec<IdentityTokenIdentifier> = detail_list
+            .iter()
+            .filter(|itb| itb.balance.is_none())
+            .map(|itb| IdentityTokenIdentifier {
+                identity_id: itb.identity_id,
+                token_id: itb.token_id,
+            })
+            .collect();
+        if unknown.is_empty() {
+            return action;
+        }
+
+        if ui
+            .button(format!("Check all ({})", unknown.len()))
+            .on_hover_text("Fetch the balances of all identities whose balance is unknown")
+            .clicked()
+        {
+            action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(
+                TokenTask::QueryIdentityTokenBalances(unknown.clone()),
+            )));
+            self.balance_check_batch = Some(unknown);
+        }
+        ui.add_space(5.0);
+
+        action
+    }
+
+    /// Renders an "Estimate all" button that estimates unclaimed rewards for every identity
+    /// eligible to claim, and the total once every estimate is known.
+    fn render_estimate_all_rewards(
+        &mut self,
+        ui: &mut Ui,
+        detail_list: &[IdentityTokenMaybeBalanceWithActions],
+        decimals: u8,
+    ) -> AppAction {
+        let mut action = AppAction::None;
+
+        // Estimates are stored on the balance record, so rows with unknown balances are skipped
+        let eligible: Vec<&IdentityTokenMaybeBalanceWithActions> = detail_list
+            .iter()
+            .filter(|itb| itb.available_actions.can_estimate && itb.balance.is_some())
+            .collect();
+        if eligible.is_empty() {
+            return action;
+        }
+
+        ui.horizontal(|ui| {
+            if let Some(batch) = &self.reward_estimation_batch {
+                ui.add(egui::Spinner::new());
+                ui.label(format!(
+                    "Estimating rewards: {} of {} remaining",
+                    batch.len(),
+                    eligible.len()
+                ));
+                return;
+            }
+
+            if ui
+                .button("Estimate all")
+                .on_hover_text("Estimate unclaimed rewards for every eligible identity")
+                .clicked()
+            {
+                let ids: Vec<IdentityTokenIdentifier> = eligible
+                    .iter()
+                    .map(|itb| IdentityTokenIdentifier {
+                        identity_id: itb.identity_id,
+                        token_id: itb.token_id,
+                    })
+                    .collect();
+                let tasks = ids
+                    .iter()
+                    .map(|id| {
+                        BackendTask::TokenTask(Box::new(
+                            TokenTask::EstimatePerpetualTokenRewardsWithExplanation {
+                                identity_id: id.identity_id,
+                                token_id: id.token_id,
+                            },
+                        ))
+                    })
+                    .collect();
+                // One at a time, so a token with many holders doesn't flood the node
+                action = AppAction::BackendTasks(tasks, BackendTasksExecutionMode::Sequential);
+                self.reward_estimation_batch = Some(ids);
+                self.refreshing_status =
+                    RefreshingStatus::Refreshing(Utc::now().timestamp() as u64);
+            }
+
+            let estimates: Option<Vec<TokenAmount>> = eligible
+                .iter()
+                .map(|itb| itb.estimated_unclaimed_rewards)
+                .collect();
+            if let Some(estimates) = estimates {
+                let total = estimates.iter().fold(0, |total: TokenAmount, amount| {
+                    total.saturating_add(*amount)
+                });
+                ui.label(format!(
+                    "Total estimated rewards: {}",
+                    self.display_token_amount(total, decimals)
+                ));
+            }
+        });
+        ui.add_space(5.0);
+
+        action
+    }
+
     fn render_actions(
         &mut self,
         itb: &IdentityTokenMaybeBalanceWithActions,
@@ -593,6 +1037,7 @@ impl TokensScreen {
     ) -> AppAction {
         let mut pos = 0;
         let mut action = AppAction::None;
+        let is_paused = self.is_token_paused(&itb.token_id);
 
         ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
             ui.add_space(-9.0);
@@ -600,7 +1045,13 @@ impl TokensScreen {
             ui.spacing_mut().item_spacing.x = 5.0;
 
             if range.contains(&pos) {
-                if itb.available_actions.can_transfer {
+                if is_paused {
+                    ui.add_enabled(
+                        false,
+                        egui::Button::new(RichText::new("Transfer").color(Color32::GRAY)),
+                    )
+                    .on_disabled_hover_text(TOKEN_PAUSED_HOVER);
+                } else if itb.available_actions.can_transfer {
                     if let Some(balance) = itb.balance {
                         // Transfer
                         if ui.button("Transfer").clicked() {
@@ -618,7 +1069,7 @@ impl TokensScreen {
                         false,
                         egui::Button::new(RichText::new("Transfer").color(Color32::GRAY)),
                     )
-                    .on_hover_text("Transfer not available");
+                    .on_disabled_hover_text("Transferring requires a positive token balance");
                 }
             }
 
@@ -756,7 +1207,10 @@ impl TokensScreen {
             }
             if itb.available_actions.can_do_emergency_action {
                 if range.contains(&pos) {
-                    if ui.button("Pause").clicked() {
+                    if is_paused {
+                        ui.add_enabled(false, egui::Button::new("Pause"))
+                            .on_disabled_hover_text("Token is already paused");
+                    } else if ui.button("Pause").clicked() {
                         match IdentityTokenInfo::try_from_identity_token_maybe_balance_with_actions_with_lookup(itb, &self.app_context) {
                             Ok(info) => {
                                 action = AppAction::AddScreen(
@@ -778,7 +1232,11 @@ impl TokensScreen {
                 }
 
                 if range.contains(&pos) {
-                    if ui.button("Resume").clicked() {
+                    let known_active = self.token_paused_statuses.get(&itb.token_id) == Some(&false);
+                    if known_active {
+                        ui.add_enabled(false, egui::Button::new("Resume"))
+                            .on_disabled_hover_text("Token is not paused");
+                    } else if ui.button("Resume").clicked() {
                         match IdentityTokenInfo::try_from_identity_token_maybe_balance_with_actions_with_lookup(itb, &self.app_context) {
                             Ok(info) => {
                                 action = AppAction::AddScreen(
@@ -839,7 +1297,13 @@ impl TokensScreen {
                         .copied()
                         .unwrap_or(false);
 
-                    if is_loading {
+                    if is_paused {
+                        ui.add_enabled(
+                            false,
+                            egui::Button::new(RichText::new("Purchase").color(Color32::GRAY)),
+                        )
+                        .on_disabled_hover_text(TOKEN_PAUSED_HOVER);
+                    } else if is_loading {
                         // Show loading spinner
                         ui.add(egui::Spinner::new());
                     } else if has_pricing_data {
@@ -899,6 +1363,23 @@ impl TokensScreen {
                                     "No credits available for purchase".to_string()
                                 }
                             });
+
+                            // Offer to top up the identity so it can afford at least one token
+                            if let (Some(Some(pricing)), Ok(Some(identity))) = (
+                                self.token_pricing_data.get(&itb.token_id),
+                                self.app_context.get_identity_by_id(&itb.identity_id),
+                            ) {
+                                if ui.button("Top up credits").clicked() {
+                                    action = AppAction::AddScreen(Screen::TopUpIdentityScreen(
+                                        TopUpIdentityScreen::new_for_shortfall(
+                                            identity,
+                                            &self.app_context,
+                                            get_min_token_price(pricing),
+                                        ),
+                                    ));
+                                    ui.close_menu();
+                                }
+                            }
                         }
                     }
                 }
@@ -923,6 +1404,40 @@ impl TokensScreen {
                         }
                     };
 
+                    ui.close_menu();
+                }
+            }
+            if itb.available_actions.can_set_price {
+                pos += 1;
+            }
+            if (itb.available_actions.can_transfer || itb.available_actions.can_mint)
+                && range.contains(&pos)
+            {
+                // Distribute to many recipients at once
+                if is_paused {
+                    ui.add_enabled(false, egui::Button::new("Distribute"))
+                        .on_disabled_hover_text(TOKEN_PAUSED_HOVER);
+                } else if ui
+                    .button("Distribute")
+                    .on_hover_text("Transfer or mint the token to a list of recipients")
+                    .clicked()
+                {
+                    match IdentityTokenInfo::try_from_identity_token_maybe_balance_with_actions_with_lookup(itb, &self.app_context) {
+                        Ok(info) => {
+                            action = AppAction::AddScreen(
+                                Screen::DistributeTokensScreen(
+                                    DistributeTokensScreen::new(
+                                        info,
+                                        &self.app_context,
+                                    ),
+                                ),
+                            );
+                        }
+                        Err(e) => {
+                            self.set_error_message(Some(e));
+                        }
+                    };
+
                     ui.close_menu();
                 }
             }
@@ -933,41 +1448,227 @@ impl TokensScreen {
         action
     }
 
+    /// Totals of the tracked tokens, computed from what is stored so they follow the balances
+    /// as they are fetched
+    fn render_token_summary(&self, ui: &mut Ui) {
+        let summary = token_summary(
+            self.all_known_tokens.len(),
+            self.my_tokens.values().map(|itb| {
+                let claimable =
+                    itb.available_actions.can_claim && itb.estimated_unclaimed_rewards != Some(0);
+                (itb.token_id, itb.identity_id, claimable)
+            }),
+        );
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!(
+                    "{} tokens tracked  |  {} identities holding them",
+                    summary.tracked_tokens, summary.identities
+                ))
+                .color(DashColors::text_secondary(dark_mode)),
+            );
+            if summary.claimable_tokens > 0 {
+                ui.label(
+                    RichText::new(format!(
+                        "|  {} with rewards to claim",
+                        summary.claimable_tokens
+                    ))
+                    .color(DashColors::text_primary(dark_mode)),
+                )
+                .on_hover_text(
+                    "Tokens an identity can claim rewards of, unless their rewards were estimated to be none",
+                );
+            }
+        });
+        ui.add_space(5.0);
+    }
+
     /// Renders the top-level token list (one row per unique token).
     /// When the user clicks on a token, we set `selected_token_id`.
     fn render_token_list(&mut self, ui: &mut Ui) -> Result<AppAction, String> {
         let mut action = AppAction::None;
+        let mut pin_toggled: Option<Identifier> = None;
+        let mut pinned_token_opened: Option<Identifier> = None;
         // Space allocation for UI elements is handled by the layout system
 
+        self.render_token_summary(ui);
+
+        ui.horizontal(|ui| {
+            if ui
+                .button("Add by Contract ID")
+                .on_hover_text("Track a token by entering the ID of its data contract")
+                .clicked()
+            {
+                self.open_add_token_by_contract_id();
+            }
+            if self.all_known_tokens.len() >= 2
+                && ui
+                    .button("Compare Tokens")
+                    .on_hover_text("Compare the configurations of two tokens side by side")
+                    .clicked()
+            {
+                self.open_token_comparison();
+            }
+            let selected_count = self.tokens_selected_for_removal.len();
+            if selected_count > 0
+                && ui
+                    .button(format!("Remove Selected ({})", selected_count))
+                    .on_hover_text("Remove all checked tokens from DET")
+                    .clicked()
+            {
+                self.confirm_remove_selected_tokens_popup = true;
+            }
+            self.render_claim_all_button(ui);
+        });
+
+        // Pinned tokens first, otherwise keep the known-token order
+        let mut tokens: Vec<&TokenInfoWithDataContract> = self.all_known_tokens.values().collect();
+        tokens.sort_by_key(|t| !self.pinned_tokens.contains(&t.token_id));
+
+        // Quick access to pinned tokens
+        if !self.pinned_tokens.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(RichText::new("Pinned:").strong());
+                for token_info in tokens
+                    .iter()
+                    .filter(|t| self.pinned_tokens.contains(&t.token_id))
+                {
+                    if ui
+                        .button(format!("★ {}", token_info.token_name))
+                        .on_hover_text("Open token")
+                        .clicked()
+                    {
+                        pinned_token_opened = Some(token_info.token_id);
+                    }
+                }
+            });
+            ui.add_space(5.0);
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Filter by name or ID:");
+            if ui
+                .text_edit_singleline(&mut self.token_list_filter)
+                .changed()
+            {
+                self.token_list_page = 0;
+            }
+        });
+        ui.add_space(5.0);
+        let tokens: Vec<&TokenInfoWithDataContract> = tokens
+            .into_iter()
+            .filter(|t| matches_token_filter(&t.token_name, &t.token_id, &self.token_list_filter))
+            .collect();
+        if tokens.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                render_no_matching_tokens(ui);
+            });
+            return Ok(action);
+        }
+
+        // Only the current page of the (sorted) list is rendered
+        let (page, page_count) = clamp_page(
+            tokens.len(),
+            self.token_list_page,
+            self.token_list_page_size,
+        );
+        self.token_list_page = page;
+        if tokens.len() > TOKEN_LIST_PAGE_SIZES[0] {
+            ui.horizontal(|ui| {
+                if ui.add_enabled(page > 0, Button::new("Previous")).clicked() {
+                    self.token_list_page -= 1;
+                }
+                ui.label(format!(
+                    "Page {} of {} ({} tokens)",
+                    page + 1,
+                    page_count,
+                    tokens.len()
+                ));
+                if ui
+                    .add_enabled(page + 1 < page_count, Button::new("Next"))
+                    .clicked()
+                {
+                    self.token_list_page += 1;
+                }
+
+                ui.add_space(10.0);
+                ui.label("Per page:");
+                ComboBox::from_id_salt("token_list_page_size")
+                    .selected_text(self.token_list_page_size.to_string())
+                    .show_ui(ui, |ui| {
+                        for page_size in TOKEN_LIST_PAGE_SIZES {
+                            if ui
+                                .selectable_value(
+                                    &mut self.token_list_page_size,
+                                    page_size,
+                                    page_size.to_string(),
+                                )
+                                .changed()
+                            {
+                                self.token_list_page = 0;
+                            }
+                        }
+                    });
+            });
+            ui.add_space(5.0);
+        }
+        let page_start = self.token_list_page * self.token_list_page_size;
+        let page_end = (page_start + self.token_list_page_size).min(tokens.len());
+        let tokens = &tokens[page_start..page_end];
+
         // A simple table with columns: [Token Name | Token ID | Total Balance]
         egui::ScrollArea::both().show(ui, |ui| {
             ui.set_min_width(ui.available_width());
             ui.set_max_width(ui.available_width());
 
+            let ctx = ui.ctx().clone();
+            let id_salt = self.token_list_column_widths.id_salt();
+            let widths = self
+                .token_list_column_widths
+                .initial_widths(&[150.0, 200.0, 80.0, 80.0]);
             TableBuilder::new(ui)
+                .id_salt(id_salt)
                 .striped(false)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(Align::Center))
-                .column(Column::initial(150.0).resizable(true)) // Token Name
-                .column(Column::initial(200.0).resizable(true)) // Token ID
-                .column(Column::initial(80.0).resizable(true)) // Description
-                .column(Column::initial(80.0).resizable(true)) // Actions
+                .column(Column::exact(24.0)) // Selected
+                .column(Column::initial(widths[0]).resizable(true)) // Token Name
+                .column(Column::initial(widths[1]).resizable(true)) // Token ID
+                .column(Column::initial(widths[2]).resizable(true)) // Description
+                .column(Column::initial(widths[3]).resizable(true)) // Actions
                 .header(30.0, |mut header| {
+                    header.col(|_ui| {});
                     header.col(|ui| {
-                        ui.label("Token Name");
+                        let response = ui.label("Token Name");
+                        self.token_list_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                     header.col(|ui| {
-                        ui.label("Token ID");
+                        let response = ui.label("Token ID");
+                        self.token_list_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                     header.col(|ui| {
-                        ui.label("Description");
+                        let response = ui.label("Description");
+                        self.token_list_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                     header.col(|ui| {
-                        ui.label("Actions");
+                        let response = ui.label("Actions");
+                        self.token_list_column_widths
+                            .header_context_menu(&response, &self.app_context);
                     });
                 })
                 .body(|mut body| {
-                    for token_info in self.all_known_tokens.values() {
+                    // The selection column has a fixed width, so it isn't saved
+                    self.token_list_column_widths.track(
+                        &ctx,
+                        &self.app_context,
+                        &body.widths()[1..],
+                    );
+                    for token_info in tokens {
                         let TokenInfoWithDataContract {
                             token_id,
                             token_name,
@@ -975,12 +1676,38 @@ impl TokensScreen {
                             ..
                         } = token_info;
                         body.row(30.0, |mut row| {
+                            row.col(|ui| {
+                                let mut selected =
+                                    self.tokens_selected_for_removal.contains(token_id);
+                                if ui
+                                    .checkbox(&mut selected, "")
+                                    .on_hover_text("Select to remove together with other tokens")
+                                    .changed()
+                                {
+                                    if selected {
+                                        self.tokens_selected_for_removal.insert(*token_id);
+                                    } else {
+                                        self.tokens_selected_for_removal.remove(token_id);
+                                    }
+                                }
+                            });
                             row.col(|ui| {
                                 ui.with_layout(egui::Layout::top_down(egui::Align::LEFT), |ui| {
                                     ui.add_space(-1.0);
                                     // By making the label into a button or using `ui.selectable_label`,
                                     // we can respond to clicks.
-                                    if ui.button(token_name).clicked() {
+                                    let name_clicked = ui
+                                        .horizontal(|ui| {
+                                            let clicked = ui.button(token_name).clicked();
+                                            if self.token_paused_statuses.get(token_id)
+                                                == Some(&true)
+                                            {
+                                                render_paused_badge(ui);
+                                            }
+                                            clicked
+                                        })
+                                        .inner;
+                                    if name_clicked {
                                         self.selected_token = Some(*token_id);
                                         // Check if we need to fetch pricing data for this token
                                         if !self.token_pricing_data.contains_key(token_id) {
@@ -997,7 +1724,7 @@ impl TokensScreen {
                                 });
                             });
                             row.col(|ui| {
-                                ui.label(token_id.to_string(Encoding::Base58));
+                                render_identifier_cell(ui, token_id, None);
                             });
                             row.col(|ui| {
                                 ui.label(description.as_ref().unwrap_or(&"None".to_string()));
@@ -1012,6 +1739,17 @@ impl TokensScreen {
                                     ui.add_space(-1.0);
 
                                     ui.horizontal(|ui| {
+                                        // Pin toggle
+                                        let is_pinned = self.pinned_tokens.contains(token_id);
+                                        let (star, hover) = if is_pinned {
+                                            ("★", "Unpin token")
+                                        } else {
+                                            ("☆", "Pin token to the top")
+                                        };
+                                        if ui.button(star).on_hover_text(hover).clicked() {
+                                            pin_toggled = Some(*token_id);
+                                        }
+
                                         // Remove button
                                         if ui
                                             .button("X")
@@ -1028,6 +1766,228 @@ impl TokensScreen {
                     }
                 });
         });
+
+        if let Some(token_id) = pinned_token_opened {
+            self.selected_token = Some(token_id);
+            if !self.token_pricing_data.contains_key(&token_id) {
+                self.pricing_loading_state.insert(token_id, true);
+                action = AppAction::BackendTask(BackendTask::TokenTask(Box::new(
+                    TokenTask::QueryTokenPricing(token_id),
+                )));
+            }
+        }
+
+        if let Some(token_id) = pin_toggled {
+            self.toggle_token_pinned(token_id);
+        }
+
         Ok(action)
     }
 }
+
+fn render_no_matching_tokens(ui: &mut Ui) {
+    ui.label(
+        RichText::new("No matching tokens found.")
+            .heading()
+            .strong()
+            .color(Color32::GRAY),
+    );
+}
+
+/// Whether a token's name or base58 ID contains the filter, ignoring case
+fn matches_token_filter(token_name: &str, token_id: &Identifier, filter: &str) -> bool {
+    let filter = filter.trim().to_lowercase();
+    filter.is_empty()
+        || token_name.to_lowercase().contains(&filter)
+        || token_id
+            .to_string(Encoding::Base58)
+            .to_lowercase()
+            .contains(&filter)
+}
+
+/// Page sizes offered for the token list
+pub(super) const TOKEN_LIST_PAGE_SIZES: [usize; 3] = [25, 50, 100];
+
+/// Clamps a page index to the pages of a list of `len` items, returning it with the number
+/// of pages. An empty list still has one (empty) page.
+fn clamp_page(len: usize, page: usize, page_size: usize) -> (usize, usize) {
+    let page_count = len.div_ceil(page_size).max(1);
+    (page.min(page_count - 1), page_count)
+}
+
+/// Totals shown above the token list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TokenSummary {
+    tracked_tokens: usize,
+    /// Identities with a balance of at least one tracked token
+    identities: usize,
+    /// Tokens some identity can claim rewards of, unless they were estimated to be none
+    claimable_tokens: usize,
+}
+
+/// Sums up `tracked_tokens` tokens and the balances held of them, each given as its token,
+/// identity and whether it has rewards to claim
+fn token_summary(
+    tracked_tokens: usize,
+    balances: impl Iterator<Item = (Identifier, Identifier, bool)>,
+) -> TokenSummary {
+    let mut identities = HashSet::new();
+    let mut claimable_tokens = HashSet::new();
+    for (token_id, identity_id, claimable) in balances {
+        identities.insert(identity_id);
+        if claimable {
+            claimable_tokens.insert(token_id);
+        }
+    }
+    TokenSummary {
+        tracked_tokens,
+        identities: identities.len(),
+        claimable_tokens: claimable_tokens.len(),
+    }
+}
+
+/// Placeholder for values that haven't been fetched, so they aren't mistaken for zero.
+const UNKNOWN_CSV_VALUE: &str = "unknown";
+
+/// Builds the holders CSV for a token: one row per identity in the detail view.
+///
+/// Frozen status isn't fetched by the tool, so that column is always "unknown".
+fn token_holders_csv(rows: &[IdentityTokenMaybeBalanceWithActions], decimals: u8) -> String {
+    let mut csv = String::from("identity_id,alias,balance,raw_balance,frozen,estimated_rewards\n");
+    for row in rows {
+        let (balance, raw_balance) = match row.balance {
+            Some(balance) => (format_token_amount(balance, decimals), balance.to_string()),
+            None => (UNKNOWN_CSV_VALUE.to_string(), UNKNOWN_CSV_VALUE.to_string()),
+        };
+        let estimated_rewards = row
+            .estimated_unclaimed_rewards
+            .map(|rewards| format_token_amount(rewards, decimals))
+            .unwrap_or_else(|| UNKNOWN_CSV_VALUE.to_string());
+        let fields = [
+            row.identity_id.to_string(Encoding::Base58),
+            row.identity_alias.clone().unwrap_or_default(),
+            balance,
+            raw_balance,
+            UNKNOWN_CSV_VALUE.to_string(),
+            estimated_rewards,
+        ];
+        csv.push_str(
+            &fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Builds a Markdown record of a reward explanation with the same sections as the
+/// explanation popup, so it can be kept or shared.
+fn reward_explanation_markdown(
+    token_name: &str,
+    cached: &CachedRewardExplanation,
+    decimals: u8,
+    platform_version: &PlatformVersion,
+    timezone: &str,
+) -> String {
+    let explanation = &cached.explanation;
+    let mut markdown = format!("# Reward Estimation Details: {}\n\n", token_name);
+    markdown.push_str(&format!(
+        "- Calculated at: {}\n",
+        cached
+            .calculated_at
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S %Z")
+    ));
+    markdown.push_str(&format!(
+        "- Total estimated rewards: {} tokens ({} raw)\n",
+        format_token_amount(explanation.total_amount, decimals),
+        explanation.total_amount
+    ));
+    markdown.push_str(&format!("- Token decimals: {}\n", decimals));
+
+    markdown.push_str("\n## Basic Explanation\n\n");
+    markdown.push_str(&explanation.short_explanation(decimals, platform_version, timezone));
+    markdown.push_str("\n\n## Detailed Explanation\n\n");
+    markdown.push_str(&explanation.detailed_explanation());
+    markdown.push('\n');
+
+    if !explanation.evaluation_steps.is_empty() {
+        markdown.push_str("\n## Step-by-Step Breakdown\n");
+        for (i, step) in explanation.evaluation_steps.iter().enumerate() {
+            markdown.push_str(&format!("\n### Step {}\n\n", i + 1));
+            if let Some(step_explanation) = explanation.explanation_for_step(step.step_index) {
+                markdown.push_str(&step_explanation);
+                markdown.push('\n');
+            }
+        }
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_token_filter() {
+        let token_id = Identifier::new([1; 32]);
+        let id_prefix: String = token_id
+            .to_string(Encoding::Base58)
+            .chars()
+            .take(6)
+            .collect();
+
+        assert!(matches_token_filter("Gold Coin", &token_id, ""));
+        assert!(matches_token_filter("Gold Coin", &token_id, "gold"));
+        assert!(matches_token_filter("Gold Coin", &token_id, " COIN "));
+        assert!(matches_token_filter(
+            "Gold Coin",
+            &token_id,
+            &id_prefix.to_uppercase()
+        ));
+        assert!(!matches_token_filter("Gold Coin", &token_id, "silver"));
+    }
+
+    #[test]
+    fn test_clamp_page() {
+        assert_eq!(clamp_page(0, 0, 25), (0, 1));
+        assert_eq!(clamp_page(25, 0, 25), (0, 1));
+        assert_eq!(clamp_page(26, 1, 25), (1, 2));
+        // A page past the end, e.g. after removing tokens, moves to the last page
+        assert_eq!(clamp_page(60, 5, 25), (2, 3));
+    }
+
+    #[test]
+    fn test_token_summary() {
+        let (token_a, token_b) = (Identifier::new([1; 32]), Identifier::new([2; 32]));
+        let (alice, bob) = (Identifier::new([3; 32]), Identifier::new([4; 32]));
+        let summary = token_summary(
+            3,
+            [
+                (token_a, alice, true),
+                (token_a, bob, true),
+                (token_b, alice, false),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(
+            summary,
+            TokenSummary {
+                tracked_tokens: 3,
+                identities: 2,
+                claimable_tokens: 1,
+            }
+        );
+        assert_eq!(
+            token_summary(0, std::iter::empty()),
+            TokenSummary {
+                tracked_tokens: 0,
+                identities: 0,
+                claimable_tokens: 0,
+            }
+        );
+    }
+}
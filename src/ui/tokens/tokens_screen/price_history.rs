@@ -0,0 +1,222 @@
+use super::TokensScreen;
+use super::my_tokens::get_min_token_price;
+use crate::ui::MessageType;
+use crate::ui::theme::DashColors;
+use chrono::{DateTime, Local, Utc};
+use dash_sdk::dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Align2, FontId, Pos2, Rect, RichText, Sense, Stroke, Ui, vec2};
+
+/// Height of the price history chart
+const CHART_HEIGHT: f32 = 160.0;
+
+fn format_time_ms(time_ms: u64) -> String {
+    DateTime::from_timestamp_millis(time_ms as i64)
+        .map(|time| {
+            time.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+fn describe_schedule(schedule: &TokenPricingSchedule) -> String {
+    match schedule {
+        TokenPricingSchedule::SinglePrice(price) => format!("{} credits per token", price),
+        TokenPricingSchedule::SetPrices(tiers) => tiers
+            .iter()
+            .map(|(amount, price)| format!("from {} tokens: {} credits each", amount, price))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Points of a step line through `(time, price)` samples sorted by time, scaled to 0..=1 on
+/// both axes with the lowest price at 0. Each price holds until the next sample, and a single
+/// sample spans the whole width.
+fn step_chart_points(samples: &[(u64, u64)]) -> Vec<(f32, f32)> {
+    let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+        return vec![];
+    };
+    let min_price = samples.iter().map(|(_, price)| *price).min().unwrap_or(0);
+    let max_price = samples.iter().map(|(_, price)| *price).max().unwrap_or(0);
+    let y = |price: u64| {
+        if max_price == min_price {
+            0.5
+        } else {
+            (price - min_price) as f32 / (max_price - min_price) as f32
+        }
+    };
+    let time_span = last.0.saturating_sub(first.0);
+    if time_span == 0 {
+        let price = y(last.1);
+        return vec![(0.0, price), (1.0, price)];
+    }
+    let x = |time: u64| time.saturating_sub(first.0) as f32 / time_span as f32;
+
+    let mut points = vec![(0.0, y(first.1))];
+    for window in samples.windows(2) {
+        let time = x(window[1].0);
+        points.push((time, y(window[0].1)));
+        points.push((time, y(window[1].1)));
+    }
+    points
+}
+
+impl TokensScreen {
+    /// Renders a step chart of the lowest tier price of a token over the pricing schedules
+    /// fetched for it, with a way to clear them.
+    pub(super) fn render_price_history(&mut self, ui: &mut Ui, token_id: Identifier) {
+        if self
+            .price_history
+            .as_ref()
+            .is_none_or(|(id, _)| *id != token_id)
+        {
+            let history = self
+                .app_context
+                .db
+                .get_token_price_history(&token_id, &self.app_context)
+                .unwrap_or_default();
+            self.price_history = Some((token_id, history));
+        }
+        let Some((_, history)) = self.price_history.as_ref() else {
+            return;
+        };
+
+        ui.label(
+            RichText::new(
+                "Lowest tier price per token, recorded each time the pricing of this token is fetched.",
+            )
+            .small()
+            .color(egui::Color32::GRAY),
+        );
+        if history.is_empty() {
+            ui.label("No prices recorded yet.");
+            return;
+        }
+
+        let samples: Vec<(u64, u64)> = history
+            .iter()
+            .map(|entry| (entry.time_ms, get_min_token_price(&entry.schedule)))
+            .collect();
+        let min_price = samples.iter().map(|(_, price)| *price).min().unwrap_or(0);
+        let max_price = samples.iter().map(|(_, price)| *price).max().unwrap_or(0);
+
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let text_color = DashColors::text_secondary(dark_mode);
+        let (response, painter) = ui.allocate_painter(
+            vec2(ui.available_width().min(600.0), CHART_HEIGHT),
+            Sense::hover(),
+        );
+        let frame = response.rect;
+        painter.rect_stroke(
+            frame,
+            4.0,
+            Stroke::new(1.0, DashColors::border(dark_mode)),
+            egui::StrokeKind::Inside,
+        );
+        // Leave room for the price labels on the left and the dates below
+        let plot = Rect::from_min_max(frame.min + vec2(80.0, 10.0), frame.max - vec2(10.0, 25.0));
+        let to_screen = |(x, y): (f32, f32)| {
+            Pos2::new(
+                plot.left() + x * plot.width(),
+                plot.bottom() - y * plot.height(),
+            )
+        };
+
+        let points: Vec<Pos2> = step_chart_points(&samples)
+            .into_iter()
+            .map(to_screen)
+            .collect();
+        painter.add(egui::Shape::line(
+            points,
+            Stroke::new(2.0, DashColors::DASH_BLUE),
+        ));
+
+        let font = FontId::proportional(11.0);
+        painter.text(
+            Pos2::new(frame.left() + 5.0, plot.top()),
+            Align2::LEFT_TOP,
+            format!("{} cr", max_price),
+            font.clone(),
+            text_color,
+        );
+        painter.text(
+            Pos2::new(frame.left() + 5.0, plot.bottom()),
+            Align2::LEFT_BOTTOM,
+            format!("{} cr", min_price),
+            font.clone(),
+            text_color,
+        );
+        painter.text(
+            Pos2::new(plot.left(), frame.bottom() - 5.0),
+            Align2::LEFT_BOTTOM,
+            format_time_ms(samples[0].0),
+            font.clone(),
+            text_color,
+        );
+        painter.text(
+            Pos2::new(plot.right(), frame.bottom() - 5.0),
+            Align2::RIGHT_BOTTOM,
+            format_time_ms(samples[samples.len() - 1].0),
+            font,
+            text_color,
+        );
+
+        // Describe the schedule in effect at the hovered time
+        let hovered = response.hover_pos().and_then(|pos| {
+            let first_time = samples[0].0;
+            let time_span = samples[samples.len() - 1].0.saturating_sub(first_time);
+            let fraction = ((pos.x - plot.left()) / plot.width()).clamp(0.0, 1.0);
+            let hovered_time = first_time + (fraction as f64 * time_span as f64) as u64;
+            history
+                .iter()
+                .take_while(|entry| entry.time_ms <= hovered_time)
+                .last()
+        });
+        if let Some(entry) = hovered {
+            response.on_hover_text_at_pointer(format!(
+                "Since {}\n{}",
+                format_time_ms(entry.time_ms),
+                describe_schedule(&entry.schedule)
+            ));
+        }
+
+        ui.add_space(5.0);
+        if ui
+            .button("Clear Price History")
+            .on_hover_text("Delete the recorded prices of this token")
+            .clicked()
+        {
+            match self
+                .app_context
+                .db
+                .clear_token_price_history(&token_id, &self.app_context)
+            {
+                Ok(()) => self.price_history = Some((token_id, vec![])),
+                Err(e) => {
+                    self.backend_message = Some((
+                        format!("Error clearing price history: {}", e),
+                        MessageType::Error,
+                        Utc::now(),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_chart_points() {
+        assert!(step_chart_points(&[]).is_empty());
+        assert_eq!(step_chart_points(&[(5, 10)]), vec![(0.0, 0.5), (1.0, 0.5)]);
+        assert_eq!(
+            step_chart_points(&[(0, 10), (50, 30), (100, 20)]),
+            vec![(0.0, 0.0), (0.5, 0.0), (0.5, 1.0), (1.0, 1.0), (1.0, 0.5)]
+        );
+    }
+}
@@ -2,16 +2,21 @@ use crate::context::AppContext;
 use crate::model::qualified_contract::QualifiedContract;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::ui::tokens::tokens_screen::validate_perpetual_distribution_recipient;
+use chrono::{DateTime, Utc};
 use dash_sdk::dpp::balances::credits::TokenAmount;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::distribution_function::evaluate_interval::IntervalEvaluationExplanation;
 use dash_sdk::dpp::data_contract::associated_token::token_perpetual_distribution::methods::v0::TokenPerpetualDistributionV0Accessors;
 use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
-use dash_sdk::dpp::data_contract::{TokenConfiguration, TokenContractPosition};
+use dash_sdk::dpp::data_contract::{
+    GroupContractPosition, TokenConfiguration, TokenContractPosition,
+};
 use dash_sdk::dpp::group::action_taker::{ActionGoal, ActionTaker};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::{DataContract, Identifier};
 
 /// Token info
@@ -56,6 +61,22 @@ impl From<IdentityTokenBalance> for IdentityTokenIdentifier {
     }
 }
 
+/// A reward explanation and when it was calculated, so it can be recognized as outdated.
+#[derive(Clone, Debug)]
+pub struct CachedRewardExplanation {
+    pub explanation: IntervalEvaluationExplanation,
+    pub calculated_at: DateTime<Utc>,
+    /// Token balance of the identity when the rewards were estimated
+    pub balance: Option<TokenAmount>,
+}
+
+impl CachedRewardExplanation {
+    /// Whether balances were refreshed after the explanation was calculated
+    pub fn is_stale(&self, balances_refreshed_at: Option<DateTime<Utc>>) -> bool {
+        balances_refreshed_at.is_some_and(|refreshed_at| refreshed_at > self.calculated_at)
+    }
+}
+
 impl From<IdentityTokenMaybeBalanceWithActions> for IdentityTokenIdentifier {
     fn from(value: IdentityTokenMaybeBalanceWithActions) -> Self {
         let IdentityTokenMaybeBalanceWithActions {
@@ -422,6 +443,9 @@ impl IdentityTokenAvailableActions {
         if self.can_update_config {
             count += 1;
         }
+        if self.can_transfer || self.can_mint {
+            count += 1;
+        } // Distribute
 
         count
     }
@@ -560,3 +584,172 @@ pub fn get_available_token_actions_for_identity(
         can_update_config,
     }
 }
+
+/// Explains why each action left out by [`get_available_token_actions_for_identity`] is
+/// unavailable, as `(action, reason)` pairs in the order the actions are rendered.
+pub fn unavailable_token_action_reasons(
+    available_actions: &IdentityTokenAvailableActions,
+    token_configuration: &TokenConfiguration,
+    contract: &DataContract,
+) -> Vec<(&'static str, String)> {
+    let main_group = token_configuration.main_control_group();
+    let contract_owner_id = contract.owner_id();
+    let authorization_reason = |activity: &str, takers: &AuthorizedActionTakers| {
+        describe_missing_authorization(activity, takers, main_group, contract_owner_id)
+    };
+
+    let mut reasons = Vec::new();
+    if !available_actions.can_transfer {
+        reasons.push((
+            "Transfer",
+            "Transferring requires a positive token balance; this identity's balance is zero or \
+             not yet known."
+                .to_string(),
+        ));
+    }
+    if !available_actions.can_claim {
+        reasons.push((
+            "Claim",
+            "Claiming requires a pre-programmed distribution or a perpetual distribution that \
+             pays out to this identity; this token has neither."
+                .to_string(),
+        ));
+    }
+    if !available_actions.can_mint {
+        reasons.push((
+            "Mint",
+            authorization_reason(
+                "Minting",
+                token_configuration
+                    .manual_minting_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    if !available_actions.can_burn {
+        reasons.push((
+            "Burn",
+            authorization_reason(
+                "Burning",
+                token_configuration
+                    .manual_burning_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    if !available_actions.can_freeze {
+        reasons.push((
+            "Freeze",
+            authorization_reason(
+                "Freezing",
+                token_configuration
+                    .freeze_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    if !available_actions.can_destroy {
+        reasons.push((
+            "Destroy Frozen Identity Tokens",
+            authorization_reason(
+                "Destroying frozen funds",
+                token_configuration
+                    .destroy_frozen_funds_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    if !available_actions.can_unfreeze {
+        reasons.push((
+            "Unfreeze",
+            authorization_reason(
+                "Unfreezing",
+                token_configuration
+                    .unfreeze_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    if !available_actions.can_do_emergency_action {
+        reasons.push((
+            "Pause / Resume",
+            authorization_reason(
+                "Pausing and resuming",
+                token_configuration
+                    .emergency_action_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    if !available_actions.can_update_config {
+        reasons.push((
+            "Update Config",
+            "Updating the configuration requires being an admin of at least one change control \
+             rule; your identity is not an admin of any."
+                .to_string(),
+        ));
+    }
+    if !available_actions.can_maybe_purchase {
+        reasons.push((
+            "Purchase",
+            "Purchasing requires the token to have a direct purchase price set; it has none or \
+             its pricing has not been fetched yet."
+                .to_string(),
+        ));
+    }
+    if !available_actions.can_set_price {
+        reasons.push((
+            "Set Price",
+            authorization_reason(
+                "Setting the direct purchase price",
+                token_configuration
+                    .distribution_rules()
+                    .change_direct_purchase_pricing_rules()
+                    .authorized_to_make_change_action_takers(),
+            ),
+        ));
+    }
+    reasons
+}
+
+/// Describes who may perform an action, for an identity that was found not to be authorized.
+fn describe_missing_authorization(
+    activity: &str,
+    takers: &AuthorizedActionTakers,
+    main_group: Option<GroupContractPosition>,
+    contract_owner_id: Identifier,
+) -> String {
+    match takers {
+        AuthorizedActionTakers::NoOne => {
+            format!(
+                "{} is disabled for this token; no one is authorized.",
+                activity
+            )
+        }
+        AuthorizedActionTakers::ContractOwner => format!(
+            "{} is restricted to the contract owner ({}); your identity is not the owner.",
+            activity,
+            contract_owner_id.to_string(Encoding::Base58)
+        ),
+        AuthorizedActionTakers::Identity(identity_id) => format!(
+            "{} is restricted to identity {}; your identity is a different one.",
+            activity,
+            identity_id.to_string(Encoding::Base58)
+        ),
+        AuthorizedActionTakers::MainGroup => match main_group {
+            Some(position) => format!(
+                "{} requires being in the main control group (group {}); your identity is not a \
+                 member.",
+                activity, position
+            ),
+            None => format!(
+                "{} requires the main control group, but this token has none configured.",
+                activity
+            ),
+        },
+        AuthorizedActionTakers::Group(position) => format!(
+            "{} requires being in control group {}; your identity is not a member.",
+            activity, position
+        ),
+    }
+}
@@ -0,0 +1,350 @@
+use super::{TokenInfoWithDataContract, TokensScreen};
+use crate::ui::helpers::format_token_amount;
+use crate::ui::theme::DashColors;
+use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
+use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, ComboBox, RichText, Ui};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// State of the "Compare Tokens" window
+#[derive(Debug, Default)]
+pub(super) struct TokenComparison {
+    left: Option<Identifier>,
+    right: Option<Identifier>,
+    only_differences: bool,
+}
+
+/// A configuration field of the two compared tokens, `None` where a token doesn't have it
+#[derive(Debug, PartialEq)]
+struct ComparisonRow {
+    field: String,
+    left: Option<String>,
+    right: Option<String>,
+}
+
+impl ComparisonRow {
+    fn differs(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+/// The fields most worth comparing, formatted for reading
+fn summary_rows(
+    left: &TokenInfoWithDataContract,
+    right: &TokenInfoWithDataContract,
+) -> Vec<ComparisonRow> {
+    type Field = fn(&TokenInfoWithDataContract) -> String;
+    let fields: [(&str, Field); 7] = [
+        ("Base supply", |token| {
+            let config = &token.token_configuration;
+            format_token_amount(config.base_supply(), config.conventions().decimals())
+        }),
+        ("Max supply", |token| {
+            let config = &token.token_configuration;
+            config
+                .max_supply()
+                .map(|max_supply| format_token_amount(max_supply, config.conventions().decimals()))
+                .unwrap_or_else(|| "Unlimited".to_string())
+        }),
+        ("Decimals", |token| {
+            token
+                .token_configuration
+                .conventions()
+                .decimals()
+                .to_string()
+        }),
+        ("Perpetual distribution", |token| {
+            yes_no(
+                token
+                    .token_configuration
+                    .distribution_rules()
+                    .perpetual_distribution()
+                    .is_some(),
+            )
+        }),
+        ("Pre-programmed distribution", |token| {
+            yes_no(
+                token
+                    .token_configuration
+                    .distribution_rules()
+                    .pre_programmed_distribution()
+                    .is_some(),
+            )
+        }),
+        ("Main control group", |token| {
+            token
+                .token_configuration
+                .main_control_group()
+                .map(|position| format!("Group {}", position))
+                .unwrap_or_else(|| "None".to_string())
+        }),
+        ("Contract groups", |token| {
+            token.data_contract.groups().len().to_string()
+        }),
+    ];
+
+    fields
+        .into_iter()
+        .map(|(field, value)| ComparisonRow {
+            field: field.to_string(),
+            left: Some(value(left)),
+            right: Some(value(right)),
+        })
+        .collect()
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "Yes" } else { "No" }.to_string()
+}
+
+/// Flattens JSON into dotted field paths, so the same field of two configurations lines up
+fn flatten_json(value: &Value, path: &str, fields: &mut BTreeMap<String, String>) {
+    let child_path = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                flatten_json(child, &child_path(key), fields);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, child) in items.iter().enumerate() {
+                flatten_json(child, &child_path(&i.to_string()), fields);
+            }
+        }
+        Value::String(text) => {
+            fields.insert(path.to_string(), text.clone());
+        }
+        other => {
+            fields.insert(path.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Every field of the two JSON configurations, aligned by path
+fn json_rows(left: &Value, right: &Value) -> Vec<ComparisonRow> {
+    let mut left_fields = BTreeMap::new();
+    let mut right_fields = BTreeMap::new();
+    flatten_json(left, "", &mut left_fields);
+    flatten_json(right, "", &mut right_fields);
+
+    let mut paths: Vec<&String> = left_fields.keys().chain(right_fields.keys()).collect();
+    paths.sort();
+    paths.dedup();
+    paths
+        .into_iter()
+        .map(|path| ComparisonRow {
+            field: path.clone(),
+            left: left_fields.get(path).cloned(),
+            right: right_fields.get(path).cloned(),
+        })
+        .collect()
+}
+
+impl TokensScreen {
+    pub(super) fn open_token_comparison(&mut self) {
+        let mut token_ids = self.all_known_tokens.keys().copied();
+        self.token_comparison = Some(TokenComparison {
+            left: token_ids.next(),
+            right: token_ids.next(),
+            only_differences: false,
+        });
+    }
+
+    /// Renders the "Compare Tokens" window when it is open
+    pub(super) fn render_token_comparison_window(&mut self, ui: &mut Ui) {
+        let Some(mut comparison) = self.token_comparison.take() else {
+            return;
+        };
+
+        let mut is_open = true;
+        egui::Window::new("Compare Tokens")
+            .resizable(true)
+            .collapsible(false)
+            .default_width(800.0)
+            .default_height(500.0)
+            .open(&mut is_open)
+            .show(ui.ctx(), |ui| {
+                ui.horizontal(|ui| {
+                    self.render_comparison_token_picker(
+                        ui,
+                        "compare_token_left",
+                        &mut comparison.left,
+                    );
+                    ui.label("vs");
+                    self.render_comparison_token_picker(
+                        ui,
+                        "compare_token_right",
+                        &mut comparison.right,
+                    );
+                    ui.add_space(10.0);
+                    ui.checkbox(&mut comparison.only_differences, "Only show differences");
+                });
+                ui.separator();
+
+                let (Some(left), Some(right)) = (
+                    comparison
+                        .left
+                        .and_then(|id| self.all_known_tokens.get(&id)),
+                    comparison
+                        .right
+                        .and_then(|id| self.all_known_tokens.get(&id)),
+                ) else {
+                    ui.label("Pick two tokens to compare.");
+                    return;
+                };
+
+                let summary = summary_rows(left, right);
+                let configuration = match (
+                    serde_json::to_value(&left.token_configuration),
+                    serde_json::to_value(&right.token_configuration),
+                ) {
+                    (Ok(left_json), Ok(right_json)) => json_rows(&left_json, &right_json),
+                    _ => Vec::new(),
+                };
+                let differences = configuration.iter().filter(|row| row.differs()).count();
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.label(RichText::new("Summary").strong());
+                    render_comparison_grid(
+                        ui,
+                        "compare_tokens_summary",
+                        (&left.token_name, &right.token_name),
+                        &summary,
+                        comparison.only_differences,
+                    );
+
+                    ui.add_space(10.0);
+                    ui.label(
+                        RichText::new(format!("Configuration ({} differing fields)", differences))
+                            .strong(),
+                    );
+                    if configuration.is_empty() {
+                        ui.label("Unable to display configuration details");
+                    } else {
+                        render_comparison_grid(
+                            ui,
+                            "compare_tokens_configuration",
+                            (&left.token_name, &right.token_name),
+                            &configuration,
+                            comparison.only_differences,
+                        );
+                    }
+                });
+            });
+
+        if is_open {
+            self.token_comparison = Some(comparison);
+        }
+    }
+
+    fn render_comparison_token_picker(
+        &self,
+        ui: &mut Ui,
+        id_salt: &str,
+        selected: &mut Option<Identifier>,
+    ) {
+        let selected_text = selected
+            .and_then(|id| self.all_known_tokens.get(&id))
+            .map(|token| token.token_name.clone())
+            .unwrap_or_else(|| "Select a token".to_string());
+        ComboBox::from_id_salt(id_salt)
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for token in self.all_known_tokens.values() {
+                    ui.selectable_value(selected, Some(token.token_id), &token.token_name);
+                }
+            });
+    }
+}
+
+/// Renders comparison rows in aligned columns, highlighting the fields that differ
+fn render_comparison_grid(
+    ui: &mut Ui,
+    id_salt: &str,
+    (left_name, right_name): (&str, &str),
+    rows: &[ComparisonRow],
+    only_differences: bool,
+) {
+    egui::Grid::new(id_salt)
+        .num_columns(3)
+        .striped(true)
+        .spacing([20.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(RichText::new("Field").underline());
+            ui.label(RichText::new(left_name).underline());
+            ui.label(RichText::new(right_name).underline());
+            ui.end_row();
+
+            for row in rows {
+                let differs = row.differs();
+                if only_differences && !differs {
+                    continue;
+                }
+                let color = if differs {
+                    DashColors::WARNING
+                } else {
+                    DashColors::text_primary(ui.ctx().style().visuals.dark_mode)
+                };
+                ui.label(RichText::new(&row.field).color(color));
+                for value in [&row.left, &row.right] {
+                    match value {
+                        Some(value) => ui.label(RichText::new(value).monospace().color(color)),
+                        None => ui.label(RichText::new("—").color(Color32::GRAY)),
+                    };
+                }
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_rows_align_fields() {
+        let left = json!({
+            "conventions": { "decimals": 8 },
+            "maxSupply": null,
+            "keepsHistory": { "transfers": true },
+        });
+        let right = json!({
+            "conventions": { "decimals": 2 },
+            "maxSupply": 1000,
+            "extra": "only here",
+        });
+
+        let rows = json_rows(&left, &right);
+        let row = |field: &str| rows.iter().find(|row| row.field == field).unwrap();
+
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.field.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "conventions.decimals",
+                "extra",
+                "keepsHistory.transfers",
+                "maxSupply"
+            ]
+        );
+        assert!(row("conventions.decimals").differs());
+        assert_eq!(row("maxSupply").left.as_deref(), Some("null"));
+        assert_eq!(row("extra").left, None);
+        assert_eq!(row("extra").right.as_deref(), Some("only here"));
+
+        let same = json_rows(&left, &left);
+        assert!(same.iter().all(|row| !row.differs()));
+    }
+}
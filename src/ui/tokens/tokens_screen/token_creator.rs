@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::HashSet;
 use chrono::Utc;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::v0::{TokenConfigurationPreset, TokenConfigurationPresetFeatures};
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::v0::TokenConfigurationPresetFeatures::{MostRestrictive, WithAllAdvancedActions, WithExtremeActions, WithMintingAndBurningActions, WithOnlyEmergencyAction};
@@ -17,7 +17,8 @@ use crate::backend_task::BackendTask;
 use crate::backend_task::tokens::TokenTask;
 use crate::ui::components::styled::{StyledCheckbox, ClickableCollapsingHeader};
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::helpers::{add_identity_key_chooser, TransactionType};
+use crate::ui::helpers::{add_identity_key_chooser, render_json_validation_errors, TransactionType};
+use crate::utils::json_validation::{parse_json, validate_document_schemas_json};
 use crate::ui::tokens::tokens_screen::{TokenBuildArgs, TokenCreatorStatus, TokenNameLanguage, TokensScreen, ChangeControlRulesUI};
 
 impl TokensScreen {
@@ -1138,11 +1139,8 @@ impl TokensScreen {
                 ui.add_space(5.0);
 
                 // Show validation result
-                if let Some(ref error) = self.document_schemas_error {
-                    ui.colored_label(
-                        Color32::DARK_RED,
-                        format!("Schema validation error: {}", error),
-                    );
+                if !self.document_schemas_errors.is_empty() {
+                    render_json_validation_errors(ui, &self.document_schemas_errors);
                 } else if self.parsed_document_schemas.is_some() {
                     let schema_count = self.parsed_document_schemas.as_ref().unwrap().len();
                     if schema_count > 0 {
@@ -1157,49 +1155,28 @@ impl TokensScreen {
 
     /// Parse and validate the document schemas JSON input
     fn parse_document_schemas(&mut self) {
-        self.document_schemas_error = None;
+        self.document_schemas_errors.clear();
         self.parsed_document_schemas = None;
 
         if self.document_schemas_input.trim().is_empty() {
             return;
         }
 
-        match serde_json::from_str::<serde_json::Value>(&self.document_schemas_input) {
-            Ok(json_value) => {
-                match json_value.as_object() {
-                    Some(obj) => {
-                        let mut schemas = BTreeMap::new();
-
-                        for (key, value) in obj {
-                            // Basic validation - ensure it's an object with required fields
-                            if let Some(schema_obj) = value.as_object() {
-                                if schema_obj.contains_key("type") {
-                                    schemas.insert(key.clone(), value.clone());
-                                } else {
-                                    self.document_schemas_error = Some(format!(
-                                        "Document schema '{}' missing required 'type' field",
-                                        key
-                                    ));
-                                    return;
-                                }
-                            } else {
-                                self.document_schemas_error =
-                                    Some(format!("Document schema '{}' must be an object", key));
-                                return;
-                            }
-                        }
-
-                        self.parsed_document_schemas = Some(schemas);
-                    }
-                    None => {
-                        self.document_schemas_error =
-                            Some("Document schemas must be a JSON object".to_string());
-                    }
-                }
-            }
+        let json_value = match parse_json(&self.document_schemas_input) {
+            Ok(json_value) => json_value,
             Err(e) => {
-                self.document_schemas_error = Some(format!("Invalid JSON: {}", e));
+                self.document_schemas_errors.push(e);
+                return;
             }
+        };
+
+        self.document_schemas_errors = validate_document_schemas_json(&json_value);
+        if !self.document_schemas_errors.is_empty() {
+            return;
+        }
+
+        if let serde_json::Value::Object(obj) = json_value {
+            self.parsed_document_schemas = Some(obj.into_iter().collect());
         }
     }
 
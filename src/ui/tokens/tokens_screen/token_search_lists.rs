@@ -0,0 +1,196 @@
+use crate::app::{AppAction, BackendTasksExecutionMode};
+use crate::backend_task::BackendTask;
+use crate::backend_task::tokens::TokenTask;
+use crate::ui::MessageType;
+use crate::ui::helpers::render_identifier_cell;
+use crate::ui::tokens::tokens_screen::{
+    TokenInfo, TokenSearchListEntry, TokenSearchListKind, TokensScreen,
+};
+use chrono::Utc;
+use dash_sdk::platform::Identifier;
+use egui::{RichText, Ui};
+
+impl TokensScreen {
+    pub(super) fn token_search_list_kind(
+        &self,
+        token_id: &Identifier,
+    ) -> Option<TokenSearchListKind> {
+        self.token_search_lists
+            .get(token_id)
+            .map(|entry| entry.kind)
+    }
+
+    /// Whether a search result should be hidden because one of the contract's tokens was
+    /// dismissed. Search results are contracts, so this is decided per contract.
+    pub(super) fn is_contract_denylisted(&self, data_contract_id: &Identifier) -> bool {
+        self.token_search_lists.values().any(|entry| {
+            entry.kind == TokenSearchListKind::Denylist
+                && entry.data_contract_id == *data_contract_id
+        })
+    }
+
+    /// Puts a token on the allowlist or denylist. Allowlisted tokens are tracked right away.
+    pub(super) fn set_token_search_list(
+        &mut self,
+        token: &TokenInfo,
+        kind: TokenSearchListKind,
+    ) -> AppAction {
+        let entry = TokenSearchListEntry {
+            token_id: token.token_id,
+            data_contract_id: token.data_contract_id,
+            token_name: token.token_name.clone(),
+            kind,
+        };
+        if let Err(e) = self
+            .app_context
+            .db
+            .set_token_search_list(&entry, &self.app_context)
+        {
+            self.backend_message = Some((
+                format!("Error updating token lists: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ));
+            return AppAction::None;
+        }
+        self.token_search_lists.insert(token.token_id, entry);
+
+        if kind == TokenSearchListKind::Allowlist
+            && !self.all_known_tokens.contains_key(&token.token_id)
+        {
+            self.pending_auto_track_tokens.push(token.clone());
+            return self.auto_track_tokens_action();
+        }
+        AppAction::None
+    }
+
+    fn remove_token_from_search_lists(&mut self, token_id: &Identifier) {
+        if let Err(e) = self
+            .app_context
+            .db
+            .remove_token_from_search_lists(token_id, &self.app_context)
+        {
+            self.backend_message = Some((
+                format!("Error updating token lists: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ));
+            return;
+        }
+        self.token_search_lists.shift_remove(token_id);
+    }
+
+    fn clear_token_search_list(&mut self, kind: TokenSearchListKind) {
+        if let Err(e) = self
+            .app_context
+            .db
+            .clear_token_search_list(kind, &self.app_context)
+        {
+            self.backend_message = Some((
+                format!("Error updating token lists: {}", e),
+                MessageType::Error,
+                Utc::now(),
+            ));
+            return;
+        }
+        self.token_search_lists
+            .retain(|_, entry| entry.kind != kind);
+    }
+
+    /// Queues the allowlisted tokens of the selected contract that aren't tracked yet.
+    pub(super) fn queue_allowlisted_tokens_for_tracking(&mut self) {
+        let missing = self
+            .selected_token_infos
+            .iter()
+            .filter(|token| {
+                self.token_search_list_kind(&token.token_id) == Some(TokenSearchListKind::Allowlist)
+                    && !self.all_known_tokens.contains_key(&token.token_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        self.pending_auto_track_tokens.extend(missing);
+    }
+
+    /// Saves the queued allowlisted tokens locally and refreshes balances.
+    pub(super) fn auto_track_tokens_action(&mut self) -> AppAction {
+        let tokens = std::mem::take(&mut self.pending_auto_track_tokens);
+        if tokens.is_empty() {
+            return AppAction::None;
+        }
+
+        self.backend_message = Some((
+            format!("Tracking {} allowlisted token(s)...", tokens.len()),
+            MessageType::Info,
+            Utc::now(),
+        ));
+
+        let mut tasks = tokens
+            .into_iter()
+            .map(|token| BackendTask::TokenTask(Box::new(TokenTask::SaveTokenLocally(token))))
+            .collect::<Vec<_>>();
+        tasks.push(BackendTask::TokenTask(Box::new(
+            TokenTask::QueryMyTokenBalances,
+        )));
+        AppAction::BackendTasks(tasks, BackendTasksExecutionMode::SequentialBestEffort)
+    }
+
+    /// Renders the allowlist and denylist so entries can be removed or cleared.
+    pub(super) fn render_token_search_lists(&mut self, ui: &mut Ui) {
+        let header = format!("Allowlist and Denylist ({})", self.token_search_lists.len());
+        egui::CollapsingHeader::new(header)
+            .id_salt("token_search_lists")
+            .show(ui, |ui| {
+                for kind in [
+                    TokenSearchListKind::Allowlist,
+                    TokenSearchListKind::Denylist,
+                ] {
+                    let (title, explanation, clear_label) = match kind {
+                        TokenSearchListKind::Allowlist => (
+                            "Always Tracked",
+                            "Added to My Tokens whenever a search turns them up.",
+                            "Clear allowlist",
+                        ),
+                        TokenSearchListKind::Denylist => (
+                            "Dismissed",
+                            "Their contracts are hidden from search results.",
+                            "Clear denylist",
+                        ),
+                    };
+                    let entries = self
+                        .token_search_lists
+                        .values()
+                        .filter(|entry| entry.kind == kind)
+                        .cloned()
+                        .collect::<Vec<_>>();
+
+                    ui.add_space(5.0);
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(title).strong());
+                        ui.label(RichText::new(explanation).weak());
+                    });
+
+                    if entries.is_empty() {
+                        ui.label("No tokens.");
+                    } else {
+                        egui::Grid::new(("token_search_list", kind.as_str()))
+                            .num_columns(3)
+                            .spacing([10.0, 4.0])
+                            .show(ui, |ui| {
+                                for entry in &entries {
+                                    ui.label(&entry.token_name);
+                                    render_identifier_cell(ui, &entry.token_id, None);
+                                    if ui.small_button("Remove").clicked() {
+                                        self.remove_token_from_search_lists(&entry.token_id);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                        if ui.button(clear_label).clicked() {
+                            self.clear_token_search_list(kind);
+                        }
+                    }
+                    ui.add_space(5.0);
+                }
+            });
+    }
+}
@@ -5,11 +5,18 @@ use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
+use crate::ui::components::mainnet_confirmation::{
+    IrreversibleAction, MainnetConfirmation, is_large_token_transfer,
+};
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::token_amount_input::{parse_token_amount, render_token_amount_input};
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button, format_token_amount,
+};
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
 use crate::ui::theme::DashColors;
@@ -31,81 +38,6 @@ use super::tokens_screen::IdentityTokenBalance;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 
-fn format_token_amount(amount: u64, decimals: u8) -> String {
-    if decimals == 0 {
-        return amount.to_string();
-    }
-
-    let divisor = 10u64.pow(decimals as u32);
-    let whole = amount / divisor;
-    let fraction = amount % divisor;
-
-    if fraction == 0 {
-        whole.to_string()
-    } else {
-        // Format with the appropriate number of decimal places, removing trailing zeros
-        let fraction_str = format!("{:0width$}", fraction, width = decimals as usize);
-        let trimmed = fraction_str.trim_end_matches('0');
-        format!("{}.{}", whole, trimmed)
-    }
-}
-
-fn parse_token_amount(input: &str, decimals: u8) -> Result<u64, String> {
-    if decimals == 0 {
-        return input
-            .parse::<u64>()
-            .map_err(|_| "Invalid amount: must be a whole number".to_string());
-    }
-
-    let parts: Vec<&str> = input.split('.').collect();
-    match parts.len() {
-        1 => {
-            // No decimal point, parse as whole number
-            let whole = parts[0]
-                .parse::<u64>()
-                .map_err(|_| "Invalid amount: must be a number".to_string())?;
-            let multiplier = 10u64.pow(decimals as u32);
-            whole
-                .checked_mul(multiplier)
-                .ok_or_else(|| "Amount too large".to_string())
-        }
-        2 => {
-            // Has decimal point
-            let whole = if parts[0].is_empty() {
-                0
-            } else {
-                parts[0]
-                    .parse::<u64>()
-                    .map_err(|_| "Invalid amount: whole part must be a number".to_string())?
-            };
-
-            let fraction_str = parts[1];
-            if fraction_str.len() > decimals as usize {
-                return Err(format!(
-                    "Too many decimal places. Maximum allowed: {}",
-                    decimals
-                ));
-            }
-
-            // Pad with zeros if needed
-            let padded_fraction = format!("{:0<width$}", fraction_str, width = decimals as usize);
-            let fraction = padded_fraction
-                .parse::<u64>()
-                .map_err(|_| "Invalid amount: decimal part must be a number".to_string())?;
-
-            let multiplier = 10u64.pow(decimals as u32);
-            let whole_part = whole
-                .checked_mul(multiplier)
-                .ok_or_else(|| "Amount too large".to_string())?;
-
-            whole_part
-                .checked_add(fraction)
-                .ok_or_else(|| "Amount too large".to_string())
-        }
-        _ => Err("Invalid amount: too many decimal points".to_string()),
-    }
-}
-
 #[derive(PartialEq)]
 pub enum TransferTokensStatus {
     NotStarted,
@@ -127,6 +59,7 @@ pub struct TransferTokensScreen {
     max_amount: u64,
     pub app_context: Arc<AppContext>,
     confirmation_popup: bool,
+    mainnet_confirmation: MainnetConfirmation,
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
     wallet_password: String,
     show_password: bool,
@@ -189,6 +122,7 @@ impl TransferTokensScreen {
             max_amount,
             app_context: app_context.clone(),
             confirmation_popup: false,
+            mainnet_confirmation: MainnetConfirmation::default(),
             selected_wallet,
             wallet_password: String::new(),
             show_password: false,
@@ -199,16 +133,16 @@ impl TransferTokensScreen {
         ui.horizontal(|ui| {
             ui.label("Amount:");
 
-            ui.text_edit_singleline(&mut self.amount);
-
+            let decimals = self
+                .identity_token_balance
+                .token_config
+                .conventions()
+                .decimals();
             if ui.button("Max").clicked() {
-                let decimals = self
-                    .identity_token_balance
-                    .token_config
-                    .conventions()
-                    .decimals();
                 self.amount = format_token_amount(self.max_amount, decimals);
             }
+            let _ =
+                render_token_amount_input(ui, &mut self.amount, decimals, Some(self.max_amount));
         });
     }
 
@@ -290,7 +224,25 @@ impl TransferTokensScreen {
                     self.amount, self.identity_token_balance.token_alias, self.receiver_identity_id
                 ));
 
-                if ui.button("Confirm").clicked() {
+                let decimals = self
+                    .identity_token_balance
+                    .token_config
+                    .conventions()
+                    .decimals();
+                let is_large_transfer = parse_token_amount(&self.amount, decimals)
+                    .is_ok_and(|amount| is_large_token_transfer(amount, self.max_amount));
+                let confirmed = !is_large_transfer
+                    || self.mainnet_confirmation.show(
+                        ui,
+                        &self.app_context,
+                        IrreversibleAction::LargeTransfer,
+                        Some(&self.identity_token_balance.token_alias),
+                    );
+
+                if ui
+                    .add_enabled(confirmed, egui::Button::new("Confirm"))
+                    .clicked()
+                {
                     self.confirmation_popup = false;
                     let now = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -439,6 +391,7 @@ impl ScreenLike for TransferTokensScreen {
                 self.identity_token_balance.token_alias
             ));
             ui.add_space(10.0);
+            action |= render_low_balance_banner(ui, &self.app_context, &self.identity);
 
             let has_keys = if self.app_context.is_developer_mode() {
                 !self.identity.identity.public_keys().is_empty()
@@ -570,7 +523,16 @@ impl ScreenLike for TransferTokensScreen {
                     .fill(Color32::from_rgb(0, 128, 255))
                     .frame(true)
                     .corner_radius(3.0);
-                if ui.add(button).clicked() {
+                if add_signing_key_guarded_button(
+                    ui,
+                    button,
+                    self.selected_key.as_ref(),
+                    &self.identity,
+                    TransactionType::TokenTransfer,
+                    &self.app_context,
+                )
+                .clicked()
+                {
                     let decimals = self
                         .identity_token_balance
                         .token_config
@@ -588,6 +550,7 @@ impl ScreenLike for TransferTokensScreen {
                                 );
                             } else {
                                 self.confirmation_popup = true;
+                                self.mainnet_confirmation.reset();
                             }
                         }
                         Err(e) => {
@@ -1,31 +1,42 @@
 use super::tokens_screen::IdentityTokenInfo;
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
+use crate::backend_task::contract::ContractTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::low_balance_banner::render_low_balance_banner;
+use crate::ui::components::mainnet_confirmation::{IrreversibleAction, MainnetConfirmation};
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::token_action_simulation::TokenActionSimulation;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
 use crate::ui::contracts_documents::group_actions_screen::GroupActionsScreen;
-use crate::ui::helpers::{TransactionType, add_identity_key_chooser, render_group_action_text};
+use crate::ui::helpers::{
+    TransactionType, add_identity_key_chooser, add_signing_key_guarded_button,
+    copy_with_confirmation, format_token_amount, recently_copied, render_group_action_text,
+};
 use crate::ui::identities::get_selected_wallet;
 use crate::ui::identities::keys::add_key_screen::AddKeyScreen;
 use crate::ui::identities::keys::key_info_screen::KeyInfoScreen;
+use crate::ui::theme::DashColors;
 use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike};
 use chrono::{DateTime, Utc};
+use dash_sdk::dpp::balances::credits::TokenAmount;
 use dash_sdk::dpp::data_contract::GroupContractPosition;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::accessors::v1::DataContractV1Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::TokenConfigurationConvention;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::accessors::v0::TokenConfigurationConventionV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_configuration_item::TokenConfigurationChangeItem;
+use dash_sdk::dpp::data_contract::associated_token::token_configuration_localization::accessors::v0::TokenConfigurationLocalizationV0Getters;
 use dash_sdk::dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
 use dash_sdk::dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
-use dash_sdk::dpp::data_contract::group::Group;
+use dash_sdk::dpp::data_contract::group::{Group, GroupMemberPower};
 use dash_sdk::dpp::data_contract::group::accessors::v0::GroupV0Getters;
 use dash_sdk::dpp::group::{GroupStateTransitionInfo, GroupStateTransitionInfoStatus};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
@@ -33,8 +44,8 @@ use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::{DataContract, Identifier, IdentityPublicKey};
 use eframe::egui::{self, Color32, Context, Ui};
-use egui::RichText;
-use std::collections::HashSet;
+use egui::{Key, Modifiers, RichText};
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -57,6 +68,11 @@ pub struct UpdateTokenConfigScreen {
     group: Option<(GroupContractPosition, Group)>,
     is_unilateral_group_member: bool,
     pub group_action_id: Option<Identifier>,
+    /// Members that already signed the group action being signed, with their power
+    group_action_signers: Option<BTreeMap<Identifier, GroupMemberPower>>,
+    group_action_signers_requested: bool,
+    /// Why the signers of the group action couldn't be fetched
+    group_action_signers_error: Option<String>,
 
     // Input state fields
     pub authorized_identity_input: Option<String>,
@@ -66,6 +82,338 @@ pub struct UpdateTokenConfigScreen {
     wallet_password: String,
     show_password: bool,
     error_message: Option<String>, // unused
+    mainnet_confirmation: MainnetConfirmation,
+    simulation: TokenActionSimulation,
+    /// Tokens held by the local identities, a lower bound of the circulating supply
+    known_circulating_supply: Option<TokenAmount>,
+    /// Why the change in the clipboard couldn't be pasted
+    paste_error: Option<String>,
+    edit_history: EditHistory,
+}
+
+/// Most edits of the change editor that can be undone
+const MAX_EDIT_HISTORY: usize = 50;
+
+/// What the change editor shows, as restored by undo and redo
+#[derive(Debug, Clone, PartialEq)]
+struct ChangeEditorState {
+    change_item: TokenConfigurationChangeItem,
+    update_text: String,
+    authorized_identity_input: Option<String>,
+    authorized_group_input: Option<String>,
+}
+
+/// Undo and redo stacks of the change editor
+#[derive(Debug, Default)]
+struct EditHistory {
+    undo: VecDeque<ChangeEditorState>,
+    redo: Vec<ChangeEditorState>,
+    /// State the editor ended the previous frame in
+    last: Option<ChangeEditorState>,
+}
+
+impl EditHistory {
+    /// Records the state the editor ends a frame in. A new change item counts as an edit,
+    /// pushing the previous state on the undo stack and dropping the redo stack. Other
+    /// differences, such as JSON that doesn't parse yet, only update the state returned to.
+    fn observe(&mut self, state: ChangeEditorState) {
+        if let Some(last) = self.last.take() {
+            if last.change_item != state.change_item {
+                self.push_undo(last);
+                self.redo.clear();
+            }
+        }
+        self.last = Some(state);
+    }
+
+    fn push_undo(&mut self, state: ChangeEditorState) {
+        if self.undo.len() == MAX_EDIT_HISTORY {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(state);
+    }
+
+    fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// The state before the last edit, for the editor to go back to
+    fn undo(&mut self) -> Option<ChangeEditorState> {
+        let previous = self.undo.pop_back()?;
+        if let Some(current) = self.last.replace(previous.clone()) {
+            self.redo.push(current);
+        }
+        Some(previous)
+    }
+
+    /// The state the last undo left, for the editor to go back to
+    fn redo(&mut self) -> Option<ChangeEditorState> {
+        let next = self.redo.pop()?;
+        if let Some(current) = self.last.replace(next.clone()) {
+            self.push_undo(current);
+        }
+        Some(next)
+    }
+}
+
+/// Lines of unchanged context shown around each change in the config diff
+const DIFF_CONTEXT_LINES: usize = 2;
+
+/// A line of a unified diff
+#[derive(Debug, PartialEq)]
+enum DiffLine<'a> {
+    Unchanged(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff of two texts, from their longest common subsequence of lines
+fn diff_lines<'a>(before: &'a str, after: &'a str) -> Vec<DiffLine<'a>> {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+
+    // common[i][j] is the length of the longest common subsequence of before[i..] and after[j..]
+    let mut common = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            common[i][j] = if before[i] == after[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() && j < after.len() {
+        if before[i] == after[j] {
+            lines.push(DiffLine::Unchanged(before[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            lines.push(DiffLine::Removed(before[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(after[j]));
+            j += 1;
+        }
+    }
+    lines.extend(before[i..].iter().map(|line| DiffLine::Removed(line)));
+    lines.extend(after[j..].iter().map(|line| DiffLine::Added(line)));
+    lines
+}
+
+/// Checks a new max supply against the supply that already exists. `None` means unlimited.
+fn validate_max_supply(
+    max_supply: Option<TokenAmount>,
+    base_supply: TokenAmount,
+    known_circulating_supply: Option<TokenAmount>,
+) -> Result<(), String> {
+    let Some(max_supply) = max_supply else {
+        return Ok(());
+    };
+    if max_supply < base_supply {
+        return Err(format!(
+            "Max supply can't be below the base supply of {}",
+            base_supply
+        ));
+    }
+    if let Some(circulating) = known_circulating_supply {
+        if max_supply < circulating {
+            return Err(format!(
+                "Max supply can't be below the {} tokens already held by your identities",
+                circulating
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Shortest and longest a token name, singular or plural, may be, as in the token creator
+const TOKEN_NAME_LENGTH: std::ops::RangeInclusive<usize> = 3..=50;
+
+/// Checks edited conventions for what Platform would reject: a missing English name, or names
+/// too short or too long
+fn validate_conventions(conventions: &TokenConfigurationConvention) -> Result<(), String> {
+    let TokenConfigurationConvention::V0(conventions) = conventions;
+    if !conventions.localizations.contains_key("en") {
+        return Err("The conventions need an English (\"en\") localization".to_string());
+    }
+    for (language, localization) in &conventions.localizations {
+        for (form, name) in [
+            ("name", localization.singular_form()),
+            ("plural form", localization.plural_form()),
+        ] {
+            if !TOKEN_NAME_LENGTH.contains(&name.len()) {
+                return Err(format!(
+                    "The {} in \"{}\" must be between {} and {} characters",
+                    form,
+                    language,
+                    TOKEN_NAME_LENGTH.start(),
+                    TOKEN_NAME_LENGTH.end()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Warning for conventions changing the number of decimals, which changes how every existing
+/// balance reads
+fn decimals_change_warning(
+    new: &TokenConfigurationConvention,
+    current: &TokenConfigurationConvention,
+) -> Option<String> {
+    let (new, current) = (new.decimals(), current.decimals());
+    if new == current {
+        return None;
+    }
+    let mut warning = format!(
+        "⚠ Changing the decimals from {} to {} reinterprets every existing balance.",
+        current, new
+    );
+    // Both powers must fit a u64 for the example to be formatted
+    if let (Some(one_token), Some(_)) = (
+        10u64.checked_pow(u32::from(current)),
+        10u64.checked_pow(u32::from(new)),
+    ) {
+        warning.push_str(&format!(
+            " A balance of 1 token would read as {}.",
+            format_token_amount(one_token, new)
+        ));
+    }
+    Some(warning)
+}
+
+/// The action takers a change item sets, for the items changing who can take an action
+fn changed_action_takers(item: &TokenConfigurationChangeItem) -> Option<&AuthorizedActionTakers> {
+    match item {
+        TokenConfigurationChangeItem::ManualMinting(t)
+        | TokenConfigurationChangeItem::ManualMintingAdminGroup(t)
+        | TokenConfigurationChangeItem::ManualBurning(t)
+        | TokenConfigurationChangeItem::ManualBurningAdminGroup(t)
+        | TokenConfigurationChangeItem::Freeze(t)
+        | TokenConfigurationChangeItem::FreezeAdminGroup(t)
+        | TokenConfigurationChangeItem::Unfreeze(t)
+        | TokenConfigurationChangeItem::UnfreezeAdminGroup(t)
+        | TokenConfigurationChangeItem::DestroyFrozenFunds(t)
+        | TokenConfigurationChangeItem::DestroyFrozenFundsAdminGroup(t)
+        | TokenConfigurationChangeItem::EmergencyAction(t)
+        | TokenConfigurationChangeItem::EmergencyActionAdminGroup(t)
+        | TokenConfigurationChangeItem::ConventionsControlGroup(t)
+        | TokenConfigurationChangeItem::ConventionsAdminGroup(t)
+        | TokenConfigurationChangeItem::MaxSupplyControlGroup(t)
+        | TokenConfigurationChangeItem::MaxSupplyAdminGroup(t)
+        | TokenConfigurationChangeItem::PerpetualDistributionControlGroup(t)
+        | TokenConfigurationChangeItem::PerpetualDistributionAdminGroup(t)
+        | TokenConfigurationChangeItem::NewTokensDestinationIdentityControlGroup(t)
+        | TokenConfigurationChangeItem::NewTokensDestinationIdentityAdminGroup(t)
+        | TokenConfigurationChangeItem::MintingAllowChoosingDestinationControlGroup(t)
+        | TokenConfigurationChangeItem::MintingAllowChoosingDestinationAdminGroup(t)
+        | TokenConfigurationChangeItem::MarketplaceTradeModeControlGroup(t)
+        | TokenConfigurationChangeItem::MarketplaceTradeModeAdminGroup(t) => Some(t),
+        _ => None,
+    }
+}
+
+/// Checks that a change copied from another token's update screen can be applied to this
+/// token. `takers` are who this token allows to make the change, `group_positions` the groups
+/// of its contract.
+fn check_pasted_change(
+    item: &TokenConfigurationChangeItem,
+    takers: &AuthorizedActionTakers,
+    group_positions: &[GroupContractPosition],
+    main_control_group: Option<GroupContractPosition>,
+    base_supply: TokenAmount,
+    known_circulating_supply: Option<TokenAmount>,
+) -> Result<(), String> {
+    let label = token_change_item_label(item);
+    match item {
+        TokenConfigurationChangeItem::TokenConfigurationNoChange => {
+            return Err("The copied change doesn't change anything".to_string());
+        }
+        TokenConfigurationChangeItem::MarketplaceTradeMode(_) => {
+            return Err(format!("{} changes are not supported yet", label));
+        }
+        TokenConfigurationChangeItem::MaxSupply(max_supply) => {
+            validate_max_supply(*max_supply, base_supply, known_circulating_supply)?;
+        }
+        _ => {}
+    }
+    if *takers == AuthorizedActionTakers::NoOne {
+        return Err(format!("This token doesn't allow changing the {}", label));
+    }
+    if changed_action_takers(item) == Some(&AuthorizedActionTakers::MainGroup)
+        && main_control_group.is_none()
+    {
+        return Err(format!(
+            "The {} change refers to the main control group, which this token doesn't have",
+            label
+        ));
+    }
+    validate_referenced_group(item, group_positions)
+}
+
+/// Checks that the group a change refers to, if any, is one of the `group_positions` of the
+/// token's contract
+fn validate_referenced_group(
+    item: &TokenConfigurationChangeItem,
+    group_positions: &[GroupContractPosition],
+) -> Result<(), String> {
+    let referenced_group = match (item, changed_action_takers(item)) {
+        (TokenConfigurationChangeItem::MainControlGroup(position), _) => *position,
+        (_, Some(AuthorizedActionTakers::Group(position))) => Some(*position),
+        _ => None,
+    };
+    match referenced_group {
+        Some(position) if !group_positions.contains(&position) => Err(format!(
+            "The {} change refers to group {}, which this token's contract doesn't have",
+            token_change_item_label(item),
+            position
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Label of a group in the group selector, with its size and the power it requires
+fn group_option_label(position: GroupContractPosition, group: &Group) -> String {
+    format!(
+        "Group {} ({} members, {} power required)",
+        position,
+        group.members().len(),
+        group.required_power()
+    )
+}
+
+/// Power of the members who already signed a group action, and whether `signer_id` signing it
+/// too reaches the power the group requires
+fn group_signing_progress(
+    group: &Group,
+    signers: &BTreeMap<Identifier, GroupMemberPower>,
+    signer_id: &Identifier,
+) -> (u32, bool) {
+    let signed_power = signers
+        .values()
+        .fold(0u32, |total, power| total.saturating_add(*power));
+    let own_power = if signers.contains_key(signer_id) {
+        0
+    } else {
+        group.members().get(signer_id).copied().unwrap_or(0)
+    };
+    (
+        signed_power,
+        signed_power.saturating_add(own_power) >= group.required_power(),
+    )
+}
+
+/// Whether a group selector label contains the typed filter, ignoring case
+fn matches_group_filter(label: &str, filter: &str) -> bool {
+    label.to_lowercase().contains(&filter.trim().to_lowercase())
 }
 
 impl UpdateTokenConfigScreen {
@@ -97,6 +445,18 @@ impl UpdateTokenConfigScreen {
             &mut error_message,
         );
 
+        let known_circulating_supply = app_context
+            .identity_token_balances()
+            .ok()
+            .map(|balances| {
+                balances
+                    .values()
+                    .filter(|balance| balance.token_id == identity_token_info.token_id)
+                    .map(|balance| balance.balance)
+                    .fold(0, TokenAmount::saturating_add)
+            })
+            .filter(|held| *held > 0);
+
         Self {
             identity_token_info: identity_token_info.clone(),
             backend_message: None,
@@ -115,11 +475,19 @@ impl UpdateTokenConfigScreen {
             wallet_password: String::new(),
             show_password: false,
             error_message,
+            mainnet_confirmation: MainnetConfirmation::default(),
+            simulation: TokenActionSimulation::default(),
+            known_circulating_supply,
+            paste_error: None,
+            edit_history: EditHistory::default(),
 
             identity: identity_token_info.identity,
             group,
             is_unilateral_group_member,
             group_action_id: None,
+            group_action_signers: None,
+            group_action_signers_requested: false,
+            group_action_signers_error: None,
         }
     }
 
@@ -228,6 +596,7 @@ impl UpdateTokenConfigScreen {
                 // we need to initialize group based on the change item
                 self.update_group_based_on_change_item();
             }
+            action |= self.render_group_action_signers(ui);
         }
 
         // Clone the token configuration to avoid borrowing issues
@@ -242,8 +611,8 @@ impl UpdateTokenConfigScreen {
                 .width(270.0)
                 .show_ui(ui, |ui| {
                     /* ───────── “No change” ───────── */
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::TokenConfigurationNoChange,
                             "No Change",
@@ -256,8 +625,8 @@ impl UpdateTokenConfigScreen {
                     ui.separator();
 
                     /* ───────── Conventions + groups ───────── */
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::Conventions(
                                 default_token_configuration.conventions().clone(),
@@ -272,8 +641,8 @@ impl UpdateTokenConfigScreen {
                         self.text_input_error = "".to_string();
                         self.update_group_based_on_change_item();
                     };
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::ConventionsControlGroup(
                                 *default_token_configuration
@@ -286,8 +655,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::ConventionsAdminGroup(
                                 *default_token_configuration
@@ -304,8 +673,8 @@ impl UpdateTokenConfigScreen {
                     ui.separator();
 
                     /* ───────── Max‑supply + groups ───────── */
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MaxSupply(
                                 default_token_configuration.max_supply(),
@@ -316,8 +685,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MaxSupplyControlGroup(
                                 *default_token_configuration
@@ -330,8 +699,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MaxSupplyAdminGroup(
                                 *default_token_configuration
@@ -348,8 +717,8 @@ impl UpdateTokenConfigScreen {
                     ui.separator();
 
                     /* ───────── Perpetual‑dist + groups ───────── */
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::PerpetualDistribution(
                                 default_token_configuration
@@ -366,8 +735,8 @@ impl UpdateTokenConfigScreen {
                             "The perpetual distribution can not be modified".to_string();
                         self.update_group_based_on_change_item();
                     };
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::PerpetualDistributionControlGroup(
                                 *default_token_configuration
@@ -381,8 +750,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::PerpetualDistributionAdminGroup(
                                 *default_token_configuration
@@ -400,8 +769,8 @@ impl UpdateTokenConfigScreen {
                     ui.separator();
 
                     /* ───────── New‑tokens destination + groups ───────── */
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::NewTokensDestinationIdentity(
                                 default_token_configuration
@@ -415,8 +784,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::NewTokensDestinationIdentityControlGroup(
                                 *default_token_configuration
@@ -430,8 +799,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::NewTokensDestinationIdentityAdminGroup(
                                 *default_token_configuration
@@ -449,8 +818,8 @@ impl UpdateTokenConfigScreen {
                     ui.separator();
 
                     /* ───────── Mint‑dest‑choice + groups ───────── */
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MintingAllowChoosingDestination(
                                 default_token_configuration
@@ -463,8 +832,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MintingAllowChoosingDestinationControlGroup(
                                 *default_token_configuration
@@ -478,8 +847,8 @@ impl UpdateTokenConfigScreen {
                     {
                         self.update_group_based_on_change_item();
                     }
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MintingAllowChoosingDestinationAdminGroup(
                                 *default_token_configuration
@@ -499,8 +868,8 @@ impl UpdateTokenConfigScreen {
                     /* ───────── Remaining AuthorizedActionTakers variants ───────── */
                     macro_rules! aat_item {
                         ($variant:ident, $label:expr) => {
-                            if ui
-                                .selectable_value(
+                            if change_item_option(
+                                    ui,
                                     &mut self.change_item,
                                     TokenConfigurationChangeItem::$variant(
                                         AuthorizedActionTakers::ContractOwner,
@@ -542,8 +911,8 @@ impl UpdateTokenConfigScreen {
 
                     ui.separator();
 
-                    if ui
-                        .selectable_value(
+                    if change_item_option(
+                            ui,
                             &mut self.change_item,
                             TokenConfigurationChangeItem::MainControlGroup(
                                 default_token_configuration.main_control_group(),
@@ -570,8 +939,9 @@ impl UpdateTokenConfigScreen {
                 if text_response.changed() {
                     match serde_json::from_str::<TokenConfigurationConvention>(&self.update_text) {
                         Ok(new_conv) => {
+                            self.text_input_error =
+                                validate_conventions(&new_conv).err().unwrap_or_default();
                             *conv = new_conv;
-                            self.text_input_error = "".to_string();
                         }
                         Err(e) => {
                             self.text_input_error = format!("Invalid JSON: {}", e);
@@ -590,12 +960,27 @@ impl UpdateTokenConfigScreen {
                         ui.colored_label(Color32::RED, &self.text_input_error);
                     }
                 });
+
+                if let Some(warning) = decimals_change_warning(
+                    conv,
+                    self.identity_token_info.token_config.conventions(),
+                ) {
+                    ui.add_space(5.0);
+                    ui.label(RichText::new(warning).color(Color32::RED).strong().size(15.0));
+                }
             }
             TokenConfigurationChangeItem::MaxSupply(opt_amt) => {
                 let mut txt = opt_amt.map(|a| a.to_string()).unwrap_or_default();
                 if ui.text_edit_singleline(&mut txt).changed() {
                     *opt_amt = txt.parse::<u64>().ok();
                 }
+                if let Err(e) = validate_max_supply(
+                    *opt_amt,
+                    self.identity_token_info.token_config.base_supply(),
+                    self.known_circulating_supply,
+                ) {
+                    ui.colored_label(Color32::RED, e);
+                }
             }
             TokenConfigurationChangeItem::MintingAllowChoosingDestination(b) => {
                 ui.checkbox(b, "Allow user to choose destination when minting");
@@ -677,6 +1062,9 @@ impl UpdateTokenConfigScreen {
         });
         });
         ui.add_space(10.0);
+        self.render_edit_history(ui);
+        self.render_copy_paste_change(ui);
+        ui.add_space(10.0);
         ui.separator();
         ui.add_space(10.0);
 
@@ -706,6 +1094,11 @@ impl UpdateTokenConfigScreen {
             });
         }
 
+        if self.change_item != TokenConfigurationChangeItem::TokenConfigurationNoChange {
+            ui.add_space(10.0);
+            self.render_config_diff(ui);
+        }
+
         let button_text = render_group_action_text(
             ui,
             &self.group,
@@ -723,7 +1116,72 @@ impl UpdateTokenConfigScreen {
             && self.change_item != TokenConfigurationChangeItem::TokenConfigurationNoChange
         {
             ui.add_space(20.0);
-            if ui.add(button).clicked() {
+            let confirmed = !changes_control_groups(&self.change_item)
+                || self.mainnet_confirmation.show(
+                    ui,
+                    &self.app_context,
+                    IrreversibleAction::ControlGroupChange,
+                    Some(&self.identity_token_info.token_alias),
+                );
+            let max_supply_valid = match &self.change_item {
+                TokenConfigurationChangeItem::MaxSupply(max_supply) => validate_max_supply(
+                    *max_supply,
+                    self.identity_token_info.token_config.base_supply(),
+                    self.known_circulating_supply,
+                )
+                .is_ok(),
+                _ => true,
+            };
+            // Invalid JSON leaves the last valid conventions in place, so its error blocks too
+            let conventions_valid = match &self.change_item {
+                TokenConfigurationChangeItem::Conventions(conventions) => {
+                    self.text_input_error.is_empty() && validate_conventions(conventions).is_ok()
+                }
+                _ => true,
+            };
+            let group_positions: Vec<GroupContractPosition> = self
+                .identity_token_info
+                .data_contract
+                .contract
+                .groups()
+                .keys()
+                .copied()
+                .collect();
+            let group_valid = match validate_referenced_group(&self.change_item, &group_positions) {
+                Ok(()) => true,
+                Err(e) => {
+                    ui.colored_label(Color32::DARK_RED, e);
+                    false
+                }
+            };
+            let clicked = ui
+                .add_enabled_ui(
+                    confirmed && max_supply_valid && conventions_valid && group_valid,
+                    |ui| {
+                        add_signing_key_guarded_button(
+                            ui,
+                            button,
+                            self.signing_key.as_ref(),
+                            &self.identity,
+                            TransactionType::TokenAction,
+                            &self.app_context,
+                        )
+                    },
+                )
+                .inner
+                .clicked();
+            self.simulation.checkbox(ui);
+            if clicked {
+                // The button is disabled without a valid key, but never panic if that changes
+                let Some(signing_key) = self.signing_key.clone() else {
+                    self.backend_message = Some((
+                        "Please select a key to sign the transaction with".to_string(),
+                        MessageType::Error,
+                        Utc::now(),
+                    ));
+                    return action;
+                };
+
                 let group_info = if self.group_action_id.is_some() {
                     self.group.as_ref().map(|(pos, _)| {
                         GroupStateTransitionInfoStatus::GroupStateTransitionInfoOtherSigner(
@@ -741,25 +1199,262 @@ impl UpdateTokenConfigScreen {
                 };
 
                 self.update_status = UpdateTokenConfigStatus::Updating(Utc::now());
-                action |= AppAction::BackendTask(BackendTask::TokenTask(Box::new(
-                    TokenTask::UpdateTokenConfig {
+                self.mainnet_confirmation.reset();
+                action |=
+                    AppAction::BackendTask(self.simulation.task(TokenTask::UpdateTokenConfig {
                         identity_token_info: Box::new(self.identity_token_info.clone()),
                         change_item: self.change_item.clone(),
-                        signing_key: self.signing_key.clone().expect("Signing key must be set"),
+                        signing_key,
                         public_note: if self.group_action_id.is_some() {
                             None
                         } else {
                             self.public_note.clone()
                         },
                         group_info,
-                    },
-                )));
+                    }));
             }
         }
 
         action
     }
 
+    fn editor_state(&self) -> ChangeEditorState {
+        ChangeEditorState {
+            change_item: self.change_item.clone(),
+            update_text: self.update_text.clone(),
+            authorized_identity_input: self.authorized_identity_input.clone(),
+            authorized_group_input: self.authorized_group_input.clone(),
+        }
+    }
+
+    fn restore_editor_state(&mut self, state: ChangeEditorState) {
+        self.change_item = state.change_item;
+        self.update_text = state.update_text;
+        self.authorized_identity_input = state.authorized_identity_input;
+        self.authorized_group_input = state.authorized_group_input;
+        self.text_input_error = match &self.change_item {
+            TokenConfigurationChangeItem::Conventions(conventions) => {
+                match serde_json::from_str::<TokenConfigurationConvention>(&self.update_text) {
+                    Ok(_) => validate_conventions(conventions).err().unwrap_or_default(),
+                    Err(e) => format!("Invalid JSON: {}", e),
+                }
+            }
+            TokenConfigurationChangeItem::PerpetualDistribution(_) => {
+                "The perpetual distribution can not be modified".to_string()
+            }
+            _ => String::new(),
+        };
+        self.paste_error = None;
+        self.update_group_based_on_change_item();
+    }
+
+    /// Undo and Redo buttons for the edits of the change, also bound to Ctrl+Z and
+    /// Ctrl+Shift+Z. Not shown while signing an existing group action.
+    fn render_edit_history(&mut self, ui: &mut Ui) {
+        if self.group_action_id.is_some() {
+            return;
+        }
+        self.edit_history.observe(self.editor_state());
+
+        // A focused text field keeps the shortcuts for its own undo
+        let (mut undo, mut redo) = if ui.ctx().wants_keyboard_input() {
+            (false, false)
+        } else {
+            ui.input_mut(|i| {
+                // Checked first, as Ctrl+Z also matches with Shift held
+                let redo = i.consume_key(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z);
+                (i.consume_key(Modifiers::COMMAND, Key::Z), redo)
+            })
+        };
+        ui.horizontal(|ui| {
+            undo |= ui
+                .add_enabled(self.edit_history.can_undo(), egui::Button::new("Undo"))
+                .on_hover_text("Undo the last edit of the change (Ctrl+Z)")
+                .clicked();
+            redo |= ui
+                .add_enabled(self.edit_history.can_redo(), egui::Button::new("Redo"))
+                .on_hover_text("Redo the last undone edit (Ctrl+Shift+Z)")
+                .clicked();
+        });
+
+        let state = if redo {
+            self.edit_history.redo()
+        } else if undo {
+            self.edit_history.undo()
+        } else {
+            None
+        };
+        if let Some(state) = state {
+            self.restore_editor_state(state);
+        }
+    }
+
+    /// Buttons to copy the change as JSON, to propose it on another token, and to paste a change
+    /// copied from another token. Pasting is not offered while signing an existing group action.
+    fn render_copy_paste_change(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let copy_id = ui.id().with("copy_token_config_change");
+            let copy_text = if recently_copied(ui.ctx(), copy_id) {
+                "✔ Copied"
+            } else {
+                "Copy Change"
+            };
+            if ui
+                .add_enabled(
+                    self.change_item != TokenConfigurationChangeItem::TokenConfigurationNoChange,
+                    egui::Button::new(copy_text),
+                )
+                .on_hover_text("Copy this change to propose it on another token")
+                .clicked()
+            {
+                match serde_json::to_string_pretty(&self.change_item) {
+                    Ok(json) => copy_with_confirmation(ui.ctx(), copy_id, json),
+                    Err(e) => self.paste_error = Some(format!("Unable to copy the change: {}", e)),
+                }
+            }
+
+            if self.group_action_id.is_none()
+                && ui
+                    .button("Paste Change")
+                    .on_hover_text("Apply a change copied from another token's update screen")
+                    .clicked()
+            {
+                self.paste_change();
+            }
+        });
+        if let Some(error) = &self.paste_error {
+            ui.colored_label(Color32::RED, error);
+        }
+    }
+
+    /// Replaces the change item with the one in the clipboard, when it fits this token
+    fn paste_change(&mut self) {
+        let pasted = arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .map_err(|e| format!("Unable to read the clipboard: {}", e))
+            .and_then(|text| {
+                serde_json::from_str::<TokenConfigurationChangeItem>(&text).map_err(|_| {
+                    "The clipboard doesn't hold a token configuration change".to_string()
+                })
+            });
+        let item = match pasted {
+            Ok(item) => item,
+            Err(e) => {
+                self.paste_error = Some(e);
+                return;
+            }
+        };
+
+        let token_config = &self.identity_token_info.token_config;
+        let group_positions: Vec<GroupContractPosition> = self
+            .identity_token_info
+            .data_contract
+            .contract
+            .groups()
+            .keys()
+            .cloned()
+            .collect();
+        if let Err(e) = check_pasted_change(
+            &item,
+            &token_config.authorized_action_takers_for_configuration_item(&item),
+            &group_positions,
+            token_config.main_control_group(),
+            token_config.base_supply(),
+            self.known_circulating_supply,
+        ) {
+            self.paste_error = Some(format!("Can't paste this change: {}", e));
+            return;
+        }
+
+        self.update_text = String::new();
+        self.text_input_error = String::new();
+        match &item {
+            TokenConfigurationChangeItem::Conventions(conventions) => {
+                self.update_text = serde_json::to_string_pretty(conventions).unwrap_or_default();
+                self.text_input_error = validate_conventions(conventions).err().unwrap_or_default();
+            }
+            TokenConfigurationChangeItem::PerpetualDistribution(_) => {
+                self.text_input_error =
+                    "The perpetual distribution can not be modified".to_string();
+            }
+            _ => {}
+        }
+        match changed_action_takers(&item) {
+            Some(AuthorizedActionTakers::Identity(id)) => {
+                self.authorized_identity_input = Some(id.to_string(Encoding::Base58));
+            }
+            Some(AuthorizedActionTakers::Group(position)) => {
+                self.authorized_group_input = Some(position.to_string());
+            }
+            _ => {}
+        }
+        self.change_item = item;
+        self.paste_error = None;
+        self.update_group_based_on_change_item();
+    }
+
+    /// Collapsible unified diff of the token configuration before and after the change
+    fn render_config_diff(&self, ui: &mut Ui) {
+        egui::CollapsingHeader::new("Review Changes")
+            .id_salt("token_config_review_changes")
+            .show(ui, |ui| {
+                let current = &self.identity_token_info.token_config;
+                let mut updated = current.clone();
+                updated.apply_token_configuration_item(self.change_item.clone());
+                let (Ok(before), Ok(after)) = (
+                    serde_json::to_string_pretty(current),
+                    serde_json::to_string_pretty(&updated),
+                ) else {
+                    ui.colored_label(Color32::RED, "Unable to serialize the token configuration");
+                    return;
+                };
+
+                let lines = diff_lines(&before, &after);
+                let changed: Vec<usize> = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, line)| !matches!(line, DiffLine::Unchanged(_)))
+                    .map(|(index, _)| index)
+                    .collect();
+                if changed.is_empty() {
+                    ui.label("This change leaves the configuration as it is.");
+                    return;
+                }
+
+                let dark_mode = ui.ctx().style().visuals.dark_mode;
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        let mut skipped = false;
+                        for (index, line) in lines.iter().enumerate() {
+                            let near_change = changed.iter().any(|&changed_index| {
+                                changed_index.abs_diff(index) <= DIFF_CONTEXT_LINES
+                            });
+                            if !near_change {
+                                if !skipped {
+                                    ui.label(RichText::new("⋯").monospace().weak());
+                                    skipped = true;
+                                }
+                                continue;
+                            }
+                            skipped = false;
+                            let (text, color) = match line {
+                                DiffLine::Unchanged(text) => {
+                                    (format!("  {}", text), DashColors::text_secondary(dark_mode))
+                                }
+                                DiffLine::Removed(text) => {
+                                    (format!("- {}", text), DashColors::ERROR)
+                                }
+                                DiffLine::Added(text) => {
+                                    (format!("+ {}", text), DashColors::SUCCESS)
+                                }
+                            };
+                            ui.label(RichText::new(text).monospace().color(color));
+                        }
+                    });
+            });
+    }
+
     /* ===================================================================== */
     /* Helper: render AuthorizedActionTakers editor                          */
     /* ===================================================================== */
@@ -817,8 +1512,10 @@ impl UpdateTokenConfigScreen {
                         )
                         .clicked()
                     {
-                        *takers = AuthorizedActionTakers::Group(0);
-                        authorized_group_input.get_or_insert_with(|| "0".to_owned());
+                        // Start from a group the contract has
+                        let position = data_contract.groups().keys().next().copied().unwrap_or(0);
+                        *takers = AuthorizedActionTakers::Group(position);
+                        *authorized_group_input = Some(position.to_string());
                     }
                 });
 
@@ -856,27 +1553,153 @@ impl UpdateTokenConfigScreen {
                 }
             }
 
-            let contract_group_positions: Vec<u16> =
-                data_contract.groups().keys().cloned().collect();
             if let AuthorizedActionTakers::Group(g) = takers {
                 authorized_group_input.get_or_insert_with(|| g.to_string());
+                let groups = data_contract.groups();
+                let selected_text = match groups.get(&*g) {
+                    Some(group) => group_option_label(*g, group),
+                    None => format!("Group {} (not in this contract)", g),
+                };
                 egui::ComboBox::from_id_salt("group_position_selector")
-                    .selected_text(format!(
-                        "Group Position: {}",
-                        authorized_group_input.as_deref().unwrap_or(&g.to_string())
-                    ))
+                    .selected_text(selected_text)
+                    .width(320.0)
                     .show_ui(ui, |ui| {
-                        for position in &contract_group_positions {
-                            if ui
-                                .selectable_value(g, *position, format!("Group {}", position))
-                                .clicked()
-                            {
+                        // The typed filter lives as long as the selector is in use
+                        let filter_id = ui.make_persistent_id("group_position_filter");
+                        let mut filter = ui
+                            .data_mut(|d| d.get_temp::<String>(filter_id))
+                            .unwrap_or_default();
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut filter)
+                                .hint_text("Type to filter groups")
+                                .desired_width(300.0),
+                        );
+                        if ui.memory(|m| m.focused().is_none()) {
+                            response.request_focus();
+                        }
+
+                        let mut any_match = false;
+                        for (position, group) in groups {
+                            let label = group_option_label(*position, group);
+                            if !matches_group_filter(&label, &filter) {
+                                continue;
+                            }
+                            any_match = true;
+                            if ui.selectable_value(g, *position, label).clicked() {
                                 *authorized_group_input = Some(position.to_string());
+                                filter.clear();
                             }
                         }
+                        if !any_match {
+                            ui.label(RichText::new("No matching groups").color(Color32::GRAY));
+                        }
+                        ui.data_mut(|d| d.insert_temp(filter_id, filter));
                     });
+                if !groups.contains_key(&*g) {
+                    ui.label(RichText::new("×").color(Color32::RED).strong())
+                        .on_hover_text("This group doesn't exist in the token's contract");
+                }
+            }
+        });
+    }
+
+    /// Lists the group members with the ones who already signed the group action, fetching
+    /// them first when needed
+    fn render_group_action_signers(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let (Some(action_id), Some((group_position, group))) =
+            (self.group_action_id, self.group.clone())
+        else {
+            return action;
+        };
+        if !self.group_action_signers_requested {
+            self.group_action_signers_requested = true;
+            action = AppAction::BackendTask(BackendTask::ContractTask(Box::new(
+                ContractTask::FetchGroupActionSigners {
+                    contract_id: self.identity_token_info.data_contract.contract.id(),
+                    group_position,
+                    action_id,
+                },
+            )));
+        }
+
+        ui.add_space(5.0);
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Signing progress").strong());
+                if ui.small_button("Refresh").clicked() {
+                    self.group_action_signers = None;
+                    self.group_action_signers_requested = false;
+                    self.group_action_signers_error = None;
+                }
+            });
+            if let Some(error) = &self.group_action_signers_error {
+                ui.colored_label(Color32::DARK_RED, error);
+                return;
             }
+            let Some(signers) = &self.group_action_signers else {
+                ui.horizontal(|ui| {
+                    ui.add(egui::Spinner::new());
+                    ui.label("Loading signatures...");
+                });
+                return;
+            };
+
+            let own_id = self.identity.identity.id();
+            egui::Grid::new("group_action_signers")
+                .num_columns(3)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(RichText::new("Member").underline());
+                    ui.label(RichText::new("Power").underline());
+                    ui.label(RichText::new("Signed").underline());
+                    ui.end_row();
+                    for (member, power) in group.members() {
+                        let mut label = member.to_string(Encoding::Base58);
+                        if *member == own_id {
+                            label.push_str(" (you)");
+                        }
+                        ui.label(label);
+                        ui.label(power.to_string());
+                        if signers.contains_key(member) {
+                            ui.label(RichText::new("✔").color(Color32::DARK_GREEN));
+                        } else {
+                            ui.label("");
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            let (signed_power, finalizes) = group_signing_progress(&group, signers, &own_id);
+            ui.label(format!(
+                "Signed power: {} of {} required",
+                signed_power,
+                group.required_power()
+            ));
+            let (note, color) = if signers.contains_key(&own_id) {
+                ("You already signed this action.".to_string(), Color32::GRAY)
+            } else if finalizes {
+                (
+                    "Your signature will finalize this action.".to_string(),
+                    Color32::DARK_GREEN,
+                )
+            } else {
+                let own_power = group.members().get(&own_id).copied().unwrap_or(0);
+                (
+                    format!(
+                        "After your signature, {} more power is still needed.",
+                        group
+                            .required_power()
+                            .saturating_sub(signed_power.saturating_add(own_power))
+                    ),
+                    Color32::from_rgb(180, 100, 0),
+                )
+            };
+            ui.colored_label(color, note);
         });
+        ui.add_space(5.0);
+
+        action
     }
 
     fn show_success_screen(&self, ui: &mut Ui) -> AppAction {
@@ -925,6 +1748,34 @@ impl UpdateTokenConfigScreen {
 }
 
 impl ScreenLike for UpdateTokenConfigScreen {
+    fn refresh_on_arrival(&mut self) {
+        // Signatures may have been added while the screen was away
+        self.group_action_signers = None;
+        self.group_action_signers_requested = false;
+        self.group_action_signers_error = None;
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::GroupActionSigners { action_id, signers } =
+            &backend_task_success_result
+        {
+            if Some(*action_id) == self.group_action_id {
+                self.group_action_signers = Some(signers.clone());
+            }
+            return;
+        }
+        if let BackendTaskSuccessResult::SimulationResult {
+            transition,
+            size_bytes,
+            minimum_fee,
+        } = backend_task_success_result
+        {
+            self.simulation
+                .set_result(&transition, size_bytes, minimum_fee);
+            self.update_status = UpdateTokenConfigStatus::NotUpdating;
+        }
+    }
+
     fn display_message(&mut self, message: &str, message_type: MessageType) {
         match message_type {
             MessageType::Success => {
@@ -936,9 +1787,14 @@ impl ScreenLike for UpdateTokenConfigScreen {
             }
             MessageType::Error => {
                 self.backend_message = Some((message.to_string(), MessageType::Error, Utc::now()));
-                if message.contains("Failed to update token config") {
+                if message.contains("Failed to update token config")
+                    || message.contains("Simulation failed")
+                {
                     self.update_status = UpdateTokenConfigStatus::NotUpdating;
                 }
+                if message.contains("Error fetching group action signers") {
+                    self.group_action_signers_error = Some(message.to_string());
+                }
             }
             MessageType::Info => {
                 self.backend_message = Some((message.to_string(), MessageType::Info, Utc::now()));
@@ -996,6 +1852,7 @@ impl ScreenLike for UpdateTokenConfigScreen {
 
                 ui.heading("Update Token Configuration");
                 ui.add_space(10.0);
+                action |= render_low_balance_banner(ui, &self.app_context, &self.identity);
 
             // Check if user has any auth keys
             let has_keys = if self.app_context.is_developer_mode() {
@@ -1089,6 +1946,8 @@ impl ScreenLike for UpdateTokenConfigScreen {
                     };
                 }
 
+                self.simulation.show_result(ui);
+
                 if self.update_status != UpdateTokenConfigStatus::NotUpdating {
                     ui.add_space(10.0);
                     if let UpdateTokenConfigStatus::Updating(start_time) = &self.update_status {
@@ -1134,6 +1993,38 @@ impl ScreenWithWalletUnlock for UpdateTokenConfigScreen {
     }
 }
 
+/// Whether the change item changes who is allowed to control the token
+fn changes_control_groups(item: &TokenConfigurationChangeItem) -> bool {
+    matches!(
+        item,
+        TokenConfigurationChangeItem::MainControlGroup(_)
+            | TokenConfigurationChangeItem::ManualMinting(_)
+            | TokenConfigurationChangeItem::ManualMintingAdminGroup(_)
+            | TokenConfigurationChangeItem::ManualBurning(_)
+            | TokenConfigurationChangeItem::ManualBurningAdminGroup(_)
+            | TokenConfigurationChangeItem::Freeze(_)
+            | TokenConfigurationChangeItem::FreezeAdminGroup(_)
+            | TokenConfigurationChangeItem::Unfreeze(_)
+            | TokenConfigurationChangeItem::UnfreezeAdminGroup(_)
+            | TokenConfigurationChangeItem::DestroyFrozenFunds(_)
+            | TokenConfigurationChangeItem::DestroyFrozenFundsAdminGroup(_)
+            | TokenConfigurationChangeItem::EmergencyAction(_)
+            | TokenConfigurationChangeItem::EmergencyActionAdminGroup(_)
+            | TokenConfigurationChangeItem::ConventionsControlGroup(_)
+            | TokenConfigurationChangeItem::ConventionsAdminGroup(_)
+            | TokenConfigurationChangeItem::MaxSupplyControlGroup(_)
+            | TokenConfigurationChangeItem::MaxSupplyAdminGroup(_)
+            | TokenConfigurationChangeItem::PerpetualDistributionControlGroup(_)
+            | TokenConfigurationChangeItem::PerpetualDistributionAdminGroup(_)
+            | TokenConfigurationChangeItem::NewTokensDestinationIdentityControlGroup(_)
+            | TokenConfigurationChangeItem::NewTokensDestinationIdentityAdminGroup(_)
+            | TokenConfigurationChangeItem::MintingAllowChoosingDestinationControlGroup(_)
+            | TokenConfigurationChangeItem::MintingAllowChoosingDestinationAdminGroup(_)
+            | TokenConfigurationChangeItem::MarketplaceTradeModeControlGroup(_)
+            | TokenConfigurationChangeItem::MarketplaceTradeModeAdminGroup(_)
+    )
+}
+
 /// Returns a simple label for UI display
 fn token_change_item_label(item: &TokenConfigurationChangeItem) -> &'static str {
     match item {
@@ -1193,3 +2084,381 @@ fn token_change_item_label(item: &TokenConfigurationChangeItem) -> &'static str
         TokenConfigurationChangeItem::MainControlGroup(_) => "Main Control Group",
     }
 }
+
+/// Explains what changing `item` does, and so who ends up able to change what, for the hover
+/// text of the change item combo box
+fn token_change_item_description(item: &TokenConfigurationChangeItem) -> &'static str {
+    match item {
+        TokenConfigurationChangeItem::TokenConfigurationNoChange => {
+            "Leave the token configuration as it is."
+        }
+        TokenConfigurationChangeItem::Conventions(_) => {
+            "Change the token's names, localizations and number of decimals shown to users. \
+             Balances themselves are not affected."
+        }
+        TokenConfigurationChangeItem::ConventionsControlGroup(_) => {
+            "Change who may update the token conventions."
+        }
+        TokenConfigurationChangeItem::ConventionsAdminGroup(_) => {
+            "Change who may decide who updates the token conventions. Whoever holds this role \
+             can take control of the conventions at any time."
+        }
+        TokenConfigurationChangeItem::MaxSupply(_) => {
+            "Change the maximum number of tokens that can ever exist. Minting beyond it is \
+             rejected."
+        }
+        TokenConfigurationChangeItem::MaxSupplyControlGroup(_) => {
+            "Change who may update the max supply."
+        }
+        TokenConfigurationChangeItem::MaxSupplyAdminGroup(_) => {
+            "Change who may decide who updates the max supply."
+        }
+        TokenConfigurationChangeItem::PerpetualDistribution(_) => {
+            "The schedule releasing tokens over time. It can not be modified from this screen."
+        }
+        TokenConfigurationChangeItem::PerpetualDistributionControlGroup(_) => {
+            "Change who may update the perpetual distribution."
+        }
+        TokenConfigurationChangeItem::PerpetualDistributionAdminGroup(_) => {
+            "Change who may decide who updates the perpetual distribution."
+        }
+        TokenConfigurationChangeItem::NewTokensDestinationIdentity(_) => {
+            "Change the identity receiving newly minted tokens when the minter doesn't pick a \
+             recipient."
+        }
+        TokenConfigurationChangeItem::NewTokensDestinationIdentityControlGroup(_) => {
+            "Change who may update the identity receiving newly minted tokens."
+        }
+        TokenConfigurationChangeItem::NewTokensDestinationIdentityAdminGroup(_) => {
+            "Change who may decide who updates the identity receiving newly minted tokens."
+        }
+        TokenConfigurationChangeItem::MintingAllowChoosingDestination(_) => {
+            "Change whether minters may send newly minted tokens to any identity instead of the \
+             new-tokens destination."
+        }
+        TokenConfigurationChangeItem::MintingAllowChoosingDestinationControlGroup(_) => {
+            "Change who may allow or forbid minters choosing the destination."
+        }
+        TokenConfigurationChangeItem::MintingAllowChoosingDestinationAdminGroup(_) => {
+            "Change who may decide who allows or forbids minters choosing the destination."
+        }
+        TokenConfigurationChangeItem::ManualMinting(_) => {
+            "Change who may mint new tokens, increasing the supply."
+        }
+        TokenConfigurationChangeItem::ManualMintingAdminGroup(_) => {
+            "Change who may decide who mints new tokens."
+        }
+        TokenConfigurationChangeItem::ManualBurning(_) => {
+            "Change who may burn tokens from their own balance, decreasing the supply."
+        }
+        TokenConfigurationChangeItem::ManualBurningAdminGroup(_) => {
+            "Change who may decide who burns tokens."
+        }
+        TokenConfigurationChangeItem::Freeze(_) => {
+            "Change who may freeze an identity's tokens, preventing it from transferring them."
+        }
+        TokenConfigurationChangeItem::FreezeAdminGroup(_) => {
+            "Change who may decide who freezes tokens."
+        }
+        TokenConfigurationChangeItem::Unfreeze(_) => {
+            "Change who may unfreeze an identity's frozen tokens."
+        }
+        TokenConfigurationChangeItem::UnfreezeAdminGroup(_) => {
+            "Change who may decide who unfreezes tokens."
+        }
+        TokenConfigurationChangeItem::DestroyFrozenFunds(_) => {
+            "Change who may destroy the tokens of a frozen identity. Destroyed tokens are gone \
+             for good."
+        }
+        TokenConfigurationChangeItem::DestroyFrozenFundsAdminGroup(_) => {
+            "Change who may decide who destroys frozen tokens."
+        }
+        TokenConfigurationChangeItem::EmergencyAction(_) => {
+            "Change who may pause and resume the token, stopping every transfer while paused."
+        }
+        TokenConfigurationChangeItem::EmergencyActionAdminGroup(_) => {
+            "Change who may decide who pauses and resumes the token."
+        }
+        TokenConfigurationChangeItem::MarketplaceTradeMode(_) => {
+            "Change whether and how the token can be traded on the marketplace."
+        }
+        TokenConfigurationChangeItem::MarketplaceTradeModeControlGroup(_) => {
+            "Change who may update the marketplace trade mode."
+        }
+        TokenConfigurationChangeItem::MarketplaceTradeModeAdminGroup(_) => {
+            "Change who may decide who updates the marketplace trade mode."
+        }
+        TokenConfigurationChangeItem::MainControlGroup(_) => {
+            "Change which group acts as the \"Main Group\". Every rule set to the main group \
+             follows this choice."
+        }
+    }
+}
+
+/// An entry of the change item combo box, described on hover by
+/// [`token_change_item_description`]
+fn change_item_option(
+    ui: &mut Ui,
+    current: &mut TokenConfigurationChangeItem,
+    item: TokenConfigurationChangeItem,
+    label: &str,
+) -> egui::Response {
+    let description = token_change_item_description(&item);
+    ui.selectable_value(current, item, label)
+        .on_hover_text(description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::data_contract::associated_token::token_configuration_convention::v0::TokenConfigurationConventionV0;
+    use dash_sdk::dpp::data_contract::associated_token::token_configuration_localization::TokenConfigurationLocalization;
+    use dash_sdk::dpp::data_contract::associated_token::token_configuration_localization::v0::TokenConfigurationLocalizationV0;
+    use dash_sdk::dpp::data_contract::group::v0::GroupV0;
+
+    #[test]
+    fn test_diff_lines() {
+        let before = "{\n  \"decimals\": 8,\n  \"name\": \"a\"\n}";
+        let after = "{\n  \"decimals\": 2,\n  \"name\": \"a\",\n  \"extra\": 1\n}";
+        assert_eq!(
+            diff_lines(before, after),
+            vec![
+                DiffLine::Unchanged("{"),
+                DiffLine::Removed("  \"decimals\": 8,"),
+                DiffLine::Removed("  \"name\": \"a\""),
+                DiffLine::Added("  \"decimals\": 2,"),
+                DiffLine::Added("  \"name\": \"a\","),
+                DiffLine::Added("  \"extra\": 1"),
+                DiffLine::Unchanged("}"),
+            ]
+        );
+        assert!(
+            diff_lines(before, before)
+                .iter()
+                .all(|line| matches!(line, DiffLine::Unchanged(_)))
+        );
+    }
+
+    #[test]
+    fn test_group_signing_progress() {
+        let (alice, bob, carol) = (
+            Identifier::new([1; 32]),
+            Identifier::new([2; 32]),
+            Identifier::new([3; 32]),
+        );
+        let group = Group::V0(GroupV0 {
+            members: BTreeMap::from([(alice, 1), (bob, 1), (carol, 2)]),
+            required_power: 3,
+        });
+
+        assert_eq!(
+            group_signing_progress(&group, &BTreeMap::from([(carol, 2)]), &alice),
+            (2, true)
+        );
+        assert_eq!(
+            group_signing_progress(&group, &BTreeMap::from([(alice, 1)]), &bob),
+            (1, false)
+        );
+        // A member who already signed doesn't count twice
+        assert_eq!(
+            group_signing_progress(&group, &BTreeMap::from([(carol, 2)]), &carol),
+            (2, false)
+        );
+    }
+
+    #[test]
+    fn test_validate_referenced_group() {
+        let group_item = TokenConfigurationChangeItem::Freeze(AuthorizedActionTakers::Group(2));
+        assert!(validate_referenced_group(&group_item, &[0, 2]).is_ok());
+        assert!(validate_referenced_group(&group_item, &[0, 1]).is_err());
+        assert!(
+            validate_referenced_group(
+                &TokenConfigurationChangeItem::MainControlGroup(Some(3)),
+                &[0]
+            )
+            .is_err()
+        );
+        assert!(
+            validate_referenced_group(
+                &TokenConfigurationChangeItem::Freeze(AuthorizedActionTakers::ContractOwner),
+                &[]
+            )
+            .is_ok()
+        );
+
+        assert!(matches_group_filter(
+            "Group 12 (3 members, 2 power required)",
+            " 3 MEMBERS"
+        ));
+        assert!(!matches_group_filter(
+            "Group 12 (3 members, 2 power required)",
+            "group 4"
+        ));
+    }
+
+    #[test]
+    fn test_check_pasted_change() {
+        let owner = AuthorizedActionTakers::ContractOwner;
+        let group_item = TokenConfigurationChangeItem::Freeze(AuthorizedActionTakers::Group(1));
+        assert!(check_pasted_change(&group_item, &owner, &[0, 1], None, 0, None).is_ok());
+        assert!(
+            check_pasted_change(&group_item, &owner, &[0], None, 0, None)
+                .unwrap_err()
+                .contains("group 1")
+        );
+        assert!(
+            check_pasted_change(
+                &group_item,
+                &AuthorizedActionTakers::NoOne,
+                &[1],
+                None,
+                0,
+                None
+            )
+            .is_err()
+        );
+
+        let main_group_item =
+            TokenConfigurationChangeItem::ManualMinting(AuthorizedActionTakers::MainGroup);
+        assert!(check_pasted_change(&main_group_item, &owner, &[0], Some(0), 0, None).is_ok());
+        assert!(check_pasted_change(&main_group_item, &owner, &[0], None, 0, None).is_err());
+        assert!(
+            check_pasted_change(
+                &TokenConfigurationChangeItem::MainControlGroup(Some(2)),
+                &owner,
+                &[0],
+                Some(0),
+                0,
+                None
+            )
+            .is_err()
+        );
+
+        let max_supply = TokenConfigurationChangeItem::MaxSupply(Some(500));
+        assert!(check_pasted_change(&max_supply, &owner, &[], None, 100, None).is_ok());
+        assert!(check_pasted_change(&max_supply, &owner, &[], None, 1_000, None).is_err());
+        assert!(
+            check_pasted_change(
+                &TokenConfigurationChangeItem::TokenConfigurationNoChange,
+                &owner,
+                &[],
+                None,
+                0,
+                None
+            )
+            .is_err()
+        );
+    }
+
+    fn conventions(decimals: u8, names: &[(&str, &str, &str)]) -> TokenConfigurationConvention {
+        TokenConfigurationConvention::V0(TokenConfigurationConventionV0 {
+            localizations: names
+                .iter()
+                .map(|(language, singular, plural)| {
+                    (
+                        language.to_string(),
+                        TokenConfigurationLocalization::V0(TokenConfigurationLocalizationV0 {
+                            should_capitalize: true,
+                            singular_form: singular.to_string(),
+                            plural_form: plural.to_string(),
+                        }),
+                    )
+                })
+                .collect(),
+            decimals,
+        })
+    }
+
+    #[test]
+    fn test_validate_conventions() {
+        assert!(validate_conventions(&conventions(8, &[("en", "Acme", "Acmes")])).is_ok());
+        assert!(
+            validate_conventions(&conventions(
+                8,
+                &[("en", "Acme", "Acmes"), ("es", "Acme", "Acmes")]
+            ))
+            .is_ok()
+        );
+        // English is required
+        assert!(validate_conventions(&conventions(8, &[("es", "Acme", "Acmes")])).is_err());
+        assert_eq!(
+            validate_conventions(&conventions(8, &[("en", "Acme", "Ac")])),
+            Err("The plural form in \"en\" must be between 3 and 50 characters".to_string())
+        );
+        let long_name = "a".repeat(51);
+        assert!(validate_conventions(&conventions(8, &[("en", &long_name, "Acmes")])).is_err());
+    }
+
+    #[test]
+    fn test_decimals_change_warning() {
+        let current = conventions(8, &[("en", "Acme", "Acmes")]);
+        assert_eq!(decimals_change_warning(&current, &current), None);
+        assert_eq!(
+            decimals_change_warning(&conventions(2, &[("en", "Acme", "Acmes")]), &current),
+            Some(
+                "⚠ Changing the decimals from 8 to 2 reinterprets every existing balance. \
+                 A balance of 1 token would read as 1000000."
+                    .to_string()
+            )
+        );
+        // No example when the amounts don't fit a u64
+        assert_eq!(
+            decimals_change_warning(&conventions(30, &[("en", "Acme", "Acmes")]), &current),
+            Some(
+                "⚠ Changing the decimals from 8 to 30 reinterprets every existing balance."
+                    .to_string()
+            )
+        );
+    }
+
+    fn editor_state(max_supply: Option<TokenAmount>, text: &str) -> ChangeEditorState {
+        ChangeEditorState {
+            change_item: TokenConfigurationChangeItem::MaxSupply(max_supply),
+            update_text: text.to_string(),
+            authorized_identity_input: None,
+            authorized_group_input: None,
+        }
+    }
+
+    #[test]
+    fn test_edit_history() {
+        let mut history = EditHistory::default();
+        history.observe(editor_state(None, ""));
+        assert!(!history.can_undo());
+
+        // Only a new change item counts as an edit
+        history.observe(editor_state(None, "typing"));
+        assert!(!history.can_undo());
+        history.observe(editor_state(Some(1), ""));
+        history.observe(editor_state(Some(2), ""));
+
+        assert_eq!(history.undo(), Some(editor_state(Some(1), "")));
+        assert_eq!(history.undo(), Some(editor_state(None, "typing")));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(editor_state(Some(1), "")));
+
+        // An edit after an undo drops what could be redone
+        history.observe(editor_state(Some(1), ""));
+        history.observe(editor_state(Some(3), ""));
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(editor_state(Some(1), "")));
+
+        // The history is capped, dropping the oldest edits
+        for supply in 0..(MAX_EDIT_HISTORY as u64 * 2) {
+            history.observe(editor_state(Some(supply), ""));
+        }
+        let mut undone = 0;
+        while history.undo().is_some() {
+            undone += 1;
+        }
+        assert_eq!(undone, MAX_EDIT_HISTORY);
+    }
+
+    #[test]
+    fn test_validate_max_supply() {
+        assert!(validate_max_supply(None, 1_000, Some(5_000)).is_ok());
+        assert!(validate_max_supply(Some(5_000), 1_000, Some(5_000)).is_ok());
+        assert!(validate_max_supply(Some(999), 1_000, None).is_err());
+        assert!(validate_max_supply(Some(4_999), 1_000, Some(5_000)).is_err());
+    }
+}
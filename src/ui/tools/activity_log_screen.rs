@@ -0,0 +1,432 @@
+use crate::app::AppAction;
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use crate::model::activity_log_item::ActivityLogItem;
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::tools_subscreen_chooser_panel::add_tools_subscreen_chooser_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::{
+    DialogKeyAction, TimestampStyle, dialog_key_action, format_timestamp_millis,
+};
+use crate::ui::theme::DashColors;
+use crate::ui::{MessageType, RootScreenType, ScreenLike};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Context, Ui};
+use egui::RichText;
+use egui_extras::{Column, TableBuilder};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// Dialogs of the activity log screen asking for confirmation
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PendingConfirmation {
+    RunAgain(i64),
+    ClearLog,
+}
+
+/// Screen listing the state transitions submitted from the app, across sessions.
+pub struct ActivityLogScreen {
+    pub(crate) app_context: Arc<AppContext>,
+    items: Vec<ActivityLogItem>,
+    /// Names of the local identities, to label the entries they signed
+    identity_names: BTreeMap<Identifier, String>,
+    action_filter: Option<String>,
+    identity_filter: Option<Identifier>,
+    selected_item: Option<i64>,
+    pending_confirmation: Option<PendingConfirmation>,
+    /// Whether an action submitted again from this screen has not finished yet
+    run_again_pending: bool,
+    message: Option<(String, MessageType)>,
+}
+
+impl ActivityLogScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            items: Vec::new(),
+            identity_names: BTreeMap::new(),
+            action_filter: None,
+            identity_filter: None,
+            selected_item: None,
+            pending_confirmation: None,
+            run_again_pending: false,
+            message: None,
+        };
+        screen.load_items();
+        screen
+    }
+
+    fn load_items(&mut self) {
+        match self
+            .app_context
+            .db
+            .get_activity_log_items(self.app_context.network)
+        {
+            Ok(items) => self.items = items,
+            Err(e) => {
+                self.message = Some((
+                    format!("Error loading the activity log: {}", e),
+                    MessageType::Error,
+                ))
+            }
+        }
+        self.identity_names = self
+            .app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|identity| (identity.identity.id(), identity.display_short_string()))
+            .collect();
+    }
+
+    fn identity_label(&self, identity_id: &Identifier) -> String {
+        self.identity_names
+            .get(identity_id)
+            .cloned()
+            .unwrap_or_else(|| identity_id.to_string(Encoding::Base58))
+    }
+
+    fn matches_filters(&self, item: &ActivityLogItem) -> bool {
+        self.action_filter
+            .as_ref()
+            .is_none_or(|action| *action == item.action)
+            && self
+                .identity_filter
+                .is_none_or(|identity_id| item.identity_id == Some(identity_id))
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        let actions: BTreeSet<String> = self.items.iter().map(|item| item.action.clone()).collect();
+        let identities: Vec<(Identifier, String)> = self
+            .items
+            .iter()
+            .filter_map(|item| item.identity_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|identity_id| (identity_id, self.identity_label(&identity_id)))
+            .collect();
+
+        ui.horizontal(|ui| {
+            ui.label("Type:");
+            egui::ComboBox::from_id_salt("activity_log_action_filter")
+                .selected_text(self.action_filter.as_deref().unwrap_or("All"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.action_filter, None, "All");
+                    for action in actions {
+                        let label = action.clone();
+                        ui.selectable_value(&mut self.action_filter, Some(action), label);
+                    }
+                });
+
+            ui.add_space(10.0);
+            ui.label("Identity:");
+            let selected_identity = self
+                .identity_filter
+                .map(|identity_id| self.identity_label(&identity_id))
+                .unwrap_or_else(|| "All".to_string());
+            egui::ComboBox::from_id_salt("activity_log_identity_filter")
+                .selected_text(selected_identity)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.identity_filter, None, "All");
+                    for (identity_id, label) in identities {
+                        ui.selectable_value(&mut self.identity_filter, Some(identity_id), label);
+                    }
+                });
+
+            ui.add_space(10.0);
+            if ui.button("Refresh").clicked() {
+                self.load_items();
+            }
+            if ui
+                .add_enabled(!self.items.is_empty(), egui::Button::new("Clear Log"))
+                .clicked()
+            {
+                self.pending_confirmation = Some(PendingConfirmation::ClearLog);
+            }
+        });
+
+        if let Some((message, message_type)) = &self.message {
+            let color = match message_type {
+                MessageType::Error => DashColors::ERROR,
+                MessageType::Success => DashColors::SUCCESS,
+                MessageType::Info => DashColors::text_primary(ui.ctx().style().visuals.dark_mode),
+            };
+            ui.colored_label(color, message);
+        }
+    }
+
+    fn render_items(&mut self, ui: &mut Ui) {
+        let items: Vec<&ActivityLogItem> = self
+            .items
+            .iter()
+            .filter(|item| self.matches_filters(item))
+            .collect();
+        if items.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label("No submitted actions match the filters.");
+            });
+            return;
+        }
+
+        let developer_mode = self.app_context.is_developer_mode();
+        let mut clicked = None;
+        TableBuilder::new(ui)
+            .id_salt("activity_log_table")
+            .striped(true)
+            .resizable(true)
+            .sense(egui::Sense::click())
+            .max_scroll_height(300.0)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::initial(170.0))
+            .column(Column::initial(160.0))
+            .column(Column::initial(150.0))
+            .column(Column::initial(220.0))
+            .column(Column::remainder())
+            .header(25.0, |mut header| {
+                for title in ["Time", "Type", "Identity", "Target", "Result"] {
+                    header.col(|ui| {
+                        ui.heading(title);
+                    });
+                }
+            })
+            .body(|mut body| {
+                for item in items {
+                    body.row(25.0, |mut row| {
+                        row.set_selected(self.selected_item == Some(item.id));
+                        row.col(|ui| {
+                            ui.label(format_timestamp_millis(
+                                item.time_ms,
+                                TimestampStyle::Absolute,
+                                developer_mode,
+                            ));
+                        });
+                        row.col(|ui| {
+                            ui.label(&item.action);
+                        });
+                        row.col(|ui| {
+                            ui.label(
+                                item.identity_id
+                                    .map(|identity_id| self.identity_label(&identity_id))
+                                    .unwrap_or_default(),
+                            );
+                        });
+                        row.col(|ui| {
+                            let target = item.target.as_deref().unwrap_or_default();
+                            ui.add(egui::Label::new(target).truncate())
+                                .on_hover_text(target);
+                        });
+                        row.col(|ui| match &item.error {
+                            Some(error) => {
+                                ui.colored_label(DashColors::ERROR, "Failed")
+                                    .on_hover_text(error);
+                            }
+                            None => {
+                                ui.colored_label(DashColors::SUCCESS, "Succeeded");
+                            }
+                        });
+                        if row.response().clicked() {
+                            clicked = Some(item.id);
+                        }
+                    });
+                }
+            });
+        if clicked.is_some() {
+            self.selected_item = clicked;
+        }
+    }
+
+    fn render_selected_item(&mut self, ui: &mut Ui) {
+        let Some(item) = self
+            .selected_item
+            .and_then(|id| self.items.iter().find(|item| item.id == id))
+        else {
+            return;
+        };
+
+        ui.separator();
+        ui.heading(&item.action);
+        egui::Grid::new("activity_log_details")
+            .num_columns(2)
+            .spacing([10.0, 5.0])
+            .show(ui, |ui| {
+                ui.label("Time:");
+                ui.label(format_timestamp_millis(
+                    item.time_ms,
+                    TimestampStyle::AbsoluteAndRelative,
+                    self.app_context.is_developer_mode(),
+                ));
+                ui.end_row();
+                if let Some(identity_id) = item.identity_id {
+                    ui.label("Identity:");
+                    ui.label(identity_id.to_string(Encoding::Base58));
+                    ui.end_row();
+                }
+                if let Some(target) = &item.target {
+                    ui.label("Target:");
+                    ui.label(target);
+                    ui.end_row();
+                }
+                ui.label("Parameters:");
+                ui.add(egui::Label::new(item.parameters.as_str()).wrap());
+                ui.end_row();
+                ui.label("Result:");
+                match &item.error {
+                    Some(error) => ui.colored_label(DashColors::ERROR, error),
+                    None => ui.colored_label(DashColors::SUCCESS, "Succeeded"),
+                };
+                ui.end_row();
+            });
+
+        ui.add_space(5.0);
+        let can_run_again = self.app_context.activity_task(item.id).is_some();
+        let item_id = item.id;
+        let parameters = item.parameters.clone();
+        ui.horizontal(|ui| {
+            if ui.button("Copy Parameters").clicked() {
+                ui.ctx().copy_text(parameters);
+            }
+            if ui
+                .add_enabled(can_run_again, egui::Button::new("Run Again"))
+                .on_disabled_hover_text(
+                    "Only actions submitted since the app started can be run again",
+                )
+                .clicked()
+            {
+                self.pending_confirmation = Some(PendingConfirmation::RunAgain(item_id));
+            }
+        });
+    }
+
+    fn render_confirmation(&mut self, ctx: &Context) -> AppAction {
+        let Some(pending) = self.pending_confirmation else {
+            return AppAction::None;
+        };
+        let (title, text, confirm) = match pending {
+            PendingConfirmation::RunAgain(_) => (
+                "Run Again",
+                "This submits the action again with the same parameters, as a new state \
+                 transition. Continue?",
+                "Run Again",
+            ),
+            PendingConfirmation::ClearLog => (
+                "Clear Activity Log",
+                "Delete every entry of the activity log of this network?",
+                "Clear",
+            ),
+        };
+
+        let mut action = AppAction::None;
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let key_action = dialog_key_action(ui.ctx());
+                ui.label(text);
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Cancel").clicked() || key_action == DialogKeyAction::Cancel {
+                        self.pending_confirmation = None;
+                    }
+                    if ui.button(confirm).clicked() || key_action == DialogKeyAction::Confirm {
+                        self.pending_confirmation = None;
+                        action = self.confirm(pending);
+                    }
+                });
+            });
+        action
+    }
+
+    fn confirm(&mut self, pending: PendingConfirmation) -> AppAction {
+        match pending {
+            PendingConfirmation::RunAgain(id) => match self.app_context.activity_task(id) {
+                Some(task) => {
+                    self.message = Some((
+                        "Submitting the action again...".to_string(),
+                        MessageType::Info,
+                    ));
+                    self.run_again_pending = true;
+                    AppAction::BackendTask(task)
+                }
+                None => AppAction::None,
+            },
+            PendingConfirmation::ClearLog => {
+                match self.app_context.clear_activity_log() {
+                    Ok(()) => {
+                        self.selected_item = None;
+                        self.message = None;
+                    }
+                    Err(e) => {
+                        self.message = Some((
+                            format!("Error clearing the activity log: {}", e),
+                            MessageType::Error,
+                        ))
+                    }
+                }
+                self.load_items();
+                AppAction::None
+            }
+        }
+    }
+}
+
+impl ScreenLike for ActivityLogScreen {
+    fn refresh(&mut self) {
+        self.load_items();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type));
+        self.run_again_pending = false;
+        // The action that just finished was recorded, successful or not
+        self.load_items();
+    }
+
+    fn display_task_result(&mut self, _backend_task_success_result: BackendTaskSuccessResult) {
+        if self.run_again_pending {
+            self.display_message("The action was submitted again", MessageType::Success);
+        } else {
+            self.load_items();
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![("Tools", AppAction::None)],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            RootScreenType::RootScreenToolsActivityLogScreen,
+        );
+
+        action |= add_tools_subscreen_chooser_panel(ctx, self.app_context.as_ref());
+
+        action |= island_central_panel(ctx, |ui| {
+            ui.heading("Activity Log");
+            ui.label(
+                RichText::new(
+                    "State transitions submitted from this app on this network, newest first.",
+                )
+                .weak(),
+            );
+            ui.separator();
+            self.render_controls(ui);
+            ui.add_space(5.0);
+            self.render_items(ui);
+            self.render_selected_item(ui);
+            AppAction::None
+        });
+
+        action |= self.render_confirmation(ctx);
+
+        action
+    }
+}
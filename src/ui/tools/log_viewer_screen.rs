@@ -0,0 +1,240 @@
+use crate::app::AppAction;
+use crate::app_dir::app_user_data_file_path;
+use crate::context::AppContext;
+use crate::logging::{LOG_FILE_NAME, LogEntry, log_buffer};
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::tools_subscreen_chooser_panel::add_tools_subscreen_chooser_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::theme::DashColors;
+use crate::ui::{MessageType, RootScreenType, ScreenLike};
+use eframe::egui::{self, Context, ScrollArea, TextStyle, Ui};
+use egui::{Color32, RichText};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::Level;
+
+/// How often the displayed entries are refreshed while new logs keep coming in
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// Screen that shows the recent application logs, filterable by level and module.
+pub struct LogViewerScreen {
+    pub(crate) app_context: Arc<AppContext>,
+    max_level: Level,
+    module_filter: String,
+    follow: bool,
+    entries: Vec<LogEntry>,
+    /// Buffer version and filter the entries were loaded with
+    loaded_with: Option<(u64, Level, String)>,
+    last_refresh: Instant,
+    message: Option<(String, MessageType)>,
+}
+
+impl LogViewerScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            max_level: Level::INFO,
+            module_filter: String::new(),
+            follow: true,
+            entries: Vec::new(),
+            loaded_with: None,
+            last_refresh: Instant::now(),
+            message: None,
+        }
+    }
+
+    /// Reloads the entries when the filter changed right away, and when new logs arrived at
+    /// most every [`REFRESH_INTERVAL`].
+    fn refresh_entries(&mut self) {
+        let version = log_buffer().version();
+        let filter_changed = match &self.loaded_with {
+            Some((_, level, module)) => *level != self.max_level || *module != self.module_filter,
+            None => true,
+        };
+        let logs_changed = self
+            .loaded_with
+            .as_ref()
+            .is_some_and(|(loaded_version, _, _)| *loaded_version != version);
+        if !filter_changed && !(logs_changed && self.last_refresh.elapsed() >= REFRESH_INTERVAL) {
+            return;
+        }
+
+        self.entries = log_buffer().filtered(self.max_level, &self.module_filter);
+        self.loaded_with = Some((version, self.max_level, self.module_filter.clone()));
+        self.last_refresh = Instant::now();
+    }
+
+    fn entries_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(LogEntry::to_line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn export_entries(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("det-logs.txt")
+            .add_filter("Text", &["txt", "log"])
+            .save_file()
+        else {
+            return;
+        };
+
+        self.message = Some(match std::fs::write(&path, self.entries_text()) {
+            Ok(()) => (
+                format!(
+                    "Exported {} log entries to {}",
+                    self.entries.len(),
+                    path.display()
+                ),
+                MessageType::Success,
+            ),
+            Err(e) => (format!("Failed to export logs: {}", e), MessageType::Error),
+        });
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Level:");
+            egui::ComboBox::from_id_salt("log_viewer_level")
+                .selected_text(self.max_level.as_str())
+                .show_ui(ui, |ui| {
+                    for level in LEVELS {
+                        ui.selectable_value(&mut self.max_level, level, level.as_str());
+                    }
+                });
+
+            ui.add_space(10.0);
+            ui.label("Module:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.module_filter)
+                    .hint_text("e.g. dash_sdk")
+                    .desired_width(200.0),
+            );
+
+            ui.add_space(10.0);
+            ui.checkbox(&mut self.follow, "Follow");
+
+            ui.add_space(10.0);
+            if ui.button("Copy").clicked() {
+                ui.ctx().copy_text(self.entries_text());
+                self.message = Some((
+                    format!("Copied {} log entries", self.entries.len()),
+                    MessageType::Info,
+                ));
+            }
+            if ui.button("Export").clicked() {
+                self.export_entries();
+            }
+            if ui.button("Clear").clicked() {
+                log_buffer().clear();
+                self.loaded_with = None;
+                self.message = None;
+            }
+        });
+
+        if let Ok(path) = app_user_data_file_path(LOG_FILE_NAME) {
+            ui.label(
+                RichText::new(format!("Full log file: {}", path.display()))
+                    .small()
+                    .weak(),
+            );
+        }
+
+        if let Some((message, message_type)) = &self.message {
+            let color = match message_type {
+                MessageType::Error => DashColors::ERROR,
+                MessageType::Success => DashColors::SUCCESS,
+                MessageType::Info => DashColors::text_primary(ui.ctx().style().visuals.dark_mode),
+            };
+            ui.colored_label(color, message);
+        }
+    }
+
+    fn render_entries(&self, ui: &mut Ui) {
+        if self.entries.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label("No log entries match the filter.");
+            });
+            return;
+        }
+
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let row_height = ui.text_style_height(&TextStyle::Monospace);
+        ScrollArea::both()
+            .id_salt("log_viewer_entries")
+            .auto_shrink([false, false])
+            .stick_to_bottom(self.follow)
+            .show_rows(ui, row_height, self.entries.len(), |ui, range| {
+                for entry in &self.entries[range] {
+                    ui.add(
+                        egui::Label::new(
+                            RichText::new(entry.to_line())
+                                .monospace()
+                                .color(level_color(entry.level, dark_mode)),
+                        )
+                        .extend(),
+                    );
+                }
+            });
+    }
+}
+
+fn level_color(level: Level, dark_mode: bool) -> Color32 {
+    match level {
+        Level::ERROR => DashColors::ERROR,
+        Level::WARN => DashColors::WARNING,
+        Level::INFO => DashColors::text_primary(dark_mode),
+        _ => DashColors::text_secondary(dark_mode),
+    }
+}
+
+impl ScreenLike for LogViewerScreen {
+    fn refresh(&mut self) {
+        self.loaded_with = None;
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![("Tools", AppAction::None)],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            RootScreenType::RootScreenToolsLogViewerScreen,
+        );
+
+        action |= add_tools_subscreen_chooser_panel(ctx, self.app_context.as_ref());
+
+        self.refresh_entries();
+
+        action |= island_central_panel(ctx, |ui| {
+            ui.heading("Logs");
+            ui.separator();
+            self.render_controls(ui);
+            ui.add_space(5.0);
+            self.render_entries(ui);
+            AppAction::None
+        });
+
+        // Keep picking up new entries while the screen is open
+        ctx.request_repaint_after(REFRESH_INTERVAL);
+
+        action
+    }
+}
@@ -1,5 +1,7 @@
+pub mod activity_log_screen;
 pub mod contract_visualizer_screen;
 pub mod document_visualizer_screen;
+pub mod log_viewer_screen;
 pub mod platform_info_screen;
 pub mod proof_log_screen;
 pub mod proof_visualizer_screen;
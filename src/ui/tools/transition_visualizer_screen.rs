@@ -21,6 +21,57 @@ use serde_json::Value;
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+/// Human-readable overview of a decoded state transition.
+struct TransitionSummary {
+    name: String,
+    owner_id: Identifier,
+    user_fee_increase: u16,
+    signature_public_key_id: Option<u32>,
+    is_signed: bool,
+    transaction_id: Option<String>,
+    size: usize,
+    /// Problems that would make the transition fail or that the user should know about
+    warnings: Vec<String>,
+}
+
+impl TransitionSummary {
+    fn new(state_transition: &StateTransition, size: usize, app_context: &AppContext) -> Self {
+        let owner_id = state_transition.owner_id();
+        let is_signed = state_transition
+            .signature()
+            .is_some_and(|signature| !signature.is_empty());
+        let signature_public_key_id = state_transition.signature_public_key_id();
+
+        let mut warnings = Vec::new();
+        if !is_signed {
+            warnings.push("The transition is not signed and will be rejected.".to_string());
+        }
+        if signature_public_key_id.is_some()
+            && app_context
+                .get_identity_by_id(&owner_id)
+                .ok()
+                .flatten()
+                .is_none()
+        {
+            warnings.push(
+                "The owner identity is not loaded in DET, so its keys can't be checked locally."
+                    .to_string(),
+            );
+        }
+
+        Self {
+            name: state_transition.name(),
+            owner_id,
+            user_fee_increase: state_transition.user_fee_increase(),
+            signature_public_key_id,
+            is_signed,
+            transaction_id: state_transition.transaction_id().ok().map(hex::encode),
+            size,
+            warnings,
+        }
+    }
+}
+
 #[derive(PartialEq)]
 enum TransitionBroadcastStatus {
     NotStarted,
@@ -33,6 +84,8 @@ pub struct TransitionVisualizerScreen {
     pub app_context: Arc<AppContext>,
     input_data: String,
     parsed_json: Option<String>,
+    parsed_transition: Option<StateTransition>,
+    summary: Option<TransitionSummary>,
     broadcast_status: TransitionBroadcastStatus,
     show_contract_dialog: bool,
     selected_contract_id: Option<String>,
@@ -46,6 +99,8 @@ impl TransitionVisualizerScreen {
             app_context: app_context.clone(),
             input_data: String::new(),
             parsed_json: None,
+            parsed_transition: None,
+            summary: None,
             broadcast_status: TransitionBroadcastStatus::NotStarted,
             show_contract_dialog: false,
             selected_contract_id: None,
@@ -84,6 +139,8 @@ impl TransitionVisualizerScreen {
     fn parse_input(&mut self) {
         // Clear previous parse results...
         self.parsed_json = None;
+        self.parsed_transition = None;
+        self.summary = None;
         self.detected_contract_ids.clear();
 
         // Reset the broadcast status so we no longer show old errors
@@ -113,8 +170,16 @@ impl TransitionVisualizerScreen {
                 // Try to deserialize into a StateTransition
                 match StateTransition::deserialize_from_bytes(&bytes) {
                     Ok(state_transition) => {
+                        self.summary = Some(TransitionSummary::new(
+                            &state_transition,
+                            bytes.len(),
+                            &self.app_context,
+                        ));
+
                         // Convert to JSON
-                        match serde_json::to_string_pretty(&state_transition) {
+                        let json = serde_json::to_string_pretty(&state_transition);
+                        self.parsed_transition = Some(state_transition);
+                        match json {
                             Ok(json) => {
                                 self.parsed_json = Some(json.clone());
 
@@ -169,6 +234,49 @@ impl TransitionVisualizerScreen {
         }
     }
 
+    fn show_summary(ui: &mut Ui, summary: &TransitionSummary) {
+        ui.group(|ui| {
+            egui::Grid::new("transition_summary_grid")
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Type:");
+                    ui.label(RichText::new(&summary.name).strong());
+                    ui.end_row();
+
+                    ui.label("Owner identity:");
+                    ui.label(summary.owner_id.to_string(Encoding::Base58));
+                    ui.end_row();
+
+                    ui.label("User fee increase:");
+                    ui.label(format!("{}%", summary.user_fee_increase));
+                    ui.end_row();
+
+                    ui.label("Signed:");
+                    ui.label(match (summary.is_signed, summary.signature_public_key_id) {
+                        (true, Some(key_id)) => format!("Yes, with key {}", key_id),
+                        (true, None) => "Yes".to_string(),
+                        (false, _) => "No".to_string(),
+                    });
+                    ui.end_row();
+
+                    if let Some(transaction_id) = &summary.transaction_id {
+                        ui.label("Transaction ID:");
+                        ui.label(transaction_id);
+                        ui.end_row();
+                    }
+
+                    ui.label("Size:");
+                    ui.label(format!("{} bytes", summary.size));
+                    ui.end_row();
+                });
+
+            for warning in &summary.warnings {
+                ui.colored_label(Color32::DARK_RED, format!("⚠ {}", warning));
+            }
+        });
+    }
+
     fn show_output(&mut self, ui: &mut Ui) -> AppAction {
         let mut app_action = AppAction::None;
 
@@ -176,6 +284,11 @@ impl TransitionVisualizerScreen {
         ui.add_space(10.0);
         ui.label("Parsed State Transition:");
 
+        if let Some(summary) = &self.summary {
+            ui.add_space(5.0);
+            Self::show_summary(ui, summary);
+        }
+
         // Show detected contract IDs if any
         if !self.detected_contract_ids.is_empty() {
             ui.add_space(5.0);
@@ -233,20 +346,24 @@ impl TransitionVisualizerScreen {
                     .frame(true)
                     .corner_radius(3.0);
 
-                    if ui.add(button).clicked() {
-                        // Mark as submitting
-                        let now = SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .expect("Time went backwards")
-                            .as_secs();
-                        self.broadcast_status = TransitionBroadcastStatus::Submitting(now);
-
-                        if let Some(json) = &self.parsed_json {
-                            if let Ok(state_transition) = serde_json::from_str(json) {
-                                app_action = AppAction::BackendTask(
-                                    BackendTask::BroadcastStateTransition(state_transition),
-                                );
-                            }
+                    // Broadcasting arbitrary transitions is a developer-only troubleshooting aid
+                    let developer_mode = self.app_context.is_developer_mode();
+                    let response = ui.add_enabled(developer_mode, button);
+                    if !developer_mode {
+                        response.on_disabled_hover_text(
+                            "Enable developer mode in the network settings to broadcast",
+                        );
+                    } else if response.clicked() {
+                        if let Some(state_transition) = self.parsed_transition.clone() {
+                            // Mark as submitting
+                            let now = SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .expect("Time went backwards")
+                                .as_secs();
+                            self.broadcast_status = TransitionBroadcastStatus::Submitting(now);
+                            app_action = AppAction::BackendTask(
+                                BackendTask::BroadcastStateTransition(state_transition),
+                            );
                         }
                     }
                 }
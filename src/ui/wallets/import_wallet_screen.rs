@@ -2,6 +2,7 @@ use crate::app::AppAction;
 use crate::context::AppContext;
 use crate::ui::ScreenLike;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::password_strength::PasswordStrengthMeter;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use eframe::egui::Context;
@@ -19,7 +20,6 @@ use dash_sdk::dpp::dashcore::bip32::{ExtendedPrivKey, ExtendedPubKey};
 use egui::{Color32, ComboBox, Direction, Grid, Layout, RichText, Stroke, Ui, Vec2};
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, RwLock};
-use zxcvbn::zxcvbn;
 
 pub struct ImportWalletScreen {
     seed_phrase_words: Vec<String>,
@@ -27,8 +27,7 @@ pub struct ImportWalletScreen {
     seed_phrase: Option<Mnemonic>,
     password: String,
     alias_input: String,
-    password_strength: f64,
-    estimated_time_to_crack: String,
+    password_strength: PasswordStrengthMeter,
     error: Option<String>,
     pub app_context: Arc<AppContext>,
     use_password_for_app: bool,
@@ -42,8 +41,7 @@ impl ImportWalletScreen {
             seed_phrase: None,
             password: String::new(),
             alias_input: String::new(),
-            password_strength: 0.0,
-            estimated_time_to_crack: "".to_string(),
+            password_strength: PasswordStrengthMeter::default(),
             error: None,
             app_context: app_context.clone(),
             use_password_for_app: true,
@@ -317,59 +315,12 @@ impl ScreenLike for ImportWalletScreen {
                     ui.horizontal(|ui| {
                         ui.label("Optional Password:");
                         if ui.text_edit_singleline(&mut self.password).changed() {
-                            if !self.password.is_empty() {
-                                let estimate = zxcvbn(&self.password, &[]);
-
-                                // Convert Score to u8
-                                let score_u8 = u8::from(estimate.score());
-
-                                // Use the score to determine password strength percentage
-                                self.password_strength = score_u8 as f64 * 25.0; // Since score ranges from 0 to 4
-
-                                // Get the estimated crack time in seconds
-                                let estimated_seconds = estimate.crack_times().offline_slow_hashing_1e4_per_second();
-
-                                // Format the estimated time to a human-readable string
-                                self.estimated_time_to_crack = estimated_seconds.to_string();
-                            } else {
-                                self.password_strength = 0.0;
-                                self.estimated_time_to_crack = String::new();
-                            }
+                            self.password_strength.update(&self.password);
                         }
                     });
 
                     ui.add_space(10.0);
-                    ui.horizontal(|ui| {
-                        ui.label("Password Strength:");
-
-                        // Since score ranges from 0 to 4, adjust percentage accordingly
-                        let strength_percentage = (self.password_strength / 100.0).min(1.0);
-                        let fill_color = match self.password_strength as i32 {
-                            0..=25 => Color32::from_rgb(255, 182, 193),    // Light pink
-                            26..=50 => Color32::from_rgb(255, 224, 130),   // Light yellow
-                            51..=75 => Color32::from_rgb(144, 238, 144),   // Light green
-                            _ => Color32::from_rgb(90, 200, 90),           // Medium green
-                        };
-                        ui.add(
-                            egui::ProgressBar::new(strength_percentage as f32)
-                                .desired_width(200.0)
-                                .show_percentage()
-                                .text(match self.password_strength as i32 {
-                                    0 => "None".to_string(),
-                                    1..=25 => "Very Weak".to_string(),
-                                    26..=50 => "Weak".to_string(),
-                                    51..=75 => "Strong".to_string(),
-                                    _ => "Very Strong".to_string(),
-                                })
-                                .fill(fill_color),
-                        );
-                    });
-
-                    ui.add_space(10.0);
-                    ui.label(format!(
-                        "Estimated time to crack: {}",
-                        self.estimated_time_to_crack
-                    ));
+                    self.password_strength.show(ui);
 
                     // if self.app_context.password_info.is_none() {
                     //     ui.add_space(10.0);
@@ -5,6 +5,7 @@ use crate::context::AppContext;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::table_column_widths::TableColumnWidths;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::theme::DashColors;
 use crate::ui::{MessageType, RootScreenType, ScreenLike, ScreenType};
@@ -45,6 +46,8 @@ pub struct WalletsBalancesScreen {
     refreshing: bool,
     show_rename_dialog: bool,
     rename_input: String,
+    address_column_widths: TableColumnWidths,
+    asset_lock_column_widths: TableColumnWidths,
 }
 
 pub trait DerivationPathHelpers {
@@ -134,6 +137,8 @@ impl WalletsBalancesScreen {
             refreshing: false,
             show_rename_dialog: false,
             rename_input: String::new(),
+            address_column_widths: TableColumnWidths::load(app_context, "wallet_addresses"),
+            asset_lock_column_widths: TableColumnWidths::load(app_context, "wallet_asset_locks"),
         }
     }
 
@@ -253,6 +258,8 @@ impl WalletsBalancesScreen {
 
     fn render_wallet_selection(&mut self, ui: &mut Ui) {
         let dark_mode = ui.ctx().style().visuals.dark_mode;
+        // Watch-only export asked for, and whether it goes to a file rather than the clipboard
+        let mut watch_only_export = None;
         if self.app_context.has_wallet.load(Ordering::Relaxed) {
             let wallets = &self.app_context.wallets.read().unwrap();
             let wallet_aliases: Vec<String> = wallets
@@ -305,6 +312,26 @@ impl WalletsBalancesScreen {
                         self.show_rename_dialog = true;
                         self.rename_input = wallet.alias.clone().unwrap_or_default();
                     }
+
+                    ui.menu_button("Export Watch-Only", |ui| {
+                        let network = self.app_context.network;
+                        if ui.button("Save to File...").clicked() {
+                            watch_only_export =
+                                Some((true, wallet.watch_only_descriptors(network)));
+                            ui.close_menu();
+                        }
+                        if ui.button("Copy to Clipboard").clicked() {
+                            watch_only_export =
+                                Some((false, wallet.watch_only_descriptors(network)));
+                            ui.close_menu();
+                        }
+                    })
+                    .response
+                    .on_hover_text(
+                        "Export the extended public key and descriptors to track this wallet in \
+                         another tool. No private keys are included, so the wallet doesn't need \
+                         to be unlocked.",
+                    );
                 }
 
                 // Balance and rename button on same row
@@ -324,6 +351,40 @@ impl WalletsBalancesScreen {
         } else {
             ui.label("No wallets available.");
         }
+
+        if let Some((to_file, export)) = watch_only_export {
+            if to_file {
+                self.save_watch_only_export(export);
+            } else {
+                ui.ctx().copy_text(export);
+                self.display_message(
+                    "Watch-only descriptors copied to the clipboard",
+                    MessageType::Success,
+                );
+            }
+        }
+    }
+
+    /// Asks for a file name and writes the watch-only export of the selected wallet to it.
+    fn save_watch_only_export(&mut self, export: String) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("wallet-watch-only.json")
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match std::fs::write(&path, export) {
+            Ok(()) => self.display_message(
+                &format!("Exported watch-only descriptors to {}", path.display()),
+                MessageType::Success,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to export watch-only descriptors: {}", e),
+                MessageType::Error,
+            ),
+        }
     }
 
     fn render_address_table(&mut self, ui: &mut Ui) -> AppAction {
@@ -413,16 +474,22 @@ impl WalletsBalancesScreen {
         egui::ScrollArea::both()
             .id_salt("address_table")
             .show(ui, |ui| {
+                let ctx = ui.ctx().clone();
+                let id_salt = self.address_column_widths.id_salt();
+                let widths = self
+                    .address_column_widths
+                    .initial_widths(&[100.0, 60.0, 150.0, 100.0, 60.0]);
                 TableBuilder::new(ui)
+                    .id_salt(id_salt)
                     .striped(false)
                     .resizable(true)
                     .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
                     .column(Column::auto()) // Address
-                    .column(Column::initial(100.0)) // Balance
-                    .column(Column::initial(60.0)) // UTXOs
-                    .column(Column::initial(150.0)) // Total Received
-                    .column(Column::initial(100.0)) // Type
-                    .column(Column::initial(60.0)) // Index
+                    .column(Column::initial(widths[0])) // Balance
+                    .column(Column::initial(widths[1])) // UTXOs
+                    .column(Column::initial(widths[2])) // Total Received
+                    .column(Column::initial(widths[3])) // Type
+                    .column(Column::initial(widths[4])) // Index
                     .column(Column::remainder()) // Derivation Path
                     .header(30.0, |mut header| {
                         header.col(|ui| {
@@ -434,7 +501,10 @@ impl WalletsBalancesScreen {
                             } else {
                                 "Address"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::Address);
                             }
                         });
@@ -447,7 +517,10 @@ impl WalletsBalancesScreen {
                             } else {
                                 "Total Received (DASH)"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::Balance);
                             }
                         });
@@ -460,7 +533,10 @@ impl WalletsBalancesScreen {
                             } else {
                                 "UTXOs"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::UTXOs);
                             }
                         });
@@ -473,7 +549,10 @@ impl WalletsBalancesScreen {
                             } else {
                                 "Balance (DASH)"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::TotalReceived);
                             }
                         });
@@ -486,7 +565,10 @@ impl WalletsBalancesScreen {
                             } else {
                                 "Type"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::Type);
                             }
                         });
@@ -499,7 +581,10 @@ impl WalletsBalancesScreen {
                             } else {
                                 "Index"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::Index);
                             }
                         });
@@ -512,12 +597,21 @@ impl WalletsBalancesScreen {
                             } else {
                                 "Full Path"
                             };
-                            if ui.button(label).clicked() {
+                            let response = ui.button(label);
+                            self.address_column_widths
+                                .header_context_menu(&response, &self.app_context);
+                            if response.clicked() {
                                 self.toggle_sort(SortColumn::DerivationPath);
                             }
                         });
                     })
                     .body(|mut body| {
+                        // Only the columns between the auto sized address and the remainder are kept
+                        self.address_column_widths.track(
+                            &ctx,
+                            &self.app_context,
+                            &body.widths()[1..6],
+                        );
                         for data in &address_data {
                             body.row(25.0, |mut row| {
                                 row.col(|ui| {
@@ -598,33 +692,43 @@ impl WalletsBalancesScreen {
                         egui::ScrollArea::both()
                             .id_salt("asset_locks_table")
                             .show(ui, |ui| {
+                                let ctx = ui.ctx().clone();
+                                let id_salt = self.asset_lock_column_widths.id_salt();
+                                let widths = self.asset_lock_column_widths.initial_widths(&[200.0, 100.0, 100.0, 100.0, 100.0]);
                                 TableBuilder::new(ui)
+                        .id_salt(id_salt)
                         .striped(false)
                         .resizable(true)
                         .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                        .column(Column::initial(200.0)) // Transaction ID
-                        .column(Column::initial(100.0)) // Address
-                        .column(Column::initial(100.0)) // Amount (Duffs)
-                        .column(Column::initial(100.0)) // InstantLock status
-                        .column(Column::initial(100.0)) // Usable status
+                        .column(Column::initial(widths[0])) // Transaction ID
+                        .column(Column::initial(widths[1])) // Address
+                        .column(Column::initial(widths[2])) // Amount (Duffs)
+                        .column(Column::initial(widths[3])) // InstantLock status
+                        .column(Column::initial(widths[4])) // Usable status
                         .header(30.0, |mut header| {
                             header.col(|ui| {
-                                ui.label("Transaction ID");
+                                let response = ui.label("Transaction ID");
+                                self.asset_lock_column_widths.header_context_menu(&response, &self.app_context);
                             });
                             header.col(|ui| {
-                                ui.label("Address");
+                                let response = ui.label("Address");
+                                self.asset_lock_column_widths.header_context_menu(&response, &self.app_context);
                             });
                             header.col(|ui| {
-                                ui.label("Amount (Duffs)");
+                                let response = ui.label("Amount (Duffs)");
+                                self.asset_lock_column_widths.header_context_menu(&response, &self.app_context);
                             });
                             header.col(|ui| {
-                                ui.label("InstantLock");
+                                let response = ui.label("InstantLock");
+                                self.asset_lock_column_widths.header_context_menu(&response, &self.app_context);
                             });
                             header.col(|ui| {
-                                ui.label("Usable");
+                                let response = ui.label("Usable");
+                                self.asset_lock_column_widths.header_context_menu(&response, &self.app_context);
                             });
                         })
                         .body(|mut body| {
+                            self.asset_lock_column_widths.track(&ctx, &self.app_context, body.widths());
                             for (tx, address, amount, islock, proof) in &wallet.unused_asset_locks {
                                 body.row(25.0, |mut row| {
                                     row.col(|ui| {
@@ -0,0 +1,361 @@
+//! Structural validation for user-pasted data contract and document schema JSON.
+//!
+//! These checks run before handing the JSON to the SDK so that the user gets
+//! precise, field-level feedback (path + reason) instead of a single opaque
+//! parse error.
+
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// A single validation problem found in a JSON document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonValidationError {
+    /// JSONPath-like location of the problem, e.g. `$.documentSchemas.note.properties`
+    pub path: String,
+    /// Human-readable explanation of what is wrong
+    pub reason: String,
+}
+
+impl JsonValidationError {
+    pub fn new(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error that applies to the document as a whole.
+    pub fn root(reason: impl Into<String>) -> Self {
+        Self::new("$", reason)
+    }
+}
+
+impl fmt::Display for JsonValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.reason)
+    }
+}
+
+/// Parses raw JSON text, reporting syntax errors with their line and column.
+pub fn parse_json(input: &str) -> Result<Value, JsonValidationError> {
+    serde_json::from_str(input).map_err(|e| {
+        JsonValidationError::new(
+            format!("line {}, column {}", e.line(), e.column()),
+            format!("Invalid JSON: {}", e),
+        )
+    })
+}
+
+/// Validates the overall structure of a data contract JSON document.
+///
+/// Returns every problem found; an empty vector means the structure looks valid
+/// and the JSON can be handed to the SDK for full validation.
+pub fn validate_data_contract_json(value: &Value) -> Vec<JsonValidationError> {
+    let mut errors = Vec::new();
+
+    let Some(root) = value.as_object() else {
+        errors.push(JsonValidationError::root(
+            "Data contract must be a JSON object",
+        ));
+        return errors;
+    };
+
+    for field in ["id", "ownerId"] {
+        match root.get(field) {
+            None => errors.push(JsonValidationError::new(
+                format!("$.{}", field),
+                "Missing required field",
+            )),
+            Some(Value::String(_)) => {}
+            Some(_) => errors.push(JsonValidationError::new(
+                format!("$.{}", field),
+                "Must be a base58 encoded identifier string",
+            )),
+        }
+    }
+
+    match root.get("documentSchemas") {
+        None => {}
+        Some(Value::Object(schemas)) => {
+            validate_document_schemas_map("$.documentSchemas", schemas, &mut errors)
+        }
+        Some(_) => errors.push(JsonValidationError::new(
+            "$.documentSchemas",
+            "Must be an object mapping document type names to schemas",
+        )),
+    }
+
+    if root.get("tokens").is_some_and(|tokens| !tokens.is_object()) {
+        errors.push(JsonValidationError::new(
+            "$.tokens",
+            "Must be an object mapping token positions to token configurations",
+        ));
+    }
+
+    errors
+}
+
+/// Validates a JSON object mapping document type names to document schemas,
+/// as pasted in the token creator's "Document Schemas" section.
+pub fn validate_document_schemas_json(value: &Value) -> Vec<JsonValidationError> {
+    let mut errors = Vec::new();
+    match value.as_object() {
+        Some(schemas) => validate_document_schemas_map("$", schemas, &mut errors),
+        None => errors.push(JsonValidationError::root(
+            "Document schemas must be a JSON object",
+        )),
+    }
+    errors
+}
+
+fn validate_document_schemas_map(
+    path: &str,
+    schemas: &Map<String, Value>,
+    errors: &mut Vec<JsonValidationError>,
+) {
+    for (name, schema) in schemas {
+        let schema_path = format!("{}.{}", path, name);
+        if name.is_empty() || name.len() > 64 {
+            errors.push(JsonValidationError::new(
+                &schema_path,
+                "Document type name must be between 1 and 64 characters",
+            ));
+        }
+        validate_document_schema(&schema_path, schema, errors);
+    }
+}
+
+fn validate_document_schema(path: &str, schema: &Value, errors: &mut Vec<JsonValidationError>) {
+    let Some(schema) = schema.as_object() else {
+        errors.push(JsonValidationError::new(
+            path,
+            "Document schema must be an object",
+        ));
+        return;
+    };
+
+    match schema.get("type") {
+        Some(Value::String(t)) if t == "object" => {}
+        Some(_) => errors.push(JsonValidationError::new(
+            format!("{}.type", path),
+            "Document schema type must be \"object\"",
+        )),
+        None => errors.push(JsonValidationError::new(
+            format!("{}.type", path),
+            "Missing required field",
+        )),
+    }
+
+    match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) => {}
+        Some(_) => errors.push(JsonValidationError::new(
+            format!("{}.additionalProperties", path),
+            "Must be false",
+        )),
+        None => errors.push(JsonValidationError::new(
+            format!("{}.additionalProperties", path),
+            "Missing required field (must be false)",
+        )),
+    }
+
+    let properties = match schema.get("properties") {
+        Some(Value::Object(properties)) => {
+            if properties.is_empty() {
+                errors.push(JsonValidationError::new(
+                    format!("{}.properties", path),
+                    "Must define at least one property",
+                ));
+            }
+            for (property_name, property) in properties {
+                let property_path = format!("{}.properties.{}", path, property_name);
+                match property.as_object() {
+                    Some(property) => {
+                        if !property.contains_key("type") && !property.contains_key("$ref") {
+                            errors.push(JsonValidationError::new(
+                                format!("{}.type", property_path),
+                                "Missing required field",
+                            ));
+                        }
+                    }
+                    None => errors.push(JsonValidationError::new(
+                        property_path,
+                        "Property definition must be an object",
+                    )),
+                }
+            }
+            Some(properties)
+        }
+        Some(_) => {
+            errors.push(JsonValidationError::new(
+                format!("{}.properties", path),
+                "Must be an object",
+            ));
+            None
+        }
+        None => {
+            errors.push(JsonValidationError::new(
+                format!("{}.properties", path),
+                "Missing required field",
+            ));
+            None
+        }
+    };
+
+    match schema.get("required") {
+        None => {}
+        Some(Value::Array(required)) => {
+            for (i, entry) in required.iter().enumerate() {
+                let entry_path = format!("{}.required[{}]", path, i);
+                match entry.as_str() {
+                    // System fields such as $createdAt are always allowed
+                    Some(name) if name.starts_with('$') => {}
+                    Some(name) => {
+                        if properties.is_some_and(|p| !p.contains_key(name)) {
+                            errors.push(JsonValidationError::new(
+                                entry_path,
+                                format!(
+                                    "Required property '{}' is not defined in properties",
+                                    name
+                                ),
+                            ));
+                        }
+                    }
+                    None => errors.push(JsonValidationError::new(
+                        entry_path,
+                        "Must be a property name string",
+                    )),
+                }
+            }
+        }
+        Some(_) => errors.push(JsonValidationError::new(
+            format!("{}.required", path),
+            "Must be an array of property names",
+        )),
+    }
+
+    match schema.get("indices") {
+        None => {}
+        Some(Value::Array(indices)) => {
+            for (i, index) in indices.iter().enumerate() {
+                validate_index(&format!("{}.indices[{}]", path, i), index, errors);
+            }
+        }
+        Some(_) => errors.push(JsonValidationError::new(
+            format!("{}.indices", path),
+            "Must be an array",
+        )),
+    }
+}
+
+fn validate_index(path: &str, index: &Value, errors: &mut Vec<JsonValidationError>) {
+    let Some(index) = index.as_object() else {
+        errors.push(JsonValidationError::new(path, "Index must be an object"));
+        return;
+    };
+
+    match index.get("name") {
+        Some(Value::String(name)) if !name.is_empty() => {}
+        Some(_) => errors.push(JsonValidationError::new(
+            format!("{}.name", path),
+            "Must be a non-empty string",
+        )),
+        None => errors.push(JsonValidationError::new(
+            format!("{}.name", path),
+            "Missing required field",
+        )),
+    }
+
+    match index.get("properties") {
+        Some(Value::Array(properties)) if !properties.is_empty() => {
+            for (i, property) in properties.iter().enumerate() {
+                // Each entry is a single-key object like {"ownerId": "asc"}
+                let valid = property
+                    .as_object()
+                    .is_some_and(|p| p.len() == 1 && p.values().all(|v| v == "asc"));
+                if !valid {
+                    errors.push(JsonValidationError::new(
+                        format!("{}.properties[{}]", path, i),
+                        "Must be an object with a single property set to \"asc\"",
+                    ));
+                }
+            }
+        }
+        Some(_) => errors.push(JsonValidationError::new(
+            format!("{}.properties", path),
+            "Must be a non-empty array",
+        )),
+        None => errors.push(JsonValidationError::new(
+            format!("{}.properties", path),
+            "Missing required field",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn valid_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string", "position": 0 }
+            },
+            "required": ["message", "$createdAt"],
+            "indices": [
+                { "name": "ownerId", "properties": [{ "$ownerId": "asc" }] }
+            ],
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn test_valid_document_schemas() {
+        let schemas = json!({ "note": valid_schema() });
+        assert!(validate_document_schemas_json(&schemas).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_json_reports_location() {
+        let err = parse_json("{\n  \"a\": }").unwrap_err();
+        assert_eq!(err.path, "line 2, column 8");
+    }
+
+    #[test]
+    fn test_field_level_errors() {
+        let mut schema = valid_schema();
+        schema["additionalProperties"] = json!(true);
+        schema["required"] = json!(["missing"]);
+        schema["indices"][0]["properties"] = json!([]);
+        let contract = json!({
+            "id": "GWRSAVFMjXx8HpQFaNJMqBV7MBgMK4br5UESsB4S31Ec",
+            "ownerId": "GWRSAVFMjXx8HpQFaNJMqBV7MBgMK4br5UESsB4S31Ec",
+            "documentSchemas": { "note": schema }
+        });
+
+        let paths: Vec<String> = validate_data_contract_json(&contract)
+            .into_iter()
+            .map(|e| e.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "$.documentSchemas.note.additionalProperties",
+                "$.documentSchemas.note.required[0]",
+                "$.documentSchemas.note.indices[0].properties",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contract_must_be_object() {
+        let errors = validate_data_contract_json(&json!([1, 2, 3]));
+        assert_eq!(
+            errors,
+            vec![JsonValidationError::root(
+                "Data contract must be a JSON object"
+            )]
+        );
+    }
+}
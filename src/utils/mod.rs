@@ -1,4 +1,5 @@
 pub mod egui_mpsc;
+pub mod json_validation;
 pub mod parsers;
 pub mod path;
 pub mod tasks;